@@ -34,19 +34,18 @@ fn clonepy_impl_enum(ast: &syn::DeriveInput, en: &syn::DataEnum) -> TokenStream2
         variants.push(quote!(#name(x) => #name(x.clone_py(py))));
     }
 
-    // Build clone implementation
+    // Build clone implementation, using the GIL token that was given
+    // instead of acquiring the GIL again.
     let name = &ast.ident;
     let expanded = quote! {
         #[automatically_derived]
         #[allow(unused)]
         impl ClonePy for #name {
             fn clone_py(&self, py: Python) -> Self {
-                Python::with_gil(|py| {
-                    use self::#name::*;
-                    match self {
-                        #(#variants,)*
-                    }
-                })
+                use self::#name::*;
+                match self {
+                    #(#variants,)*
+                }
             }
         }
     };
@@ -54,13 +53,34 @@ fn clonepy_impl_enum(ast: &syn::DeriveInput, en: &syn::DataEnum) -> TokenStream2
     expanded
 }
 
-fn clonepy_impl_struct(ast: &syn::DeriveInput, _en: &syn::DataStruct) -> TokenStream2 {
+fn clonepy_impl_struct(ast: &syn::DeriveInput, st: &syn::DataStruct) -> TokenStream2 {
+    // Build clone_py for each field, so that `Py<T>` fields are cloned
+    // with `clone_ref` instead of requiring the struct to be `Clone`.
+    let body = match &st.fields {
+        syn::Fields::Named(n) => {
+            let fields = n.named.iter().map(|field| {
+                let name = field.ident.as_ref().unwrap();
+                quote!(#name: self.#name.clone_py(py))
+            });
+            quote!(Self { #(#fields,)* })
+        }
+        syn::Fields::Unnamed(u) => {
+            let fields = (0..u.unnamed.len()).map(|i| {
+                let index = syn::Index::from(i);
+                quote!(self.#index.clone_py(py))
+            });
+            quote!(Self( #(#fields,)* ))
+        }
+        syn::Fields::Unit => quote!(Self),
+    };
+
     let name = &ast.ident;
     let expanded = quote! {
         #[automatically_derived]
+        #[allow(unused)]
         impl ClonePy for #name {
-            fn clone_py(&self, _py: Python) -> Self {
-                self.clone()
+            fn clone_py(&self, py: Python) -> Self {
+                #body
             }
         }
     };
@@ -70,9 +90,52 @@ fn clonepy_impl_struct(ast: &syn::DeriveInput, _en: &syn::DataStruct) -> TokenSt
 
 // ---
 
+#[proc_macro_derive(DisplayPy)]
+pub fn displaypy_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as syn::DeriveInput);
+    match &ast.data {
+        syn::Data::Enum(e) => TokenStream::from(displaypy_impl_enum(&ast, &e)),
+        _ => panic!("#[derive(DisplayPy)] only supports enums"),
+    }
+}
+
+fn displaypy_impl_enum(ast: &syn::DeriveInput, en: &syn::DataEnum) -> TokenStream2 {
+    let mut variants = Vec::new();
+
+    // Build fmt_py for each variant
+    for variant in &en.variants {
+        let name = &variant.ident;
+        variants.push(quote!(#name(x) => x.fmt_py(py, f)));
+    }
+
+    // Build fmt_py implementation, and a Display implementation using it
+    let name = &ast.ident;
+    let expanded = quote! {
+        #[automatically_derived]
+        #[allow(unused)]
+        impl DisplayPy for #name {
+            fn fmt_py(&self, py: Python, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                use self::#name::*;
+                match self {
+                    #(#variants,)*
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl std::fmt::Display for #name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                Python::with_gil(|py| self.fmt_py(py, f))
+            }
+        }
+    };
+
+    expanded
+}
+
 // ---
 
-#[proc_macro_derive(EqPy)]
+#[proc_macro_derive(EqPy, attributes(eqpy))]
 pub fn eqpy_derive(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as syn::DeriveInput);
     match &ast.data {
@@ -89,10 +152,14 @@ fn eqpy_impl_enum(ast: &syn::DeriveInput, en: &syn::DataEnum) -> TokenStream2 {
     for variant in &en.variants {
         let name = &variant.ident;
         variants.push(quote!{
-            ( #name(l), #name(r) ) => (*l.as_ref(py).borrow()).eq_py(&*r.as_ref(py).borrow(), py)
+            ( #name(l), #name(r) ) => l.eq_py(r, py)
         });
     }
 
+    // Compare the data stored outside of the variants with the function
+    // given in `#[eqpy(with = "...")]`, if any
+    let with = eqpy_with(ast).map(|path| quote!(&& #path(self, other, py)));
+
     // Build eq implementation
     let name = &ast.ident;
     let expanded = quote! {
@@ -101,10 +168,11 @@ fn eqpy_impl_enum(ast: &syn::DeriveInput, en: &syn::DataEnum) -> TokenStream2 {
         impl EqPy for #name {
             fn eq_py(&self, other: &Self, py: Python) -> bool {
                 use self::#name::*;
-                match (self, other) {
+                let eq = match (self, other) {
                     #(#variants,)*
                     _ => false
-                }
+                };
+                eq #with
             }
         }
     };
@@ -112,6 +180,34 @@ fn eqpy_impl_enum(ast: &syn::DeriveInput, en: &syn::DataEnum) -> TokenStream2 {
     expanded
 }
 
+/// Get the path given in an `#[eqpy(with = "...")]` attribute, if any.
+fn eqpy_with(ast: &syn::DeriveInput) -> Option<syn::ExprPath> {
+    let attr = ast.attrs.iter().find(|attr| attr.path.is_ident("eqpy"))?;
+    match attr.parse_meta().expect("could not parse #[eqpy] argument") {
+        syn::Meta::List(l) => l.nested.iter().find_map(|arg| match arg {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(nv)) if nv.path.is_ident("with") => {
+                match &nv.lit {
+                    syn::Lit::Str(s) => Some(s.parse().expect("#[eqpy(with)] must be a path")),
+                    _ => panic!("#[eqpy(with)] must be a string literal"),
+                }
+            }
+            _ => None,
+        }),
+        _ => panic!("#[eqpy] argument must be a list"),
+    }
+}
+
+/// Check whether a field is annotated with `#[eqpy(ignore)]`.
+fn eqpy_ignored(field: &syn::Field) -> bool {
+    field.attrs.iter().any(|attr| {
+        attr.path.is_ident("eqpy")
+            && attr
+                .parse_args::<syn::Ident>()
+                .map(|arg| arg == "ignore")
+                .unwrap_or(false)
+    })
+}
+
 fn eqpy_impl_struct(ast: &syn::DeriveInput, en: &syn::DataStruct) -> TokenStream2 {
     let mut expression: syn::Expr = parse_quote!(true);
 
@@ -121,7 +217,7 @@ fn eqpy_impl_struct(ast: &syn::DeriveInput, en: &syn::DataStruct) -> TokenStream
     // };
 
     if let syn::Fields::Named(n) = &en.fields {
-        for field in n.named.iter() {
+        for field in n.named.iter().filter(|field| !eqpy_ignored(field)) {
             let name = field.ident.as_ref().unwrap();
             let condition = parse_quote!(self.#name.eq_py(&other.#name, py));
             expression = syn::Expr::from(syn::ExprBinary {
@@ -151,6 +247,116 @@ fn eqpy_impl_struct(ast: &syn::DeriveInput, en: &syn::DataStruct) -> TokenStream
 
 // ---
 
+#[proc_macro_derive(RichCmpPy)]
+pub fn richcmppy_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as syn::DeriveInput);
+    match &ast.data {
+        syn::Data::Struct(_) => TokenStream::from(richcmppy_impl_struct(&ast)),
+        _ => panic!("#[derive(RichCmpPy)] only supports structs"),
+    }
+}
+
+fn richcmppy_impl_struct(ast: &syn::DeriveInput) -> TokenStream2 {
+    // Build richcmp_py on top of `EqPy`, so that equality uses exactly the
+    // fields compared by `eq_py`, and ordering is left to Python.
+    let name = &ast.ident;
+    let expanded = quote! {
+        #[automatically_derived]
+        impl RichCmpPy for #name {
+            fn richcmp_py(
+                &self,
+                other: &pyo3::types::PyAny,
+                op: pyo3::class::basic::CompareOp,
+            ) -> pyo3::PyResult<pyo3::PyObject> {
+                use pyo3::class::basic::CompareOp;
+                use pyo3::ToPyObject;
+
+                let py = other.py();
+                let eq = || match other.extract::<pyo3::PyRef<Self>>() {
+                    Ok(other) => self.eq_py(&*other, py),
+                    Err(_) => false,
+                };
+                match op {
+                    CompareOp::Eq => Ok(eq().to_object(py)),
+                    CompareOp::Ne => Ok((!eq()).to_object(py)),
+                    _ => Ok(py.NotImplemented()),
+                }
+            }
+        }
+    };
+
+    expanded
+}
+
+// ---
+
+#[proc_macro_derive(HashPy, attributes(eqpy))]
+pub fn hashpy_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as syn::DeriveInput);
+    match &ast.data {
+        syn::Data::Enum(e) => TokenStream::from(hashpy_impl_enum(&ast, &e)),
+        syn::Data::Struct(s) => TokenStream::from(hashpy_impl_struct(&ast, &s)),
+        _ => panic!("#[derive(HashPy)] only supports enums or structs"),
+    }
+}
+
+fn hashpy_impl_enum(ast: &syn::DeriveInput, en: &syn::DataEnum) -> TokenStream2 {
+    let mut variants = Vec::new();
+
+    // Build hash_py for each variant
+    for variant in &en.variants {
+        let name = &variant.ident;
+        variants.push(quote!(#name(x) => x.hash_py(state, py)));
+    }
+
+    // Build hash implementation, hashing the variant first since values
+    // of different variants are never equal with `eq_py`.
+    let name = &ast.ident;
+    let expanded = quote! {
+        #[automatically_derived]
+        #[allow(unused)]
+        impl HashPy for #name {
+            fn hash_py<H: std::hash::Hasher>(&self, state: &mut H, py: Python) {
+                use self::#name::*;
+                std::hash::Hash::hash(&std::mem::discriminant(self), state);
+                match self {
+                    #(#variants,)*
+                }
+            }
+        }
+    };
+
+    expanded
+}
+
+fn hashpy_impl_struct(ast: &syn::DeriveInput, st: &syn::DataStruct) -> TokenStream2 {
+    // Hash the same fields as `eq_py`, skipping those with `#[eqpy(ignore)]`.
+    let fields = match &st.fields {
+        syn::Fields::Named(n) => n
+            .named
+            .iter()
+            .filter(|field| !eqpy_ignored(field))
+            .map(|field| field.ident.as_ref().unwrap())
+            .collect::<Vec<_>>(),
+        _ => unreachable!(),
+    };
+
+    let name = &ast.ident;
+    let expanded = quote! {
+        #[automatically_derived]
+        #[allow(unused)]
+        impl HashPy for #name {
+            fn hash_py<H: std::hash::Hasher>(&self, state: &mut H, py: Python) {
+                #(self.#fields.hash_py(state, py);)*
+            }
+        }
+    };
+
+    expanded
+}
+
+// ---
+
 #[proc_macro_derive(PyWrapper, attributes(wraps))]
 pub fn pywrapper_derive(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as syn::DeriveInput);
@@ -162,6 +368,8 @@ pub fn pywrapper_derive(input: TokenStream) -> TokenStream {
         output.extend(frompyobject_impl_enum(&ast, &e));
         output.extend(aspyptr_impl_enum(&ast, &e));
         output.extend(intopy_impl_enum(&ast, &e));
+        output.extend(tryasobject_impl_enum(&ast, &e));
+        output.extend(assignpy_impl_enum(&ast, &e));
         // output.extend(pyobjectprotocol_impl_enum(&ast, &e))
     } else {
         panic!("only supports enums");
@@ -277,7 +485,7 @@ fn frompyobject_impl_enum(ast: &syn::DeriveInput, en: &syn::DataEnum) -> TokenSt
             path.segments.iter().next().unwrap().ident.span(),
         );
 
-        variants.push(quote!(#lit => ob.extract::<pyo3::Py<#path>>().map(#wrapped::#name)));
+        variants.push(quote!(#lit => ob.extract::<pyo3::Py<#path>>().map(|x| #wrapped::#name(x.into()))));
     }
 
     let meta = ast
@@ -288,19 +496,51 @@ fn frompyobject_impl_enum(ast: &syn::DeriveInput, en: &syn::DataEnum) -> TokenSt
         .parse_meta()
         .expect("could not parse #[wraps] argument");
 
-    let base = match meta {
-        syn::Meta::List(l) => match l.nested.iter().next().unwrap() {
-            syn::NestedMeta::Meta(syn::Meta::Path(p)) => p.clone(),
-            _ => panic!("#[wraps] argument must be a class ident"),
-        },
+    let (base, parse) = match meta {
+        syn::Meta::List(l) => {
+            let base = match l.nested.iter().next().unwrap() {
+                syn::NestedMeta::Meta(syn::Meta::Path(p)) => p.clone(),
+                _ => panic!("#[wraps] argument must be a class ident"),
+            };
+            // `#[wraps(Base, parse)]` converts user-defined subclasses
+            // through `ParseClause`, other subclasses are rejected
+            let parse = l.nested.iter().skip(1).any(|arg| match arg {
+                syn::NestedMeta::Meta(syn::Meta::Path(p)) => p.is_ident("parse"),
+                _ => panic!("#[wraps] options must be idents"),
+            });
+            (base, parse)
+        }
         _ => panic!("#[wraps] argument must be a class ident"),
     };
 
+    // Build the conversion of unknown subclasses
+    let fallback = if parse {
+        let err_sub = syn::LitStr::new(
+            &format!("subclasses of {} must implement raw_tag and raw_value", quote!(#base)),
+            base.span(),
+        );
+        quote! {
+            // user-defined subclasses are converted through
+            // their serialized representation
+            _ => {
+                let err = |_| pyo3::exceptions::PyTypeError::new_err(#err_sub);
+                let tag = ob.call_method0("raw_tag").and_then(|t| t.str()).map_err(err)?;
+                let value = ob.call_method0("raw_value").and_then(|v| v.str()).map_err(err)?;
+                let text = format!("{}: {}", tag, value);
+                <#wrapped as crate::utils::ParseClause>::parse_clause(ob.py(), &text)
+            }
+        }
+    } else {
+        let err_sub = syn::LitStr::new(
+            &format!("subclassing {} is not supported", quote!(#base)),
+            base.span(),
+        );
+        quote! {
+            _ => Err(pyo3::exceptions::PyTypeError::new_err(#err_sub))
+        }
+    };
+
     // Build FromPyObject implementation
-    let err_sub = syn::LitStr::new(
-        &format!("subclassing {} is not supported", quote!(#base)),
-        base.span(),
-    );
     let err_ty = syn::LitStr::new(
         &format!("expected {} instance, {{}} found", quote!(#base)),
         base.span(),
@@ -320,7 +560,7 @@ fn frompyobject_impl_enum(ast: &syn::DeriveInput, en: &syn::DataEnum) -> TokenSt
                 if ob.is_instance_of::<#base>() {
                     match ty.as_ref() {
                         #(#variants,)*
-                        _ => Err(pyo3::exceptions::PyTypeError::new_err(#err_sub))
+                        #fallback
                     }
                 } else {
                     Err(pyo3::exceptions::PyTypeError::new_err(format!(
@@ -342,7 +582,7 @@ fn intopy_impl_enum(ast: &syn::DeriveInput, en: &syn::DataEnum) -> TokenStream2
     for variant in &en.variants {
         let name = &variant.ident;
         variants.push(quote!(
-            #name(x) => (&x.as_ref(py).borrow()).clone_py(py).into_py(py)
+            #name(x) => (&x.borrow(py)).clone_py(py).into_py(py)
         ));
     }
 
@@ -364,8 +604,358 @@ fn intopy_impl_enum(ast: &syn::DeriveInput, en: &syn::DataEnum) -> TokenStream2
     expanded
 }
 
+fn tryasobject_impl_enum(ast: &syn::DeriveInput, en: &syn::DataEnum) -> TokenStream2 {
+    let mut variants = Vec::new();
+
+    // Build try_as_object for each variant
+    for variant in &en.variants {
+        let name = &variant.ident;
+        variants.push(quote!(#name(x) => x.try_as_object(py)));
+    }
+
+    // Build TryAsObject implementation
+    let name = &ast.ident;
+    let expanded = quote! {
+        #[automatically_derived]
+        impl crate::utils::TryAsObject for #name {
+            fn try_as_object(&self, py: Python) -> Option<pyo3::PyObject> {
+                use crate::utils::TryAsObject;
+                use self::#name::*;
+                match self {
+                    #(#variants,)*
+                }
+            }
+        }
+    };
+
+    expanded
+}
+
+fn assignpy_impl_enum(ast: &syn::DeriveInput, en: &syn::DataEnum) -> TokenStream2 {
+    let mut variants = Vec::new();
+
+    // Build assign_py for each pair of identical variants
+    for variant in &en.variants {
+        let name = &variant.ident;
+        variants.push(quote!((#name(x), #name(y)) => x.assign_py(y, py)));
+    }
+
+    // Build AssignPy implementation, rejecting different variants
+    let name = &ast.ident;
+    let expanded = quote! {
+        #[automatically_derived]
+        impl crate::utils::AssignPy for #name {
+            #[allow(unreachable_patterns)]
+            fn assign_py(&self, other: &Self, py: Python) -> bool {
+                use crate::utils::AssignPy;
+                use self::#name::*;
+                match (self, other) {
+                    #(#variants,)*
+                    _ => false,
+                }
+            }
+        }
+    };
+
+    expanded
+}
+
 // ---
 
+#[proc_macro_attribute]
+pub fn obo_clause(attr: TokenStream, input: TokenStream) -> TokenStream {
+    // extract proc-macro arguments
+    let meta = parse_macro_input!(attr as syn::AttributeArgs);
+    let tag: syn::LitStr = match meta
+        .iter()
+        .filter_map(|m| match m {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(nv)) => Some(nv),
+            _ => None,
+        })
+        .find(|nv| nv.path.get_ident() == Some(&syn::Ident::new("tag", Span::call_site())))
+        .expect("#[obo_clause] requires a `tag` argument")
+        .lit
+    {
+        syn::Lit::Str(ref s) => s.clone(),
+        _ => panic!("`tag` argument of #[obo_clause] must be a string"),
+    };
+
+    // add additional methods to the impl block
+    let ast = parse_macro_input!(input as syn::ItemImpl);
+    TokenStream::from(obo_clause_impl_methods(&tag, ast))
+}
+
+fn obo_clause_impl_methods(tag: &syn::LitStr, mut imp: syn::ItemImpl) -> TokenStream2 {
+    // only generate `raw_value` if the clause does not already define it,
+    // e.g. for clauses where the raw value omits the trailing xrefs
+    let has_raw_value = imp.items.iter().any(|item| match item {
+        syn::ImplItem::Method(m) => m.sig.ident == "raw_value",
+        _ => false,
+    });
+
+    imp.items.push(parse_quote! {
+        fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
+            pyo3::ToPyObject::to_object(pyo3::intern!(slf.py(), #tag), slf.py())
+        }
+    });
+    if !has_raw_value {
+        imp.items.push(parse_quote! {
+            fn raw_value(&self, py: Python) -> String {
+                let text = crate::utils::DisplayPy::display_py(self, py).to_string();
+                text[Self::TAG.len() + 1..].trim_start().to_string()
+            }
+        });
+    }
+
+    // expose the tag to the Rust code, e.g. for `impl_display_py!`
+    let self_ty = &imp.self_ty;
+    quote! {
+        impl #self_ty {
+            /// The tag of the clause in OBO syntax.
+            pub const TAG: &'static str = #tag;
+        }
+
+        #imp
+    }
+}
+
+#[proc_macro_attribute]
+pub fn pylist(attr: TokenStream, input: TokenStream) -> TokenStream {
+    // extract proc-macro arguments
+    let meta = parse_macro_input!(attr as syn::AttributeArgs);
+    let arg = |name: &str| -> syn::LitStr {
+        match meta
+            .iter()
+            .filter_map(|m| match m {
+                syn::NestedMeta::Meta(syn::Meta::NameValue(nv)) => Some(nv),
+                _ => None,
+            })
+            .find(|nv| nv.path.get_ident() == Some(&syn::Ident::new(name, Span::call_site())))
+            .unwrap_or_else(|| panic!("#[pylist] requires a `{}` argument", name))
+            .lit
+        {
+            syn::Lit::Str(ref s) => s.clone(),
+            _ => panic!("`{}` argument of #[pylist] must be a string", name),
+        }
+    };
+    let ty: syn::Type = arg("type")
+        .parse()
+        .expect("`type` argument of #[pylist] is not a valid type");
+    let module = arg("module");
+
+    // generate the list class from the unit struct declaration
+    let st = parse_macro_input!(input as syn::ItemStruct);
+    TokenStream::from(pylist_impl(&ty, &module, st))
+}
+
+/// Get the name of the Python class of the elements of a list.
+///
+/// `Py<T>` elements are reported with the name of `T`, since they are
+/// accessed as `T` instances from Python.
+fn pylist_element_name(ty: &syn::Type) -> String {
+    let segment = match ty {
+        syn::Type::Path(p) => p.path.segments.last().expect("empty type path"),
+        _ => panic!("`type` argument of #[pylist] must be a type path"),
+    };
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) if segment.ident == "Py" => {
+            match args.args.first() {
+                Some(syn::GenericArgument::Type(inner)) => pylist_element_name(inner),
+                _ => panic!("`Py` element type of #[pylist] requires a type argument"),
+            }
+        }
+        _ => segment.ident.to_string(),
+    }
+}
+
+fn pylist_impl(ty: &syn::Type, module: &syn::LitStr, st: syn::ItemStruct) -> TokenStream2 {
+    let attrs = &st.attrs;
+    let vis = &st.vis;
+    let name = &st.ident;
+    let field = syn::Ident::new("items", Span::call_site());
+    let empty_repr = format!("{}()", name);
+    let repr = format!("{}({{!r}})", name);
+    let contains_err = format!(
+        "'in <{}>' requires {} as left operand, not {{}}",
+        name,
+        pylist_element_name(ty)
+    );
+
+    let imp: syn::ItemImpl = parse_quote! {
+        #[pymethods]
+        impl #name {
+            #[new]
+            #[pyo3(signature = (items = None))]
+            fn __init__(py: Python, items: Option<&PyAny>) -> PyResult<Self> {
+                match items {
+                    Some(items) => Self::collect(py, items),
+                    None => Ok(Self::default()),
+                }
+            }
+
+            fn __repr__(&self, py: Python) -> PyResult<PyObject> {
+                if self.#field.is_empty() {
+                    Ok(pyo3::ToPyObject::to_object(#empty_repr, py))
+                } else {
+                    let items = pyo3::ToPyObject::to_object(&self.#field, py);
+                    let fmt = pyo3::types::PyString::new(py, #repr);
+                    fmt.call_method1("format", (items,)).map(|r| r.into())
+                }
+            }
+
+            fn __str__(&self) -> String {
+                self.to_string()
+            }
+
+            fn __len__(&self) -> usize {
+                self.#field.len()
+            }
+
+            fn __sizeof__(&self, py: Python) -> PyResult<usize> {
+                Ok(std::mem::size_of::<pyo3::PyCell<Self>>()
+                    + crate::utils::sizeof_vec(&self.#field, py)?)
+            }
+
+            fn __contains__(&self, item: &PyAny) -> PyResult<bool> {
+                let py = item.py();
+                match <#ty as pyo3::prelude::FromPyObject>::extract(item) {
+                    Ok(x) => Ok(self
+                        .#field
+                        .iter()
+                        .any(|y| crate::utils::EqPy::eq_py(y, &x, py))),
+                    Err(_) => {
+                        let ty = item.get_type().name()?;
+                        let msg = format!(#contains_err, ty);
+                        Err(pyo3::exceptions::PyTypeError::new_err(msg))
+                    }
+                }
+            }
+
+            fn __richcmp__(&self, other: &PyAny, op: pyo3::class::basic::CompareOp) -> PyResult<PyObject> {
+                crate::utils::RichCmpPy::richcmp_py(self, other, op)
+            }
+
+            fn __deepcopy__(&self, py: Python, memo: &PyAny) -> PyResult<Self> {
+                let deepcopy = py.import(pyo3::intern!(py, "copy"))?.getattr(pyo3::intern!(py, "deepcopy"))?;
+                let items = self
+                    .#field
+                    .iter()
+                    .map(|x| deepcopy.call1((pyo3::ToPyObject::to_object(x, py), memo))?.extract())
+                    .collect::<PyResult<Vec<#ty>>>()?;
+                Ok(Self::new(items))
+            }
+        }
+    };
+    let methods = listlike_impl_methods(&field, ty, None, imp);
+
+    quote! {
+        #(#attrs)*
+        #[pyclass(module = #module)]
+        #[derive(Debug, Default)]
+        #vis struct #name {
+            #field: Vec<#ty>,
+        }
+
+        impl #name {
+            /// Create a new list from a vector of elements.
+            pub fn new(items: Vec<#ty>) -> Self {
+                Self { #field: items }
+            }
+
+            /// Create a new list from the elements of a Python iterable.
+            pub fn collect(py: Python, items: &PyAny) -> PyResult<Self> {
+                pyo3::types::PyIterator::from_object(py, items)?
+                    .map(|res| res.and_then(<#ty as pyo3::prelude::FromPyObject>::extract))
+                    .collect::<PyResult<Vec<#ty>>>()
+                    .map(Self::new)
+            }
+
+            /// Check whether the list is empty.
+            pub fn is_empty(&self) -> bool {
+                self.#field.is_empty()
+            }
+
+            /// Iterate over the elements of the list.
+            pub fn iter(&self) -> std::slice::Iter<'_, #ty> {
+                self.#field.iter()
+            }
+        }
+
+        #[automatically_derived]
+        impl crate::utils::ClonePy for #name {
+            fn clone_py(&self, py: Python) -> Self {
+                Self::new(crate::utils::ClonePy::clone_py(&self.#field, py))
+            }
+        }
+
+        #[automatically_derived]
+        impl crate::utils::EqPy for #name {
+            fn eq_py(&self, other: &Self, py: Python) -> bool {
+                crate::utils::EqPy::eq_py(&self.#field, &other.#field, py)
+            }
+        }
+
+        #[automatically_derived]
+        impl crate::utils::HashPy for #name {
+            fn hash_py<H: std::hash::Hasher>(&self, state: &mut H, py: Python) {
+                crate::utils::HashPy::hash_py(&self.#field, state, py)
+            }
+        }
+
+        #[automatically_derived]
+        impl crate::utils::RichCmpPy for #name {
+            fn richcmp_py(
+                &self,
+                other: &PyAny,
+                op: pyo3::class::basic::CompareOp,
+            ) -> PyResult<PyObject> {
+                use pyo3::class::basic::CompareOp;
+                let py = other.py();
+                let eq = || match other.extract::<pyo3::PyRef<Self>>() {
+                    Ok(other) => crate::utils::EqPy::eq_py(self, &*other, py),
+                    Err(_) => false,
+                };
+                match op {
+                    CompareOp::Eq => Ok(pyo3::ToPyObject::to_object(&eq(), py)),
+                    CompareOp::Ne => Ok(pyo3::ToPyObject::to_object(&!eq(), py)),
+                    _ => Ok(py.NotImplemented()),
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl crate::utils::DisplayPy for #name {
+            fn fmt_py(&self, py: Python, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("[")?;
+                for (i, item) in self.#field.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    crate::utils::DisplayPy::fmt_py(item, py, f)?;
+                }
+                f.write_str("]")
+            }
+        }
+
+        #[automatically_derived]
+        impl std::fmt::Display for #name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                Python::with_gil(|py| crate::utils::DisplayPy::fmt_py(self, py, f))
+            }
+        }
+
+        #[automatically_derived]
+        impl pyo3::ToPyObject for #name {
+            fn to_object(&self, py: Python) -> PyObject {
+                let copy = crate::utils::ClonePy::clone_py(self, py);
+                pyo3::IntoPy::into_py(copy, py)
+            }
+        }
+
+        #methods
+    }
+}
+
 #[proc_macro_attribute]
 pub fn listlike(attr: TokenStream, input: TokenStream) -> TokenStream {
     // extract proc-macro arguments
@@ -409,16 +999,125 @@ pub fn listlike(attr: TokenStream, input: TokenStream) -> TokenStream {
         panic!("`type` argument of #[pylist] must be a string");
     };
 
+    let frozen: Option<syn::Ident> = meta
+        .iter()
+        .filter_map(|m| match m {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(nv)) => Some(nv),
+            _ => None,
+        })
+        .find(|nv| nv.path.get_ident() == Some(&syn::Ident::new("frozen", Span::call_site())))
+        .map(|nv| match nv.lit {
+            syn::Lit::Str(ref s) => s
+                .parse()
+                .expect("`frozen` argument of #[pylist] is not a valid identifier"),
+            _ => panic!("`frozen` argument of #[pylist] must be a string"),
+        });
+
     // add additional methods to the impl block
     let ast = parse_macro_input!(input as syn::ItemImpl);
-    TokenStream::from(listlike_impl_methods(&field, &ty, ast))
+    TokenStream::from(listlike_impl_methods(&field, &ty, frozen.as_ref(), ast))
 }
 
 fn listlike_impl_methods(
     field: &syn::Ident,
     ty: &syn::Type,
+    frozen: Option<&syn::Ident>,
     mut imp: syn::ItemImpl,
 ) -> TokenStream2 {
+    // reject modifications of frozen containers, if they can be frozen
+    let self_ty = &imp.self_ty;
+    let check = |this: TokenStream2| match frozen {
+        Some(flag) => quote! {
+            crate::utils::check_mutable(#this.#flag, stringify!(#self_ty))?;
+        },
+        None => quote!(),
+    };
+    let check_self = check(quote!(self));
+    let check_slf = check(quote!(slf.try_borrow()?));
+
+    imp.items.push(parse_quote! {
+        fn __getitem__(&self, py: Python, index: &PyAny) -> PyResult<PyObject> {
+            if let Ok(slice) = index.downcast::<pyo3::types::PySlice>() {
+                let mut copy = self.clone_py(py);
+                copy.#field = crate::utils::get_slice(&self.#field, slice, py)?.into();
+                Py::new(py, copy).map(|x| x.to_object(py))
+            } else {
+                match crate::utils::normalize_index(index.extract()?, self.#field.len()) {
+                    Some(i) => Ok(self.#field[i].to_object(py)),
+                    None => Err(pyo3::exceptions::PyIndexError::new_err(
+                        "list index out of range",
+                    )),
+                }
+            }
+        }
+    });
+    imp.items.push(parse_quote! {
+        fn __setitem__(&mut self, index: &PyAny, object: &PyAny) -> PyResult<()> {
+            #check_self
+            if let Ok(slice) = index.downcast::<pyo3::types::PySlice>() {
+                let items = pyo3::types::PyIterator::from_object(index.py(), object)?
+                    .map(|res| res.and_then(<#ty as pyo3::prelude::FromPyObject>::extract))
+                    .collect::<PyResult<Vec<#ty>>>()?;
+                crate::utils::set_slice(&mut self.#field, slice, items)
+            } else {
+                let item = <#ty as pyo3::prelude::FromPyObject>::extract(object)?;
+                match crate::utils::normalize_index(index.extract()?, self.#field.len()) {
+                    Some(i) => {
+                        self.#field[i] = item;
+                        Ok(())
+                    }
+                    None => Err(pyo3::exceptions::PyIndexError::new_err(
+                        "list assignment index out of range",
+                    )),
+                }
+            }
+        }
+    });
+    imp.items.push(parse_quote! {
+        fn __delitem__(&mut self, index: &PyAny) -> PyResult<()> {
+            #check_self
+            if let Ok(slice) = index.downcast::<pyo3::types::PySlice>() {
+                crate::utils::del_slice(&mut self.#field, slice)
+            } else {
+                match crate::utils::normalize_index(index.extract()?, self.#field.len()) {
+                    Some(i) => {
+                        self.#field.remove(i);
+                        Ok(())
+                    }
+                    None => Err(pyo3::exceptions::PyIndexError::new_err(
+                        "list assignment index out of range",
+                    )),
+                }
+            }
+        }
+    });
+    imp.items.push(parse_quote! {
+        fn __add__(slf: &pyo3::PyCell<Self>, other: &PyAny) -> PyResult<PyObject> {
+            let py = other.py();
+            let items = match pyo3::types::PyIterator::from_object(py, other) {
+                Ok(iterator) => iterator
+                    .map(|res| res.and_then(<#ty as pyo3::prelude::FromPyObject>::extract))
+                    .collect::<PyResult<Vec<#ty>>>()?,
+                Err(_) => return Ok(py.NotImplemented()),
+            };
+            let mut copy = slf.try_borrow()?.clone_py(py);
+            copy.#field.extend(items);
+            Py::new(py, copy).map(|x| x.to_object(py))
+        }
+    });
+    imp.items.push(parse_quote! {
+        fn __iadd__(slf: &pyo3::PyCell<Self>, other: &PyAny) -> PyResult<()> {
+            Self::extend(slf, other)
+        }
+    });
+    imp.items.push(parse_quote! {
+        fn __reversed__(slf: &pyo3::PyCell<Self>) -> PyResult<PyObject> {
+            let py = slf.py();
+            let len = slf.borrow().#field.len();
+            let iter = crate::utils::ReverseIterator::new(slf.to_object(py), len);
+            Ok(Py::new(py, iter)?.to_object(py))
+        }
+    });
     imp.items.push(parse_quote! {
         /// Append object to the end of the list.
         ///
@@ -428,6 +1127,7 @@ fn listlike_impl_methods(
         ///         required type).
         #[pyo3(text_signature = "(self, object)")]
         fn append(&mut self, object: &PyAny) -> PyResult<()> {
+            #check_self
             let item = <#ty as pyo3::prelude::FromPyObject>::extract(object)?;
             self.#field.push(item);
             Ok(())
@@ -436,8 +1136,10 @@ fn listlike_impl_methods(
     imp.items.push(parse_quote! {
         /// Remove all items from list.
         #[pyo3(text_signature = "(self)")]
-        fn clear(&mut self) {
+        fn clear(&mut self) -> PyResult<()> {
+            #check_self
             self.#field.clear();
+            Ok(())
         }
     });
     imp.items.push(parse_quote! {
@@ -450,6 +1152,12 @@ fn listlike_impl_methods(
             })
         }
     });
+    imp.items.push(parse_quote! {
+        /// Return a shallow copy of the list.
+        fn __copy__(&self) -> PyResult<Py<Self>> {
+            self.copy()
+        }
+    });
     imp.items.push(parse_quote! {
         /// Return number of occurrences of value.
         ///
@@ -464,14 +1172,55 @@ fn listlike_impl_methods(
             Ok(self.#field.iter().filter(|&x| x.eq_py(&item, py)).count())
         }
     });
-    // |  extend($self, iterable, /)
-    // |      Extend list by appending elements from the iterable.
-    // |
-    // |  index(self, value, start=0, stop=9223372036854775807, /)
-    // |      Return first index of value.
-    // |
-    // |      Raises ValueError if the value is not present.
-    // |
+    imp.items.push(parse_quote! {
+        /// Extend list by appending elements from the iterable.
+        ///
+        /// Raises:
+        ///     TypeError: when any of the elements is not of the right
+        ///         type for this container (see type-level documentation
+        ///         for the required type). In that case, the list is
+        ///         left unchanged.
+        #[pyo3(text_signature = "(self, iterable)")]
+        fn extend(slf: &pyo3::PyCell<Self>, iterable: &PyAny) -> PyResult<()> {
+            #check_slf
+            let py = iterable.py();
+            let items = pyo3::types::PyIterator::from_object(py, iterable)?
+                .map(|res| res.and_then(<#ty as pyo3::prelude::FromPyObject>::extract))
+                .collect::<PyResult<Vec<#ty>>>()?;
+            slf.try_borrow_mut()?.#field.extend(items);
+            Ok(())
+        }
+    });
+    imp.items.push(parse_quote! {
+        /// Return first index of value.
+        ///
+        /// Raises:
+        ///     TypeError: when the object is not of the right type for
+        ///         this container (see type-level documentation for the
+        ///         required type).
+        ///     ValueError: when the value is not present.
+        #[pyo3(
+            text_signature = "(self, value, start=0, stop=9223372036854775807)",
+            signature = (value, start = 0, stop = isize::MAX)
+        )]
+        fn index(&self, value: &PyAny, start: isize, stop: isize) -> PyResult<usize> {
+            let py = value.py();
+            let item = <#ty as pyo3::prelude::FromPyObject>::extract(value)?;
+            // Wrap and clamp bounds like `list.index` does
+            let len = self.#field.len() as isize;
+            let start = if start < 0 { (start + len).max(0) } else { start.min(len) };
+            let stop = if stop < 0 { (stop + len).max(0) } else { stop.min(len) };
+            if start < stop {
+                let slice = &self.#field[start as usize..stop as usize];
+                if let Some(i) = slice.iter().position(|x| x.eq_py(&item, py)) {
+                    return Ok(start as usize + i);
+                }
+            }
+            Err(pyo3::exceptions::PyValueError::new_err(
+                "list.index(x): x not in list",
+            ))
+        }
+    });
     imp.items.push(parse_quote! {
         /// Insert `object` before `index`.
         ///
@@ -479,6 +1228,7 @@ fn listlike_impl_methods(
         /// `object` will be added at the end of the list.
         #[pyo3(text_signature = "(self, index, object)")]
         fn insert(&mut self, mut index: isize, object: &PyAny) -> PyResult<()> {
+            #check_self
             let item = <#ty as pyo3::prelude::FromPyObject>::extract(object)?;
             if index >= self.#field.len() as isize {
                 self.#field.push(item);
@@ -498,6 +1248,7 @@ fn listlike_impl_methods(
         ///     IndexError: when list is empty or index is out of range.
         #[pyo3(text_signature = "(self, index=-1)", signature=(index=-1))]
         fn pop(&mut self, mut index: isize) -> PyResult<#ty> {
+            #check_self
             // Wrap once to allow negative indexing
             if index < 0 {
                 index += self.#field.len() as isize;
@@ -510,19 +1261,103 @@ fn listlike_impl_methods(
             }
         }
     });
-    // |  remove(self, value, /)
-    // |      Remove first occurrence of value.
-    // |
-    // |      Raises ValueError if the value is not present.
+    imp.items.push(parse_quote! {
+        /// Remove first occurrence of value.
+        ///
+        /// Raises:
+        ///     TypeError: when the object is not of the right type for
+        ///         this container (see type-level documentation for the
+        ///         required type).
+        ///     ValueError: when the value is not present.
+        #[pyo3(text_signature = "(self, value)")]
+        fn remove(&mut self, value: &PyAny) -> PyResult<()> {
+            #check_self
+            let py = value.py();
+            let item = <#ty as pyo3::prelude::FromPyObject>::extract(value)?;
+            match self.#field.iter().position(|x| x.eq_py(&item, py)) {
+                Some(index) => {
+                    self.#field.remove(index);
+                    Ok(())
+                }
+                None => Err(pyo3::exceptions::PyValueError::new_err(
+                    "list.remove(x): x not in list",
+                )),
+            }
+        }
+    });
     imp.items.push(parse_quote! {
         /// Reverse *IN PLACE*.
         #[pyo3(text_signature = "(self)")]
-        fn reverse(&mut self) {
-            self.#field.reverse()
+        fn reverse(&mut self) -> PyResult<()> {
+            #check_self
+            self.#field.reverse();
+            Ok(())
+        }
+    });
+    imp.items.push(parse_quote! {
+        /// Stable sort *IN PLACE*.
+        ///
+        /// Without a ``key`` function, elements are ordered by their
+        /// serialized OBO string.
+        ///
+        /// Arguments:
+        ///     key (callable, optional): a function of one argument that is
+        ///         used to extract a comparison key from each element.
+        ///     reverse (bool): whether to sort in descending order.
+        #[pyo3(
+            text_signature = "(self, *, key=None, reverse=False)",
+            signature = (*, key = None, reverse = false)
+        )]
+        fn sort(slf: &pyo3::PyCell<Self>, key: Option<&PyAny>, reverse: bool) -> PyResult<()> {
+            #check_slf
+            let py = slf.py();
+            // Compute the comparison key of every element, without keeping
+            // the container borrowed while calling the key function
+            let objects = slf
+                .try_borrow()?
+                .#field
+                .iter()
+                .map(|x| x.to_object(py))
+                .collect::<Vec<PyObject>>();
+            let keys = objects
+                .iter()
+                .map(|object| match key {
+                    Some(f) => f.call1((object,)),
+                    None => object.as_ref(py).str().map(|s| s.as_ref()),
+                })
+                .collect::<PyResult<Vec<&PyAny>>>()?;
+            // Sort the element indices by key with the builtin `sorted`,
+            // so that keys are compared with the usual Python semantics
+            let keys = pyo3::types::PyList::new(py, keys);
+            let kwargs = pyo3::types::PyDict::new(py);
+            kwargs.set_item("key", keys.getattr("__getitem__")?)?;
+            kwargs.set_item("reverse", reverse)?;
+            let indices = (0..keys.len()).collect::<Vec<usize>>();
+            let order = py
+                .import("builtins")?
+                .call_method("sorted", (indices,), Some(kwargs))?
+                .extract::<Vec<usize>>()?;
+            // Reorder the elements, unless the key function changed them
+            let mut this = slf.try_borrow_mut()?;
+            let unchanged = this.#field.len() == objects.len()
+                && this
+                    .#field
+                    .iter()
+                    .zip(objects.iter())
+                    .all(|(x, object)| x.to_object(py).is(object));
+            if !unchanged {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "list modified during sort",
+                ));
+            }
+            let mut items = std::mem::take(&mut this.#field)
+                .into_iter()
+                .map(Some)
+                .collect::<Vec<_>>();
+            this.#field = order.into_iter().map(|i| items[i].take().unwrap()).collect();
+            Ok(())
         }
     });
-    // |  sort(self, /, *, key=None, reverse=False)
-    // |      Stable sort *IN PLACE*.
     quote!(#imp)
 }
 
@@ -0,0 +1,122 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// The comment lines attached to a frame and to its clauses.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FrameComments {
+    /// The comment lines preceding the frame.
+    pub frame: Vec<String>,
+    /// The comment lines preceding each clause, by clause index.
+    pub clauses: HashMap<usize, Vec<String>>,
+}
+
+/// The comment lines of an OBO document, attached to frames and clauses.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SourceComments {
+    /// The comment lines attached to the header frame.
+    pub header: FrameComments,
+    /// The comment lines attached to each entity frame, in document order.
+    pub entities: Vec<FrameComments>,
+    /// The comment lines following the last frame of the document.
+    pub footer: Vec<String>,
+}
+
+/// Extract the comment lines of an OBO document.
+///
+/// Comment lines are attached to the following clause, or to the following
+/// frame when they precede a frame header or an `id` clause. Comment lines
+/// at the end of the document are attached to the document footer. Blank
+/// lines following a comment line are recorded as empty strings, so that
+/// the layout of a comment block is kept.
+pub fn scan(text: &str) -> SourceComments {
+    let mut comments = SourceComments::default();
+    let mut pending = Vec::new();
+    let mut clause = 0;
+    let mut seen_id = true;
+
+    for line in text.lines() {
+        let line = line.trim();
+        if let Some(comment) = line.strip_prefix('!') {
+            pending.push(comment.trim_start().to_string());
+        } else if line.is_empty() {
+            if !pending.is_empty() {
+                pending.push(String::new());
+            }
+        } else if line.starts_with('[') {
+            let mut frame = FrameComments::default();
+            frame.frame.append(&mut pending);
+            comments.entities.push(frame);
+            clause = 0;
+            seen_id = false;
+        } else {
+            let frame = comments.entities.last_mut().unwrap_or(&mut comments.header);
+            if !seen_id {
+                frame.frame.append(&mut pending);
+                seen_id = true;
+            } else {
+                if !pending.is_empty() {
+                    frame.clauses.insert(clause, std::mem::take(&mut pending));
+                }
+                clause += 1;
+            }
+        }
+    }
+
+    // the blank line ending the document does not belong to the footer
+    while pending.last().is_some_and(String::is_empty) {
+        pending.pop();
+    }
+    comments.footer = pending;
+    comments
+}
+
+/// Blank the comment lines of an OBO document, which the parser rejects.
+///
+/// Comment lines are replaced with empty lines rather than removed, so
+/// that the line numbers reported in syntax errors match the document.
+pub fn blank(text: &str) -> Cow<'_, str> {
+    if !text.lines().any(|line| line.trim_start().starts_with('!')) {
+        return Cow::Borrowed(text);
+    }
+    let mut blanked = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\r', '\n']);
+        if content.trim_start().starts_with('!') {
+            blanked.push_str(&line[content.len()..]);
+        } else {
+            blanked.push_str(line);
+        }
+    }
+    Cow::Owned(blanked)
+}
+
+/// Write comment lines, using a blank line for each empty comment.
+pub fn write_comments(out: &mut String, comments: &[String]) {
+    for comment in comments {
+        if comment.is_empty() {
+            out.push('\n');
+        } else {
+            writeln!(out, "! {}", comment).unwrap();
+        }
+    }
+}
+
+/// Insert comment lines into the serialized text of a frame.
+///
+/// `skip` is the number of lines of `text` preceding the first clause,
+/// which is 0 for a header frame, and 2 for an entity frame.
+pub fn interleave(text: &str, frame: &[String], clauses: &[Vec<String>], skip: usize) -> String {
+    if frame.is_empty() && clauses.iter().all(Vec::is_empty) {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    write_comments(&mut out, frame);
+    for (i, line) in text.lines().enumerate() {
+        if let Some(comments) = i.checked_sub(skip).and_then(|c| clauses.get(c)) {
+            write_comments(&mut out, comments);
+        }
+        writeln!(out, "{}", line).unwrap();
+    }
+    out
+}
@@ -0,0 +1,119 @@
+use std::io::BufRead;
+use std::io::Read;
+use std::io::Result as IoResult;
+use std::io::Write;
+use std::str::FromStr;
+
+use flate2::bufread::MultiGzDecoder;
+use flate2::write::GzEncoder;
+
+/// The magic bytes starting a gzip stream.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// The compression of a serialized document.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Compression {
+    /// Detect the compression from the content or the path of the file.
+    Infer,
+    /// Use gzip compression.
+    Gzip,
+    /// Use no compression.
+    None,
+}
+
+impl Compression {
+    /// Get the compression given as a Python argument.
+    pub fn from_arg(compression: Option<&str>) -> Result<Self, String> {
+        compression.map(Self::from_str).unwrap_or(Ok(Compression::None))
+    }
+}
+
+impl FromStr for Compression {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "infer" => Ok(Compression::Infer),
+            "gzip" => Ok(Compression::Gzip),
+            other => Err(format!("invalid compression: {:?}", other)),
+        }
+    }
+}
+
+/// A reader decompressing its input if needed.
+pub enum Decoder<R: BufRead> {
+    Plain(R),
+    Gzip(MultiGzDecoder<R>),
+}
+
+impl<R: BufRead> Decoder<R> {
+    /// Wrap `reader`, detecting gzip input from its magic bytes if inferred.
+    pub fn new(mut reader: R, compression: Compression) -> IoResult<Self> {
+        let gzip = match compression {
+            Compression::Infer => reader.fill_buf()?.starts_with(&GZIP_MAGIC),
+            Compression::Gzip => true,
+            Compression::None => false,
+        };
+        if gzip {
+            Ok(Decoder::Gzip(MultiGzDecoder::new(reader)))
+        } else {
+            Ok(Decoder::Plain(reader))
+        }
+    }
+}
+
+impl<R: BufRead> Read for Decoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        match self {
+            Decoder::Plain(r) => r.read(buf),
+            Decoder::Gzip(r) => r.read(buf),
+        }
+    }
+}
+
+/// A writer compressing its output if needed.
+pub enum Encoder<W: Write> {
+    Plain(W),
+    Gzip(GzEncoder<W>),
+}
+
+impl<W: Write> Encoder<W> {
+    /// Wrap `writer`, using gzip if inferred from a `.gz` path.
+    pub fn new(writer: W, compression: Compression, path: Option<&str>) -> Self {
+        let gzip = match compression {
+            Compression::Infer => path.map(|p| p.ends_with(".gz")).unwrap_or(false),
+            Compression::Gzip => true,
+            Compression::None => false,
+        };
+        if gzip {
+            Encoder::Gzip(GzEncoder::new(writer, flate2::Compression::default()))
+        } else {
+            Encoder::Plain(writer)
+        }
+    }
+
+    /// Write the trailer of the compressed stream, if any, and flush.
+    pub fn finish(self) -> IoResult<W> {
+        let mut writer = match self {
+            Encoder::Plain(w) => w,
+            Encoder::Gzip(w) => w.finish()?,
+        };
+        writer.flush()?;
+        Ok(writer)
+    }
+}
+
+impl<W: Write> Write for Encoder<W> {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        match self {
+            Encoder::Plain(w) => w.write(buf),
+            Encoder::Gzip(w) => w.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        match self {
+            Encoder::Plain(w) => w.flush(),
+            Encoder::Gzip(w) => w.flush(),
+        }
+    }
+}
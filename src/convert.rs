@@ -0,0 +1,95 @@
+use std::str::FromStr;
+
+/// A serialization format supported by `fastobo.convert`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    /// The OBO 1.4 flat file format.
+    Obo,
+    /// The OBO graphs JSON format.
+    OboJson,
+    /// The OWL2 Functional-style syntax.
+    Ofn,
+    /// The OWL2 XML syntax.
+    Owx,
+    /// The RDF/XML serialization of OWL2.
+    Rdf,
+}
+
+impl Format {
+    /// Detect the format of a file from the extension of its path.
+    ///
+    /// A trailing `.gz` extension is ignored, since compression is handled
+    /// separately from the format.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let path = path.strip_suffix(".gz").unwrap_or(path);
+        let extension = path.rsplit_once('.')?.1.to_lowercase();
+        match extension.as_str() {
+            "obo" => Some(Format::Obo),
+            "json" | "obojson" => Some(Format::OboJson),
+            "ofn" => Some(Format::Ofn),
+            "owx" => Some(Format::Owx),
+            "owl" | "rdf" => Some(Format::Rdf),
+            _ => None,
+        }
+    }
+
+    /// Detect the format of a file from the first bytes of its content.
+    ///
+    /// Content which cannot be recognized as JSON, OWL or XML is assumed
+    /// to be in OBO format, since an OBO document may start with any
+    /// header clause.
+    pub fn from_content(content: &[u8]) -> Self {
+        let text = String::from_utf8_lossy(content);
+        let text = text.trim_start_matches('\u{feff}').trim_start();
+        if text.starts_with('{') {
+            Format::OboJson
+        } else if text.starts_with("Prefix(") || text.starts_with("Ontology(") {
+            Format::Ofn
+        } else if text.starts_with('<') {
+            if text.contains("<rdf:RDF") {
+                Format::Rdf
+            } else {
+                Format::Owx
+            }
+        } else {
+            Format::Obo
+        }
+    }
+
+    /// Detect the format of a file from the media type of its content.
+    ///
+    /// Parameters of the media type, such as the charset, are ignored.
+    /// Generic media types like ``text/plain`` are not recognized, since
+    /// OBO documents have no registered media type.
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let mime = content_type.split(';').next()?.trim().to_lowercase();
+        match mime.as_str() {
+            "application/json" => Some(Format::OboJson),
+            "text/owl-functional" => Some(Format::Ofn),
+            "application/owl+xml" => Some(Format::Owx),
+            "application/rdf+xml" => Some(Format::Rdf),
+            _ => None,
+        }
+    }
+
+    /// Check whether documents in this format can be loaded.
+    ///
+    /// OWL documents cannot be converted back to OBO documents.
+    pub fn is_readable(&self) -> bool {
+        matches!(self, Format::Obo | Format::OboJson)
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "obo" => Ok(Format::Obo),
+            "obojson" | "json" => Ok(Format::OboJson),
+            "ofn" => Ok(Format::Ofn),
+            "owx" => Ok(Format::Owx),
+            "rdf" | "owl" => Ok(Format::Rdf),
+            other => Err(format!("invalid format: {:?}", other)),
+        }
+    }
+}
@@ -1,41 +1,70 @@
 use std::cmp::Ord;
 use std::cmp::Ordering;
+use std::str::FromStr;
 
 use fastobo::ast::Date;
 use fastobo::ast::Time;
 
+use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::IntoPyDict;
 use pyo3::types::PyDate;
 use pyo3::types::PyDateAccess;
 use pyo3::types::PyDateTime;
 use pyo3::types::PyTimeAccess;
+use pyo3::types::PyString;
 use pyo3::types::PyTzInfo;
 
+use crate::error::Error;
+use crate::raise;
+
 /// Extract the timezone from a Python datetime using the `tzinfo` attribute.
+///
+/// The offset is extracted down to the minute. `datetime.timezone.utc` is
+/// extracted as the `Z` timezone, while any other timezone with a null
+/// offset is extracted as `+00:00`, or `-00:00` if that is its name.
 pub fn extract_timezone<'py>(
     py: Python<'py>,
     datetime: &'py PyDateTime,
 ) -> PyResult<Option<fastobo::ast::IsoTimezone>> {
     use fastobo::ast::IsoTimezone::*;
     let tzinfo = datetime.to_object(py).getattr(py, "tzinfo")?;
-    if !tzinfo.is_none(py) {
-        let timedelta = tzinfo.call_method1(py, "utcoffset", (datetime,))?;
-        let total_seconds = timedelta
-            .call_method0(py, "total_seconds")?
-            .extract::<f64>(py)? as i64;
-        let hh = total_seconds / 3600;
-        let mm = (total_seconds / 60) % 60;
-        match total_seconds.cmp(&0) {
-            Ordering::Equal => Ok(Some(Utc)),
-            Ordering::Less => Ok(Some(Minus((-hh) as u8, ((mm + 60) % 60) as u8))),
-            Ordering::Greater => Ok(Some(Plus(hh as u8, mm as u8))),
+    if tzinfo.is_none(py) {
+        return Ok(None);
+    }
+    let timedelta = tzinfo.call_method1(py, "utcoffset", (datetime,))?;
+    if timedelta.is_none(py) {
+        return Ok(None);
+    }
+    let total_seconds = timedelta
+        .call_method0(py, "total_seconds")?
+        .extract::<f64>(py)? as i64;
+    let hh = (total_seconds.abs() / 3600) as u8;
+    let mm = ((total_seconds.abs() / 60) % 60) as u8;
+    match total_seconds.cmp(&0) {
+        Ordering::Equal => {
+            let utc = py.import("datetime")?.getattr("timezone")?.getattr("utc")?;
+            let name = tzinfo
+                .call_method1(py, "tzname", (datetime,))?
+                .extract::<Option<String>>(py)?;
+            if tzinfo.as_ref(py).is(utc) {
+                Ok(Some(Utc))
+            } else if name.as_deref() == Some("-00:00") {
+                Ok(Some(Minus(0, 0)))
+            } else {
+                Ok(Some(Plus(0, 0)))
+            }
         }
-    } else {
-        Ok(None)
+        Ordering::Less => Ok(Some(Minus(hh, mm))),
+        Ordering::Greater => Ok(Some(Plus(hh, mm))),
     }
 }
 
 /// Convert a Python `datetime.datetime` to a `fastobo::ast::IsoDateTime`.
+///
+/// Microseconds are stored as the fraction of the seconds, and omitted
+/// when null so that the time is serialized without a fraction.
 pub fn datetime_to_isodatetime<'py>(
     py: Python<'py>,
     datetime: &'py PyDateTime,
@@ -50,6 +79,9 @@ pub fn datetime_to_isodatetime<'py>(
         datetime.get_minute(),
         datetime.get_second(),
     );
+    if datetime.get_microsecond() != 0 {
+        time = time.with_fraction(datetime.get_microsecond() as f32 / 1_000_000.0);
+    }
     if let Some(timezone) = extract_timezone(py, datetime)? {
         time = time.with_timezone(timezone);
     }
@@ -70,6 +102,10 @@ pub fn isodatetime_to_datetime<'py>(
         let timedelta = datetime.getattr("timedelta")?.to_object(py);
         match tz {
             Utc => Some(timezone.getattr(py, "utc")?),
+            // use a named timezone so that a null offset is not
+            // normalized to `datetime.timezone.utc`, and serialized as `Z`
+            Plus(0, 0) => Some(timezone.call1(py, (timedelta.call0(py)?, "+00:00"))?),
+            Minus(0, 0) => Some(timezone.call1(py, (timedelta.call0(py)?, "-00:00"))?),
             Plus(hh, mm) => {
                 let args = (0u8, 0u8, 0u8, 0u8, *mm, *hh);
                 Some(timezone.call1(py, (timedelta.call1(py, args)?,))?)
@@ -95,7 +131,7 @@ pub fn isodatetime_to_datetime<'py>(
         datetime
             .time()
             .fraction()
-            .map(|f| (f * 1000.0) as u32)
+            .map(|f| ((f * 1_000_000.0).round() as u32).min(999_999))
             .unwrap_or(0),
         tz
             .as_ref()
@@ -104,6 +140,41 @@ pub fn isodatetime_to_datetime<'py>(
     )
 }
 
+/// Convert a Python `datetime.datetime` to a `fastobo::ast::NaiveDateTime`.
+///
+/// Naive dates only have a precision to the minute, so the seconds and
+/// microseconds of `datetime` are truncated.
+pub fn datetime_to_naivedatetime<'py>(
+    _py: Python<'py>,
+    datetime: &'py PyDateTime,
+) -> PyResult<fastobo::ast::NaiveDateTime> {
+    Ok(fastobo::ast::NaiveDateTime::new(
+        datetime.get_day(),
+        datetime.get_month(),
+        datetime.get_year() as u16,
+        datetime.get_hour(),
+        datetime.get_minute(),
+    ))
+}
+
+/// Convert a `fastobo::ast::NaiveDateTime` to a Python `datetime.datetime`.
+pub fn naivedatetime_to_datetime<'py>(
+    py: Python<'py>,
+    datetime: &fastobo::ast::NaiveDateTime,
+) -> PyResult<&'py PyDateTime> {
+    PyDateTime::new(
+        py,
+        datetime.year() as i32,
+        datetime.month(),
+        datetime.day(),
+        datetime.hour(),
+        datetime.minute(),
+        0,
+        0,
+        None,
+    )
+}
+
 /// Convert a Python `datetime.date` to a `fastobo::ast::IsoDate`.
 pub fn date_to_isodate<'py>(py: Python<'py>, date: &'py PyDate) -> PyResult<fastobo::ast::IsoDate> {
     Ok(fastobo::ast::IsoDate::new(
@@ -121,3 +192,88 @@ pub fn isodate_to_date<'py>(
     // Create the `datetime.datetime` instance
     PyDate::new(py, date.year() as i32, date.month(), date.day())
 }
+
+/// The policy to apply to naive `datetime.datetime` objects.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NaivePolicy {
+    /// Serialize the datetime without a timezone.
+    #[default]
+    Keep,
+    /// Assume the datetime is in UTC.
+    Utc,
+    /// Assume the datetime is in the local timezone of the system.
+    Local,
+    /// Reject the datetime with a `ValueError`.
+    Error,
+}
+
+impl FromStr for NaivePolicy {
+    type Err = PyErr;
+    fn from_str(s: &str) -> PyResult<Self> {
+        match s {
+            "keep" => Ok(NaivePolicy::Keep),
+            "utc" => Ok(NaivePolicy::Utc),
+            "local" => Ok(NaivePolicy::Local),
+            "error" => Ok(NaivePolicy::Error),
+            invalid => Err(PyValueError::new_err(format!(
+                "expected 'keep', 'utc', 'local' or 'error', found {:?}",
+                invalid
+            ))),
+        }
+    }
+}
+
+/// Apply a `NaivePolicy` to a Python `datetime.datetime`.
+///
+/// Aware datetimes are returned unchanged.
+pub fn apply_naive_policy<'py>(
+    py: Python<'py>,
+    datetime: &'py PyDateTime,
+    policy: NaivePolicy,
+) -> PyResult<&'py PyDateTime> {
+    if !datetime.getattr("tzinfo")?.is_none() {
+        return Ok(datetime);
+    }
+    match policy {
+        NaivePolicy::Keep => Ok(datetime),
+        NaivePolicy::Utc => {
+            let utc = py.import("datetime")?.getattr("timezone")?.getattr("utc")?;
+            let kwargs = [("tzinfo", utc)].into_py_dict(py);
+            Ok(datetime.call_method("replace", (), Some(kwargs))?.downcast()?)
+        }
+        NaivePolicy::Local => Ok(datetime.call_method0("astimezone")?.downcast()?),
+        NaivePolicy::Error => Err(PyValueError::new_err(
+            "naive datetime are not allowed, use an aware datetime instead",
+        )),
+    }
+}
+
+/// Extract a `fastobo::ast::CreationDate` from a Python object.
+///
+/// The object can be a `datetime.date`, a `datetime.datetime`, or a `str`
+/// with an ISO-8601 date or datetime, which is parsed as written. Naive
+/// datetimes are handled according to `policy`.
+pub fn extract_creation_date<'py>(
+    py: Python<'py>,
+    object: &'py PyAny,
+    policy: NaivePolicy,
+) -> PyResult<fastobo::ast::CreationDate> {
+    if let Ok(dt) = object.downcast::<PyDateTime>() {
+        let dt = apply_naive_policy(py, dt, policy)?;
+        datetime_to_isodatetime(py, dt).map(From::from)
+    } else if let Ok(d) = object.downcast::<PyDate>() {
+        date_to_isodate(py, d).map(From::from)
+    } else if let Ok(s) = object.downcast::<PyString>() {
+        match fastobo::ast::CreationDate::from_str(s.to_str()?) {
+            Ok(date) => Ok(date),
+            Err(e) => {
+                let err = PyErr::from(Error::from(e));
+                raise!(py, PyValueError("could not parse creation date") from err)
+            }
+        }
+    } else {
+        let ty = object.get_type().name()?;
+        let msg = format!("expected datetime.date, datetime.datetime or str, found {}", ty);
+        Err(PyTypeError::new_err(msg))
+    }
+}
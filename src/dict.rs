@@ -0,0 +1,302 @@
+use std::fmt::Display;
+use std::mem::take;
+use std::str::FromStr;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyAny;
+use pyo3::types::PyDict;
+use pyo3::types::PyList;
+
+use fastobo::ast as obo;
+use fastobo::error::SyntaxError;
+use fastobo::semantics::OboClause;
+
+use crate::error::Error;
+
+/// Get the raw tag and the raw value of a clause.
+fn clause_tag_value<C>(clause: &C) -> (String, String)
+where
+    C: OboClause + Display,
+{
+    let text = clause.to_string();
+    let value = text.split_once(": ").map(|(_, value)| value).unwrap_or("");
+    (clause.tag().to_string(), value.to_string())
+}
+
+/// Convert a clause to a `dict` with its raw tag and raw value.
+pub fn clause_to_dict<C>(py: Python, clause: C) -> PyResult<PyObject>
+where
+    C: OboClause + Display,
+{
+    let dict = PyDict::new(py);
+    let (tag, value) = clause_tag_value(&clause);
+    dict.set_item("tag", tag)?;
+    dict.set_item("value", value)?;
+    Ok(dict.to_object(py))
+}
+
+/// Parse a clause from a raw tag and a raw value.
+fn clause_from_tag_value<C>(tag: &str, value: &str) -> PyResult<C>
+where
+    C: FromStr<Err = SyntaxError>,
+{
+    let text = format!("{}: {}", tag, value);
+    C::from_str(&text).map_err(|e| PyErr::from(Error::from(e)))
+}
+
+/// Parse a clause from a `dict` with a raw tag and a raw value.
+pub fn clause_from_dict<C>(object: &PyAny) -> PyResult<C>
+where
+    C: FromStr<Err = SyntaxError>,
+{
+    let tag = object.get_item("tag")?.extract::<&str>()?;
+    let value = object.get_item("value")?.extract::<&str>()?;
+    clause_from_tag_value(tag, value)
+}
+
+/// Convert qualifiers to a `list` of `dict` with their key and value.
+fn qualifiers_to_list(py: Python, qualifiers: &obo::QualifierList) -> PyResult<PyObject> {
+    let list = PyList::empty(py);
+    for qualifier in qualifiers.iter() {
+        let dict = PyDict::new(py);
+        dict.set_item("key", qualifier.key().to_string())?;
+        dict.set_item("value", qualifier.value().as_str())?;
+        list.append(dict)?;
+    }
+    Ok(list.to_object(py))
+}
+
+/// Parse qualifiers from an iterable of `dict` with a key and a value.
+fn qualifiers_from_list(object: &PyAny) -> PyResult<obo::QualifierList> {
+    object
+        .iter()?
+        .map(|item| {
+            let item = item?;
+            let key = item.get_item("key")?.extract::<&str>()?;
+            let value = item.get_item("value")?.extract::<&str>()?;
+            let key = obo::RelationIdent::from_str(key).map_err(|e| PyErr::from(Error::from(e)))?;
+            Ok(obo::Qualifier::new(key, obo::QuotedString::new(value)))
+        })
+        .collect::<PyResult<Vec<_>>>()
+        .map(obo::QualifierList::new)
+}
+
+/// Parse the optional `qualifiers` of a clause `dict`.
+fn line_qualifiers_from_dict(object: &PyAny) -> PyResult<Option<obo::QualifierList>> {
+    match object.downcast::<PyDict>()?.get_item("qualifiers") {
+        Some(qualifiers) if !qualifiers.is_none() => qualifiers_from_list(qualifiers).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Convert a sequence of clauses to a `list` of `dict`.
+fn clauses_to_list<I>(py: Python, clauses: I) -> PyResult<PyObject>
+where
+    I: IntoIterator,
+    I::Item: OboClause + Display,
+{
+    let list = PyList::empty(py);
+    for clause in clauses {
+        list.append(clause_to_dict(py, clause)?)?;
+    }
+    Ok(list.to_object(py))
+}
+
+/// Parse a sequence of clauses from an iterable of `dict`.
+fn clauses_from_list<C>(object: &PyAny) -> PyResult<Vec<C>>
+where
+    C: FromStr<Err = SyntaxError>,
+{
+    object
+        .iter()?
+        .map(|item| item.and_then(clause_from_dict))
+        .collect()
+}
+
+/// Convert the lines of an entity frame to a `list` or a `dict` of clauses.
+///
+/// When `grouped` is `true`, the raw values of the clauses are grouped by
+/// raw tag, otherwise each clause is converted with `clause_to_dict`. In
+/// both cases, the qualifiers of a clause are only included when present.
+fn lines_to_object<I, C>(py: Python, lines: I, grouped: bool) -> PyResult<PyObject>
+where
+    I: IntoIterator<Item = obo::Line<C>>,
+    C: OboClause + Display,
+{
+    if !grouped {
+        let list = PyList::empty(py);
+        for line in lines {
+            let qualifiers = line.qualifiers().cloned();
+            let dict = clause_to_dict(py, line.into_inner())?;
+            if let Some(q) = qualifiers {
+                dict.as_ref(py).set_item("qualifiers", qualifiers_to_list(py, &q)?)?;
+            }
+            list.append(dict)?;
+        }
+        return Ok(list.to_object(py));
+    }
+
+    let groups = PyDict::new(py);
+    for line in lines {
+        let (tag, value) = clause_tag_value::<C>(line.as_ref());
+        let value = match line.qualifiers() {
+            None => value.to_object(py),
+            Some(q) => {
+                let dict = PyDict::new(py);
+                dict.set_item("value", value)?;
+                dict.set_item("qualifiers", qualifiers_to_list(py, q)?)?;
+                dict.to_object(py)
+            }
+        };
+        match groups.get_item(&tag) {
+            Some(values) => values.downcast::<PyList>()?.append(value)?,
+            None => groups.set_item(tag, PyList::new(py, [value]))?,
+        }
+    }
+    Ok(groups.to_object(py))
+}
+
+/// Parse the lines of an entity frame from a `list` or a `dict` of clauses.
+fn lines_from_object<C>(object: &PyAny) -> PyResult<Vec<obo::Line<C>>>
+where
+    C: FromStr<Err = SyntaxError>,
+{
+    let mut lines = Vec::new();
+    if let Ok(groups) = object.downcast::<PyDict>() {
+        for (tag, values) in groups.iter() {
+            let tag = tag.extract::<&str>()?;
+            for value in values.iter()? {
+                let value = value?;
+                let line = match value.extract::<&str>() {
+                    Ok(v) => obo::Line::new().and_inner(clause_from_tag_value::<C>(tag, v)?),
+                    Err(_) => {
+                        let v = value.get_item("value")?.extract::<&str>()?;
+                        obo::Line::new()
+                            .and_inner(clause_from_tag_value::<C>(tag, v)?)
+                            .and_qualifiers(line_qualifiers_from_dict(value)?)
+                    }
+                };
+                lines.push(line);
+            }
+        }
+    } else {
+        for item in object.iter()? {
+            let item = item?;
+            let line = obo::Line::new()
+                .and_inner(clause_from_dict::<C>(item)?)
+                .and_qualifiers(line_qualifiers_from_dict(item)?);
+            lines.push(line);
+        }
+    }
+    Ok(lines)
+}
+
+/// Check the `type` of a frame `dict` is the expected one.
+fn check_type(object: &PyAny, expected: &str) -> PyResult<()> {
+    let ty = object.get_item("type")?.extract::<&str>()?;
+    if ty == expected {
+        Ok(())
+    } else {
+        let msg = format!("expected {:?} frame, found {:?}", expected, ty);
+        Err(PyValueError::new_err(msg))
+    }
+}
+
+/// Parse the identifier of a frame `dict`.
+fn ident_from_dict<I>(object: &PyAny) -> PyResult<I>
+where
+    I: FromStr<Err = SyntaxError>,
+{
+    let id = object.get_item("id")?.extract::<&str>()?;
+    I::from_str(id).map_err(|e| PyErr::from(Error::from(e)))
+}
+
+/// Convert a header frame to a `dict`.
+pub fn header_to_dict(py: Python, frame: obo::HeaderFrame) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+    dict.set_item("type", "Header")?;
+    dict.set_item("clauses", clauses_to_list(py, frame)?)?;
+    Ok(dict.to_object(py))
+}
+
+/// Parse a header frame from a `dict`.
+pub fn header_from_dict(object: &PyAny) -> PyResult<obo::HeaderFrame> {
+    check_type(object, "Header")?;
+    let clauses = clauses_from_list::<obo::HeaderClause>(object.get_item("clauses")?)?;
+    Ok(clauses.into_iter().collect())
+}
+
+/// Convert an entity frame to a `dict`, optionally grouping clauses by tag.
+pub fn entity_to_dict(py: Python, frame: obo::EntityFrame, grouped: bool) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+    match frame {
+        obo::EntityFrame::Term(term) => {
+            dict.set_item("type", "Term")?;
+            dict.set_item("id", term.id().as_ref().to_string())?;
+            dict.set_item("clauses", lines_to_object(py, *term, grouped)?)?;
+        }
+        obo::EntityFrame::Typedef(typedef) => {
+            dict.set_item("type", "Typedef")?;
+            dict.set_item("id", typedef.id().as_ref().to_string())?;
+            dict.set_item("clauses", lines_to_object(py, *typedef, grouped)?)?;
+        }
+        obo::EntityFrame::Instance(instance) => {
+            dict.set_item("type", "Instance")?;
+            dict.set_item("id", instance.id().as_ref().to_string())?;
+            dict.set_item("clauses", lines_to_object(py, *instance, grouped)?)?;
+        }
+    }
+    Ok(dict.to_object(py))
+}
+
+/// Parse an entity frame from a `dict`.
+pub fn entity_from_dict(object: &PyAny) -> PyResult<obo::EntityFrame> {
+    match object.get_item("type")?.extract::<&str>()? {
+        "Term" => {
+            let id = ident_from_dict::<obo::ClassIdent>(object)?;
+            let clauses = lines_from_object(object.get_item("clauses")?)?;
+            let frame = obo::TermFrame::with_clauses(id, clauses);
+            Ok(obo::EntityFrame::from(frame))
+        }
+        "Typedef" => {
+            let id = ident_from_dict::<obo::RelationIdent>(object)?;
+            let clauses = lines_from_object(object.get_item("clauses")?)?;
+            let frame = obo::TypedefFrame::with_clauses(id, clauses);
+            Ok(obo::EntityFrame::from(frame))
+        }
+        "Instance" => {
+            let id = ident_from_dict::<obo::InstanceIdent>(object)?;
+            let clauses = lines_from_object(object.get_item("clauses")?)?;
+            let frame = obo::InstanceFrame::with_clauses(id, clauses);
+            Ok(obo::EntityFrame::from(frame))
+        }
+        other => {
+            let msg = format!("expected 'Term', 'Typedef' or 'Instance' frame, found {:?}", other);
+            Err(PyValueError::new_err(msg))
+        }
+    }
+}
+
+/// Convert an OBO document to a `dict`.
+pub fn doc_to_dict(py: Python, mut doc: obo::OboDoc) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+    dict.set_item("header", header_to_dict(py, take(doc.header_mut()))?)?;
+    let entities = PyList::empty(py);
+    for frame in take(doc.entities_mut()) {
+        entities.append(entity_to_dict(py, frame, false)?)?;
+    }
+    dict.set_item("entities", entities)?;
+    Ok(dict.to_object(py))
+}
+
+/// Parse an OBO document from a `dict`.
+pub fn doc_from_dict(object: &PyAny) -> PyResult<obo::OboDoc> {
+    let header = header_from_dict(object.get_item("header")?)?;
+    let doc = object
+        .get_item("entities")?
+        .iter()?
+        .map(|item| item.and_then(entity_from_dict))
+        .collect::<PyResult<obo::OboDoc>>()?;
+    Ok(doc.and_header(header))
+}
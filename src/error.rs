@@ -18,6 +18,8 @@ use fastobo::ast as obo;
 use crate::py::exceptions::SingleClauseError;
 use crate::py::exceptions::DuplicateClausesError;
 use crate::py::exceptions::MissingClauseError;
+use crate::py::exceptions::MissingNamespaceError;
+use crate::py::exceptions::MissingOntologyClauseError;
 use crate::py::exceptions::DisconnectedChannelError;
 
 // ---------------------------------------------------------------------------
@@ -170,7 +172,7 @@ impl From<GraphError> for PyErr {
 
 // ---------------------------------------------------------------------------
 
-/// A wrapper to convert `fastobo_graphs::error::Error` into a `PyErr`.
+/// A wrapper to convert `fastobo_owl::Error` into a `PyErr`.
 pub struct OwlError(fastobo_owl::Error);
 
 impl From<fastobo_owl::Error> for OwlError {
@@ -182,6 +184,16 @@ impl From<fastobo_owl::Error> for OwlError {
 impl From<OwlError> for PyErr {
     fn from(err: OwlError) -> Self {
         match err.0 {
+            fastobo_owl::Error::Cardinality(fastobo::error::CardinalityError::MissingClause { name })
+                if name == "ontology" =>
+            {
+                MissingOntologyClauseError::new_err((name, Some("header")))
+            }
+            fastobo_owl::Error::Cardinality(fastobo::error::CardinalityError::MissingClause { name })
+                if name == "default-namespace" || name == "namespace" =>
+            {
+                MissingNamespaceError::new_err((name, Some("header")))
+            }
             fastobo_owl::Error::Cardinality(error) => {
                 Error::from(fastobo::error::Error::CardinalityError {
                     id: Some(obo::Ident::from(obo::UnprefixedIdent::new("header"))),
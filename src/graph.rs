@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyAny;
+
+use fastobo::ast as obo;
+use fastobo::visit::IdCompactor;
+use fastobo::visit::VisitMut;
+use fastobo_graphs::error::Result as GraphResult;
+use fastobo_graphs::model::Graph;
+use fastobo_graphs::model::Meta;
+use fastobo_graphs::model::Node;
+use fastobo_graphs::FromGraph;
+
+use crate::owl::IriMapper;
+
+/// Add the elements of `src` missing from `dst` at the end of `dst`.
+fn extend_missing<T: Clone + PartialEq>(dst: &mut Vec<T>, src: &[T]) {
+    for item in src {
+        if !dst.contains(item) {
+            dst.push(item.clone());
+        }
+    }
+}
+
+/// Merge the graph metadata `stored` into `meta`.
+///
+/// `meta` is the metadata translated from an OBO header, which takes
+/// precedence, while the values of `stored` without an OBO equivalent,
+/// such as a graph title or description, are added to it.
+pub fn merge_meta(meta: &mut Meta, stored: &Meta) {
+    if meta.definition.is_none() {
+        meta.definition = stored.definition.clone();
+    }
+    if meta.version.is_none() {
+        meta.version = stored.version.clone();
+    }
+    meta.deprecated |= stored.deprecated;
+    extend_missing(&mut meta.comments, &stored.comments);
+    extend_missing(&mut meta.subsets, &stored.subsets);
+    extend_missing(&mut meta.xrefs, &stored.xrefs);
+    extend_missing(&mut meta.synonyms, &stored.synonyms);
+    extend_missing(&mut meta.basic_property_values, &stored.basic_property_values);
+}
+
+/// Convert graph metadata to a `dict` with the layout of the JSON format.
+pub fn meta_to_py(py: Python, meta: &Meta) -> PyResult<PyObject> {
+    let json = serde_json::to_string(meta).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let loads = py.import(pyo3::intern!(py, "json"))?.getattr(pyo3::intern!(py, "loads"))?;
+    Ok(loads.call1((json,))?.to_object(py))
+}
+
+/// Extract graph metadata from a `dict` with the layout of the JSON format.
+pub fn meta_from_py(obj: &PyAny) -> PyResult<Meta> {
+    let py = obj.py();
+    let dumps = py.import(pyo3::intern!(py, "json"))?.getattr(pyo3::intern!(py, "dumps"))?;
+    let json = dumps.call1((obj,))?.extract::<String>()?;
+    serde_json::from_str(&json).map_err(|e| PyValueError::new_err(format!("invalid graph meta: {}", e)))
+}
+
+// ---------------------------------------------------------------------------
+
+/// Convert a graph node to an entity frame.
+///
+/// Identifiers are compacted like in `OboDoc::from_graph`, so that the
+/// OBO Library IRIs of the node become prefixed identifiers again. The
+/// subsets of the node are skipped, see `strip_subsets`.
+pub fn node_to_frame(mut node: Node) -> GraphResult<Option<obo::EntityFrame>> {
+    strip_subsets(&mut node);
+    let mut frame = Option::<obo::EntityFrame>::from_graph(node)?;
+    if let Some(f) = frame.as_mut() {
+        IdCompactor::new().visit_entity_frame(f);
+    }
+    Ok(frame)
+}
+
+/// Remove the subsets from the metadata of a graph node.
+///
+/// Subset IRIs would otherwise be translated to URL identifiers, which
+/// are then mangled by identifier compaction: they are translated to
+/// subset clauses by `add_node_clauses` instead.
+fn strip_subsets(node: &mut Node) {
+    if let Some(meta) = node.meta.as_mut() {
+        meta.subsets.clear();
+    }
+}
+
+/// Get the identifier of the entity frame translated from a graph node.
+fn node_ident(node: &Node) -> Option<obo::Ident> {
+    let frame = node_to_frame(node.clone()).ok()??;
+    Some(frame_ident(&frame))
+}
+
+/// Get the identifier of an entity frame.
+fn frame_ident(frame: &obo::EntityFrame) -> obo::Ident {
+    match frame {
+        obo::EntityFrame::Term(t) => t.id().as_ref().clone().into(),
+        obo::EntityFrame::Typedef(t) => t.id().as_ref().clone().into(),
+        obo::EntityFrame::Instance(i) => i.id().as_ref().clone().into(),
+    }
+}
+
+/// Get the subset identifier corresponding to a subset IRI of a graph.
+///
+/// Subsets are declared as `<ontology IRI>#<subset>` in OBO graphs, and
+/// only the local part is kept as an unprefixed identifier.
+fn subset_ident(iri: &str) -> obo::SubsetIdent {
+    let local = iri.rsplit(['#', '/']).next().unwrap_or(iri);
+    obo::SubsetIdent::from(obo::Ident::from(obo::UnprefixedIdent::new(local)))
+}
+
+/// Collect the metadata of the nodes of `graph`, by entity identifier.
+///
+/// The subsets are then removed from the nodes, since `add_doc_clauses`
+/// translates them to subset clauses.
+pub fn collect_node_meta(graph: &mut Graph) -> HashMap<obo::Ident, Meta> {
+    let metas = graph
+        .nodes
+        .iter()
+        .filter_map(|node| Some((node_ident(node)?, (**node.meta.as_ref()?).clone())))
+        .collect();
+    graph.nodes.iter_mut().for_each(strip_subsets);
+    metas
+}
+
+macro_rules! add_meta_clauses {
+    ($frame:expr, $clause:ident, $meta:expr) => {{
+        let clauses = $frame.clauses_mut();
+        let meta = $meta;
+        let obsolete = clauses
+            .iter()
+            .any(|l| matches!(l.as_ref(), obo::$clause::IsObsolete(_)));
+        if meta.deprecated && !obsolete {
+            clauses.push(obo::Line::new().and_inner(obo::$clause::IsObsolete(true)));
+        }
+        let commented = clauses
+            .iter()
+            .any(|l| matches!(l.as_ref(), obo::$clause::Comment(_)));
+        if !meta.comments.is_empty() && !commented {
+            let comment = obo::UnquotedString::new(meta.comments.join(" "));
+            clauses.push(obo::Line::new().and_inner(obo::$clause::Comment(Box::new(comment))));
+        }
+        for subset in meta.subsets.iter().map(|iri| subset_ident(iri)) {
+            let declared = clauses
+                .iter()
+                .any(|l| matches!(l.as_ref(), obo::$clause::Subset(s) if **s == subset));
+            if !declared {
+                clauses.push(obo::Line::new().and_inner(obo::$clause::Subset(Box::new(subset))));
+            }
+        }
+    }};
+}
+
+/// Add the clauses corresponding to the metadata of a graph node to a frame.
+///
+/// The `deprecated`, `comments` and `subsets` metadata of the node are
+/// translated to `is_obsolete`, `comment` and `subset` clauses, unless
+/// the frame already declares them.
+pub fn add_node_clauses(frame: &mut obo::EntityFrame, meta: &Meta) {
+    match frame {
+        obo::EntityFrame::Term(t) => add_meta_clauses!(t, TermClause, meta),
+        obo::EntityFrame::Typedef(t) => add_meta_clauses!(t, TypedefClause, meta),
+        obo::EntityFrame::Instance(_) => (),
+    }
+}
+
+/// Add the clauses corresponding to the node metadata `metas` to `doc`.
+pub fn add_doc_clauses(doc: &mut obo::OboDoc, metas: &HashMap<obo::Ident, Meta>) {
+    for frame in doc.entities_mut() {
+        if let Some(meta) = metas.get(&frame_ident(frame)) {
+            add_node_clauses(frame, meta);
+        }
+    }
+}
+
+macro_rules! collect_meta {
+    ($frame:expr, $clause:ident, $mapper:expr) => {{
+        let mut meta = Meta::default();
+        for line in $frame.clauses() {
+            match line.as_ref() {
+                obo::$clause::IsObsolete(b) => meta.deprecated = *b,
+                obo::$clause::Comment(c) => meta.comments.push(c.as_str().to_string()),
+                obo::$clause::Subset(s) => meta.subsets.push($mapper.iri(s.as_ref().as_ref())),
+                _ => (),
+            }
+        }
+        meta
+    }};
+}
+
+/// Collect the node metadata corresponding to the clauses of `doc`.
+pub fn collect_doc_meta(doc: &obo::OboDoc) -> HashMap<obo::Ident, Meta> {
+    let mapper = IriMapper::new(doc);
+    doc.entities()
+        .iter()
+        .filter_map(|frame| {
+            let meta = match frame {
+                obo::EntityFrame::Term(t) => collect_meta!(t, TermClause, mapper),
+                obo::EntityFrame::Typedef(t) => collect_meta!(t, TypedefClause, mapper),
+                obo::EntityFrame::Instance(_) => return None,
+            };
+            Some((frame_ident(frame), meta))
+        })
+        .collect()
+}
+
+/// Merge the node metadata `metas` into the nodes of `graph`.
+pub fn apply_node_meta(graph: &mut Graph, metas: &HashMap<obo::Ident, Meta>) {
+    for node in graph.nodes.iter_mut() {
+        if let Some(stored) = node_ident(node).and_then(|id| metas.get(&id)) {
+            let meta = node.meta.get_or_insert_with(Default::default);
+            meta.deprecated |= stored.deprecated;
+            extend_missing(&mut meta.comments, &stored.comments);
+            extend_missing(&mut meta.subsets, &stored.subsets);
+        }
+    }
+}
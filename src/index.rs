@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::mem::take;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use fastobo::ast as obo;
+
+/// A counter of the modifications that may change the edges of a document.
+static GENERATION: AtomicUsize = AtomicUsize::new(0);
+
+/// Record a modification of a document, a frame, a clause or an identifier.
+///
+/// Modifications are not tracked per document, so this invalidates every
+/// index cached by `generation`.
+pub fn invalidate() {
+    GENERATION.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Get the number of modifications recorded with `invalidate` so far.
+///
+/// An index built from a document stays valid for as long as the
+/// generation does not change.
+pub fn generation() -> usize {
+    GENERATION.load(Ordering::Relaxed)
+}
+
+/// An adjacency index over the relationships between entities of a document.
+///
+/// Edges are extracted from `is_a` clauses (for the `is_a` relation) and
+/// from `relationship` clauses (for any other relation) of term and typedef
+/// frames, so that transitive queries can be answered without going through
+/// the Python objects.
+#[derive(Debug, Default)]
+pub struct RelationIndex {
+    parents: HashMap<obo::Ident, Vec<obo::Ident>>,
+    children: HashMap<obo::Ident, Vec<obo::Ident>>,
+}
+
+impl RelationIndex {
+    /// Build an index of the edges of `doc` labeled with one of `relations`.
+    pub fn new<S: AsRef<str>>(mut doc: obo::OboDoc, relations: &[S]) -> Self {
+        let follows = |rel: &str| relations.iter().any(|r| r.as_ref() == rel);
+        let mut index = Self::default();
+        for frame in take(doc.entities_mut()).into_iter() {
+            match frame {
+                obo::EntityFrame::Term(term) => {
+                    let id: obo::Ident = term.id().as_ref().clone().into();
+                    for line in term.into_iter() {
+                        match line.into_inner() {
+                            obo::TermClause::IsA(target) if follows("is_a") => {
+                                index.add_edge(id.clone(), (*target).into());
+                            }
+                            obo::TermClause::Relationship(rel, target)
+                                if follows(&rel.to_string()) =>
+                            {
+                                index.add_edge(id.clone(), (*target).into());
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+                obo::EntityFrame::Typedef(typedef) => {
+                    let id: obo::Ident = typedef.id().as_ref().clone().into();
+                    for line in typedef.into_iter() {
+                        match line.into_inner() {
+                            obo::TypedefClause::IsA(target) if follows("is_a") => {
+                                index.add_edge(id.clone(), (*target).into());
+                            }
+                            obo::TypedefClause::Relationship(rel, target)
+                                if follows(&rel.to_string()) =>
+                            {
+                                index.add_edge(id.clone(), (*target).into());
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+                obo::EntityFrame::Instance(_) => (),
+            }
+        }
+        index
+    }
+
+    fn add_edge(&mut self, child: obo::Ident, parent: obo::Ident) {
+        self.children
+            .entry(parent.clone())
+            .or_default()
+            .push(child.clone());
+        self.parents.entry(child).or_default().push(parent);
+    }
+
+    /// Get the identifiers of all the entities that can be reached from `id`.
+    fn closure<'a>(
+        edges: &'a HashMap<obo::Ident, Vec<obo::Ident>>,
+        id: &obo::Ident,
+    ) -> HashSet<&'a obo::Ident> {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<&obo::Ident> = edges.get(id).into_iter().flatten().collect();
+        while let Some(next) = stack.pop() {
+            if seen.insert(next) {
+                stack.extend(edges.get(next).into_iter().flatten());
+            }
+        }
+        seen
+    }
+
+    /// Get the transitive ancestors of the entity with the given identifier.
+    pub fn ancestors(&self, id: &obo::Ident) -> HashSet<&obo::Ident> {
+        Self::closure(&self.parents, id)
+    }
+
+    /// Get the transitive descendants of the entity with the given identifier.
+    pub fn descendants(&self, id: &obo::Ident) -> HashSet<&obo::Ident> {
+        Self::closure(&self.children, id)
+    }
+
+    /// Get the closest ancestors of `id` that are part of `selected`.
+    fn nearest<'a>(
+        &'a self,
+        id: &obo::Ident,
+        selected: &HashSet<obo::Ident>,
+    ) -> HashSet<&'a obo::Ident> {
+        let mut nearest = HashSet::new();
+        let mut seen = HashSet::new();
+        let mut stack: Vec<&obo::Ident> = self.parents.get(id).into_iter().flatten().collect();
+        while let Some(next) = stack.pop() {
+            if !seen.insert(next) {
+                continue;
+            } else if selected.contains(next) {
+                nearest.insert(next);
+            } else {
+                stack.extend(self.parents.get(next).into_iter().flatten());
+            }
+        }
+        nearest
+    }
+
+    /// Extract the frames of `doc` with an identifier in `selected`.
+    ///
+    /// Term clauses referencing a term outside of the selection are removed,
+    /// except for `is_a` clauses which are replaced with `is_a` clauses to
+    /// the closest selected superclasses. Typedef frames are only kept if
+    /// they are referenced by one of the extracted term frames, and
+    /// instance frames are always removed.
+    pub fn extract_subset(
+        &self,
+        mut doc: obo::OboDoc,
+        selected: &HashSet<obo::Ident>,
+    ) -> obo::OboDoc {
+        let mut terms = Vec::new();
+        let mut typedefs = Vec::new();
+        let mut relations: HashSet<obo::Ident> = HashSet::new();
+
+        for frame in take(doc.entities_mut()).into_iter() {
+            match frame {
+                obo::EntityFrame::Term(term) => {
+                    let id: obo::Ident = term.id().as_ref().clone().into();
+                    if !selected.contains(&id) {
+                        continue;
+                    }
+                    let class = term.id().as_ref().clone();
+                    let mut superclasses = HashSet::new();
+                    let mut clauses = Vec::new();
+                    for line in term.into_iter() {
+                        match line.as_ref() {
+                            obo::TermClause::IsA(target) => {
+                                let target: obo::Ident = target.as_ref().clone().into();
+                                if selected.contains(&target) {
+                                    if superclasses.insert(target) {
+                                        clauses.push(line);
+                                    }
+                                } else {
+                                    for superclass in self.nearest(&target, selected) {
+                                        if superclasses.insert(superclass.clone()) {
+                                            let class = obo::ClassIdent::from(superclass.clone());
+                                            let clause = obo::TermClause::IsA(Box::new(class));
+                                            clauses.push(obo::Line::new().and_inner(clause));
+                                        }
+                                    }
+                                }
+                            }
+                            obo::TermClause::Relationship(rel, target)
+                            | obo::TermClause::IntersectionOf(Some(rel), target) => {
+                                let target: obo::Ident = target.as_ref().clone().into();
+                                if selected.contains(&target) {
+                                    relations.insert(rel.as_ref().clone().into());
+                                    clauses.push(line);
+                                }
+                            }
+                            obo::TermClause::IntersectionOf(None, target)
+                            | obo::TermClause::UnionOf(target)
+                            | obo::TermClause::EquivalentTo(target)
+                            | obo::TermClause::DisjointFrom(target)
+                            | obo::TermClause::ReplacedBy(target)
+                            | obo::TermClause::Consider(target) => {
+                                let target: obo::Ident = target.as_ref().clone().into();
+                                if selected.contains(&target) {
+                                    clauses.push(line);
+                                }
+                            }
+                            _ => clauses.push(line),
+                        }
+                    }
+                    terms.push(obo::TermFrame::with_clauses(class, clauses));
+                }
+                obo::EntityFrame::Typedef(typedef) => typedefs.push(typedef),
+                obo::EntityFrame::Instance(_) => (),
+            }
+        }
+
+        let entities = doc.entities_mut();
+        entities.extend(terms.into_iter().map(obo::EntityFrame::from));
+        for typedef in typedefs {
+            let id: obo::Ident = typedef.id().as_ref().clone().into();
+            if relations.contains(&id) {
+                entities.push(obo::EntityFrame::Typedef(typedef));
+            }
+        }
+        doc
+    }
+
+    /// Iterate over the identifiers of all entities with a child.
+    pub fn parents(&self) -> impl Iterator<Item = &obo::Ident> {
+        self.children.keys()
+    }
+}
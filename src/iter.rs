@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::fs::File;
@@ -11,8 +12,13 @@ use std::ops::Deref;
 use std::ops::DerefMut;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::Mutex;
+use std::sync::mpsc::Receiver;
+use std::sync::mpsc::SyncSender;
+use std::thread::JoinHandle;
 
 use pyo3::exceptions::PyValueError;
+use pyo3::panic::PanicException;
 use pyo3::prelude::*;
 use pyo3::types::PyBytes;
 use pyo3::types::PyString;
@@ -21,8 +27,20 @@ use pyo3::AsPyPointer;
 use fastobo::parser::Parser;
 use fastobo::parser::SequentialParser;
 use fastobo::parser::ThreadedParser;
+use fastobo_graphs::model::Node;
+use fastobo_graphs::FromGraph;
+use serde::de::DeserializeSeed;
+use serde::de::Deserializer;
+use serde::de::Error as _;
+use serde::de::IgnoredAny;
+use serde::de::MapAccess;
+use serde::de::SeqAccess;
+use serde::de::Visitor;
 
+use crate::compression::Compression;
+use crate::compression::Decoder;
 use crate::error::Error;
+use crate::error::GraphError;
 use crate::py::doc::EntityFrame;
 use crate::py::header::frame::HeaderFrame;
 use crate::pyfile::PyFileGILRead;
@@ -67,6 +85,143 @@ impl Read for Handle {
 
 // ---------------------------------------------------------------------------
 
+/// A reader skipping the entity frames with an identifier outside of a set of prefixes.
+///
+/// Frames are filtered line by line, by looking at their `id` clause,
+/// so that skipped frames are never tokenized nor parsed. The header
+/// frame is always kept.
+pub struct FrameFilter<R: BufRead> {
+    reader: R,
+    prefixes: Option<HashSet<String>>,
+    buffer: Vec<u8>,
+    position: usize,
+    pending: Vec<u8>,
+    header: bool,
+}
+
+impl<R: BufRead> FrameFilter<R> {
+    pub fn new(reader: R, prefixes: Option<HashSet<String>>) -> Self {
+        Self {
+            reader,
+            prefixes,
+            buffer: Vec::new(),
+            position: 0,
+            pending: Vec::new(),
+            header: true,
+        }
+    }
+
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Check whether a line starts a new frame.
+    fn is_frame_start(line: &[u8]) -> bool {
+        line.iter()
+            .find(|b| !b.is_ascii_whitespace())
+            .map(|&b| b == b'[')
+            .unwrap_or(false)
+    }
+
+    /// Get the prefix of the identifier declared in the `id` clause of a frame.
+    fn id_prefix(frame: &[u8]) -> Option<&str> {
+        let text = std::str::from_utf8(frame).ok()?;
+        let value = text.lines().find_map(|l| l.trim_start().strip_prefix("id:"))?;
+        let id = value.split_whitespace().next()?;
+        Some(id.split_once(':').map(|(prefix, _)| prefix).unwrap_or(""))
+    }
+
+    /// Read the lines until the start of the next frame into `buffer`.
+    fn read_block(&mut self) -> Result<(), IoError> {
+        self.buffer.clear();
+        self.position = 0;
+        self.buffer.append(&mut self.pending);
+        let mut line = Vec::new();
+        loop {
+            line.clear();
+            if self.reader.read_until(b'\n', &mut line)? == 0 {
+                return Ok(());
+            }
+            if Self::is_frame_start(&line) {
+                self.pending.extend_from_slice(&line);
+                return Ok(());
+            }
+            self.buffer.extend_from_slice(&line);
+        }
+    }
+
+    /// Fill `buffer` with the next block to yield, skipping filtered frames.
+    fn fill_block(&mut self) -> Result<(), IoError> {
+        loop {
+            self.read_block()?;
+            if self.buffer.is_empty() || std::mem::take(&mut self.header) {
+                return Ok(());
+            }
+            let prefixes = self.prefixes.as_ref().unwrap();
+            match Self::id_prefix(&self.buffer) {
+                Some(prefix) if !prefixes.contains(prefix) => continue,
+                _ => return Ok(()),
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Read for FrameFilter<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, IoError> {
+        let n = {
+            let data = self.fill_buf()?;
+            let n = data.len().min(buf.len());
+            buf[..n].copy_from_slice(&data[..n]);
+            n
+        };
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl<R: BufRead> BufRead for FrameFilter<R> {
+    fn fill_buf(&mut self) -> Result<&[u8], IoError> {
+        if self.prefixes.is_none() {
+            return self.reader.fill_buf();
+        }
+        if self.position >= self.buffer.len() {
+            self.fill_block()?;
+        }
+        Ok(&self.buffer[self.position..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        if self.prefixes.is_none() {
+            self.reader.consume(amt)
+        } else {
+            self.position += amt;
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+
+/// The default capacity of the buffer used to read OBO documents, in bytes.
+pub const DEFAULT_BUFFER_SIZE: usize = 8 * 1024;
+
+/// Check the capacity of the buffer used to read OBO documents.
+pub fn check_buffer_size(buffer_size: usize) -> PyResult<usize> {
+    if buffer_size == 0 {
+        Err(PyValueError::new_err("buffer size must be strictly positive"))
+    } else {
+        Ok(buffer_size)
+    }
+}
+
+/// Check the number of frames parsed ahead by a `FrameReader`.
+pub fn check_queue_size(queue_size: usize) -> PyResult<usize> {
+    if queue_size == 0 {
+        Err(PyValueError::new_err("queue size must be strictly positive"))
+    } else {
+        Ok(queue_size)
+    }
+}
+
 /// An enum providing the same API for the sequential and threaded parsers from `fastobo`.
 pub enum InternalParser<B: BufRead> {
     Sequential(SequentialParser<B>),
@@ -167,19 +322,110 @@ impl<B: BufRead> Parser<B> for InternalParser<B> {
 
 // ---------------------------------------------------------------------------
 
+/// A background thread joined when dropped.
+struct Worker(Option<JoinHandle<()>>);
+
+impl Worker {
+    /// Wait for the thread to finish, and raise an error if it panicked.
+    fn join(&mut self, py: Python) -> PyResult<()> {
+        match self.0.take() {
+            // release the GIL in case the thread is reading a Python file.
+            Some(handle) => py.allow_threads(|| handle.join()).map_err(|payload| {
+                let msg = if let Some(s) = payload.downcast_ref::<&str>() {
+                    s.to_string()
+                } else if let Some(s) = payload.downcast_ref::<String>() {
+                    s.clone()
+                } else {
+                    String::from("reader thread panicked")
+                };
+                PanicException::new_err(msg)
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for Worker {
+    fn drop(&mut self) {
+        Python::with_gil(|py| {
+            if let Err(err) = self.join(py) {
+                // errors cannot be raised from a destructor, so report
+                // them with `sys.unraisablehook` instead.
+                err.restore(py);
+                unsafe { pyo3::ffi::PyErr_WriteUnraisable(std::ptr::null_mut()) };
+            }
+        })
+    }
+}
+
+/// A queue of frames parsed ahead by a background thread.
+struct FrameQueue {
+    // declared first so that the channel is disconnected before the
+    // worker is joined, which stops it at the next frame it sends.
+    receiver: Mutex<Receiver<PyResult<fastobo::ast::Frame>>>,
+    worker: Worker,
+}
+
+impl FrameQueue {
+    fn new<P>(parser: P, capacity: usize) -> Self
+    where
+        P: Iterator<Item = fastobo::error::Result<fastobo::ast::Frame>> + Send + 'static,
+    {
+        let (sender, receiver) = std::sync::mpsc::sync_channel(capacity);
+        let worker = std::thread::spawn(move || {
+            for result in parser {
+                // errors raised by a Python file-handle are stored in the
+                // state of this thread, and need to be sent explicitly.
+                let item = result.map_err(|e| {
+                    Python::with_gil(PyErr::take)
+                        .unwrap_or_else(|| PyErr::from(Error::from(e)))
+                });
+                let failed = item.is_err();
+                if sender.send(item).is_err() || failed {
+                    break;
+                }
+            }
+        });
+        Self {
+            receiver: Mutex::new(receiver),
+            worker: Worker(Some(worker)),
+        }
+    }
+}
+
+/// The source of the entity frames yielded by a `FrameReader`.
+enum FrameSource {
+    /// Frames parsed on demand while iterating.
+    Parser(Box<InternalParser<FrameFilter<BufReader<Handle>>>>),
+    /// Frames parsed ahead in a background thread.
+    Queue(FrameQueue),
+}
+
 // FIXME: May cause memory leaks?
 /// An iterator over the frames of an OBO document.
 ///
 /// See help(fastobo.iter) for more information.
 #[pyclass(module = "fastobo")]
 pub struct FrameReader {
-    inner: InternalParser<BufReader<Handle>>,
+    source: FrameSource,
     header: Py<HeaderFrame>,
+    tags: Option<HashSet<String>>,
+    handle: PyObject,
 }
 
 impl FrameReader {
-    fn new(handle: BufReader<Handle>, ordered: bool, threads: i16) -> PyResult<Self> {
-        let mut inner = InternalParser::with_thread_count(handle, threads)?;
+    fn new(
+        handle: BufReader<Handle>,
+        ordered: bool,
+        threads: i16,
+        prefixes: Option<HashSet<String>>,
+        tags: Option<HashSet<String>>,
+        queue_size: Option<usize>,
+    ) -> PyResult<Self> {
+        let queue_size = queue_size.map(check_queue_size).transpose()?;
+        let name = handle.get_ref().handle();
+        let filter = FrameFilter::new(handle, prefixes);
+        let mut inner = InternalParser::with_thread_count(filter, threads)?;
         inner.ordered(ordered);
         let frame = inner
             .next()
@@ -188,20 +434,53 @@ impl FrameReader {
             .into_header() 
             .unwrap();
         let header = Python::with_gil(|py| Py::new(py, frame.into_py(py)))?;
-        Ok(Self { inner, header })
+        let source = match queue_size {
+            Some(capacity) => FrameSource::Queue(FrameQueue::new(inner, capacity)),
+            None => FrameSource::Parser(Box::new(inner)),
+        };
+        Ok(Self {
+            source,
+            header,
+            tags,
+            handle: name,
+        })
     }
 
-    pub fn from_path<P: AsRef<Path>>(path: P, ordered: bool, threads: i16) -> PyResult<Self> {
+    pub fn from_path<P: AsRef<Path>>(
+        path: P,
+        ordered: bool,
+        threads: i16,
+        prefixes: Option<HashSet<String>>,
+        tags: Option<HashSet<String>>,
+        buffer_size: usize,
+        queue_size: Option<usize>,
+    ) -> PyResult<Self> {
         let p = path.as_ref();
+        let capacity = check_buffer_size(buffer_size)?;
         match Handle::try_from(p.to_owned()) {
-            Ok(inner) => Self::new(BufReader::new(inner), ordered, threads),
+            Ok(inner) => {
+                let reader = BufReader::with_capacity(capacity, inner);
+                Self::new(reader, ordered, threads, prefixes, tags, queue_size)
+            }
             Err(e) => Error::from(e).with_path(p.display().to_string()).into(),
         }
     }
 
-    pub fn from_handle(obj: &PyAny, ordered: bool, threads: i16) -> PyResult<Self> {
+    pub fn from_handle(
+        obj: &PyAny,
+        ordered: bool,
+        threads: i16,
+        prefixes: Option<HashSet<String>>,
+        tags: Option<HashSet<String>>,
+        buffer_size: usize,
+        queue_size: Option<usize>,
+    ) -> PyResult<Self> {
+        let capacity = check_buffer_size(buffer_size)?;
         match PyFileGILRead::from_ref(obj).map(Handle::PyFile) {
-            Ok(inner) => Self::new(BufReader::new(inner), ordered, threads),
+            Ok(inner) => {
+                let reader = BufReader::with_capacity(capacity, inner);
+                Self::new(reader, ordered, threads, prefixes, tags, queue_size)
+            }
             Err(e) => Err(e),
         }
     }
@@ -212,7 +491,7 @@ impl FrameReader {
     fn __repr__(&self) -> PyResult<PyObject> {
         Python::with_gil(|py| {
             let fmt = PyString::new(py, "fastobo.iter({!r})").to_object(py);
-            fmt.call_method1(py, "format", (&self.inner.as_ref().get_ref().handle(),))
+            fmt.call_method1(py, "format", (&self.handle,))
         })
     }
 
@@ -220,22 +499,39 @@ impl FrameReader {
         Ok(slf)
     }
 
-    fn __next__(mut slf: PyRefMut<'_, Self>) -> PyResult<Option<EntityFrame>> {
-        match slf.deref_mut().inner.next() {
-            None => Ok(None),
-            Some(Ok(frame)) => {
-                let entity = frame.into_entity().unwrap();
-                Ok(Some(Python::with_gil(|py| entity.into_py(py))))
-            }
-            Some(Err(e)) => {
-                Python::with_gil(|py| {
+    fn __next__(mut slf: PyRefMut<'_, Self>, py: Python) -> PyResult<Option<EntityFrame>> {
+        let next = match &mut slf.deref_mut().source {
+            FrameSource::Parser(parser) => parser.next().map(|result| {
+                result.map_err(|e| {
                     if PyErr::occurred(py) {
-                        Err(PyErr::fetch(py))
+                        PyErr::fetch(py)
                     } else {
-                        Err(Error::from(e).into())
+                        Error::from(e).into()
                     }
                 })
+            }),
+            FrameSource::Queue(queue) => {
+                // release the GIL so that the worker can read a Python file.
+                let receiver = &queue.receiver;
+                match py.allow_threads(|| receiver.lock().unwrap().recv()) {
+                    Ok(result) => Some(result),
+                    Err(_) => {
+                        queue.worker.join(py)?;
+                        None
+                    }
+                }
+            }
+        };
+        match next {
+            None => Ok(None),
+            Some(Ok(frame)) => {
+                let mut entity = frame.into_entity().unwrap();
+                if let Some(tags) = &slf.tags {
+                    crate::transform::retain_tags(&mut entity, tags);
+                }
+                Ok(Some(entity.into_py(py)))
             }
+            Some(Err(err)) => Err(err),
         }
     }
 
@@ -243,3 +539,170 @@ impl FrameReader {
         self.header.clone_py(py)
     }
 }
+
+// ---------------------------------------------------------------------------
+
+/// The number of nodes buffered by a `GraphReader` ahead of the iteration.
+const GRAPH_QUEUE_SIZE: usize = 16;
+
+/// The level of an OBO graph document visited by a `NodeStream`.
+#[derive(Clone, Copy)]
+enum Level {
+    Document,
+    Graphs,
+    Graph,
+    Nodes,
+}
+
+/// A deserializer sending the nodes of an OBO graph document to a channel.
+///
+/// Only the `nodes` of each graph are deserialized, one at a time, while
+/// any other field is skipped without being allocated.
+#[derive(Clone, Copy)]
+struct NodeStream<'a> {
+    level: Level,
+    sender: &'a SyncSender<PyResult<Node>>,
+}
+
+impl<'a> NodeStream<'a> {
+    fn at(self, level: Level) -> Self {
+        Self { level, ..self }
+    }
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for NodeStream<'a> {
+    type Value = ();
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        match self.level {
+            Level::Document | Level::Graph => deserializer.deserialize_map(self),
+            Level::Graphs | Level::Nodes => deserializer.deserialize_seq(self),
+        }
+    }
+}
+
+impl<'de, 'a> Visitor<'de> for NodeStream<'a> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.level {
+            Level::Document => f.write_str("an OBO graph document"),
+            Level::Graphs => f.write_str("a list of OBO graphs"),
+            Level::Graph => f.write_str("an OBO graph"),
+            Level::Nodes => f.write_str("a list of OBO graph nodes"),
+        }
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let (field, next) = match self.level {
+            Level::Document => ("graphs", Level::Graphs),
+            _ => ("nodes", Level::Nodes),
+        };
+        while let Some(key) = map.next_key::<String>()? {
+            if key == field {
+                map.next_value_seed(self.at(next))?;
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        match self.level {
+            Level::Graphs => {
+                while seq.next_element_seed(self.at(Level::Graph))?.is_some() {}
+            }
+            _ => {
+                while let Some(node) = seq.next_element::<Node>()? {
+                    if self.sender.send(Ok(node)).is_err() {
+                        return Err(A::Error::custom("disconnected graph reader"));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An iterator over the entity frames of an OBO graph document.
+///
+/// See help(fastobo.iter_graph) for more information.
+#[pyclass(module = "fastobo")]
+pub struct GraphReader {
+    // declared first so that the channel is disconnected before the
+    // worker is joined, which stops it at the next node it sends.
+    receiver: Mutex<Receiver<PyResult<Node>>>,
+    worker: Worker,
+    handle: PyObject,
+}
+
+impl GraphReader {
+    fn new(handle: Handle, compression: Compression) -> PyResult<Self> {
+        let name = handle.handle();
+        let reader = Decoder::new(BufReader::new(handle), compression)?;
+        let (sender, receiver) = std::sync::mpsc::sync_channel(GRAPH_QUEUE_SIZE);
+        let worker = std::thread::spawn(move || {
+            let mut de = serde_json::Deserializer::from_reader(reader);
+            let stream = NodeStream {
+                level: Level::Document,
+                sender: &sender,
+            };
+            if let Err(e) = stream.deserialize(&mut de) {
+                // errors raised by a Python file-handle are stored in the
+                // state of this thread, and need to be sent explicitly.
+                let err = Python::with_gil(PyErr::take)
+                    .unwrap_or_else(|| PyValueError::new_err(e.to_string()));
+                let _ = sender.send(Err(err));
+            }
+        });
+        Ok(Self {
+            receiver: Mutex::new(receiver),
+            worker: Worker(Some(worker)),
+            handle: name,
+        })
+    }
+
+    pub fn from_path<P: AsRef<Path>>(path: P, compression: Compression) -> PyResult<Self> {
+        let p = path.as_ref();
+        match Handle::try_from(p.to_owned()) {
+            Ok(inner) => Self::new(inner, compression),
+            Err(e) => Error::from(e).with_path(p.display().to_string()).into(),
+        }
+    }
+
+    pub fn from_handle(obj: &PyAny, compression: Compression) -> PyResult<Self> {
+        let inner = PyFileGILRead::from_ref(obj).map(Handle::PyFile)?;
+        Self::new(inner, compression)
+    }
+}
+
+#[pymethods]
+impl GraphReader {
+    fn __repr__(&self, py: Python) -> PyResult<PyObject> {
+        let fmt = PyString::new(py, "fastobo.iter_graph({!r})").to_object(py);
+        fmt.call_method1(py, "format", (&self.handle,))
+    }
+
+    fn __iter__(slf: PyRefMut<'_, Self>) -> PyResult<PyRefMut<'_, Self>> {
+        Ok(slf)
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>, py: Python) -> PyResult<Option<EntityFrame>> {
+        // release the GIL so that the reader thread can read a Python file.
+        let receiver = &slf.receiver;
+        while let Ok(result) = py.allow_threads(|| receiver.lock().unwrap().recv()) {
+            let node = result?;
+            let meta = node.meta.clone();
+            let frame = crate::graph::node_to_frame(node).map_err(GraphError::from)?;
+            if let Some(mut frame) = frame {
+                if let Some(meta) = meta {
+                    crate::graph::add_node_clauses(&mut frame, &meta);
+                }
+                return Ok(Some(frame.into_py(py)));
+            }
+        }
+        // the channel is closed: report a panic of the reader thread
+        slf.worker.join(py)?;
+        Ok(None)
+    }
+}
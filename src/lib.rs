@@ -6,19 +6,36 @@ extern crate pyo3;
 #[macro_use]
 extern crate pyo3_built;
 extern crate libc;
+extern crate rusqlite;
+extern crate arrow;
 #[macro_use]
 extern crate fastobo_py_derive_internal;
 extern crate fastobo_graphs;
 extern crate fastobo_owl;
+extern crate flate2;
+extern crate serde;
+extern crate serde_json;
 extern crate horned_functional;
 extern crate horned_owl;
 
 #[macro_use]
 pub mod macros;
 pub mod built;
+pub mod comments;
+pub mod compression;
+pub mod convert;
 pub mod date;
+pub mod dict;
 pub mod error;
+pub mod graph;
+pub mod index;
 pub mod iter;
+pub mod location;
+pub mod owl;
 pub mod py;
 pub mod pyfile;
+pub mod rdf;
+pub mod sqlite;
+pub mod table;
+pub mod transform;
 pub mod utils;
@@ -0,0 +1,82 @@
+/// The location of a frame in a source document.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Location {
+    /// The path to the source file, if the document was loaded from a file.
+    pub file: Option<String>,
+    /// The line of the first line of the frame, starting from 1.
+    pub start: usize,
+    /// The line of the last clause of the frame, starting from 1.
+    pub end: usize,
+}
+
+/// The lines of a frame and of its clauses in a source document.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FrameLines {
+    /// The line of the first line of the frame.
+    pub start: usize,
+    /// The line of the last clause of the frame.
+    pub end: usize,
+    /// The line of each clause, in frame order.
+    pub clauses: Vec<usize>,
+}
+
+impl FrameLines {
+    /// Get the location of the frame in the given file.
+    pub fn location(&self, file: Option<&str>) -> Location {
+        Location {
+            file: file.map(String::from),
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+/// The lines of the frames of an OBO document.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SourceLines {
+    /// The lines of the header frame, or `None` if the header is empty.
+    pub header: Option<FrameLines>,
+    /// The lines of each entity frame, in document order.
+    pub entities: Vec<FrameLines>,
+}
+
+/// Extract the line numbers of the frames and clauses of an OBO document.
+///
+/// Line numbers start from 1. Blank lines and comment lines are ignored,
+/// so the end of a frame is the line of its last clause.
+pub fn scan(text: &str) -> SourceLines {
+    let mut lines = SourceLines::default();
+    let mut seen_id = true;
+
+    for (i, line) in text.lines().enumerate() {
+        let number = i + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('!') {
+            continue;
+        } else if line.starts_with('[') {
+            lines.entities.push(FrameLines {
+                start: number,
+                end: number,
+                clauses: Vec::new(),
+            });
+            seen_id = false;
+        } else {
+            let frame = match lines.entities.last_mut() {
+                Some(frame) => frame,
+                None => lines.header.get_or_insert_with(|| FrameLines {
+                    start: number,
+                    end: number,
+                    clauses: Vec::new(),
+                }),
+            };
+            frame.end = number;
+            if seen_id {
+                frame.clauses.push(number);
+            } else {
+                seen_id = true;
+            }
+        }
+    }
+
+    lines
+}
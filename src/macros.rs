@@ -8,40 +8,26 @@ macro_rules! impl_hash {
     });
 }
 
-macro_rules! impl_richcmp {
-    ($self:ident, $other:ident, $op:ident, $(self . $attr:ident)&&*) => ({
-        match $op {
-            $crate::pyo3::class::basic::CompareOp::Eq => {
-                let py = $other.py();
-                if let Ok(ref clause) = $other.extract::<Py<Self>>() {
-                    let clause = clause.as_ref(py).borrow();
-                    let res = $($self.$attr == clause.$attr)&&*;
-                    Ok(res.to_object(py))
-                } else {
-                    Ok(false.to_object(py))
-                }
+macro_rules! impl_display_py {
+    ($cls:ident $(, $field:ident)*) => {
+        impl $crate::utils::DisplayPy for $cls {
+            fn fmt_py(&self, py: Python, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str($cls::TAG)?;
+                f.write_str(":")?;
+                $(
+                    f.write_str(" ")?;
+                    $crate::utils::DisplayPy::fmt_py(&self.$field, py, f)?;
+                )*
+                Ok(())
             }
-            _ => Ok($other.py().NotImplemented())
         }
-    });
-}
 
-macro_rules! impl_richcmp_py {
-    ($self:ident, $other:ident, $op:ident, $(self . $attr:ident)&&*) => ({
-        match $op {
-            $crate::pyo3::class::basic::CompareOp::Eq => {
-                let py = $other.py();
-                if let Ok(ref clause) = $other.extract::<Py<Self>>() {
-                    let clause = clause.as_ref(py).borrow();
-                    let res = $($self.$attr.eq_py(&clause.$attr, py))&&*;
-                    Ok(res.to_object(py))
-                } else {
-                    Ok(false.to_object(py))
-                }
+        impl std::fmt::Display for $cls {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                Python::with_gil(|py| $crate::utils::DisplayPy::fmt_py(self, py, f))
             }
-            _ => Ok($other.py().NotImplemented())
         }
-    });
+    };
 }
 
 macro_rules! impl_repr {
@@ -0,0 +1,631 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::PyResult;
+
+use fastobo::ast as obo;
+use fastobo_owl::IntoOwl;
+use horned_functional::AsFunctional;
+use horned_functional::Context;
+use horned_owl::model::Annotation;
+use horned_owl::model::AnnotationSubject;
+use horned_owl::model::AnnotationValue;
+use horned_owl::model::Axiom;
+use horned_owl::model::Build;
+use horned_owl::model::ClassExpression;
+use horned_owl::model::Individual;
+use horned_owl::model::Literal;
+use horned_owl::model::MutableOntology;
+use horned_owl::model::ObjectPropertyExpression;
+use horned_owl::model::Ontology;
+use horned_owl::model::SubClassOf;
+use horned_owl::model::SubObjectPropertyExpression;
+use horned_owl::ontology::axiom_mapped::AxiomMappedOntology;
+use horned_owl::ontology::set::SetOntology;
+
+use crate::error::OwlError;
+
+/// A general class inclusion axiom declared with `relationship` qualifiers.
+///
+/// The OBO clause `relationship: R T {gci_relation="GR", gci_filler="GF"}`
+/// in the frame of class `C` translates to the OWL axiom
+/// `SubClassOf(ObjectIntersectionOf(C ObjectSomeValuesFrom(GR GF)) ObjectSomeValuesFrom(R T))`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gci {
+    pub class: obo::ClassIdent,
+    pub relation: obo::RelationIdent,
+    pub target: obo::ClassIdent,
+    pub gci_relation: obo::RelationIdent,
+    pub gci_filler: obo::ClassIdent,
+}
+
+/// Extract the GCI condition declared in the qualifiers of a clause, if any.
+fn gci_condition(line: &obo::Line<obo::TermClause>) -> Option<(obo::RelationIdent, obo::ClassIdent)> {
+    let mut relation = None;
+    let mut filler = None;
+    for qualifier in line.qualifiers()?.iter() {
+        match qualifier.key().to_string().as_str() {
+            "gci_relation" => relation = obo::RelationIdent::from_str(qualifier.value().as_str()).ok(),
+            "gci_filler" => filler = obo::ClassIdent::from_str(qualifier.value().as_str()).ok(),
+            _ => (),
+        }
+    }
+    Some((relation?, filler?))
+}
+
+/// Remove the `relationship` clauses with GCI qualifiers from `doc`.
+///
+/// The OBO to OWL translation of `fastobo-owl` ignores qualifiers, and
+/// would translate these clauses to unconditional existential restrictions,
+/// so they are extracted to be translated separately with `add_gcis`.
+pub fn take_gcis(doc: &mut obo::OboDoc) -> Vec<Gci> {
+    let mut gcis = Vec::new();
+    for frame in doc.entities_mut() {
+        if let obo::EntityFrame::Term(term) = frame {
+            let class = term.id().as_ref().clone();
+            term.clauses_mut().retain(|line| {
+                let (relation, target) = match line.as_ref() {
+                    obo::TermClause::Relationship(r, t) => (r, t),
+                    _ => return true,
+                };
+                match gci_condition(line) {
+                    Some((gci_relation, gci_filler)) => {
+                        gcis.push(Gci {
+                            class: class.clone(),
+                            relation: relation.as_ref().clone(),
+                            target: target.as_ref().clone(),
+                            gci_relation,
+                            gci_filler,
+                        });
+                        false
+                    }
+                    None => true,
+                }
+            });
+        }
+    }
+    gcis
+}
+
+/// The mapping from OBO identifiers to IRIs of the OBO to OWL translation.
+pub struct IriMapper {
+    ontology: String,
+    idspaces: HashMap<String, String>,
+    shorthands: HashMap<String, obo::Ident>,
+}
+
+impl IriMapper {
+    /// Create a new mapper using the header and typedefs of `doc`.
+    ///
+    /// Typedefs with an unprefixed identifier and a prefixed `xref` are
+    /// shorthands, which are translated using the IRI of the `xref`.
+    pub fn new(doc: &obo::OboDoc) -> Self {
+        let mut ontology = String::new();
+        let mut idspaces = HashMap::new();
+        for clause in doc.header().iter() {
+            match clause {
+                obo::HeaderClause::Ontology(name) => ontology = name.as_str().to_string(),
+                obo::HeaderClause::Idspace(prefix, url, _) => {
+                    idspaces.insert(prefix.as_str().to_string(), url.as_str().to_string());
+                }
+                _ => (),
+            }
+        }
+
+        let mut shorthands = HashMap::new();
+        for frame in doc.entities() {
+            if let obo::EntityFrame::Typedef(typedef) = frame {
+                if let obo::Ident::Unprefixed(id) = typedef.id().as_ref().as_ref() {
+                    let xref = typedef.clauses().iter().find_map(|line| match line.as_ref() {
+                        obo::TypedefClause::Xref(x) if matches!(x.id(), obo::Ident::Prefixed(_)) => {
+                            Some(x.id().clone())
+                        }
+                        _ => None,
+                    });
+                    if let Some(xref) = xref {
+                        shorthands.insert(id.as_str().to_string(), xref);
+                    }
+                }
+            }
+        }
+
+        Self {
+            ontology,
+            idspaces,
+            shorthands,
+        }
+    }
+
+    /// Get the IRI of an identifier.
+    pub fn iri(&self, id: &obo::Ident) -> String {
+        match id {
+            obo::Ident::Url(url) => url.as_str().to_string(),
+            obo::Ident::Prefixed(id) => match self.idspaces.get(id.prefix()) {
+                Some(url) => format!("{}{}", url, id.local()),
+                None => format!("http://purl.obolibrary.org/obo/{}_{}", id.prefix(), id.local()),
+            },
+            obo::Ident::Unprefixed(id) => match self.shorthands.get(id.as_str()) {
+                Some(xref) => self.iri(xref),
+                None => format!("http://purl.obolibrary.org/obo/{}#{}", self.ontology, id.as_str()),
+            },
+        }
+    }
+}
+
+/// Add the OWL axioms corresponding to `gcis` to an ontology.
+pub fn add_gcis(ont: &mut AxiomMappedOntology, mapper: &IriMapper, gcis: Vec<Gci>) {
+    let build = Build::new();
+    let class = |id: obo::ClassIdent| ClassExpression::Class(build.class(mapper.iri(id.as_ref())));
+    let property = |id: obo::RelationIdent| {
+        ObjectPropertyExpression::ObjectProperty(build.object_property(mapper.iri(id.as_ref())))
+    };
+    for gci in gcis {
+        let condition = ClassExpression::ObjectSomeValuesFrom {
+            ope: property(gci.gci_relation),
+            bce: Box::new(class(gci.gci_filler)),
+        };
+        ont.insert(SubClassOf {
+            sub: ClassExpression::ObjectIntersectionOf(vec![class(gci.class), condition]),
+            sup: ClassExpression::ObjectSomeValuesFrom {
+                ope: property(gci.relation),
+                bce: Box::new(class(gci.target)),
+            },
+        });
+    }
+}
+
+/// Get the name of an ontology from its IRI.
+///
+/// The name of the ontology is the last segment of the IRI, without its
+/// `.owl` extension.
+fn ontology_name(iri: &str) -> &str {
+    let segment = iri.trim_end_matches(['/', '#']).rsplit(['/', '#']).next().unwrap_or(iri);
+    segment.strip_suffix(".owl").unwrap_or(segment)
+}
+
+/// Add an `ontology` clause derived from `iri` to `doc` if it has none.
+///
+/// The OBO to OWL translation requires an `ontology` clause, which is used
+/// to build the IRIs of unprefixed identifiers.
+pub fn ensure_ontology(doc: &mut obo::OboDoc, iri: &str) {
+    let declared = doc
+        .header()
+        .iter()
+        .any(|clause| matches!(clause, obo::HeaderClause::Ontology(_)));
+    if !declared {
+        let name = ontology_name(iri);
+        let clause = obo::HeaderClause::Ontology(Box::new(obo::UnquotedString::new(name)));
+        doc.header_mut().push(clause);
+    }
+}
+
+/// Override the ontology IRI and the version IRI of an ontology.
+pub fn set_ontology_id(ont: &mut AxiomMappedOntology, iri: Option<&str>, version_iri: Option<&str>) {
+    let build = Build::new();
+    if let Some(iri) = iri {
+        ont.mut_id().iri = Some(build.iri(iri));
+    }
+    if let Some(viri) = version_iri {
+        ont.mut_id().viri = Some(build.iri(viri));
+    }
+}
+
+/// Get the header used to translate a frame outside of a document.
+///
+/// The header declares an `obo` ontology and default namespace, so that
+/// prefixed identifiers are translated to the usual OBO Library IRIs.
+pub fn default_header() -> obo::HeaderFrame {
+    let namespace = obo::NamespaceIdent::from(obo::Ident::from(obo::UnprefixedIdent::new("obo")));
+    let mut header = obo::HeaderFrame::new();
+    header.push(obo::HeaderClause::Ontology(Box::new(obo::UnquotedString::new("obo"))));
+    header.push(obo::HeaderClause::DefaultNamespace(Box::new(namespace)));
+    header
+}
+
+/// Translate a single entity frame to OWL, and render the resulting axioms.
+///
+/// The axioms are rendered in OWL2 Functional-style syntax using the
+/// prefixes declared in `header`. Ontology annotations translated from
+/// the header are skipped, and the axioms are sorted so that the output
+/// does not depend on the internal order of the ontology.
+pub fn frame_to_ofn(
+    header: obo::HeaderFrame,
+    frame: obo::EntityFrame,
+) -> Result<Vec<String>, fastobo_owl::Error> {
+    let mut doc = obo::OboDoc::with_header(header);
+    doc.entities_mut().push(frame);
+
+    let prefixes = doc.prefixes();
+    let mapper = IriMapper::new(&doc);
+    let gcis = take_gcis(&mut doc);
+    let mut ont = doc.into_owl::<AxiomMappedOntology>()?;
+    add_gcis(&mut ont, &mapper, gcis);
+
+    let ctx = Context::from(&prefixes);
+    let mut axioms = ont
+        .i()
+        .iter()
+        .filter(|ax| !matches!(ax.axiom, Axiom::OntologyAnnotation(_) | Axiom::Import(_)))
+        .map(|ax| {
+            // an empty annotation set is rendered as a leading space
+            if ax.ann.is_empty() {
+                ax.axiom.as_ofn_ctx(&ctx).to_string()
+            } else {
+                ax.as_ofn_ctx(&ctx).to_string()
+            }
+        })
+        .collect::<Vec<String>>();
+    axioms.sort();
+    Ok(axioms)
+}
+
+/// Convert an OBO document to an OWL ontology.
+///
+/// `ontology_iri` and `version_iri` override the identifier of the
+/// ontology translated from `doc`.
+pub fn to_owl(
+    mut doc: obo::OboDoc,
+    ontology_iri: Option<&str>,
+    version_iri: Option<&str>,
+) -> PyResult<AxiomMappedOntology> {
+    if let Some(iri) = ontology_iri {
+        ensure_ontology(&mut doc, iri);
+    }
+    let mapper = IriMapper::new(&doc);
+    let gcis = take_gcis(&mut doc);
+    let mut ont = doc.into_owl::<AxiomMappedOntology>().map_err(OwlError::from)?;
+    add_gcis(&mut ont, &mapper, gcis);
+    set_ontology_id(&mut ont, ontology_iri, version_iri);
+    Ok(ont)
+}
+
+/// Convert an OBO document to OWL, and render it in Functional-style syntax.
+///
+/// The `prefixes` are merged over the prefixes derived from `doc`, with an
+/// empty key setting the default prefix. `ontology_iri` and `version_iri`
+/// override the identifier of the ontology translated from `doc`.
+pub fn to_ofn(
+    mut doc: obo::OboDoc,
+    prefixes: Option<HashMap<String, String>>,
+    ontology_iri: Option<&str>,
+    version_iri: Option<&str>,
+) -> PyResult<String> {
+    if let Some(iri) = ontology_iri {
+        ensure_ontology(&mut doc, iri);
+    }
+    let mut mapping = doc.prefixes();
+    let mut default = None;
+    for (prefix, iri) in prefixes.unwrap_or_default() {
+        if prefix.is_empty() {
+            mapping.set_default(&iri);
+            default = Some(iri);
+        } else if let Err(e) = mapping.add_prefix(&prefix, &iri) {
+            let msg = format!("invalid prefix {:?}: {:?}", prefix, e);
+            return Err(PyValueError::new_err(msg));
+        }
+    }
+    let ont = to_owl(doc, ontology_iri, version_iri)?;
+
+    // the default prefix is not listed in the mappings, so write it here
+    let ctx = Context::from(&mapping);
+    let default = default
+        .map(|iri| format!("Prefix(:=<{}>)\n", iri))
+        .unwrap_or_default();
+    Ok(format!("{}{}{}", default, mapping.as_ofn(), ont.as_ofn_ctx(&ctx)))
+}
+
+// ---
+
+const RDFS_LABEL: &str = "http://www.w3.org/2000/01/rdf-schema#label";
+const RDFS_COMMENT: &str = "http://www.w3.org/2000/01/rdf-schema#comment";
+const OWL_DEPRECATED: &str = "http://www.w3.org/2002/07/owl#deprecated";
+const IAO_DEFINITION: &str = "http://purl.obolibrary.org/obo/IAO_0000115";
+const OBO_IN_OWL: &str = "http://www.geneontology.org/formats/oboInOwl#";
+const OBO_PURL: &str = "http://purl.obolibrary.org/obo/";
+
+/// Get the OBO identifier of an IRI.
+///
+/// This reverses the default rules of the OBO to OWL translation: OBO
+/// Library PURLs become prefixed identifiers, or unprefixed identifiers
+/// when they use the `{ontology}#{id}` form, and other IRIs are kept as
+/// URL identifiers.
+fn owl_ident<S: Display>(iri: S) -> obo::Ident {
+    let iri = iri.to_string();
+    if let Some((_, id)) = iri.strip_prefix(OBO_PURL).and_then(|rest| rest.split_once('#')) {
+        return obo::Ident::from(obo::UnprefixedIdent::new(id));
+    }
+    if let Some(id) = crate::py::id::compact_url(&iri, &HashMap::new()) {
+        return obo::Ident::from(id);
+    }
+    match obo::Url::from_str(&iri) {
+        Ok(url) => obo::Ident::from(url),
+        Err(_) => obo::Ident::from(obo::UnprefixedIdent::new(iri)),
+    }
+}
+
+/// Get the text of a literal annotation value.
+fn literal_text(value: &AnnotationValue) -> Option<&str> {
+    match value {
+        AnnotationValue::Literal(Literal::Simple { literal })
+        | AnnotationValue::Literal(Literal::Language { literal, .. })
+        | AnnotationValue::Literal(Literal::Datatype { literal, .. }) => Some(literal.as_str()),
+        AnnotationValue::IRI(_) => None,
+    }
+}
+
+/// An OBO clause translated from an OWL annotation, shared by all frames.
+enum AnnotationClause {
+    Name(String),
+    Def(String),
+    Comment(String),
+    IsObsolete(bool),
+    Synonym(String, obo::SynonymScope),
+    Namespace(String),
+    Xref(obo::Ident),
+}
+
+impl AnnotationClause {
+    /// Translate an annotation back to the OBO clause it was produced from.
+    fn new(annotation: &Annotation) -> Option<Self> {
+        let property = annotation.ap.0.to_string();
+        let text = literal_text(&annotation.av);
+        let clause = match property.as_str() {
+            RDFS_LABEL => AnnotationClause::Name(text?.to_string()),
+            IAO_DEFINITION => AnnotationClause::Def(text?.to_string()),
+            RDFS_COMMENT => AnnotationClause::Comment(text?.to_string()),
+            OWL_DEPRECATED => AnnotationClause::IsObsolete(text? == "true"),
+            _ => match property.strip_prefix(OBO_IN_OWL)? {
+                "hasExactSynonym" => AnnotationClause::Synonym(text?.to_string(), obo::SynonymScope::Exact),
+                "hasBroadSynonym" => AnnotationClause::Synonym(text?.to_string(), obo::SynonymScope::Broad),
+                "hasNarrowSynonym" => AnnotationClause::Synonym(text?.to_string(), obo::SynonymScope::Narrow),
+                "hasRelatedSynonym" => {
+                    AnnotationClause::Synonym(text?.to_string(), obo::SynonymScope::Related)
+                }
+                "hasOBONamespace" => AnnotationClause::Namespace(text?.to_string()),
+                "hasDbXref" => AnnotationClause::Xref(obo::Ident::from_str(text?).ok()?),
+                _ => return None,
+            },
+        };
+        Some(clause)
+    }
+}
+
+/// Convert an `AnnotationClause` to a clause of the given frame type.
+macro_rules! annotation_clause {
+    ($clause:ident, $annotation:expr) => {
+        match $annotation {
+            AnnotationClause::Name(name) => obo::$clause::Name(Box::new(obo::UnquotedString::new(name))),
+            AnnotationClause::Def(text) => obo::$clause::Def(Box::new(obo::Definition::with_xrefs(
+                obo::QuotedString::new(text),
+                obo::XrefList::new(Vec::new()),
+            ))),
+            AnnotationClause::Comment(text) => {
+                obo::$clause::Comment(Box::new(obo::UnquotedString::new(text)))
+            }
+            AnnotationClause::IsObsolete(obsolete) => obo::$clause::IsObsolete(obsolete),
+            AnnotationClause::Synonym(text, scope) => {
+                obo::$clause::Synonym(Box::new(obo::Synonym::with_type_and_xrefs(
+                    obo::QuotedString::new(text),
+                    scope,
+                    None,
+                    obo::XrefList::new(Vec::new()),
+                )))
+            }
+            AnnotationClause::Namespace(namespace) => {
+                let id = obo::Ident::from(obo::UnprefixedIdent::new(namespace));
+                obo::$clause::Namespace(Box::new(obo::NamespaceIdent::from(id)))
+            }
+            AnnotationClause::Xref(id) => obo::$clause::Xref(Box::new(obo::Xref::with_desc(id, None))),
+        }
+    };
+}
+
+/// A builder for the entity frames of the OWL to OBO translation.
+#[derive(Default)]
+struct FrameBuilder {
+    frames: Vec<obo::EntityFrame>,
+    index: HashMap<String, usize>,
+}
+
+impl FrameBuilder {
+    /// Declare a frame for an IRI, unless it was already declared.
+    fn declare<F>(&mut self, iri: String, frame: F)
+    where
+        F: FnOnce(obo::Ident) -> obo::EntityFrame,
+    {
+        if !self.index.contains_key(&iri) {
+            self.index.insert(iri.clone(), self.frames.len());
+            self.frames.push(frame(owl_ident(&iri)));
+        }
+    }
+
+    /// Get the frame declared for an IRI, if any.
+    fn frame(&mut self, iri: &str) -> Option<&mut obo::EntityFrame> {
+        let index = *self.index.get(iri)?;
+        self.frames.get_mut(index)
+    }
+
+    fn term(&mut self, iri: &str) -> Option<&mut obo::TermFrame> {
+        match self.frame(iri)? {
+            obo::EntityFrame::Term(term) => Some(term),
+            _ => None,
+        }
+    }
+
+    fn typedef(&mut self, iri: &str) -> Option<&mut obo::TypedefFrame> {
+        match self.frame(iri)? {
+            obo::EntityFrame::Typedef(typedef) => Some(typedef),
+            _ => None,
+        }
+    }
+
+    fn instance(&mut self, iri: &str) -> Option<&mut obo::InstanceFrame> {
+        match self.frame(iri)? {
+            obo::EntityFrame::Instance(instance) => Some(instance),
+            _ => None,
+        }
+    }
+
+    /// Add the clause translated from an annotation to the frame of an IRI.
+    fn annotate(&mut self, iri: &str, annotation: &Annotation) {
+        let clause = match AnnotationClause::new(annotation) {
+            Some(clause) => clause,
+            None => return,
+        };
+        match self.frame(iri) {
+            Some(obo::EntityFrame::Term(term)) => {
+                let clause = annotation_clause!(TermClause, clause);
+                term.clauses_mut().push(obo::Line::new().and_inner(clause));
+            }
+            Some(obo::EntityFrame::Typedef(typedef)) => {
+                let clause = annotation_clause!(TypedefClause, clause);
+                typedef.clauses_mut().push(obo::Line::new().and_inner(clause));
+            }
+            Some(obo::EntityFrame::Instance(instance)) => {
+                let clause = annotation_clause!(InstanceClause, clause);
+                instance.clauses_mut().push(obo::Line::new().and_inner(clause));
+            }
+            None => (),
+        }
+    }
+
+    /// Add the `is_a` or `relationship` clause translated from a subclass axiom.
+    fn subclass(&mut self, sub: &ClassExpression, sup: &ClassExpression) {
+        let sub = match sub {
+            ClassExpression::Class(c) => c.0.to_string(),
+            _ => return,
+        };
+        let clause = match sup {
+            ClassExpression::Class(c) => {
+                obo::TermClause::IsA(Box::new(obo::ClassIdent::from(owl_ident(&c.0))))
+            }
+            ClassExpression::ObjectSomeValuesFrom {
+                ope: ObjectPropertyExpression::ObjectProperty(op),
+                bce,
+            } => match bce.as_ref() {
+                ClassExpression::Class(c) => obo::TermClause::Relationship(
+                    Box::new(obo::RelationIdent::from(owl_ident(&op.0))),
+                    Box::new(obo::ClassIdent::from(owl_ident(&c.0))),
+                ),
+                _ => return,
+            },
+            _ => return,
+        };
+        if let Some(term) = self.term(&sub) {
+            term.clauses_mut().push(obo::Line::new().and_inner(clause));
+        }
+    }
+
+    /// Add the clauses translated from an axiom to the declared frames.
+    fn axiom(&mut self, axiom: &Axiom) {
+        match axiom {
+            Axiom::AnnotationAssertion(ax) => {
+                if let AnnotationSubject::IRI(iri) = &ax.subject {
+                    self.annotate(iri.as_ref(), &ax.ann);
+                }
+            }
+            Axiom::SubClassOf(ax) => self.subclass(&ax.sub, &ax.sup),
+            Axiom::SubObjectPropertyOf(ax) => {
+                let sub = match &ax.sub {
+                    SubObjectPropertyExpression::ObjectPropertyExpression(
+                        ObjectPropertyExpression::ObjectProperty(op),
+                    ) => op.0.to_string(),
+                    _ => return,
+                };
+                if let ObjectPropertyExpression::ObjectProperty(sup) = &ax.sup {
+                    let id = obo::RelationIdent::from(owl_ident(&sup.0));
+                    if let Some(typedef) = self.typedef(&sub) {
+                        let clause = obo::TypedefClause::IsA(Box::new(id));
+                        typedef.clauses_mut().push(obo::Line::new().and_inner(clause));
+                    }
+                }
+            }
+            Axiom::TransitiveObjectProperty(ax) => {
+                if let ObjectPropertyExpression::ObjectProperty(op) = &ax.0 {
+                    if let Some(typedef) = self.typedef(op.0.as_ref()) {
+                        let clause = obo::TypedefClause::IsTransitive(true);
+                        typedef.clauses_mut().push(obo::Line::new().and_inner(clause));
+                    }
+                }
+            }
+            Axiom::ClassAssertion(ax) => {
+                if let (Individual::Named(i), ClassExpression::Class(c)) = (&ax.i, &ax.ce) {
+                    let id = obo::ClassIdent::from(owl_ident(&c.0));
+                    if let Some(instance) = self.instance(i.0.as_ref()) {
+                        let clause = obo::InstanceClause::InstanceOf(Box::new(id));
+                        instance.clauses_mut().push(obo::Line::new().and_inner(clause));
+                    }
+                }
+            }
+            Axiom::ObjectPropertyAssertion(ax) => {
+                let (from, to) = match (&ax.from, &ax.to) {
+                    (Individual::Named(from), Individual::Named(to)) => (from, to),
+                    _ => return,
+                };
+                if let ObjectPropertyExpression::ObjectProperty(op) = &ax.ope {
+                    let relation = obo::RelationIdent::from(owl_ident(&op.0));
+                    let target = obo::InstanceIdent::from(owl_ident(&to.0));
+                    if let Some(instance) = self.instance(from.0.as_ref()) {
+                        let clause = obo::InstanceClause::Relationship(Box::new(relation), Box::new(target.into()));
+                        instance.clauses_mut().push(obo::Line::new().and_inner(clause));
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Convert an OWL ontology to an OBO document.
+///
+/// This reverses the OBO to OWL translation for the common constructs:
+/// declared classes, object properties and named individuals become
+/// entity frames, their labels, definitions, comments, synonyms, namespaces,
+/// cross-references and deprecation annotations become the matching
+/// clauses, and named subclasses, existential restrictions, subproperties,
+/// transitivity, class and object property assertions become `is_a`,
+/// `relationship`, `is_transitive` and `instance_of` clauses. Other axioms
+/// have no OBO equivalent and are skipped.
+pub fn from_owl(ont: &SetOntology) -> obo::OboDoc {
+    let mut builder = FrameBuilder::default();
+    for axiom in ont.iter() {
+        match &axiom.axiom {
+            Axiom::DeclareClass(d) => builder.declare((d.0).0.to_string(), |id| {
+                obo::EntityFrame::from(obo::TermFrame::with_clauses(obo::ClassIdent::from(id), Vec::new()))
+            }),
+            Axiom::DeclareObjectProperty(d) => builder.declare((d.0).0.to_string(), |id| {
+                let id = obo::RelationIdent::from(id);
+                obo::EntityFrame::from(obo::TypedefFrame::with_clauses(id, Vec::new()))
+            }),
+            Axiom::DeclareNamedIndividual(d) => builder.declare((d.0).0.to_string(), |id| {
+                let id = obo::InstanceIdent::from(id);
+                obo::EntityFrame::from(obo::InstanceFrame::with_clauses(id, Vec::new()))
+            }),
+            _ => (),
+        }
+    }
+    for axiom in ont.iter() {
+        builder.axiom(&axiom.axiom);
+    }
+
+    let mut header = obo::HeaderFrame::new();
+    header.push(obo::HeaderClause::FormatVersion(Box::new(obo::UnquotedString::new("1.4"))));
+    if let Some(iri) = &ont.id().iri {
+        let name = ontology_name(iri.as_ref()).to_string();
+        header.push(obo::HeaderClause::Ontology(Box::new(obo::UnquotedString::new(name))));
+    }
+
+    let mut doc = obo::OboDoc::with_header(header);
+    *doc.entities_mut() = builder.frames;
+    doc
+}
+
+/// Parse an ontology in OWL/XML syntax, and convert it to an OBO document.
+pub fn from_owx(text: &str) -> PyResult<obo::OboDoc> {
+    match horned_owl::io::owx::reader::read(&mut text.as_bytes()) {
+        Ok((ont, _)) => Ok(from_owl(&ont)),
+        Err(e) => Err(PyValueError::new_err(format!("could not read OWL ontology: {}", e))),
+    }
+}
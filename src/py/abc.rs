@@ -7,6 +7,7 @@ use std::string::ToString;
 
 use pyo3::class::gc::PyVisit;
 use pyo3::exceptions::PyNotImplementedError;
+use pyo3::exceptions::PyTypeError;
 use pyo3::gc::PyTraverseError;
 use pyo3::prelude::*;
 use pyo3::types::PyAny;
@@ -18,12 +19,22 @@ use pyo3::PyTypeInfo;
 use fastobo::ast as obo;
 
 use crate::error::Error;
+use crate::location::Location;
 use crate::utils::AbstractClass;
 use crate::utils::ClonePy;
+use crate::utils::DisplayPy;
+use crate::utils::FreezePy;
+use crate::utils::TryAsObject;
 
+use super::doc::EntityFrame;
+use super::header::clause::HeaderClause;
 use super::header::frame::HeaderFrame;
 use super::id::Ident;
+use super::instance::clause::InstanceClause;
+use super::qual::QualifierList;
+use super::term::clause::TermClause;
 use super::term::frame::TermFrame;
+use super::typedef::clause::TypedefClause;
 use super::typedef::frame::TypedefFrame;
 
 // --- Module export ---------------------------------------------------------
@@ -58,11 +69,134 @@ pub fn init(_py: Python, m: &PyModule) -> PyResult<()> {
 /// a various number of entity frames.
 #[pyclass(subclass, module = "fastobo.abc")]
 #[derive(Default)]
-pub struct AbstractFrame {}
+pub struct AbstractFrame {
+    comments: Vec<String>,
+    location: Option<Location>,
+}
 
 impl AbstractClass for AbstractFrame {
     fn initializer() -> PyClassInitializer<Self> {
-        PyClassInitializer::from(Self {})
+        PyClassInitializer::from(Self::default())
+    }
+}
+
+impl AbstractFrame {
+    /// Set the comment lines preceding a frame.
+    pub fn set_comments_of<T: ToPyObject>(frame: &T, py: Python, comments: Vec<String>) -> PyResult<()> {
+        let object = frame.to_object(py);
+        let mut base = object.extract::<PyRefMut<AbstractFrame>>(py)?;
+        base.comments = comments;
+        Ok(())
+    }
+
+    /// Set the location of a frame in its source document.
+    pub fn set_location_of<T: ToPyObject>(frame: &T, py: Python, location: Location) -> PyResult<()> {
+        let object = frame.to_object(py);
+        let mut base = object.extract::<PyRefMut<AbstractFrame>>(py)?;
+        base.location = Some(location);
+        Ok(())
+    }
+
+    /// Serialize a frame, including the comment lines of the frame and its clauses.
+    pub fn render(frame: &PyAny) -> PyResult<String> {
+        let py = frame.py();
+        let comments = frame.extract::<PyRef<AbstractFrame>>()?.comments.clone();
+        if let Ok(header) = frame.extract::<PyRef<HeaderFrame>>() {
+            let clauses = header
+                .clauses()
+                .iter()
+                .map(|clause| AbstractClause::comments_of(clause, py))
+                .collect::<PyResult<Vec<_>>>()?;
+            let text = header.display_py(py).to_string();
+            Ok(crate::comments::interleave(&text, &comments, &clauses, 0))
+        } else if let Ok(entity) = EntityFrame::extract(frame) {
+            macro_rules! comments {
+                ($frame:ident) => {
+                    $frame
+                        .borrow(py)
+                        .clauses()
+                        .iter()
+                        .map(|clause| AbstractClause::comments_of(clause, py))
+                        .collect::<PyResult<Vec<_>>>()?
+                };
+            }
+            let clauses = match &entity {
+                EntityFrame::Term(t) => comments!(t),
+                EntityFrame::Typedef(t) => comments!(t),
+                EntityFrame::Instance(i) => comments!(i),
+            };
+            let text = entity.display_py(py).to_string();
+            Ok(crate::comments::interleave(&text, &comments, &clauses, 2))
+        } else {
+            let ty = frame.get_type().name()?;
+            Err(PyTypeError::new_err(format!("cannot serialize {} instance", ty)))
+        }
+    }
+
+    /// Check whether a frame or any of its clauses has comment lines.
+    pub fn has_comments(frame: &PyAny) -> PyResult<bool> {
+        let py = frame.py();
+        if !frame.extract::<PyRef<AbstractFrame>>()?.comments.is_empty() {
+            return Ok(true);
+        }
+        for clause in frame.iter()? {
+            if !clause?.extract::<PyRef<AbstractClause>>()?.comments.is_empty() {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+#[pymethods]
+impl AbstractFrame {
+    /// `list` of `str`: the comment lines preceding the frame.
+    ///
+    /// Comment lines are only extracted when a document is parsed with
+    /// ``preserve_comments=True``, and are written back when the frame is
+    /// serialized with `str`.
+    #[getter]
+    fn get_comments(&self) -> Vec<String> {
+        self.comments.clone()
+    }
+
+    #[setter]
+    fn set_comments(&mut self, comments: Vec<String>) {
+        self.comments = comments;
+    }
+
+    /// `tuple` or `None`: the location of the frame in its source document.
+    ///
+    /// The location is a ``(file, start, end)`` tuple, where ``file`` is the
+    /// path to the source file (or `None` when parsing a string), and
+    /// ``start`` and ``end`` are the first and last lines of the frame,
+    /// starting from 1. Locations are only recorded when a document is
+    /// parsed with ``locations=True``.
+    ///
+    #[getter]
+    fn get_location(&self) -> Option<(Option<String>, usize, usize)> {
+        self.location
+            .as_ref()
+            .map(|l| (l.file.clone(), l.start, l.end))
+    }
+
+    /// Create a deep copy of the frame.
+    ///
+    /// Frames are copied through their syntax tree, so the copy does not
+    /// share any object with the original frame, including its clauses.
+    ///
+    pub fn __deepcopy__(slf: &PyCell<Self>, _memo: &PyAny) -> PyResult<PyObject> {
+        let py = slf.py();
+        if let Ok(frame) = slf.extract::<PyRef<HeaderFrame>>() {
+            let ast: obo::HeaderFrame = frame.clone_py(py).into_py(py);
+            Py::new(py, IntoPy::<HeaderFrame>::into_py(ast, py)).map(|x| x.to_object(py))
+        } else if let Ok(frame) = EntityFrame::extract(slf) {
+            let ast: obo::EntityFrame = (&frame).into_py(py);
+            Ok(IntoPy::<EntityFrame>::into_py(ast, py).to_object(py))
+        } else {
+            let ty = slf.get_type().name()?;
+            Err(PyTypeError::new_err(format!("cannot copy {} instance", ty)))
+        }
     }
 }
 
@@ -109,16 +243,115 @@ impl AbstractEntityFrame {
 ///
 #[pyclass(subclass, module = "fastobo.abc")]
 #[derive(Default)]
-pub struct AbstractClause {}
+pub struct AbstractClause {
+    comments: Vec<String>,
+    line: Option<usize>,
+    frozen: bool,
+}
 
 impl AbstractClass for AbstractClause {
     fn initializer() -> PyClassInitializer<Self> {
-        PyClassInitializer::from(Self {})
+        PyClassInitializer::from(Self::default())
+    }
+}
+
+impl AbstractClause {
+    /// Get the comment lines preceding a clause.
+    ///
+    /// A clause stored inline has no comments, since they can only be set
+    /// on its Python object.
+    pub fn comments_of<T: TryAsObject>(clause: &T, py: Python) -> PyResult<Vec<String>> {
+        let object = match clause.try_as_object(py) {
+            Some(object) => object,
+            None => return Ok(Vec::new()),
+        };
+        let base = object.extract::<PyRef<AbstractClause>>(py)?;
+        Ok(base.comments.clone())
+    }
+
+    /// Check whether two clauses are preceded by the same comment lines.
+    ///
+    /// This is used by `EqPy` so that clauses with different comments are
+    /// not considered equal.
+    pub fn eq_metadata_of<T: TryAsObject>(l: &T, r: &T, py: Python) -> bool {
+        Self::comments_of(l, py).ok() == Self::comments_of(r, py).ok()
+    }
+
+    /// Set the comment lines preceding a clause.
+    pub fn set_comments_of<T: ToPyObject>(clause: &T, py: Python, comments: Vec<String>) -> PyResult<()> {
+        let object = clause.to_object(py);
+        let mut base = object.extract::<PyRefMut<AbstractClause>>(py)?;
+        base.comments = comments;
+        Ok(())
+    }
+
+    /// Make a clause read-only, along with its trailing qualifiers.
+    ///
+    /// The values owned by the clause, such as its cross-references, are
+    /// not frozen here, since they depend on the concrete clause type.
+    pub fn freeze_object(object: &PyAny) -> PyResult<()> {
+        let py = object.py();
+        if let Ok(mut base) = object.extract::<PyRefMut<AbstractEntityClause>>() {
+            base.get_qualifiers(py)?.freeze_py(py)?;
+        }
+        object.extract::<PyRefMut<AbstractClause>>()?.frozen = true;
+        Ok(())
+    }
+
+    /// Set the line of a clause in its source document.
+    pub fn set_line_of<T: ToPyObject>(clause: &T, py: Python, line: usize) -> PyResult<()> {
+        let object = clause.to_object(py);
+        let mut base = object.extract::<PyRefMut<AbstractClause>>(py)?;
+        base.line = Some(line);
+        Ok(())
     }
 }
 
 #[pymethods]
 impl AbstractClause {
+    /// `list` of `str`: the comment lines preceding the clause.
+    ///
+    /// Comment lines are only extracted when a document is parsed with
+    /// ``preserve_comments=True``, and are written back when the frame
+    /// containing the clause is serialized.
+    #[getter]
+    fn get_comments(&self) -> Vec<String> {
+        self.comments.clone()
+    }
+
+    #[setter]
+    fn set_comments(&mut self, comments: Vec<String>) {
+        self.comments = comments;
+    }
+
+    /// `bool`: Whether the clause is frozen.
+    ///
+    /// Clauses are frozen along with the frame containing them, see
+    /// `OboDoc.freeze`.
+    #[getter]
+    fn get_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    fn __setattr__(slf: &PyCell<Self>, name: &PyAny, value: &PyAny) -> PyResult<()> {
+        let frozen = slf.try_borrow()?.frozen;
+        crate::utils::setattr_mutable(slf, frozen, name, Some(value))
+    }
+
+    fn __delattr__(slf: &PyCell<Self>, name: &PyAny) -> PyResult<()> {
+        let frozen = slf.try_borrow()?.frozen;
+        crate::utils::setattr_mutable(slf, frozen, name, None)
+    }
+
+    /// `int` or `None`: the line of the clause in its source document.
+    ///
+    /// Lines start from 1, and are only recorded when a document is parsed
+    /// with ``locations=True``.
+    #[getter]
+    fn get_line(&self) -> Option<usize> {
+        self.line
+    }
+
     /// Get the raw tag of the header clause.
     ///
     /// Returns:
@@ -151,10 +384,188 @@ impl AbstractClause {
     pub fn raw_value(&self) -> PyResult<String> {
         Err(PyNotImplementedError::new_err("BaseHeaderClause.raw_value"))
     }
+
+    /// Create a copy of the clause.
+    ///
+    /// Clauses are copied through their syntax tree, so the copy does not
+    /// share any object with the original clause, like with `copy.deepcopy`.
+    ///
+    /// Example:
+    ///     >>> clause = fastobo.term.IsAClause(fastobo.id.PrefixedIdent("MS", "1000031"))
+    ///     >>> copy = clause.__copy__()
+    ///     >>> copy == clause and copy is not clause
+    ///     True
+    ///
+    pub fn __copy__(slf: &PyCell<Self>) -> PyResult<PyObject> {
+        let py = slf.py();
+        if let Ok(clause) = HeaderClause::extract(slf) {
+            let ast: obo::HeaderClause = (&clause).into_py(py);
+            Ok(IntoPy::<HeaderClause>::into_py(ast, py).to_object(py))
+        } else if let Ok(clause) = TermClause::extract(slf) {
+            let ast: obo::TermClause = (&clause).into_py(py);
+            let copy = IntoPy::<TermClause>::into_py(ast, py);
+            let qualifiers = AbstractEntityClause::qualifiers_of(&clause, py)?;
+            AbstractEntityClause::set_qualifiers_of(&copy, py, qualifiers)?;
+            Ok(copy.to_object(py))
+        } else if let Ok(clause) = TypedefClause::extract(slf) {
+            let ast: obo::TypedefClause = (&clause).into_py(py);
+            let copy = IntoPy::<TypedefClause>::into_py(ast, py);
+            let qualifiers = AbstractEntityClause::qualifiers_of(&clause, py)?;
+            AbstractEntityClause::set_qualifiers_of(&copy, py, qualifiers)?;
+            Ok(copy.to_object(py))
+        } else if let Ok(clause) = InstanceClause::extract(slf) {
+            let ast: obo::InstanceClause = (&clause).into_py(py);
+            let copy = IntoPy::<InstanceClause>::into_py(ast, py);
+            let qualifiers = AbstractEntityClause::qualifiers_of(&clause, py)?;
+            AbstractEntityClause::set_qualifiers_of(&copy, py, qualifiers)?;
+            Ok(copy.to_object(py))
+        } else {
+            let ty = slf.get_type().name()?;
+            Err(PyTypeError::new_err(format!("cannot copy {} instance", ty)))
+        }
+    }
+
+    /// Create a deep copy of the clause.
+    pub fn __deepcopy__(slf: &PyCell<Self>, _memo: &PyAny) -> PyResult<PyObject> {
+        Self::__copy__(slf)
+    }
 }
 
 /// An abstract entity clause.
+///
+/// Entity clauses can be annotated with trailing qualifiers, which are
+/// exposed through the ``qualifiers`` property of every concrete subclass.
+///
+/// Example:
+///     >>> frame = fastobo.loads(
+///     ...     '[Term]\nid: TST:001\nis_a: TST:002 {source="TST:003"}\n'
+///     ... )[0]
+///     >>> frame[0].qualifiers[0].value
+///     'TST:003'
+///     >>> frame[0].qualifiers.clear()
+///     >>> print(frame)
+///     [Term]
+///     id: TST:001
+///     is_a: TST:002
+///     <BLANKLINE>
+///
 #[pyclass(subclass, extends=AbstractClause, module="fastobo.abc")]
-#[derive(Default, AbstractClass)]
-#[base(AbstractClause)]
-pub struct AbstractEntityClause {}
+#[derive(Default)]
+pub struct AbstractEntityClause {
+    qualifiers: Option<Py<QualifierList>>,
+}
+
+impl AbstractClass for AbstractEntityClause {
+    fn initializer() -> PyClassInitializer<Self> {
+        AbstractClause::initializer().add_subclass(Self::default())
+    }
+}
+
+impl AbstractEntityClause {
+    /// Check whether two entity clauses have the same comments and qualifiers.
+    ///
+    /// This is used by `EqPy`, so that clauses only differing by their
+    /// qualifiers are not equal even though they have different hashes.
+    pub fn eq_metadata_of<T: TryAsObject>(l: &T, r: &T, py: Python) -> bool {
+        AbstractClause::eq_metadata_of(l, r, py)
+            && Self::qualifiers_of(l, py).ok() == Self::qualifiers_of(r, py).ok()
+    }
+
+    /// Get the trailing qualifiers of an entity clause, if any.
+    pub fn qualifiers_of<T: TryAsObject>(
+        clause: &T,
+        py: Python,
+    ) -> PyResult<Option<obo::QualifierList>> {
+        let object = match clause.try_as_object(py) {
+            Some(object) => object,
+            None => return Ok(None),
+        };
+        let base = object.extract::<PyRef<AbstractEntityClause>>(py)?;
+        match &base.qualifiers {
+            Some(q) if !q.as_ref(py).borrow().is_empty() => {
+                Ok(Some((&*q.as_ref(py).borrow()).into_py(py)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Write the trailing qualifiers of an entity clause, if any.
+    ///
+    /// The qualifiers are preceded by a space, so that they can be written
+    /// right after the clause they belong to.
+    pub fn fmt_qualifiers_of<T: TryAsObject>(
+        clause: &T,
+        py: Python,
+        f: &mut Formatter,
+    ) -> FmtResult {
+        let object = match clause.try_as_object(py) {
+            Some(object) => object,
+            None => return Ok(()),
+        };
+        let base = object
+            .extract::<PyRef<AbstractEntityClause>>(py)
+            .map_err(|_| std::fmt::Error)?;
+        match &base.qualifiers {
+            Some(q) if !q.as_ref(py).borrow().is_empty() => {
+                f.write_str(" ")?;
+                q.fmt_py(py, f)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Set the trailing qualifiers of an entity clause.
+    pub fn set_qualifiers_of<T: ToPyObject>(
+        clause: &T,
+        py: Python,
+        qualifiers: Option<obo::QualifierList>,
+    ) -> PyResult<()> {
+        let object = clause.to_object(py);
+        let mut base = object.extract::<PyRefMut<AbstractEntityClause>>(py)?;
+        base.qualifiers = match qualifiers {
+            Some(q) => Some(Py::new(py, IntoPy::<QualifierList>::into_py(q, py))?),
+            None => None,
+        };
+        Ok(())
+    }
+
+    /// Get the value of the first qualifier of the clause with the given key.
+    pub fn qualifier(&self, py: Python, key: &str) -> Option<String> {
+        self.qualifiers
+            .as_ref()
+            .and_then(|q| q.as_ref(py).borrow().get(py, key))
+    }
+
+    /// Replace the qualifiers of the clause with the given key.
+    pub fn set_qualifier(&mut self, py: Python, key: &str, value: Option<String>) -> PyResult<()> {
+        let qualifiers = self.get_qualifiers(py)?;
+        let result = qualifiers.as_ref(py).borrow_mut().set(py, key, value);
+        result
+    }
+}
+
+#[pymethods]
+impl AbstractEntityClause {
+    /// `~fastobo.qual.QualifierList`: the trailing qualifiers of the clause.
+    #[getter]
+    fn get_qualifiers(&mut self, py: Python) -> PyResult<Py<QualifierList>> {
+        match &self.qualifiers {
+            Some(q) => Ok(q.clone_ref(py)),
+            None => {
+                let q = Py::new(py, QualifierList::default())?;
+                self.qualifiers = Some(q.clone_ref(py));
+                Ok(q)
+            }
+        }
+    }
+
+    #[setter]
+    fn set_qualifiers(&mut self, py: Python, qualifiers: &PyAny) -> PyResult<()> {
+        let q = match qualifiers.extract::<Py<QualifierList>>() {
+            Ok(q) => q,
+            Err(_) => Py::new(py, QualifierList::collect(py, qualifiers)?)?,
+        };
+        self.qualifiers = Some(q);
+        Ok(())
+    }
+}
@@ -1,33 +1,60 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
+use std::hash::Hash;
 use std::mem::take;
 use std::rc::Rc;
 use std::str::FromStr;
 use std::string::ToString;
+use std::sync::Arc;
+use std::sync::Mutex;
 
+use pyo3::class::basic::CompareOp;
 use pyo3::class::gc::PyVisit;
-use pyo3::exceptions::PyIndexError;
+use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::PyValueError;
 use pyo3::gc::PyTraverseError;
 use pyo3::prelude::*;
 use pyo3::types::PyAny;
+use pyo3::types::PyBytes;
+use pyo3::types::PyDict;
 use pyo3::types::PyIterator;
 use pyo3::types::PyList;
+use pyo3::types::PySet;
 use pyo3::types::PyString;
 use pyo3::PyNativeType;
 use pyo3::PyTypeInfo;
 
 use fastobo::ast as obo;
+use fastobo::semantics::OboClause;
 use fastobo::visit::VisitMut;
+use fastobo_owl::IntoOwl;
+use arrow::pyarrow::ToPyArrow;
 
+use crate::comments::FrameComments;
+use crate::comments::SourceComments;
 use crate::error::Error;
+use crate::index::RelationIndex;
+use crate::location::FrameLines;
+use crate::location::SourceLines;
+use crate::utils::check_mutable;
 use crate::utils::ClonePy;
+use crate::utils::DisplayPy;
 use crate::utils::EqPy;
+use crate::utils::RichCmpPy;
 
+use super::abc::AbstractClause;
 use super::abc::AbstractFrame;
+use super::header::clause::HeaderClause;
 use super::header::frame::HeaderFrame;
+use super::id::Ident;
+use super::instance::clause::InstanceClause;
 use super::instance::frame::InstanceFrame;
+use super::term::clause::TermClause;
 use super::term::frame::TermFrame;
+use super::typedef::clause::TypedefClause;
 use super::typedef::frame::TypedefFrame;
 
 // --- Module export ---------------------------------------------------------
@@ -36,13 +63,17 @@ use super::typedef::frame::TypedefFrame;
 #[pyo3(name = "doc")]
 pub fn init(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<self::OboDoc>()?;
+    m.add_class::<self::TermFrameView>()?;
+    m.add_class::<self::TypedefFrameView>()?;
+    m.add_class::<self::InstanceFrameView>()?;
+    m.add_class::<self::FrameDiff>()?;
     m.add("__name__", "fastobo.doc")?;
     Ok(())
 }
 
 // --- Conversion Wrapper ----------------------------------------------------
 
-#[derive(ClonePy, Debug, PyWrapper, EqPy)]
+#[derive(ClonePy, Debug, DisplayPy, PyWrapper, EqPy)]
 #[wraps(AbstractFrame)]
 pub enum EntityFrame {
     Term(Py<TermFrame>),
@@ -50,6 +81,54 @@ pub enum EntityFrame {
     Instance(Py<InstanceFrame>),
 }
 
+impl EntityFrame {
+    /// Get a new reference to the identifier of the wrapped frame.
+    pub fn id(&self, py: Python) -> Ident {
+        match self {
+            EntityFrame::Term(t) => t.borrow(py).id().clone_py(py),
+            EntityFrame::Typedef(t) => t.borrow(py).id().clone_py(py),
+            EntityFrame::Instance(i) => i.borrow(py).id().clone_py(py),
+        }
+    }
+
+    /// Check whether the wrapped frame has the given identifier.
+    ///
+    /// The identifier of the frame is compared in place, without taking
+    /// a new reference to it.
+    pub fn has_id(&self, py: Python, id: &Ident) -> bool {
+        match self {
+            EntityFrame::Term(t) => t.borrow(py).id().eq_py(id, py),
+            EntityFrame::Typedef(t) => t.borrow(py).id().eq_py(id, py),
+            EntityFrame::Instance(i) => i.borrow(py).id().eq_py(id, py),
+        }
+    }
+
+    /// Create a new frame with the clauses of both frames, without duplicates.
+    ///
+    /// Returns `None` if the frames are not of the same kind.
+    fn merge(&self, py: Python, other: &EntityFrame) -> PyResult<Option<EntityFrame>> {
+        macro_rules! merge_clauses {
+            ($variant:ident, $l:ident, $r:ident) => {{
+                let mut frame = $l.borrow(py).clone_py(py);
+                for clause in $r.borrow(py).clauses() {
+                    if !frame.clauses().iter().any(|c| c.eq_py(clause, py)) {
+                        frame.clauses_mut().push(clause.clone_py(py));
+                    }
+                }
+                Py::new(py, frame).map(EntityFrame::$variant).map(Some)
+            }};
+        }
+        match (self, other) {
+            (EntityFrame::Term(l), EntityFrame::Term(r)) => merge_clauses!(Term, l, r),
+            (EntityFrame::Typedef(l), EntityFrame::Typedef(r)) => merge_clauses!(Typedef, l, r),
+            (EntityFrame::Instance(l), EntityFrame::Instance(r)) => {
+                merge_clauses!(Instance, l, r)
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
 impl IntoPy<EntityFrame> for fastobo::ast::EntityFrame {
     fn into_py(self, py: Python) -> EntityFrame {
         match self {
@@ -67,16 +146,23 @@ impl IntoPy<EntityFrame> for fastobo::ast::EntityFrame {
     }
 }
 
-impl IntoPy<fastobo::ast::EntityFrame> for EntityFrame {
-    fn into_py(self, py: Python) -> fastobo::ast::EntityFrame {
+impl EntityFrame {
+    /// Convert the frame to an AST frame, without copying it first.
+    pub fn to_ast(&self, py: Python) -> fastobo::ast::EntityFrame {
         match self {
-            EntityFrame::Term(t) => t.borrow(py).clone_py(py).into_py(py),
-            EntityFrame::Typedef(t) => t.borrow(py).clone_py(py).into_py(py),
-            EntityFrame::Instance(i) => i.borrow(py).clone_py(py).into_py(py),
+            EntityFrame::Term(t) => t.borrow(py).to_ast(py).into(),
+            EntityFrame::Typedef(t) => t.borrow(py).to_ast(py).into(),
+            EntityFrame::Instance(i) => i.borrow(py).to_ast(py).into(),
         }
     }
 }
 
+impl IntoPy<fastobo::ast::EntityFrame> for EntityFrame {
+    fn into_py(self, py: Python) -> fastobo::ast::EntityFrame {
+        self.to_ast(py)
+    }
+}
+
 // --- OBO document ----------------------------------------------------------
 
 /// OboDoc(header=None, entities=None)
@@ -91,12 +177,28 @@ impl IntoPy<fastobo::ast::EntityFrame> for EntityFrame {
 ///         frames, either `TermFrame`, `TypedefFrame` or `InstanceFrame`.
 ///
 #[pyclass(module = "fastobo.doc")]
-#[derive(Debug, EqPy)]
+#[derive(Debug, EqPy, RichCmpPy)]
 pub struct OboDoc {
-    #[pyo3(get, set)]
+    #[pyo3(get)]
     /// `~fastobo.header.HeaderFrame`: the header containing ontology metadata.
     header: Py<HeaderFrame>,
     entities: Vec<EntityFrame>,
+    #[eqpy(ignore)]
+    frozen: bool,
+    #[eqpy(ignore)]
+    graph_meta: Option<Box<fastobo_graphs::model::Meta>>,
+    #[eqpy(ignore)]
+    footer: Vec<String>,
+    #[eqpy(ignore)]
+    relation_index: Mutex<Option<CachedIndex>>,
+}
+
+/// A relation index cached by a document, with the relations it follows.
+#[derive(Debug)]
+struct CachedIndex {
+    generation: usize,
+    relations: Vec<String>,
+    index: Arc<RelationIndex>,
 }
 
 impl OboDoc {
@@ -106,7 +208,268 @@ impl OboDoc {
     }
 
     pub fn with_entities(header: Py<HeaderFrame>, entities: Vec<EntityFrame>) -> Self {
-        Self { header, entities }
+        Self {
+            header,
+            entities,
+            frozen: false,
+            graph_meta: None,
+            footer: Vec::new(),
+            relation_index: Mutex::new(None),
+        }
+    }
+
+    /// Get a reference to the header frame of the document.
+    pub fn header(&self) -> &Py<HeaderFrame> {
+        &self.header
+    }
+
+    /// Get a reference to the entity frames of the document.
+    pub fn entities(&self) -> &Vec<EntityFrame> {
+        &self.entities
+    }
+
+    /// Convert the document to an AST document.
+    ///
+    /// Entity frames are converted with `EntityFrame::to_ast`, so that
+    /// clauses never accessed from Python are not allocated.
+    pub fn to_ast(&self, py: Python) -> fastobo::ast::OboDoc {
+        let header: HeaderFrame = self.header.as_ref(py).borrow().clone_py(py);
+        self.entities
+            .iter()
+            .map(|frame| frame.to_ast(py))
+            .collect::<fastobo::ast::OboDoc>()
+            .and_header(header.into_py(py))
+    }
+
+    /// Get an index of the edges labeled with one of `relations`.
+    ///
+    /// The index is cached, and only rebuilt when queried with different
+    /// relations, or after a document, frame or clause was modified.
+    fn relation_index(&self, py: Python, relations: &[String]) -> Arc<RelationIndex> {
+        let mut relations = relations.to_vec();
+        relations.sort_unstable();
+        relations.dedup();
+
+        let generation = crate::index::generation();
+        if let Some(cached) = self.relation_index.lock().unwrap().as_ref() {
+            if cached.generation == generation && cached.relations == relations {
+                return cached.index.clone();
+            }
+        }
+
+        let index = Arc::new(RelationIndex::new(self.to_ast(py), &relations));
+        *self.relation_index.lock().unwrap() = Some(CachedIndex {
+            generation,
+            relations,
+            index: index.clone(),
+        });
+        index
+    }
+
+    /// Get the graph metadata without OBO equivalent, if loaded from a graph.
+    pub fn graph_meta(&self) -> Option<&fastobo_graphs::model::Meta> {
+        self.graph_meta.as_deref()
+    }
+
+    /// Set the graph metadata to restore when dumping the document to a graph.
+    pub fn replace_graph_meta(&mut self, meta: Option<Box<fastobo_graphs::model::Meta>>) {
+        self.graph_meta = meta;
+    }
+
+    /// Run an identifier visitor on the document, in place or on a copy.
+    ///
+    /// When `inplace` is `true`, the document is edited with `OboDoc::edit`
+    /// instead of being copied.
+    fn visit_ids<V>(&mut self, py: Python, mut visitor: V, inplace: bool) -> PyResult<Option<Self>>
+    where
+        V: VisitMut + Send,
+    {
+        if !inplace {
+            let mut doc: obo::OboDoc = self.to_ast(py);
+            py.allow_threads(|| visitor.visit_doc(&mut doc));
+            return Ok(Some(doc.into_py(py)));
+        }
+        self.edit(py, |doc| py.allow_threads(|| visitor.visit_doc(doc)))?;
+        Ok(None)
+    }
+
+    /// Edit the AST of the document in place with the given function.
+    ///
+    /// The edited AST is then applied to the existing header and entity
+    /// frames with `OboDoc::update`, so that only the changed clauses are
+    /// modified.
+    pub fn edit<F>(&mut self, py: Python, f: F) -> PyResult<()>
+    where
+        F: FnOnce(&mut obo::OboDoc),
+    {
+        check_mutable(self.frozen, "OboDoc")?;
+        let mut doc = self.to_ast(py);
+        f(&mut doc);
+        self.update(py, doc)
+    }
+
+    /// Update the document in place to match an edited AST document.
+    ///
+    /// Frames are updated in place when their kind did not change and they
+    /// are not currently borrowed, so that the frame objects referenced from
+    /// Python remain valid. Other frames are replaced with new objects.
+    fn update(&mut self, py: Python, mut doc: obo::OboDoc) -> PyResult<()> {
+        let header = take(doc.header_mut());
+        let replaced = match self.header.try_borrow_mut(py) {
+            Ok(mut frame) => {
+                frame.update(py, header);
+                None
+            }
+            Err(_) => Some(header),
+        };
+        if let Some(header) = replaced {
+            self.header = Py::new(py, IntoPy::<HeaderFrame>::into_py(header, py))?;
+        }
+
+        let mut entities = take(&mut self.entities).into_iter();
+        for frame in take(doc.entities_mut()) {
+            let entity = match (entities.next(), frame) {
+                (Some(EntityFrame::Term(t)), obo::EntityFrame::Term(frame))
+                    if t.try_borrow_mut(py).is_ok() =>
+                {
+                    t.borrow_mut(py).update(py, *frame);
+                    EntityFrame::Term(t)
+                }
+                (Some(EntityFrame::Typedef(t)), obo::EntityFrame::Typedef(frame))
+                    if t.try_borrow_mut(py).is_ok() =>
+                {
+                    t.borrow_mut(py).update(py, *frame);
+                    EntityFrame::Typedef(t)
+                }
+                (Some(EntityFrame::Instance(i)), obo::EntityFrame::Instance(frame))
+                    if i.try_borrow_mut(py).is_ok() =>
+                {
+                    i.borrow_mut(py).update(py, *frame);
+                    EntityFrame::Instance(i)
+                }
+                (_, frame) => frame.into_py(py),
+            };
+            self.entities.push(entity);
+        }
+        Ok(())
+    }
+
+    /// Attach the comment lines extracted from the source of the document.
+    pub fn attach_comments(&mut self, py: Python, comments: SourceComments) -> PyResult<()> {
+        fn attach<C: ToPyObject>(
+            py: Python,
+            frame: PyObject,
+            clauses: Vec<C>,
+            comments: FrameComments,
+        ) -> PyResult<()> {
+            AbstractFrame::set_comments_of(&frame, py, comments.frame)?;
+            for (index, lines) in comments.clauses {
+                if let Some(clause) = clauses.get(index) {
+                    AbstractClause::set_comments_of(clause, py, lines)?;
+                }
+            }
+            Ok(())
+        }
+
+        let clauses = self.header.borrow(py).clauses().clone_py(py);
+        attach(py, self.header.to_object(py), clauses, comments.header)?;
+        for (frame, c) in self.entities.iter().zip(comments.entities) {
+            match frame {
+                EntityFrame::Term(t) => {
+                    let clauses = t.borrow(py).clauses().clone_py(py);
+                    attach(py, t.to_object(py), clauses, c)?
+                }
+                EntityFrame::Typedef(t) => {
+                    let clauses = t.borrow(py).clauses().clone_py(py);
+                    attach(py, t.to_object(py), clauses, c)?
+                }
+                EntityFrame::Instance(i) => {
+                    let clauses = i.borrow(py).clauses().clone_py(py);
+                    attach(py, i.to_object(py), clauses, c)?
+                }
+            }
+        }
+        self.footer = comments.footer;
+        Ok(())
+    }
+
+    /// Record the source locations of the frames and clauses of the document.
+    pub fn attach_locations(&self, py: Python, file: Option<&str>, lines: SourceLines) -> PyResult<()> {
+        fn attach<C: ToPyObject>(
+            py: Python,
+            frame: PyObject,
+            clauses: Vec<C>,
+            file: Option<&str>,
+            lines: FrameLines,
+        ) -> PyResult<()> {
+            AbstractFrame::set_location_of(&frame, py, lines.location(file))?;
+            for (clause, line) in clauses.iter().zip(lines.clauses) {
+                AbstractClause::set_line_of(clause, py, line)?;
+            }
+            Ok(())
+        }
+
+        if let Some(l) = lines.header {
+            let clauses = self.header.borrow(py).clauses().clone_py(py);
+            attach(py, self.header.to_object(py), clauses, file, l)?;
+        }
+        for (frame, l) in self.entities.iter().zip(lines.entities) {
+            match frame {
+                EntityFrame::Term(t) => {
+                    let clauses = t.borrow(py).clauses().clone_py(py);
+                    attach(py, t.to_object(py), clauses, file, l)?
+                }
+                EntityFrame::Typedef(t) => {
+                    let clauses = t.borrow(py).clauses().clone_py(py);
+                    attach(py, t.to_object(py), clauses, file, l)?
+                }
+                EntityFrame::Instance(i) => {
+                    let clauses = i.borrow(py).clauses().clone_py(py);
+                    attach(py, i.to_object(py), clauses, file, l)?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Merge the entity frames of another document into this document.
+    ///
+    /// Frames with an identifier not declared in this document are added
+    /// at the end of the document. Frames with an identifier already
+    /// declared by a frame of the same kind are replaced by a new frame
+    /// containing the clauses of both frames, without duplicates.
+    pub fn merge_entities(&mut self, py: Python, other: &OboDoc) -> PyResult<()> {
+        let mut index: HashMap<obo::Ident, usize> = HashMap::new();
+        for (i, frame) in self.entities.iter().enumerate() {
+            index.entry(frame.id(py).into_py(py)).or_insert(i);
+        }
+        for frame in other.entities.iter() {
+            let id: obo::Ident = frame.id(py).into_py(py);
+            match index.get(&id) {
+                None => {
+                    index.insert(id, self.entities.len());
+                    self.entities.push(frame.clone_py(py));
+                }
+                Some(&i) => {
+                    if let Some(merged) = self.entities[i].merge(py, frame)? {
+                        self.entities[i] = merged;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the index of the first entity frame with the given identifier.
+    fn position_id(&self, py: Python, id: &Ident) -> Option<usize> {
+        self.entities
+            .iter()
+            .position(|frame| frame.has_id(py, id))
+    }
+
+    /// Get the index of the first entity frame equal to the given frame.
+    fn position_frame(&self, py: Python, frame: &EntityFrame) -> Option<usize> {
+        self.entities.iter().position(|x| x.eq_py(frame, py))
     }
 }
 
@@ -115,14 +478,34 @@ impl ClonePy for OboDoc {
         Self {
             header: self.header.clone_py(py),
             entities: self.entities.clone_py(py),
+            frozen: false,
+            graph_meta: self.graph_meta.clone(),
+            footer: self.footer.clone(),
+            relation_index: Mutex::new(None),
         }
     }
 }
 
+impl DisplayPy for OboDoc {
+    fn fmt_py(&self, py: Python, f: &mut Formatter) -> FmtResult {
+        let header = self.header.borrow(py);
+        header.fmt_py(py, f)?;
+        if !header.clauses().is_empty() && !self.entities.is_empty() {
+            f.write_str("\n")?;
+        }
+        for (i, frame) in self.entities.iter().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            frame.fmt_py(py, f)?;
+        }
+        Ok(())
+    }
+}
+
 impl Display for OboDoc {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let doc: fastobo::ast::OboDoc = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        doc.fmt(f)
+        Python::with_gil(|py| self.fmt_py(py, f))
     }
 }
 
@@ -137,24 +520,17 @@ impl IntoPy<OboDoc> for fastobo::ast::OboDoc {
 
         let header = Py::new(py, h).expect("could not move header to Python heap");
 
-        OboDoc { header, entities }
+        OboDoc::with_entities(header, entities)
     }
 }
 
 impl IntoPy<fastobo::ast::OboDoc> for OboDoc {
     fn into_py(self, py: Python) -> fastobo::ast::OboDoc {
-        let header: HeaderFrame = self.header.as_ref(py).borrow().clone_py(py);
-        self.entities
-            .iter()
-            .map(|frame| {
-                <EntityFrame as IntoPy<fastobo::ast::EntityFrame>>::into_py(frame.clone_py(py), py)
-            })
-            .collect::<fastobo::ast::OboDoc>()
-            .and_header(header.into_py(py))
+        self.to_ast(py)
     }
 }
 
-#[listlike(field = "entities", type = "EntityFrame")]
+#[listlike(field = "entities", type = "EntityFrame", frozen = "frozen")]
 #[pymethods]
 impl OboDoc {
     #[new]
@@ -175,20 +551,111 @@ impl OboDoc {
         })
     }
 
-    fn __str__(&self) -> PyResult<String> {
-        Ok(self.to_string())
+    fn __deepcopy__(&self, py: Python, _memo: &PyAny) -> PyResult<Self> {
+        let doc: obo::OboDoc = self.to_ast(py);
+        let mut copy: OboDoc = doc.into_py(py);
+        copy.graph_meta = self.graph_meta.clone();
+        copy.footer = self.footer.clone();
+        Ok(copy)
+    }
+
+    fn __str__(slf: &PyCell<Self>) -> PyResult<String> {
+        let py = slf.py();
+        let doc = slf.borrow();
+        let frames = doc
+            .entities
+            .iter()
+            .map(|frame| frame.to_object(py))
+            .collect::<Vec<_>>();
+
+        // only serialize frame by frame if some comments must be written
+        let header = doc.header.as_ref(py);
+        let mut commented = !doc.footer.is_empty() || AbstractFrame::has_comments(header)?;
+        for frame in frames.iter() {
+            commented = commented || AbstractFrame::has_comments(frame.as_ref(py))?;
+        }
+        if !commented {
+            return Ok(doc.display_py(py).to_string());
+        }
+
+        let mut out = AbstractFrame::render(header)?;
+        if !header.borrow().clauses().is_empty() && !frames.is_empty() {
+            out.push('\n');
+        }
+        for (i, frame) in frames.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            out.push_str(&AbstractFrame::render(frame.as_ref(py))?);
+        }
+        if !doc.footer.is_empty() {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            crate::comments::write_comments(&mut out, &doc.footer);
+        }
+        Ok(out)
     }
 
     fn __len__(&self) -> PyResult<usize> {
         Ok(self.entities.len())
     }
 
-    fn __getitem__(&self, index: isize) -> PyResult<PyObject> {
-        if index < self.entities.len() as isize {
-            let item = &self.entities[index as usize];
-            Ok(Python::with_gil(|py| item.to_object(py)))
+    /// Get the size of the document in memory, in bytes.
+    ///
+    /// The size includes the header and all the entity frames of the
+    /// document, so that memory profilers report the actual footprint
+    /// of a parsed ontology. Identifiers shared between frames are only
+    /// counted once.
+    fn __sizeof__(&self, py: Python) -> PyResult<usize> {
+        let mut seen = HashSet::new();
+        let mut size = std::mem::size_of::<PyCell<Self>>()
+            + crate::utils::sizeof(&self.header, py)?
+            + self.entities.capacity() * std::mem::size_of::<EntityFrame>();
+        for frame in self.entities.iter() {
+            size += match frame {
+                EntityFrame::Term(f) => f.borrow(py).deep_sizeof(py, &mut seen)?,
+                EntityFrame::Typedef(f) => f.borrow(py).deep_sizeof(py, &mut seen)?,
+                EntityFrame::Instance(f) => f.borrow(py).deep_sizeof(py, &mut seen)?,
+            };
+        }
+        Ok(size)
+    }
+
+    fn __hash__(&self) -> PyResult<u64> {
+        if self.frozen {
+            Ok(impl_hash!(self.to_string()))
+        } else {
+            Err(PyTypeError::new_err("unhashable type: 'OboDoc'"))
+        }
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> EntityFrameIter {
+        EntityFrameIter::new(slf.into(), |_| true)
+    }
+
+    fn __contains__(&self, item: &PyAny) -> PyResult<bool> {
+        let py = item.py();
+        if let Ok(s) = item.downcast::<PyString>() {
+            match obo::Ident::from_str(s.to_str()?) {
+                Ok(id) => Ok(self.position_id(py, &id.into_py(py)).is_some()),
+                Err(_) => Ok(false),
+            }
+        } else if let Ok(id) = item.extract::<Ident>() {
+            Ok(self.position_id(py, &id).is_some())
+        } else if let Ok(frame) = item.extract::<EntityFrame>() {
+            Ok(self.position_frame(py, &frame).is_some())
         } else {
-            Err(PyIndexError::new_err("list index out of range"))
+            let ty = item.get_type().name()?;
+            let msg = format!(
+                "'in <OboDoc>' requires str, BaseIdent or AbstractEntityFrame as left operand, not {}",
+                ty
+            );
+            Err(PyTypeError::new_err(msg))
         }
     }
 
@@ -197,6 +664,140 @@ impl OboDoc {
         Ok(self.header.clone_ref(py))
     }
 
+    #[setter]
+    fn set_header(&mut self, header: Py<HeaderFrame>) -> PyResult<()> {
+        check_mutable(self.frozen, "OboDoc")?;
+        self.header = header;
+        Ok(())
+    }
+
+    /// `dict` or `None`: The metadata of the graph the document was loaded from.
+    ///
+    /// The metadata is given with the layout of the OBO graph JSON format,
+    /// and is restored by `~fastobo.dump_graph`, so that graph metadata
+    /// without an OBO header equivalent, such as a title or a description,
+    /// survives a round-trip through `fastobo`.
+    ///
+    /// Example:
+    ///     >>> doc = fastobo.load_graph("pato.json")
+    ///     >>> doc.graph_meta["basicPropertyValues"][2]["val"]
+    ///     'PATO - the Phenotype And Trait Ontology'
+    ///
+    #[getter]
+    fn get_graph_meta(&self, py: Python) -> PyResult<Option<PyObject>> {
+        self.graph_meta
+            .as_ref()
+            .map(|meta| crate::graph::meta_to_py(py, meta))
+            .transpose()
+    }
+
+    #[setter]
+    fn set_graph_meta(&mut self, meta: Option<&PyAny>) -> PyResult<()> {
+        check_mutable(self.frozen, "OboDoc")?;
+        self.graph_meta = match meta {
+            Some(m) if !m.is_none() => Some(Box::new(crate::graph::meta_from_py(m)?)),
+            _ => None,
+        };
+        Ok(())
+    }
+
+    /// `list` of `str`: the comment lines following the last frame.
+    ///
+    /// Comment lines are only extracted when a document is parsed with
+    /// ``preserve_comments=True``, and are written back when the document
+    /// is serialized with `str`. An empty string stands for a blank line.
+    #[getter]
+    fn get_footer(&self) -> Vec<String> {
+        self.footer.clone()
+    }
+
+    #[setter]
+    fn set_footer(&mut self, footer: Vec<String>) -> PyResult<()> {
+        check_mutable(self.frozen, "OboDoc")?;
+        self.footer = footer;
+        Ok(())
+    }
+
+    /// `bool`: Whether the document is frozen.
+    #[getter]
+    fn get_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Make the document read-only.
+    ///
+    /// The header and the entity frames of the document are frozen as well,
+    /// and so are their clauses and the values they own, such as synonyms,
+    /// cross-references, qualifiers and property values. Once frozen, the
+    /// document rejects any modification with a `TypeError`, including
+    /// in-place methods such as `OboDoc.rename`, and becomes hashable, so
+    /// it can be shared safely between threads or used as a `dict` key.
+    /// Identifiers are immutable already, and are left untouched. Methods
+    /// returning a new document, such as `OboDoc.compact_ids`, can still
+    /// be used, and return mutable documents. A `copy.copy` of a frozen
+    /// document or frame is mutable, but shares its frozen frames or
+    /// clauses with the original, while a `copy.deepcopy` is entirely
+    /// mutable.
+    ///
+    /// Example:
+    ///     >>> doc = fastobo.loads(textwrap.dedent(
+    ///     ...     """
+    ///     ...     [Term]
+    ///     ...     id: TST:001
+    ///     ...     name: test term
+    ///     ...     """
+    ///     ... ))
+    ///     >>> doc.freeze()
+    ///     >>> doc[0].append(fastobo.term.NameClause("test"))
+    ///     Traceback (most recent call last):
+    ///       ...
+    ///     TypeError: cannot modify frozen TermFrame instance
+    ///     >>> doc[0][0].name = "other"
+    ///     Traceback (most recent call last):
+    ///       ...
+    ///     TypeError: cannot modify frozen NameClause instance
+    ///     >>> len({doc: 1})
+    ///     1
+    ///
+    #[pyo3(text_signature = "(self)")]
+    fn freeze(&mut self, py: Python) -> PyResult<()> {
+        self.header.try_borrow_mut(py)?.freeze(py)?;
+        for frame in self.entities.iter() {
+            match frame {
+                EntityFrame::Term(t) => t.try_borrow_mut(py)?.freeze(py)?,
+                EntityFrame::Typedef(t) => t.try_borrow_mut(py)?.freeze(py)?,
+                EntityFrame::Instance(i) => i.try_borrow_mut(py)?.freeze(py)?,
+            }
+        }
+        self.frozen = true;
+        Ok(())
+    }
+
+    /// `~fastobo.doc.TermFrameView`: a view over the term frames of the document.
+    ///
+    /// Example:
+    ///     >>> len(ms.terms)
+    ///     2936
+    ///     >>> next(iter(ms.terms))
+    ///     TermFrame(PrefixedIdent('MS', '0000000'))
+    ///
+    #[getter]
+    fn get_terms(slf: PyRef<'_, Self>) -> TermFrameView {
+        TermFrameView::new(slf.into())
+    }
+
+    /// `~fastobo.doc.TypedefFrameView`: a view over the typedef frames of the document.
+    #[getter]
+    fn get_typedefs(slf: PyRef<'_, Self>) -> TypedefFrameView {
+        TypedefFrameView::new(slf.into())
+    }
+
+    /// `~fastobo.doc.InstanceFrameView`: a view over the instance frames of the document.
+    #[getter]
+    fn get_instances(slf: PyRef<'_, Self>) -> InstanceFrameView {
+        InstanceFrameView::new(slf.into())
+    }
+
     /// Create a semantically equivalent OBO document with compact identifiers.
     ///
     /// The OBO specification describes how to perform an URI decompaction
@@ -206,6 +807,19 @@ impl OboDoc {
     /// compact identifiers. Some URLs may not have a compact representation
     /// if they don't correspond to any decompaction rule.
     ///
+    /// Arguments:
+    ///     inplace (bool): Whether to edit the identifiers of this document
+    ///         directly instead of creating a new one. This avoids copying
+    ///         the whole document, which is useful for large ontologies,
+    ///         and keeps the existing frame and clause objects.
+    ///
+    /// Returns:
+    ///     `~fastobo.doc.OboDoc` or `None`: A new document, or `None` if
+    ///     the document was edited in place.
+    ///
+    /// Raises:
+    ///     TypeError: When attempting to edit a frozen document in place.
+    ///
     /// Example:
     ///     >>> doc = fastobo.loads(textwrap.dedent(
     ///     ...     """
@@ -228,13 +842,9 @@ impl OboDoc {
     ///     <http://owlcollab.github.io/oboformat/doc/obo-syntax.html#5.9>`_
     ///     section of the OBO format version 1.4 specification.
     ///
-    #[pyo3(text_signature = "(self, /)")]
-    fn compact_ids(&self) -> PyResult<Self> {
-        Python::with_gil(|py| {
-            let mut doc: obo::OboDoc = self.clone_py(py).into_py(py);
-            py.allow_threads(|| fastobo::visit::IdCompactor::new().visit_doc(&mut doc));
-            Ok(doc.into_py(py))
-        })
+    #[pyo3(signature = (inplace = false), text_signature = "(self, inplace=False)")]
+    fn compact_ids(&mut self, py: Python, inplace: bool) -> PyResult<Option<Self>> {
+        self.visit_ids(py, fastobo::visit::IdCompactor::new(), inplace)
     }
 
     /// Create a semantically equivalent OBO document with IRI identifiers.
@@ -243,6 +853,19 @@ impl OboDoc {
     /// using either ID spaces declared in the document header, builtin ID
     /// spaces, or a default rule using the `purl.obolibrary.org` domain.
     ///
+    /// Arguments:
+    ///     inplace (bool): Whether to edit the identifiers of this document
+    ///         directly instead of creating a new one. This avoids copying
+    ///         the whole document, which is useful for large ontologies,
+    ///         and keeps the existing frame and clause objects.
+    ///
+    /// Returns:
+    ///     `~fastobo.doc.OboDoc` or `None`: A new document, or `None` if
+    ///     the document was edited in place.
+    ///
+    /// Raises:
+    ///     TypeError: When attempting to edit a frozen document in place.
+    ///
     /// Example:
     ///     >>> doc = fastobo.loads(textwrap.dedent(
     ///     ...     """
@@ -265,12 +888,1292 @@ impl OboDoc {
     ///     <http://owlcollab.github.io/oboformat/doc/obo-syntax.html#5.9>`_
     ///     section of the OBO format version 1.4 specification.
     ///
+    #[pyo3(signature = (inplace = false), text_signature = "(self, inplace=False)")]
+    fn decompact_ids(&mut self, py: Python, inplace: bool) -> PyResult<Option<Self>> {
+        self.visit_ids(py, fastobo::visit::IdDecompactor::new(), inplace)
+    }
+
+    /// Get the effective CURIE prefix map of the document.
+    ///
+    /// This is the prefix map used by `fastobo.dump_owl` to translate the
+    /// document to OWL, so that other RDF tools can expand identifiers in
+    /// exactly the same way.
+    ///
+    /// Returns:
+    ///     `dict`: A mapping of CURIE prefixes to IRI prefixes, including
+    ///     the ID spaces declared with ``idspace`` header clauses and the
+    ///     builtin prefixes of the OBO to OWL translation (such as ``xsd``
+    ///     or ``oboInOwl``). Prefixes missing from the map are expanded with
+    ///     the default rule, using ``http://purl.obolibrary.org/obo/{prefix}_``.
+    ///
+    /// Example:
+    ///     >>> doc = fastobo.loads(textwrap.dedent(
+    ///     ...     """
+    ///     ...     idspace: MassBank http://www.massbank.jp/jsp/FwdRecord.jsp?id=
+    ///     ...     ontology: test
+    ///     ...     """
+    ///     ... ))
+    ///     >>> prefixes = doc.prefix_map()
+    ///     >>> prefixes["MassBank"]
+    ///     'http://www.massbank.jp/jsp/FwdRecord.jsp?id='
+    ///     >>> prefixes["xsd"]
+    ///     'http://www.w3.org/2001/XMLSchema#'
+    ///
     #[pyo3(text_signature = "(self, /)")]
-    fn decompact_ids(&self) -> PyResult<Self> {
-        Python::with_gil(|py| {
-            let mut doc: obo::OboDoc = self.clone_py(py).into_py(py);
-            py.allow_threads(|| fastobo::visit::IdDecompactor::new().visit_doc(&mut doc));
-            Ok(doc.into_py(py))
-        })
+    fn prefix_map<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let doc: obo::OboDoc = self.to_ast(py);
+        let prefixes = doc.prefixes();
+        let dict = PyDict::new(py);
+        for (prefix, iri) in prefixes.mappings() {
+            dict.set_item(prefix, iri)?;
+        }
+        Ok(dict)
+    }
+
+    /// Convert the document to a `networkx.MultiDiGraph`.
+    ///
+    /// The graph is built with the same layout as the one produced by
+    /// `obonet <https://github.com/dhimmel/obonet>`_: every term frame is
+    /// a node, with the raw values of its clauses stored as node attributes
+    /// (single-valued tags as `str`, other tags as `list` of `str`), and
+    /// ``is_a`` and ``relationship`` clauses are stored as edges from the
+    /// term to its target, keyed by the relationship type. Header clauses,
+    /// typedef frames and instance frames are stored in the graph attributes.
+    ///
+    /// Returns:
+    ///     `networkx.MultiDiGraph`: the graph corresponding to the document.
+    ///
+    /// Raises:
+    ///     ImportError: When the `networkx` module cannot be imported.
+    ///
+    /// Example:
+    ///     >>> graph = ms.to_networkx()
+    ///     >>> graph.nodes["MS:1000031"]["name"]
+    ///     'instrument model'
+    ///     >>> list(graph.out_edges("MS:1000031", keys=True))
+    ///     [('MS:1000031', 'MS:1000463', 'part_of')]
+    ///
+    #[pyo3(text_signature = "(self, /)")]
+    fn to_networkx(&self, py: Python) -> PyResult<PyObject> {
+        let networkx = py.import(pyo3::intern!(py, "networkx"))?;
+        let mut doc: obo::OboDoc = self.to_ast(py);
+        let graph = networkx.getattr(pyo3::intern!(py, "MultiDiGraph"))?.call0()?;
+
+        // store header clauses in the graph attributes
+        let attrs = graph.getattr(pyo3::intern!(py, "graph"))?.downcast::<PyDict>()?;
+        for clause in take(doc.header_mut()).into_iter() {
+            add_tag_value(attrs, &clause.to_string())?;
+        }
+
+        // add a node for each term and store other frames in the graph
+        let typedefs = PyList::empty(py);
+        let instances = PyList::empty(py);
+        for frame in take(doc.entities_mut()).into_iter() {
+            match frame {
+                obo::EntityFrame::Term(term) => {
+                    let id = term.id().as_ref().to_string();
+                    let node = PyDict::new(py);
+                    for line in term.into_iter() {
+                        let clause = line.into_inner();
+                        match &clause {
+                            obo::TermClause::IsA(target) => {
+                                add_edge(graph, &id, &target.to_string(), "is_a")?
+                            }
+                            obo::TermClause::Relationship(rel, target) => {
+                                add_edge(graph, &id, &target.to_string(), &rel.to_string())?
+                            }
+                            _ => (),
+                        }
+                        add_tag_value(node, &clause.to_string())?;
+                    }
+                    graph.call_method("add_node", (id,), Some(node))?;
+                }
+                obo::EntityFrame::Typedef(typedef) => {
+                    let dict = PyDict::new(py);
+                    dict.set_item("id", typedef.id().as_ref().to_string())?;
+                    for line in typedef.into_iter() {
+                        add_tag_value(dict, &line.into_inner().to_string())?;
+                    }
+                    typedefs.append(dict)?;
+                }
+                obo::EntityFrame::Instance(instance) => {
+                    let dict = PyDict::new(py);
+                    dict.set_item("id", instance.id().as_ref().to_string())?;
+                    for line in instance.into_iter() {
+                        add_tag_value(dict, &line.into_inner().to_string())?;
+                    }
+                    instances.append(dict)?;
+                }
+            }
+        }
+        attrs.set_item("typedefs", typedefs)?;
+        attrs.set_item("instances", instances)?;
+
+        Ok(graph.to_object(py))
+    }
+
+    /// Convert the document to a `pyhornedowl` ontology.
+    ///
+    /// The document is translated to OWL like with `~fastobo.dump_owl`,
+    /// and passed to `py-horned-owl <https://github.com/ontology-tools/py-horned-owl>`_
+    /// in Functional-style syntax, without going through the filesystem.
+    ///
+    /// Arguments:
+    ///     prefixes (`dict`, optional): A mapping of prefixes to IRIs to
+    ///         declare in the OWL ontology, merged over the prefixes
+    ///         derived from the document.
+    ///     ontology_iri (`str`, optional): The IRI of the OWL ontology.
+    ///     version_iri (`str`, optional): The version IRI of the OWL ontology.
+    ///
+    /// Returns:
+    ///     `pyhornedowl.PyIndexedOntology`: the OWL ontology translated from
+    ///     the document.
+    ///
+    /// Raises:
+    ///     ImportError: When the `pyhornedowl` module cannot be imported.
+    ///     ValueError: When the conversion to OWL fails.
+    ///
+    /// Example:
+    ///     >>> ont = ms.to_pyhornedowl()
+    ///     >>> ont.get_iri()
+    ///     'http://purl.obolibrary.org/obo/ms.owl'
+    ///
+    #[pyo3(
+        signature = (prefixes = None, ontology_iri = None, version_iri = None),
+        text_signature = "(self, prefixes=None, ontology_iri=None, version_iri=None)"
+    )]
+    fn to_pyhornedowl(
+        &self,
+        py: Python,
+        prefixes: Option<HashMap<String, String>>,
+        ontology_iri: Option<&str>,
+        version_iri: Option<&str>,
+    ) -> PyResult<PyObject> {
+        let pyhornedowl = py.import(pyo3::intern!(py, "pyhornedowl"))?;
+        let doc: obo::OboDoc = self.to_ast(py);
+        let ofn = crate::owl::to_ofn(doc, prefixes, ontology_iri, version_iri)?;
+        pyhornedowl
+            .call_method1(pyo3::intern!(py, "open_ontology_from_string"), (ofn, "ofn"))
+            .map(|ont| ont.to_object(py))
+    }
+
+    /// Add the RDF triples of the document to an `rdflib` graph.
+    ///
+    /// The document is translated to OWL like with `~fastobo.dump_owl`,
+    /// and the resulting axioms are mapped to RDF triples which are added
+    /// to the graph with its ``add`` method, without serializing the
+    /// ontology to RDF/XML. The prefixes of the document are bound in the
+    /// namespace manager of the graph.
+    ///
+    /// Arguments:
+    ///     graph (`rdflib.Graph`, optional): The graph to add the triples
+    ///         to. If `None` given, a new `rdflib.Graph` is created.
+    ///
+    /// Returns:
+    ///     `rdflib.Graph`: the graph containing the triples of the document.
+    ///
+    /// Raises:
+    ///     ImportError: When the `rdflib` module cannot be imported.
+    ///     ValueError: When the conversion to OWL fails.
+    ///
+    /// Example:
+    ///     >>> import rdflib
+    ///     >>> graph = ms.to_rdflib()
+    ///     >>> term = rdflib.URIRef("http://purl.obolibrary.org/obo/MS_1000031")
+    ///     >>> str(graph.value(term, rdflib.RDFS.label))
+    ///     'instrument model'
+    ///
+    #[pyo3(signature = (graph = None), text_signature = "(self, graph=None)")]
+    fn to_rdflib(&self, py: Python, graph: Option<&PyAny>) -> PyResult<PyObject> {
+        let rdflib = py.import(pyo3::intern!(py, "rdflib"))?;
+        let graph = match graph {
+            Some(graph) => graph,
+            None => rdflib.getattr(pyo3::intern!(py, "Graph"))?.call0()?,
+        };
+
+        let doc: obo::OboDoc = self.to_ast(py);
+        for (prefix, iri) in doc.prefixes().mappings() {
+            graph.call_method1(pyo3::intern!(py, "bind"), (prefix.as_str(), iri.as_str()))?;
+        }
+        let ont = crate::owl::to_owl(doc, None, None)?;
+        crate::rdf::add_triples(graph, crate::rdf::triples(&ont))?;
+
+        Ok(graph.to_object(py))
     }
+
+    /// Convert the term frames of the document to an Arrow record batch.
+    ///
+    /// The table is built directly from the syntax tree, without creating
+    /// a Python object for each clause, and contains one row per term
+    /// frame with the following columns: ``id``, ``name``, ``namespace``,
+    /// ``definition``, ``is_obsolete``, and ``parents`` with the targets
+    /// of the ``is_a`` clauses of the term.
+    ///
+    /// Returns:
+    ///     `pyarrow.RecordBatch`: The term table of the document, which can
+    ///     be passed to `polars.from_arrow` or converted with ``to_pandas``.
+    ///
+    /// Raises:
+    ///     ImportError: When the `pyarrow` module cannot be imported.
+    ///
+    /// Example:
+    ///     >>> batch = ms.to_arrow()
+    ///     >>> batch.column_names
+    ///     ['id', 'name', 'namespace', 'definition', 'is_obsolete', 'parents']
+    ///     >>> batch.num_rows == len(ms.terms)
+    ///     True
+    ///
+    #[pyo3(text_signature = "(self, /)")]
+    fn to_arrow(&self, py: Python) -> PyResult<PyObject> {
+        let doc: obo::OboDoc = self.to_ast(py);
+        let batch = crate::table::to_record_batch(crate::table::term_rows(&doc))
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        batch.to_pyarrow(py)
+    }
+
+    /// Convert the entity frames of the document to a list of records.
+    ///
+    /// Records only contain builtin Python types, and can be passed
+    /// directly to `pandas.DataFrame` or written with the `csv` module.
+    ///
+    /// Arguments:
+    ///     table (`str`): The table to build, either ``entities`` for one
+    ///         record per entity frame, with the ``id``, ``type``, ``name``,
+    ///         ``namespace``, ``definition``, ``is_obsolete`` and ``parents``
+    ///         of the entity; ``synonyms`` for one record per synonym, with
+    ///         the ``id`` of the entity and the ``synonym``, ``scope``,
+    ///         ``type`` and ``xrefs`` of the synonym; or ``xrefs`` for one
+    ///         record per ``xref`` clause, with the ``id`` of the entity and
+    ///         the ``xref`` and ``description`` of the cross-reference.
+    ///
+    /// Returns:
+    ///     `list` of `dict`: The records of the table, in document order.
+    ///
+    /// Raises:
+    ///     ValueError: When ``table`` is not a known table name.
+    ///
+    /// Example:
+    ///     >>> records = ms.to_records()
+    ///     >>> records[1]["id"], records[1]["name"]
+    ///     ('MS:1000001', 'sample number')
+    ///     >>> ms.to_records("synonyms")[0]
+    ///     {'id': 'MS:1000020', 'synonym': 'Tandem Scanning Method', 'scope': 'RELATED', 'type': None, 'xrefs': []}
+    ///
+    #[pyo3(signature = (table = "entities"), text_signature = "(self, table=\"entities\")")]
+    fn to_records(&self, py: Python, table: &str) -> PyResult<Vec<PyObject>> {
+        let doc: obo::OboDoc = self.to_ast(py);
+        match table {
+            "entities" => crate::table::entity_rows(&doc)
+                .map(|row| row.to_dict(py).map(|d| d.to_object(py)))
+                .collect(),
+            "synonyms" => crate::table::synonym_and_xref_rows(&doc).0
+                .iter()
+                .map(|row| row.to_dict(py).map(|d| d.to_object(py)))
+                .collect(),
+            "xrefs" => crate::table::synonym_and_xref_rows(&doc).1
+                .iter()
+                .map(|row| row.to_dict(py).map(|d| d.to_object(py)))
+                .collect(),
+            other => Err(PyValueError::new_err(format!("invalid table: {:?}", other))),
+        }
+    }
+
+    /// Convert the entity frames of the document to a `pandas.DataFrame`.
+    ///
+    /// Arguments:
+    ///     table (`str`): The table to build, either ``entities``,
+    ///         ``synonyms`` or ``xrefs``. See `OboDoc.to_records` for the
+    ///         columns of each table.
+    ///
+    /// Returns:
+    ///     `pandas.DataFrame`: The table, with one row per record.
+    ///
+    /// Raises:
+    ///     ImportError: When the `pandas` module cannot be imported.
+    ///     ValueError: When ``table`` is not a known table name.
+    ///
+    /// Example:
+    ///     >>> df = ms.to_pandas()
+    ///     >>> df.set_index("id").loc["MS:1000001", "name"]
+    ///     'sample number'
+    ///
+    #[pyo3(signature = (table = "entities"), text_signature = "(self, table=\"entities\")")]
+    fn to_pandas(&self, py: Python, table: &str) -> PyResult<PyObject> {
+        let columns = match table {
+            "entities" => crate::table::ENTITY_COLUMNS,
+            "synonyms" => crate::table::SYNONYM_COLUMNS,
+            "xrefs" => crate::table::XREF_COLUMNS,
+            other => return Err(PyValueError::new_err(format!("invalid table: {:?}", other))),
+        };
+        let pandas = py.import(pyo3::intern!(py, "pandas"))?;
+        let records = self.to_records(py, table)?;
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("columns", columns.to_vec())?;
+        pandas
+            .getattr(pyo3::intern!(py, "DataFrame"))?
+            .call_method(pyo3::intern!(py, "from_records"), (records,), Some(kwargs))
+            .map(|df| df.to_object(py))
+    }
+
+    /// Get the ancestors of an entity, following the given relations.
+    ///
+    /// Arguments:
+    ///     id (str or `~fastobo.id.BaseIdent`): The identifier of the
+    ///         entity to get the ancestors of.
+    ///     relations (iterable of str): The relations to follow. ``is_a``
+    ///         refers to ``is_a`` clauses, any other relation refers to
+    ///         ``relationship`` clauses with that relation identifier.
+    ///
+    /// Returns:
+    ///     `set` of `~fastobo.id.BaseIdent`: The identifiers of all the
+    ///     entities transitively reachable from ``id``.
+    ///
+    /// Example:
+    ///     >>> sorted(map(str, ms.ancestors("MS:1000121")))
+    ///     ['MS:1000031']
+    ///     >>> sorted(map(str, ms.ancestors("MS:1000031", ["is_a", "part_of"])))
+    ///     ['MS:0000000', 'MS:1000463', 'MS:1001458']
+    ///
+    #[pyo3(text_signature = "(self, id, relations=('is_a',))", signature = (id, relations = None))]
+    fn ancestors(&self, py: Python, id: &PyAny, relations: Option<Vec<String>>) -> PyResult<PyObject> {
+        let id = extract_ident(py, id)?;
+        let relations = relations.unwrap_or_else(|| vec![String::from("is_a")]);
+        let index = self.relation_index(py, &relations);
+        ident_set(py, index.ancestors(&id))
+    }
+
+    /// Get the descendants of an entity, following the given relations.
+    ///
+    /// Arguments:
+    ///     id (str or `~fastobo.id.BaseIdent`): The identifier of the
+    ///         entity to get the descendants of.
+    ///     relations (iterable of str): The relations to follow. ``is_a``
+    ///         refers to ``is_a`` clauses, any other relation refers to
+    ///         ``relationship`` clauses with that relation identifier.
+    ///
+    /// Returns:
+    ///     `set` of `~fastobo.id.BaseIdent`: The identifiers of all the
+    ///     entities from which ``id`` can be transitively reached.
+    ///
+    /// Example:
+    ///     >>> len(ms.descendants("MS:1000031"))
+    ///     330
+    ///
+    #[pyo3(text_signature = "(self, id, relations=('is_a',))", signature = (id, relations = None))]
+    fn descendants(&self, py: Python, id: &PyAny, relations: Option<Vec<String>>) -> PyResult<PyObject> {
+        let id = extract_ident(py, id)?;
+        let relations = relations.unwrap_or_else(|| vec![String::from("is_a")]);
+        let index = self.relation_index(py, &relations);
+        ident_set(py, index.descendants(&id))
+    }
+
+    /// Extract a subset of the document around the given entities.
+    ///
+    /// The extracted document contains the term frames with the given
+    /// identifiers, optionally extended with their ``is_a`` closure, and
+    /// the typedef frames of the relations used by the extracted terms.
+    /// Clauses of the extracted terms referencing a term outside of the
+    /// subset are removed, except for ``is_a`` clauses, which are replaced
+    /// by ``is_a`` clauses to the closest superclasses within the subset.
+    ///
+    /// Arguments:
+    ///     ids (iterable of str or `~fastobo.id.BaseIdent`): The identifiers
+    ///         of the terms to extract.
+    ///     closure (str): The ``is_a`` closure of the given terms to extract
+    ///         as well, either ``ancestors``, ``descendants`` or ``none``.
+    ///
+    /// Returns:
+    ///     `~fastobo.doc.OboDoc`: A new document with the subset of frames,
+    ///     and a copy of the header of this document.
+    ///
+    /// Raises:
+    ///     ValueError: When ``closure`` is not a valid closure type.
+    ///
+    /// Example:
+    ///     >>> subset = ms.extract_subset(["MS:1000121"])
+    ///     >>> [str(frame.id) for frame in subset]
+    ///     ['MS:1000031', 'MS:1000121']
+    ///
+    #[pyo3(text_signature = "(self, ids, closure='ancestors')", signature = (ids, closure = "ancestors"))]
+    fn extract_subset(&self, py: Python, ids: &PyAny, closure: &str) -> PyResult<Self> {
+        let mut selected = HashSet::new();
+        for item in ids.iter()? {
+            selected.insert(extract_ident(py, item?)?);
+        }
+
+        let doc: obo::OboDoc = self.to_ast(py);
+        let index = self.relation_index(py, &[String::from("is_a")]);
+        let closure: Vec<obo::Ident> = match closure {
+            "ancestors" => selected.iter().flat_map(|id| index.ancestors(id)).cloned().collect(),
+            "descendants" => selected.iter().flat_map(|id| index.descendants(id)).cloned().collect(),
+            "none" => Vec::new(),
+            other => {
+                let msg = format!("expected 'ancestors', 'descendants' or 'none', found {:?}", other);
+                return Err(PyValueError::new_err(msg));
+            }
+        };
+        selected.extend(closure);
+
+        let subset = py.allow_threads(|| index.extract_subset(doc, &selected));
+        Ok(subset.into_py(py))
+    }
+
+    /// Compute the transitive closure of the ``is_a`` relation.
+    ///
+    /// Returns:
+    ///     `dict`: A mapping of every entity with at least one subclass to
+    ///     the `set` of the identifiers of all of its subclasses.
+    ///
+    /// Example:
+    ///     >>> closure = ms.subclasses_closure()
+    ///     >>> len(closure[fastobo.id.PrefixedIdent("MS", "1000031")])
+    ///     330
+    ///
+    #[pyo3(text_signature = "(self, /)")]
+    fn subclasses_closure(&self, py: Python) -> PyResult<PyObject> {
+        let index = self.relation_index(py, &[String::from("is_a")]);
+        let closure = PyDict::new(py);
+        for id in index.parents() {
+            let key: Ident = id.clone().into_py(py);
+            closure.set_item(key, ident_set(py, index.descendants(id))?)?;
+        }
+        Ok(closure.to_object(py))
+    }
+
+    /// Replace references to obsolete entities with their replacement.
+    ///
+    /// Every reference to an obsolete term or typedef found in a logical
+    /// clause (such as ``is_a``, ``relationship`` or ``intersection_of``)
+    /// of a non-obsolete frame is rewritten to the target of its
+    /// ``replaced_by`` clause, provided there is exactly one. Chains of
+    /// replacements are followed until a non-obsolete entity is reached.
+    /// The document is modified in place.
+    ///
+    /// Returns:
+    ///     `list` of `tuple`: The references that could not be replaced, as
+    ///     ``(frame_id, obsolete_id, suggestions)`` tuples, where
+    ///     ``suggestions`` is a `list` of the identifiers given by the
+    ///     ``consider`` (or the ambiguous ``replaced_by``) clauses of the
+    ///     obsolete entity.
+    ///
+    /// Example:
+    ///     >>> doc = fastobo.loads(textwrap.dedent(
+    ///     ...     """
+    ///     ...     [Term]
+    ///     ...     id: TST:001
+    ///     ...     is_a: TST:002
+    ///     ...     is_a: TST:003
+    ///     ...
+    ///     ...     [Term]
+    ///     ...     id: TST:002
+    ///     ...     is_obsolete: true
+    ///     ...     replaced_by: TST:004
+    ///     ...
+    ///     ...     [Term]
+    ///     ...     id: TST:003
+    ///     ...     is_obsolete: true
+    ///     ...     consider: TST:005
+    ///     ...     """
+    ///     ... ))
+    ///     >>> doc.apply_replacements()
+    ///     [(PrefixedIdent('TST', '001'), PrefixedIdent('TST', '003'), [PrefixedIdent('TST', '005')])]
+    ///     >>> [str(clause) for clause in doc[0]]
+    ///     ['is_a: TST:004', 'is_a: TST:003']
+    ///
+    #[pyo3(text_signature = "(self, /)")]
+    fn apply_replacements(&mut self, py: Python) -> PyResult<PyObject> {
+        let mut unresolved = Vec::new();
+        self.edit(py, |doc| {
+            unresolved = py.allow_threads(|| crate::transform::apply_replacements(doc));
+        })?;
+
+        let report = PyList::empty(py);
+        for reference in unresolved {
+            let frame: Ident = reference.frame.into_py(py);
+            let obsolete: Ident = reference.obsolete.into_py(py);
+            let consider = reference
+                .consider
+                .into_iter()
+                .map(|id| IntoPy::<Ident>::into_py(id, py).to_object(py))
+                .collect::<Vec<PyObject>>();
+            report.append((frame, obsolete, PyList::new(py, consider)).to_object(py))?;
+        }
+        Ok(report.to_object(py))
+    }
+
+    /// Rename an identifier everywhere in the document.
+    ///
+    /// The identifier of the frame with the old identifier is updated, as
+    /// well as every reference to it, in any clause of the document (for
+    /// instance ``is_a``, ``relationship``, ``xref`` or ``property_value``
+    /// clauses, including in the header). The document is modified in place.
+    ///
+    /// Arguments:
+    ///     old_id (str or `~fastobo.id.BaseIdent`): The identifier to rename.
+    ///     new_id (str or `~fastobo.id.BaseIdent`): The new identifier.
+    ///     keep_alt_id (bool): Whether to add an ``alt_id`` clause with the
+    ///         old identifier to the renamed frame.
+    ///
+    /// Raises:
+    ///     ValueError: When the document already contains a frame with the
+    ///         new identifier.
+    ///
+    /// Example:
+    ///     >>> doc = fastobo.loads(textwrap.dedent(
+    ///     ...     """
+    ///     ...     [Term]
+    ///     ...     id: TST:001
+    ///     ...     name: test
+    ///     ...
+    ///     ...     [Term]
+    ///     ...     id: TST:002
+    ///     ...     is_a: TST:001
+    ///     ...     """
+    ///     ... ))
+    ///     >>> doc.rename("TST:001", "TST:100")
+    ///     >>> print(doc)
+    ///     [Term]
+    ///     id: TST:100
+    ///     name: test
+    ///     alt_id: TST:001
+    ///     <BLANKLINE>
+    ///     [Term]
+    ///     id: TST:002
+    ///     is_a: TST:100
+    ///     <BLANKLINE>
+    ///
+    #[pyo3(
+        text_signature = "(self, old_id, new_id, keep_alt_id=True)",
+        signature = (old_id, new_id, keep_alt_id = true)
+    )]
+    fn rename(
+        &mut self,
+        py: Python,
+        old_id: &PyAny,
+        new_id: &PyAny,
+        keep_alt_id: bool,
+    ) -> PyResult<()> {
+        check_mutable(self.frozen, "OboDoc")?;
+        let old = extract_ident(py, old_id)?;
+        let new = extract_ident(py, new_id)?;
+        if old != new && self.position_id(py, &new.clone().into_py(py)).is_some() {
+            let msg = format!("document already contains a frame with id {}", new);
+            return Err(PyValueError::new_err(msg));
+        }
+
+        self.edit(py, |doc| {
+            py.allow_threads(|| crate::transform::rename(doc, &old, &new, keep_alt_id));
+        })
+    }
+
+    /// Create a new document with identifiers rewritten by a mapping.
+    ///
+    /// Every identifier found in the document is rewritten, including the
+    /// identifiers of the frames and the ones referenced in clauses. When
+    /// several identifiers must be renamed, this is much faster than
+    /// calling `~OboDoc.rename` once for each of them.
+    ///
+    /// Arguments:
+    ///     mapping (dict or callable): Either a `dict` mapping identifiers
+    ///         to their replacement, or a callable taking a
+    ///         `~fastobo.id.BaseIdent` and returning the replacement, or
+    ///         `None` to keep the identifier unchanged. Identifiers can be
+    ///         given as `str` or `~fastobo.id.BaseIdent` in both cases.
+    ///
+    /// Returns:
+    ///     `~fastobo.doc.OboDoc`: A new document with rewritten identifiers.
+    ///
+    /// Raises:
+    ///     TypeError: When ``mapping`` is neither a `dict` nor a callable.
+    ///
+    /// Example:
+    ///     >>> doc = fastobo.loads(textwrap.dedent(
+    ///     ...     """
+    ///     ...     [Term]
+    ///     ...     id: TST:001
+    ///     ...
+    ///     ...     [Term]
+    ///     ...     id: TST:002
+    ///     ...     is_a: TST:001
+    ///     ...     """
+    ///     ... ))
+    ///     >>> print(doc.map_idents({"TST:001": "NEW:001"})[1])
+    ///     [Term]
+    ///     id: TST:002
+    ///     is_a: NEW:001
+    ///     <BLANKLINE>
+    ///     >>> print(doc.map_idents(lambda id: str(id).lower())[1])
+    ///     [Term]
+    ///     id: tst:002
+    ///     is_a: tst:001
+    ///     <BLANKLINE>
+    ///
+    #[pyo3(text_signature = "(self, mapping)")]
+    fn map_idents(&self, py: Python, mapping: &PyAny) -> PyResult<Self> {
+        let mut doc: obo::OboDoc = self.to_ast(py);
+        if let Ok(dict) = mapping.downcast::<PyDict>() {
+            let table = dict
+                .iter()
+                .map(|(k, v)| Ok((extract_ident(py, k)?, extract_ident(py, v)?)))
+                .collect::<PyResult<HashMap<_, _>>>()?;
+            py.allow_threads(|| crate::transform::map_idents(&mut doc, &table));
+        } else if mapping.is_callable() {
+            let mut mapper = CallableIdMapper {
+                function: mapping,
+                error: None,
+            };
+            mapper.visit_doc(&mut doc);
+            if let Some(err) = mapper.error {
+                return Err(err);
+            }
+        } else {
+            let ty = mapping.get_type().name()?;
+            let msg = format!("expected dict or callable, found {}", ty);
+            return Err(PyTypeError::new_err(msg));
+        }
+        Ok(doc.into_py(py))
+    }
+
+    /// Rename the prefixes of the identifiers of the document.
+    ///
+    /// Every prefixed identifier found in the document is rewritten, in the
+    /// frames as well as in the header, and the prefixes declared in
+    /// ``idspace`` header clauses are renamed accordingly. The document is
+    /// modified in place.
+    ///
+    /// Arguments:
+    ///     prefixes (dict): A mapping of old prefixes to new prefixes.
+    ///         Prefixes not in the mapping are left unchanged.
+    ///
+    /// Example:
+    ///     >>> doc = fastobo.loads(textwrap.dedent(
+    ///     ...     """
+    ///     ...     idspace: MassBank http://www.massbank.jp/jsp/FwdRecord.jsp?id=
+    ///     ...
+    ///     ...     [Term]
+    ///     ...     id: CHEBI:27958
+    ///     ...     xref: MassBank:EA281701
+    ///     ...     """
+    ///     ... ))
+    ///     >>> doc.remap_prefixes({"CHEBI": "CHE", "MassBank": "MB"})
+    ///     >>> print(doc)
+    ///     idspace: MB http://www.massbank.jp/jsp/FwdRecord.jsp?id=
+    ///     <BLANKLINE>
+    ///     [Term]
+    ///     id: CHE:27958
+    ///     xref: MB:EA281701
+    ///     <BLANKLINE>
+    ///
+    #[pyo3(text_signature = "(self, prefixes)")]
+    fn remap_prefixes(&mut self, py: Python, prefixes: HashMap<String, String>) -> PyResult<()> {
+        self.edit(py, |doc| {
+            py.allow_threads(|| crate::transform::remap_prefixes(doc, &prefixes));
+        })
+    }
+
+    /// Extract the entity frames matching an ID space or a namespace.
+    ///
+    /// A frame is kept if its identifier is prefixed by one of the given
+    /// ID spaces, or if it has a ``namespace`` clause with one of the given
+    /// namespaces. Criteria given as `None` are ignored.
+    ///
+    /// Arguments:
+    ///     prefixes (str or iterable of str, optional): The ID spaces of the
+    ///         frames to keep.
+    ///     namespaces (str or iterable of str, optional): The namespaces of
+    ///         the frames to keep.
+    ///
+    /// Returns:
+    ///     `~fastobo.doc.OboDoc`: A new document with the matching frames,
+    ///     and a copy of the header of this document.
+    ///
+    /// Example:
+    ///     >>> doc = fastobo.loads(textwrap.dedent(
+    ///     ...     """
+    ///     ...     [Term]
+    ///     ...     id: GO:0000001
+    ///     ...     namespace: biological_process
+    ///     ...
+    ///     ...     [Term]
+    ///     ...     id: GO:0000002
+    ///     ...     namespace: cellular_component
+    ///     ...
+    ///     ...     [Term]
+    ///     ...     id: CHEBI:27958
+    ///     ...     """
+    ///     ... ))
+    ///     >>> [str(frame.id) for frame in doc.filter(prefixes="CHEBI")]
+    ///     ['CHEBI:27958']
+    ///     >>> bp = doc.filter(namespaces=["biological_process"])
+    ///     >>> [str(frame.id) for frame in bp]
+    ///     ['GO:0000001']
+    ///
+    #[pyo3(
+        text_signature = "(self, prefixes=None, namespaces=None)",
+        signature = (prefixes = None, namespaces = None)
+    )]
+    fn filter(
+        &self,
+        py: Python,
+        prefixes: Option<&PyAny>,
+        namespaces: Option<&PyAny>,
+    ) -> PyResult<Self> {
+        let prefixes = prefixes.map(crate::utils::str_set).transpose()?;
+        let namespaces = namespaces.map(crate::utils::str_set).transpose()?;
+        let mut doc: obo::OboDoc = self.to_ast(py);
+        py.allow_threads(|| {
+            crate::transform::filter(&mut doc, prefixes.as_ref(), namespaces.as_ref())
+        });
+        Ok(doc.into_py(py))
+    }
+
+    /// Convert the document to a `dict` of builtin Python types.
+    ///
+    /// The resulting dictionary only contains `dict`, `list` and `str`
+    /// objects, so that it can be serialized with `json`, or sent to
+    /// another process, and converted back with `OboDoc.from_dict`.
+    ///
+    /// Returns:
+    ///     `dict`: A dictionary with the ``header`` of the document and its
+    ///     ``entities``, as returned by the `to_dict` method of each frame.
+    ///
+    /// Example:
+    ///     >>> doc = fastobo.loads(textwrap.dedent(
+    ///     ...     """
+    ///     ...     format-version: 1.4
+    ///     ...
+    ///     ...     [Term]
+    ///     ...     id: TST:001
+    ///     ...     is_a: TST:002
+    ///     ...     """
+    ///     ... ))
+    ///     >>> pprint(doc.to_dict())
+    ///     {'entities': [{'clauses': [{'tag': 'is_a', 'value': 'TST:002'}],
+    ///                    'id': 'TST:001',
+    ///                    'type': 'Term'}],
+    ///      'header': {'clauses': [{'tag': 'format-version', 'value': '1.4'}],
+    ///                 'type': 'Header'}}
+    ///
+    #[pyo3(text_signature = "(self)")]
+    fn to_dict(&self, py: Python) -> PyResult<PyObject> {
+        crate::dict::doc_to_dict(py, self.to_ast(py))
+    }
+
+    /// Create a new document from a `dict` created with `OboDoc.to_dict`.
+    ///
+    /// Raises:
+    ///     ValueError: When a frame has an unknown type.
+    ///     SyntaxError: When an identifier or a clause cannot be parsed.
+    ///
+    #[staticmethod]
+    #[pyo3(text_signature = "(d)")]
+    fn from_dict(py: Python, d: &PyAny) -> PyResult<Self> {
+        let doc = crate::dict::doc_from_dict(d)?;
+        Ok(doc.into_py(py))
+    }
+
+    /// Create a new document from a header and an iterable of frames.
+    ///
+    /// Unlike the `OboDoc` constructor, the header is not copied, so the
+    /// new document references the given header and frames, like a
+    /// document created with ``+`` references the frames of its operands.
+    ///
+    /// Arguments:
+    ///     header (`~fastobo.header.HeaderFrame`, optional): The header of
+    ///         the new document. If `None` given, use an empty header.
+    ///     frames (`collections.abc.Iterable`): An iterable of entity
+    ///         frames, either `TermFrame`, `TypedefFrame` or
+    ///         `InstanceFrame`.
+    ///
+    /// Raises:
+    ///     TypeError: When ``frames`` contains an object which is not an
+    ///         entity frame.
+    ///
+    /// Example:
+    ///     >>> doc = fastobo.loads(textwrap.dedent(
+    ///     ...     """
+    ///     ...     format-version: 1.4
+    ///     ...
+    ///     ...     [Term]
+    ///     ...     id: TST:001
+    ///     ...
+    ///     ...     [Typedef]
+    ///     ...     id: part_of
+    ///     ...     """
+    ///     ... ))
+    ///     >>> terms = fastobo.doc.OboDoc.from_frames(doc.header, doc.terms)
+    ///     >>> len(terms)
+    ///     1
+    ///     >>> terms.header is doc.header
+    ///     True
+    ///
+    #[staticmethod]
+    #[pyo3(signature = (header = None, frames = None), text_signature = "(header=None, frames=())")]
+    fn from_frames(
+        py: Python,
+        header: Option<Py<HeaderFrame>>,
+        frames: Option<&PyAny>,
+    ) -> PyResult<Self> {
+        let header = match header {
+            Some(h) => h,
+            None => Py::new(py, HeaderFrame::empty())?,
+        };
+        let entities = match frames {
+            Some(frames) => frames
+                .iter()?
+                .map(|res| res.and_then(EntityFrame::extract))
+                .collect::<PyResult<Vec<_>>>()?,
+            None => Vec::new(),
+        };
+        Ok(Self::with_entities(header, entities))
+    }
+}
+
+/// Extract an identifier from a `str` or a `BaseIdent` instance.
+fn extract_ident(py: Python, object: &PyAny) -> PyResult<obo::Ident> {
+    if let Ok(s) = object.downcast::<PyString>() {
+        obo::Ident::from_str(s.to_str()?).map_err(|e| PyErr::from(Error::from(e)))
+    } else {
+        Ok(object.extract::<Ident>()?.into_py(py))
+    }
+}
+
+/// A visitor replacing identifiers with the result of a Python callable.
+struct CallableIdMapper<'py> {
+    function: &'py PyAny,
+    error: Option<PyErr>,
+}
+
+impl VisitMut for CallableIdMapper<'_> {
+    fn visit_ident(&mut self, id: &mut obo::Ident) {
+        if self.error.is_some() {
+            return;
+        }
+        let py = self.function.py();
+        let arg = IntoPy::<Ident>::into_py(id.clone(), py).to_object(py);
+        let result = self.function.call1((arg,)).and_then(|new| {
+            if new.is_none() {
+                Ok(None)
+            } else {
+                extract_ident(py, new).map(Some)
+            }
+        });
+        match result {
+            Ok(Some(new)) => *id = new,
+            Ok(None) => (),
+            Err(err) => self.error = Some(err),
+        }
+    }
+}
+
+/// Build a Python `set` of identifiers.
+fn ident_set<'a, I>(py: Python, ids: I) -> PyResult<PyObject>
+where
+    I: IntoIterator<Item = &'a obo::Ident>,
+{
+    let ids = ids
+        .into_iter()
+        .map(|id| IntoPy::<Ident>::into_py(id.clone(), py).to_object(py))
+        .collect::<Vec<PyObject>>();
+    PySet::new(py, &ids).map(|set| set.to_object(py))
+}
+
+// --- NetworkX export -------------------------------------------------------
+
+/// The tags that `obonet` stores as a single value rather than a list.
+const SINGULAR_TAGS: &[&str] = &[
+    "format-version",
+    "data-version",
+    "date",
+    "saved-by",
+    "auto-generated-by",
+    "default-namespace",
+    "ontology",
+    "is_anonymous",
+    "name",
+    "namespace",
+    "def",
+    "comment",
+    "builtin",
+    "is_obsolete",
+    "created_by",
+    "creation_date",
+    "domain",
+    "range",
+    "is_cyclic",
+    "is_reflexive",
+    "is_symmetric",
+    "is_asymmetric",
+    "is_transitive",
+    "is_functional",
+    "is_inverse_functional",
+    "is_metadata_tag",
+    "is_class_level",
+    "inverse_of",
+    "instance_of",
+];
+
+/// Add the value of a serialized clause to a dictionary of attributes.
+fn add_tag_value(attrs: &PyDict, clause: &str) -> PyResult<()> {
+    let (tag, value) = clause.split_once(": ").unwrap_or((clause, ""));
+    if SINGULAR_TAGS.contains(&tag) {
+        attrs.set_item(tag, value)
+    } else if let Some(values) = attrs.get_item(tag) {
+        values.downcast::<PyList>()?.append(value)
+    } else {
+        attrs.set_item(tag, PyList::new(attrs.py(), [value]))
+    }
+}
+
+/// Add an edge keyed by a relationship type to a `networkx` graph.
+fn add_edge(graph: &PyAny, source: &str, target: &str, key: &str) -> PyResult<()> {
+    let kwargs = PyDict::new(graph.py());
+    kwargs.set_item("key", key)?;
+    graph.call_method("add_edge", (source, target), Some(kwargs))?;
+    Ok(())
+}
+
+// --- Typed views -----------------------------------------------------------
+
+/// An iterator over the entity frames of an `OboDoc` matching a filter.
+#[pyclass(module = "fastobo.doc")]
+pub struct EntityFrameIter {
+    doc: Py<OboDoc>,
+    index: usize,
+    filter: fn(&EntityFrame) -> bool,
+}
+
+impl EntityFrameIter {
+    pub fn new(doc: Py<OboDoc>, filter: fn(&EntityFrame) -> bool) -> Self {
+        Self {
+            doc,
+            index: 0,
+            filter,
+        }
+    }
+}
+
+#[pymethods]
+impl EntityFrameIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<PyObject> {
+        let py = slf.py();
+        let doc = slf.doc.clone_ref(py);
+        let entities = &doc.borrow(py).entities;
+        while slf.index < entities.len() {
+            let frame = &entities[slf.index];
+            slf.index += 1;
+            if (slf.filter)(frame) {
+                return Some(frame.to_object(py));
+            }
+        }
+        None
+    }
+}
+
+macro_rules! impl_view {
+    ($name:ident, $variant:ident, $frame:literal) => {
+        #[doc = concat!("A view over the `~fastobo.", $frame, "` objects of an `OboDoc`.")]
+        #[pyclass(module = "fastobo.doc")]
+        pub struct $name {
+            doc: Py<OboDoc>,
+        }
+
+        impl $name {
+            pub fn new(doc: Py<OboDoc>) -> Self {
+                Self { doc }
+            }
+
+            fn filter(frame: &EntityFrame) -> bool {
+                matches!(frame, EntityFrame::$variant(_))
+            }
+        }
+
+        #[pymethods]
+        impl $name {
+            fn __len__(&self, py: Python) -> usize {
+                let doc = self.doc.borrow(py);
+                doc.entities.iter().filter(|frame| Self::filter(frame)).count()
+            }
+
+            fn __iter__(&self, py: Python) -> EntityFrameIter {
+                EntityFrameIter::new(self.doc.clone_ref(py), Self::filter)
+            }
+        }
+    };
+}
+
+impl_view!(TermFrameView, Term, "term.TermFrame");
+impl_view!(TypedefFrameView, Typedef, "typedef.TypedefFrame");
+impl_view!(InstanceFrameView, Instance, "instance.InstanceFrame");
+
+// --- Diff ------------------------------------------------------------------
+
+/// A record of the changes made to a frame between two documents.
+///
+/// Clauses are compared for equality, including their qualifiers and
+/// comment but ignoring their order in the frame, so frames with only
+/// reordered clauses are not reported. When exactly one clause with a given tag was removed and one clause with
+/// the same tag was added, they are reported as a modified clause instead.
+#[pyclass(module = "fastobo.doc")]
+#[derive(Debug)]
+pub struct FrameDiff {
+    /// `~fastobo.id.BaseIdent` or `None`: the identifier of the frame, or
+    /// `None` if the changes were made to the header frame.
+    #[pyo3(get)]
+    id: PyObject,
+    /// `str`: either ``added``, ``removed`` or ``modified``.
+    #[pyo3(get)]
+    status: &'static str,
+    /// `~fastobo.abc.AbstractFrame` or `None`: the frame in the old document.
+    #[pyo3(get)]
+    old: PyObject,
+    /// `~fastobo.abc.AbstractFrame` or `None`: the frame in the new document.
+    #[pyo3(get)]
+    new: PyObject,
+    /// `list` of `~fastobo.abc.AbstractClause`: the clauses only in the new frame.
+    #[pyo3(get)]
+    added: Vec<PyObject>,
+    /// `list` of `~fastobo.abc.AbstractClause`: the clauses only in the old frame.
+    #[pyo3(get)]
+    removed: Vec<PyObject>,
+    /// `list` of `tuple`: the pairs of old and new clauses with a modified value.
+    #[pyo3(get)]
+    modified: Vec<(PyObject, PyObject)>,
+}
+
+impl FrameDiff {
+    /// Compute the changes between the frames of two documents.
+    pub fn compute(py: Python, old: obo::OboDoc, new: obo::OboDoc) -> Vec<FrameDiff> {
+        let mut old = old;
+        let mut new = new;
+        let mut diffs = Vec::new();
+
+        // compare header frames
+        let old_header = take(old.header_mut());
+        let new_header = take(new.header_mut());
+        if old_header != new_header {
+            let (added, removed, modified) = diff_clauses(
+                old_header.clone().into_iter().collect(),
+                new_header.clone().into_iter().collect(),
+                |clause| IntoPy::<HeaderClause>::into_py(clause, py).to_object(py),
+            );
+            if !added.is_empty() || !removed.is_empty() || !modified.is_empty() {
+                diffs.push(FrameDiff {
+                    id: py.None(),
+                    status: "modified",
+                    old: Py::new(py, IntoPy::<HeaderFrame>::into_py(old_header, py))
+                        .expect("could not allocate on Python heap")
+                        .to_object(py),
+                    new: Py::new(py, IntoPy::<HeaderFrame>::into_py(new_header, py))
+                        .expect("could not allocate on Python heap")
+                        .to_object(py),
+                    added,
+                    removed,
+                    modified,
+                });
+            }
+        }
+
+        // index the frames of the new document by identifier
+        let mut new_frames = take(new.entities_mut())
+            .into_iter()
+            .map(|frame| (entity_ident(&frame), Some(frame)))
+            .collect::<Vec<_>>();
+        let positions = new_frames
+            .iter()
+            .enumerate()
+            .map(|(i, (id, _))| (id.clone(), i))
+            .collect::<std::collections::HashMap<_, _>>();
+
+        // compare frames of the old document with frames of the new one
+        for old_frame in take(old.entities_mut()).into_iter() {
+            let id = entity_ident(&old_frame);
+            let new_frame = positions.get(&id).and_then(|&i| new_frames[i].1.take());
+            match new_frame {
+                None => diffs.push(FrameDiff::removed(py, id, old_frame)),
+                Some(new_frame) if new_frame == old_frame => (),
+                Some(new_frame) => diffs.extend(FrameDiff::modified(py, id, old_frame, new_frame)),
+            }
+        }
+
+        // record frames only in the new document
+        for (id, frame) in new_frames {
+            if let Some(new_frame) = frame {
+                diffs.push(FrameDiff::added(py, id, new_frame));
+            }
+        }
+
+        diffs
+    }
+
+    fn added(py: Python, id: obo::Ident, frame: obo::EntityFrame) -> Self {
+        FrameDiff {
+            id: IntoPy::<Ident>::into_py(id, py).to_object(py),
+            status: "added",
+            old: py.None(),
+            new: IntoPy::<EntityFrame>::into_py(frame, py).to_object(py),
+            added: Vec::new(),
+            removed: Vec::new(),
+            modified: Vec::new(),
+        }
+    }
+
+    fn removed(py: Python, id: obo::Ident, frame: obo::EntityFrame) -> Self {
+        FrameDiff {
+            id: IntoPy::<Ident>::into_py(id, py).to_object(py),
+            status: "removed",
+            old: IntoPy::<EntityFrame>::into_py(frame, py).to_object(py),
+            new: py.None(),
+            added: Vec::new(),
+            removed: Vec::new(),
+            modified: Vec::new(),
+        }
+    }
+
+    fn modified(py: Python, id: obo::Ident, old: obo::EntityFrame, new: obo::EntityFrame) -> Vec<Self> {
+        let (added, removed, modified) = match (old.clone(), new.clone()) {
+            (obo::EntityFrame::Term(l), obo::EntityFrame::Term(r)) => diff_clauses(
+                l.into_iter().collect(),
+                r.into_iter().collect(),
+                |line| IntoPy::<TermClause>::into_py(line, py).to_object(py),
+            ),
+            (obo::EntityFrame::Typedef(l), obo::EntityFrame::Typedef(r)) => diff_clauses(
+                l.into_iter().collect(),
+                r.into_iter().collect(),
+                |line| IntoPy::<TypedefClause>::into_py(line, py).to_object(py),
+            ),
+            (obo::EntityFrame::Instance(l), obo::EntityFrame::Instance(r)) => diff_clauses(
+                l.into_iter().collect(),
+                r.into_iter().collect(),
+                |line| IntoPy::<InstanceClause>::into_py(line, py).to_object(py),
+            ),
+            // frames of different kinds sharing an identifier
+            (old, new) => {
+                return vec![
+                    FrameDiff::removed(py, id.clone(), old),
+                    FrameDiff::added(py, id, new),
+                ]
+            }
+        };
+        // frames with reordered clauses only
+        if added.is_empty() && removed.is_empty() && modified.is_empty() {
+            return Vec::new();
+        }
+        vec![FrameDiff {
+            id: IntoPy::<Ident>::into_py(id, py).to_object(py),
+            status: "modified",
+            old: IntoPy::<EntityFrame>::into_py(old, py).to_object(py),
+            new: IntoPy::<EntityFrame>::into_py(new, py).to_object(py),
+            added,
+            removed,
+            modified,
+        }]
+    }
+}
+
+#[pymethods]
+impl FrameDiff {
+    fn __repr__(&self, py: Python) -> PyResult<PyObject> {
+        let fmt = PyString::new(py, "FrameDiff({!r}, {!r})").to_object(py);
+        fmt.call_method1(py, "format", (&self.id, self.status))
+    }
+}
+
+/// Get the identifier of an entity frame of the syntax tree.
+fn entity_ident(frame: &obo::EntityFrame) -> obo::Ident {
+    match frame {
+        obo::EntityFrame::Term(t) => t.id().as_ref().clone().into(),
+        obo::EntityFrame::Typedef(t) => t.id().as_ref().clone().into(),
+        obo::EntityFrame::Instance(i) => i.id().as_ref().clone().into(),
+    }
+}
+
+/// A clause of a frame that can be compared in a `FrameDiff`.
+trait DiffClause: Eq + Hash {
+    /// Get the tag of the clause.
+    fn diff_tag(&self) -> &str;
+}
+
+impl DiffClause for obo::HeaderClause {
+    fn diff_tag(&self) -> &str {
+        self.tag()
+    }
+}
+
+/// Lines are compared with their qualifiers and comment, like frames are.
+impl<C: OboClause + Eq + Hash> DiffClause for obo::Line<C> {
+    fn diff_tag(&self) -> &str {
+        self.as_ref().tag()
+    }
+}
+
+/// Compute the clauses added, removed and modified between two frames.
+fn diff_clauses<C, F>(
+    old: Vec<C>,
+    new: Vec<C>,
+    convert: F,
+) -> (Vec<PyObject>, Vec<PyObject>, Vec<(PyObject, PyObject)>)
+where
+    C: DiffClause,
+    F: Fn(C) -> PyObject,
+{
+    // compute the multiset differences between both clause lists, using
+    // the positions of each new clause (first occurrence last) to match
+    // every old clause in constant time
+    let mut positions: HashMap<&C, Vec<usize>> = HashMap::new();
+    for (i, clause) in new.iter().enumerate().rev() {
+        positions.entry(clause).or_default().push(i);
+    }
+    let mut matched = vec![false; new.len()];
+    let mut removed = Vec::new();
+    for clause in old {
+        match positions.get_mut(&clause).and_then(Vec::pop) {
+            Some(i) => matched[i] = true,
+            None => removed.push(clause),
+        }
+    }
+    drop(positions);
+    let added = new
+        .into_iter()
+        .zip(matched)
+        .filter_map(|(clause, matched)| (!matched).then_some(clause))
+        .collect::<Vec<C>>();
+
+    // pair clauses with a tag only appearing once in both differences
+    let count = |clauses: &[C]| {
+        let mut counts = HashMap::new();
+        for clause in clauses {
+            *counts.entry(clause.diff_tag().to_string()).or_insert(0usize) += 1;
+        }
+        counts
+    };
+    let removed_counts = count(&removed);
+    let added_counts = count(&added);
+    let unique = |clause: &C| {
+        let tag = clause.diff_tag();
+        removed_counts.get(tag) == Some(&1) && added_counts.get(tag) == Some(&1)
+    };
+    let (paired, added): (Vec<C>, Vec<C>) = added.into_iter().partition(|c| unique(c));
+    let mut paired = paired
+        .into_iter()
+        .map(|clause| (clause.diff_tag().to_string(), clause))
+        .collect::<HashMap<String, C>>();
+    let mut modified = Vec::new();
+    let mut unpaired = Vec::new();
+    for old in removed {
+        match paired.remove(old.diff_tag()) {
+            Some(new) => modified.push((convert(old), convert(new))),
+            None => unpaired.push(old),
+        }
+    }
+
+    (
+        added.into_iter().map(&convert).collect(),
+        unpaired.into_iter().map(&convert).collect(),
+        modified,
+    )
 }
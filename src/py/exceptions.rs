@@ -30,6 +30,8 @@ macro_rules! impl_pyerr {
 #[pyo3(name = "exceptions")]
 pub fn init(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<self::MissingClauseError>()?;
+    m.add_class::<self::MissingOntologyClauseError>()?;
+    m.add_class::<self::MissingNamespaceError>()?;
     m.add_class::<self::DuplicateClausesError>()?;
     m.add_class::<self::SingleClauseError>()?;
     m.add_class::<self::DisconnectedChannelError>()?;
@@ -41,9 +43,13 @@ pub fn init(py: Python, m: &PyModule) -> PyResult<()> {
 
 
 /// An error indicating a required clause is missing.
-#[pyclass(module = "fastobo.exceptions", extends = PyValueError)]
+#[pyclass(module = "fastobo.exceptions", extends = PyValueError, subclass)]
 pub struct MissingClauseError {
+    /// `str`: The name of the missing clause.
+    #[pyo3(get)]
     clause: String,
+    /// `str` or `None`: The identifier of the frame missing the clause.
+    #[pyo3(get)]
     frame: Option<String>,
 }
 
@@ -71,6 +77,64 @@ impl MissingClauseError {
     }
 }
 
+// --- MissingOntologyClauseError --------------------------------------------
+
+/// An error indicating the ``ontology`` header clause is missing.
+///
+/// The OWL translation needs the ``ontology`` clause to build the IRI of
+/// the ontology and of unprefixed identifiers. Add an `OntologyClause` to
+/// the header of the document, or pass ``ontology_iri`` to `~fastobo.dump_owl`,
+/// before retrying.
+#[pyclass(module = "fastobo.exceptions", extends = MissingClauseError)]
+pub struct MissingOntologyClauseError {}
+
+impl_pyerr!(MissingOntologyClauseError);
+
+#[pymethods]
+impl MissingOntologyClauseError {
+    #[new]
+    fn __init__(clause: String, frame: Option<String>) -> PyClassInitializer<Self> {
+        PyClassInitializer::from(MissingClauseError { clause, frame }).add_subclass(Self {})
+    }
+
+    fn __repr__(slf: PyRef<Self>) -> String {
+        let base = slf.as_ref();
+        match &base.frame {
+            None => format!("MissingOntologyClauseError({})", base.clause.as_str()),
+            Some(f) => format!("MissingOntologyClauseError({}, {})", base.clause.as_str(), f),
+        }
+    }
+}
+
+// --- MissingNamespaceError -------------------------------------------------
+
+/// An error indicating the ``default-namespace`` header clause is missing.
+///
+/// The OWL translation needs a namespace for every entity, and uses the
+/// ``default-namespace`` clause for entities without a ``namespace`` clause.
+/// Add a `DefaultNamespaceClause` to the header of the document before
+/// retrying.
+#[pyclass(module = "fastobo.exceptions", extends = MissingClauseError)]
+pub struct MissingNamespaceError {}
+
+impl_pyerr!(MissingNamespaceError);
+
+#[pymethods]
+impl MissingNamespaceError {
+    #[new]
+    fn __init__(clause: String, frame: Option<String>) -> PyClassInitializer<Self> {
+        PyClassInitializer::from(MissingClauseError { clause, frame }).add_subclass(Self {})
+    }
+
+    fn __repr__(slf: PyRef<Self>) -> String {
+        let base = slf.as_ref();
+        match &base.frame {
+            None => format!("MissingNamespaceError({})", base.clause.as_str()),
+            Some(f) => format!("MissingNamespaceError({}, {})", base.clause.as_str(), f),
+        }
+    }
+}
+
 // --- DuplicateClausesError -------------------------------------------------
 
 /// An error indicating a unique clause appears more than one.
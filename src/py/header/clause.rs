@@ -21,9 +21,11 @@ use pyo3::prelude::*;
 use pyo3::types::PyAny;
 use pyo3::types::PyDateAccess;
 use pyo3::types::PyDateTime;
+use pyo3::types::PyDict;
 use pyo3::types::PyList;
 use pyo3::types::PyString;
 use pyo3::types::PyTimeAccess;
+use pyo3::types::PyTuple;
 use pyo3::PyNativeType;
 use pyo3::PyTypeInfo;
 
@@ -33,16 +35,23 @@ use super::super::id::Ident;
 use super::super::id::Url;
 use super::super::pv::PropertyValue;
 use super::super::syn::SynonymScope;
+use crate::date::datetime_to_naivedatetime;
+use crate::date::naivedatetime_to_datetime;
 use crate::utils::AbstractClass;
 use crate::utils::ClonePy;
+use crate::utils::DisplayPy;
 use crate::utils::EqPy;
 use crate::utils::FinalClass;
+use crate::utils::FreezePy;
+use crate::utils::HashPy;
+use crate::utils::RichCmpPy;
 
 // --- Conversion Wrapper ----------------------------------------------------
 
 /// A thin wrapper for a reference to any possible `BaseHeaderClause` subclass.
-#[derive(ClonePy, Debug, EqPy, PyWrapper)]
-#[wraps(BaseHeaderClause)]
+#[derive(ClonePy, Debug, DisplayPy, EqPy, HashPy, PyWrapper)]
+#[wraps(BaseHeaderClause, parse)]
+#[eqpy(with = "AbstractClause::eq_metadata_of")]
 pub enum HeaderClause {
     FormatVersion(Py<FormatVersionClause>),
     DataVersion(Py<DataVersionClause>),
@@ -68,6 +77,16 @@ pub enum HeaderClause {
     Unreserved(Py<UnreservedClause>),
 }
 
+impl FreezePy for HeaderClause {
+    fn freeze_py(&self, py: Python) -> PyResult<()> {
+        AbstractClause::freeze_object(self.to_object(py).as_ref(py))?;
+        match self {
+            HeaderClause::PropertyValue(c) => c.try_borrow(py)?.inner.freeze_py(py),
+            _ => Ok(()),
+        }
+    }
+}
+
 impl IntoPy<HeaderClause> for fastobo::ast::HeaderClause {
     fn into_py(self, py: Python) -> HeaderClause {
         use fastobo::ast::HeaderClause::*;
@@ -155,6 +174,17 @@ impl IntoPy<fastobo::ast::HeaderClause> for HeaderClause {
 #[base(AbstractClause)]
 pub struct BaseHeaderClause {}
 
+#[pymethods]
+impl BaseHeaderClause {
+    // Allow user-defined subclasses, which are converted through their
+    // `raw_tag` and `raw_value` when they are added to a frame.
+    #[new]
+    #[pyo3(signature = (*_args, **_kwargs))]
+    fn __new__(_args: &PyTuple, _kwargs: Option<&PyDict>) -> PyClassInitializer<Self> {
+        Self::initializer()
+    }
+}
+
 // --- FormatVersion ---------------------------------------------------------
 
 /// FormatVersionClause(version)
@@ -162,7 +192,7 @@ pub struct BaseHeaderClause {}
 ///
 /// A header clause indicating the format version of the OBO document.
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Clone, ClonePy, Debug, EqPy, FinalClass)]
+#[derive(Clone, ClonePy, Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct FormatVersionClause {
     version: obo::UnquotedString,
@@ -174,11 +204,7 @@ impl FormatVersionClause {
     }
 }
 
-impl Display for FormatVersionClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        obo::HeaderClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(FormatVersionClause, version);
 
 impl From<FormatVersionClause> for obo::HeaderClause {
     fn from(clause: FormatVersionClause) -> Self {
@@ -192,6 +218,7 @@ impl IntoPy<obo::HeaderClause> for FormatVersionClause {
     }
 }
 
+#[obo_clause(tag = "format-version")]
 #[pymethods]
 impl FormatVersionClause {
     #[new]
@@ -207,8 +234,12 @@ impl FormatVersionClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.version)
+        self.richcmp_py(other, op)
     }
 
     /// `str`: the OBO format version used in document.
@@ -221,14 +252,6 @@ impl FormatVersionClause {
     fn set_version(&mut self, version: String) {
         self.version = obo::UnquotedString::new(version);
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "format-version").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.version.to_string()
-    }
 }
 
 // --- DataVersion -----------------------------------------------------------
@@ -238,7 +261,7 @@ impl FormatVersionClause {
 ///
 /// A header clause indicating the version of the data in the OBO document.
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Clone, ClonePy, Debug, EqPy, FinalClass)]
+#[derive(Clone, ClonePy, Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct DataVersionClause {
     version: UnquotedString,
@@ -262,12 +285,9 @@ impl IntoPy<obo::HeaderClause> for DataVersionClause {
     }
 }
 
-impl Display for DataVersionClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        obo::HeaderClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(DataVersionClause, version);
 
+#[obo_clause(tag = "data-version")]
 #[pymethods]
 impl DataVersionClause {
     #[new]
@@ -283,8 +303,12 @@ impl DataVersionClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.version)
+        self.richcmp_py(other, op)
     }
 
     /// `str`: the version of the data in the OBO document.
@@ -297,14 +321,6 @@ impl DataVersionClause {
     fn set_version(&mut self, version: String) {
         self.version = UnquotedString::new(version);
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "data-version").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.version.to_string()
-    }
 }
 
 // --- Date ------------------------------------------------------------------
@@ -314,7 +330,7 @@ impl DataVersionClause {
 ///
 /// A header clause indicating the date the document was last modified.
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Clone, ClonePy, Debug, EqPy, FinalClass)]
+#[derive(Clone, ClonePy, Debug, EqPy, HashPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct DateClause {
     date: obo::NaiveDateTime,
@@ -338,24 +354,15 @@ impl IntoPy<obo::HeaderClause> for DateClause {
     }
 }
 
-impl Display for DateClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        obo::HeaderClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(DateClause, date);
 
+#[obo_clause(tag = "date")]
 #[pymethods]
 impl DateClause {
     #[new]
-    fn __init__(date: &PyDateTime) -> PyClassInitializer<Self> {
-        Self::new(fastobo::ast::NaiveDateTime::new(
-            date.get_day() as u8,
-            date.get_month() as u8,
-            date.get_year() as u16,
-            date.get_hour() as u8,
-            date.get_minute() as u8,
-        ))
-        .into()
+    fn __init__(date: &PyDateTime) -> PyResult<PyClassInitializer<Self>> {
+        let date = datetime_to_naivedatetime(date.py(), date)?;
+        Ok(Self::new(date).into())
     }
 
     fn __repr__(&self) -> PyResult<PyObject> {
@@ -369,6 +376,10 @@ impl DateClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
         if let Ok(ref clause) = other.extract::<Py<Self>>() {
             let clause = &*clause.as_ref(other.py()).borrow();
@@ -391,40 +402,28 @@ impl DateClause {
     }
 
     /// `~datetime.datetime`: the date this document was last modified.
+    ///
+    /// Header dates only have a precision to the minute, so the seconds
+    /// and microseconds of the assigned `~datetime.datetime` are dropped.
+    ///
+    /// Example:
+    ///     >>> clause = fastobo.header.DateClause(
+    ///     ...     datetime.datetime(2019, 4, 8, 16, 51, 30)
+    ///     ... )
+    ///     >>> clause.date
+    ///     datetime.datetime(2019, 4, 8, 16, 51)
+    ///
     #[getter]
     fn get_date<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDateTime> {
-        PyDateTime::new(
-            py,
-            self.date.year() as i32,
-            self.date.month(),
-            self.date.day(),
-            self.date.hour(),
-            self.date.minute(),
-            0,
-            0,
-            None,
-        )
+        naivedatetime_to_datetime(py, &self.date)
     }
 
     #[setter]
-    fn set_date(&mut self, dt: &PyDateTime) {
-        self.date = fastobo::ast::NaiveDateTime::new(
-            dt.get_day(),
-            dt.get_month(),
-            dt.get_year() as u16,
-            dt.get_hour(),
-            dt.get_minute(),
-        );
-    }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "date").to_object(slf.py())
+    fn set_date(&mut self, dt: &PyDateTime) -> PyResult<()> {
+        self.date = datetime_to_naivedatetime(dt.py(), dt)?;
+        Ok(())
     }
 
-
-    fn raw_value(&self) -> String {
-        self.date.to_string()
-    }
 }
 
 // --- SavedBy ---------------------------------------------------------------
@@ -434,7 +433,7 @@ impl DateClause {
 ///
 /// A header clause containing the name of the person who saved the document.
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Clone, ClonePy, Debug, EqPy, FinalClass)]
+#[derive(Clone, ClonePy, Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct SavedByClause {
     name: UnquotedString,
@@ -446,11 +445,7 @@ impl SavedByClause {
     }
 }
 
-impl Display for SavedByClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        obo::HeaderClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(SavedByClause, name);
 
 impl From<SavedByClause> for obo::HeaderClause {
     fn from(clause: SavedByClause) -> obo::HeaderClause {
@@ -464,6 +459,7 @@ impl IntoPy<obo::HeaderClause> for SavedByClause {
     }
 }
 
+#[obo_clause(tag = "saved-by")]
 #[pymethods]
 impl SavedByClause {
     #[new]
@@ -479,8 +475,12 @@ impl SavedByClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.name)
+        self.richcmp_py(other, op)
     }
 
     /// `str`: the name of the person who saved the document.
@@ -493,14 +493,6 @@ impl SavedByClause {
     fn set_name(&mut self, name: String) {
         self.name = UnquotedString::new(name);
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "saved-py").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.name.to_string()
-    }
 }
 
 // --- AutoGeneratedBy -------------------------------------------------------
@@ -510,7 +502,7 @@ impl SavedByClause {
 ///
 /// A header clause indicating the software that generated the document.
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Clone, ClonePy, Debug, EqPy, FinalClass)]
+#[derive(Clone, ClonePy, Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct AutoGeneratedByClause {
     name: UnquotedString,
@@ -522,11 +514,7 @@ impl AutoGeneratedByClause {
     }
 }
 
-impl Display for AutoGeneratedByClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        obo::HeaderClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(AutoGeneratedByClause, name);
 
 impl From<AutoGeneratedByClause> for obo::HeaderClause {
     fn from(clause: AutoGeneratedByClause) -> obo::HeaderClause {
@@ -540,6 +528,7 @@ impl IntoPy<obo::HeaderClause> for AutoGeneratedByClause {
     }
 }
 
+#[obo_clause(tag = "auto-generated-by")]
 #[pymethods]
 impl AutoGeneratedByClause {
     #[new]
@@ -555,8 +544,12 @@ impl AutoGeneratedByClause {
         impl_repr!(self, AutoGeneratedByClause(self.name))
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.name)
+        self.richcmp_py(other, op)
     }
 
     /// `str`: the name of the software that generated the document.
@@ -569,14 +562,6 @@ impl AutoGeneratedByClause {
     fn set_name(&mut self, name: String) {
         self.name = UnquotedString::new(name);
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "auto-generated-by").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.name.to_string()
-    }
 }
 
 // --- Import ----------------------------------------------------------------
@@ -586,7 +571,7 @@ impl AutoGeneratedByClause {
 ///
 /// A clause with a URL or ontology ID referencing another OBO document.
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Clone, ClonePy, Debug, EqPy, FinalClass)]
+#[derive(Clone, ClonePy, Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct ImportClause {
     reference: obo::Import, // should be `Import` ?
@@ -596,14 +581,15 @@ impl ImportClause {
     pub fn new(reference: obo::Import) -> Self {
         Self { reference }
     }
-}
 
-impl Display for ImportClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        obo::HeaderClause::from(self.clone()).fmt(f)
+    /// Get a reference to the imported document reference.
+    pub fn reference(&self) -> &obo::Import {
+        &self.reference
     }
 }
 
+impl_display_py!(ImportClause, reference);
+
 impl From<ImportClause> for obo::HeaderClause {
     fn from(clause: ImportClause) -> Self {
         obo::HeaderClause::Import(Box::new(clause.reference))
@@ -616,6 +602,7 @@ impl IntoPy<obo::HeaderClause> for ImportClause {
     }
 }
 
+#[obo_clause(tag = "import")]
 #[pymethods]
 impl ImportClause {
     // FIXME(@althonos): should not be implicit here ?
@@ -641,8 +628,12 @@ impl ImportClause {
         impl_repr!(self, SubsetdefClause(self.reference.to_string()))
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.reference)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -650,14 +641,6 @@ impl ImportClause {
     fn get_reference(&self) -> PyResult<String> {
         Ok(self.reference.to_string()) // FIXME ?
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "import").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.reference.to_string()
-    }
 }
 
 // --- Subsetdef -------------------------------------------------------------
@@ -667,7 +650,7 @@ impl ImportClause {
 ///
 /// A header clause declaring a subset in the OBO document.
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Debug, EqPy, FinalClass)]
+#[derive(Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct SubsetdefClause {
     #[pyo3(set)]
@@ -693,12 +676,7 @@ impl ClonePy for SubsetdefClause {
     }
 }
 
-impl Display for SubsetdefClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::HeaderClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(SubsetdefClause, subset, description);
 
 impl IntoPy<obo::HeaderClause> for SubsetdefClause {
     fn into_py(self, py: Python) -> obo::HeaderClause {
@@ -709,6 +687,7 @@ impl IntoPy<obo::HeaderClause> for SubsetdefClause {
     }
 }
 
+#[obo_clause(tag = "subsetdef")]
 #[pymethods]
 impl SubsetdefClause {
     #[new]
@@ -727,8 +706,12 @@ impl SubsetdefClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.subset && self.description)
+        self.richcmp_py(other, op)
     }
 
     /// `~fastobo.id.Ident`: the identifier of the declared subset.
@@ -747,14 +730,6 @@ impl SubsetdefClause {
     fn set_description(&mut self, description: String) {
         self.description = fastobo::ast::QuotedString::new(description);
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "subsetdef").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        format!("{} {}", self.subset, self.description)
-    }
 }
 
 // --- SynonymTypedef --------------------------------------------------------
@@ -764,7 +739,7 @@ impl SubsetdefClause {
 ///
 /// A header clause declaring a synonym type in the OBO document.
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Debug, EqPy, FinalClass)]
+#[derive(Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct SynonymTypedefClause {
     #[pyo3(set)]
@@ -796,15 +771,29 @@ impl ClonePy for SynonymTypedefClause {
         SynonymTypedefClause {
             typedef: self.typedef.clone_py(py),
             description: self.description.clone(),
-            scope: self.scope.clone(),
+            scope: self.scope,
+        }
+    }
+}
+
+impl DisplayPy for SynonymTypedefClause {
+    fn fmt_py(&self, py: Python, f: &mut Formatter) -> FmtResult {
+        f.write_str(Self::TAG)?;
+        f.write_str(": ")?;
+        self.typedef.fmt_py(py, f)?;
+        f.write_char(' ')?;
+        self.description.fmt(f)?;
+        if let Some(scope) = &self.scope {
+            f.write_char(' ')?;
+            scope.fmt(f)?;
         }
+        Ok(())
     }
 }
 
 impl Display for SynonymTypedefClause {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::HeaderClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
+        Python::with_gil(|py| self.fmt_py(py, f))
     }
 }
 
@@ -818,20 +807,17 @@ impl IntoPy<obo::HeaderClause> for SynonymTypedefClause {
     }
 }
 
+#[obo_clause(tag = "synonymtypedef")]
 #[pymethods]
 impl SynonymTypedefClause {
     #[new]
     fn __init__(
         typedef: Ident,
         description: String,
-        scope: Option<&PyString>,
+        scope: Option<&PyAny>,
     ) -> PyResult<PyClassInitializer<Self>> {
         let desc = fastobo::ast::QuotedString::new(description);
-        // FIXME
-        let sc = match scope {
-            Some(s) => Some(SynonymScope::from_str(s.to_str()?)?),
-            None => None,
-        };
+        let sc = scope.map(SynonymScope::extract_scope).transpose()?;
         Ok(Self::with_scope(typedef, desc, sc).into())
     }
 
@@ -853,13 +839,12 @@ impl SynonymTypedefClause {
         }
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(
-            self,
-            other,
-            op,
-            self.typedef && self.description && self.scope
-        )
+        self.richcmp_py(other, op)
     }
 
     /// `~fastobo.id.Ident`: the identifier of the declared synonym type.
@@ -886,22 +871,10 @@ impl SynonymTypedefClause {
     }
 
     #[setter]
-    fn set_scope(&mut self, scope: Option<&str>) -> PyResult<()> {
-        self.scope = scope.map(SynonymScope::from_str).transpose()?;
+    fn set_scope(&mut self, scope: Option<&PyAny>) -> PyResult<()> {
+        self.scope = scope.map(SynonymScope::extract_scope).transpose()?;
         Ok(())
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "synonymtypedef").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> PyResult<String> {
-        if let Some(scope) = &self.scope {
-            Ok(format!("{} {} {}", self.typedef, self.description, scope))
-        } else {
-            Ok(format!("{} {}", self.typedef, self.description))
-        }
-    }
 }
 
 // --- DefaultNamespace ------------------------------------------------------
@@ -911,7 +884,7 @@ impl SynonymTypedefClause {
 ///
 /// A clause declaring the default namespace for the rest of the document.
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Debug, EqPy, FinalClass)]
+#[derive(Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct DefaultNamespaceClause {
     #[pyo3(set)]
@@ -932,12 +905,7 @@ impl ClonePy for DefaultNamespaceClause {
     }
 }
 
-impl Display for DefaultNamespaceClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::HeaderClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(DefaultNamespaceClause, namespace);
 
 impl IntoPy<obo::HeaderClause> for DefaultNamespaceClause {
     fn into_py(self, py: Python) -> obo::HeaderClause {
@@ -945,6 +913,7 @@ impl IntoPy<obo::HeaderClause> for DefaultNamespaceClause {
     }
 }
 
+#[obo_clause(tag = "default-namespace")]
 #[pymethods]
 impl DefaultNamespaceClause {
     #[new]
@@ -968,11 +937,15 @@ impl DefaultNamespaceClause {
     }
 
     fn __str__(&self) -> PyResult<String> {
-        Ok(Python::with_gil(|py| self.clone_py(py).to_string()))
+        Ok(self.to_string())
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.namespace)
+        self.richcmp_py(other, op)
     }
 
     /// `~fastobo.id.Ident`: the default namespace for this ontology.
@@ -980,14 +953,6 @@ impl DefaultNamespaceClause {
     fn get_namespace(&self) -> PyResult<&Ident> {
         Ok(&self.namespace)
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "default-namespace").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.namespace.to_string()
-    }
 }
 
 // --- NamespaceIdRuleClause -------------------------------------------------
@@ -997,7 +962,7 @@ impl DefaultNamespaceClause {
 ///
 /// A clause to describe the rule for Namespace ID generation in this document.
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Clone, ClonePy, Debug, EqPy, FinalClass)]
+#[derive(Clone, ClonePy, Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct NamespaceIdRuleClause {
     rule: fastobo::ast::UnquotedString,
@@ -1009,12 +974,7 @@ impl NamespaceIdRuleClause {
     }
 }
 
-impl Display for NamespaceIdRuleClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::HeaderClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(NamespaceIdRuleClause, rule);
 
 impl From<NamespaceIdRuleClause> for obo::HeaderClause {
     fn from(clause: NamespaceIdRuleClause) -> Self {
@@ -1028,6 +988,7 @@ impl IntoPy<obo::HeaderClause> for NamespaceIdRuleClause {
     }
 }
 
+#[obo_clause(tag = "namespace-id-rule")]
 #[pymethods]
 impl NamespaceIdRuleClause {
     #[new]
@@ -1040,11 +1001,15 @@ impl NamespaceIdRuleClause {
     }
 
     fn __str__(&self) -> PyResult<String> {
-        Ok(Python::with_gil(|py| self.clone_py(py).to_string()))
+        Ok(self.to_string())
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.rule)
+        self.richcmp_py(other, op)
     }
 
     /// `str`: the default namespace for this ontology.
@@ -1057,14 +1022,6 @@ impl NamespaceIdRuleClause {
     fn set_rule(&mut self, rule: String) {
         self.rule = fastobo::ast::UnquotedString::new(rule);
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "namespace-id-rule").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.rule.to_string()
-    }
 }
 
 // --- IdspaceClause ---------------------------------------------------------
@@ -1074,7 +1031,7 @@ impl NamespaceIdRuleClause {
 ///
 /// A clause giving the mapping between a "local" and a "global" ID space.
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Debug, EqPy, FinalClass)]
+#[derive(Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct IdspaceClause {
     prefix: ast::IdentPrefix,
@@ -1112,10 +1069,24 @@ impl ClonePy for IdspaceClause {
     }
 }
 
+impl DisplayPy for IdspaceClause {
+    fn fmt_py(&self, py: Python, f: &mut Formatter) -> FmtResult {
+        f.write_str(Self::TAG)?;
+        f.write_str(": ")?;
+        self.prefix.fmt(f)?;
+        f.write_char(' ')?;
+        self.url.fmt_py(py, f)?;
+        if let Some(description) = &self.description {
+            f.write_char(' ')?;
+            description.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
 impl Display for IdspaceClause {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::HeaderClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
+        Python::with_gil(|py| self.fmt_py(py, f))
     }
 }
 
@@ -1136,6 +1107,7 @@ impl IntoPy<obo::HeaderClause> for IdspaceClause {
     }
 }
 
+#[obo_clause(tag = "idspace")]
 #[pymethods]
 impl IdspaceClause {
     #[new]
@@ -1164,8 +1136,12 @@ impl IdspaceClause {
         }
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.prefix && self.url && self.description)
+        self.richcmp_py(other, op)
     }
 
     /// `str`: the prefix used in prefixed IDs.
@@ -1182,22 +1158,6 @@ impl IdspaceClause {
             None => Ok(None),
         }
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "idspace").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        Python::with_gil(|py| {
-            // FIXME: self.prefix may need to be escaped?
-            let url = &*self.url.as_ref(py).borrow();
-            if let Some(desc) = &self.description {
-                format!("{} {} {}", self.prefix, url, desc)
-            } else {
-                format!("{} {}", self.prefix, url)
-            }
-        })
-    }
 }
 
 // --- TreatXrefsAsEquivalentClause ------------------------------------------
@@ -1207,7 +1167,7 @@ impl IdspaceClause {
 ///
 /// A macro to treats xrefs coming from an ID space as equivalence statements.
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Clone, ClonePy, Debug, EqPy, FinalClass)]
+#[derive(Clone, ClonePy, Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct TreatXrefsAsEquivalentClause {
     idspace: ast::IdentPrefix,
@@ -1219,11 +1179,7 @@ impl TreatXrefsAsEquivalentClause {
     }
 }
 
-impl Display for TreatXrefsAsEquivalentClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        obo::HeaderClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(TreatXrefsAsEquivalentClause, idspace);
 
 impl From<TreatXrefsAsEquivalentClause> for obo::HeaderClause {
     fn from(clause: TreatXrefsAsEquivalentClause) -> Self {
@@ -1237,6 +1193,7 @@ impl IntoPy<obo::HeaderClause> for TreatXrefsAsEquivalentClause {
     }
 }
 
+#[obo_clause(tag = "treat-xrefs-as-equivalent")]
 #[pymethods]
 impl TreatXrefsAsEquivalentClause {
     #[new]
@@ -1252,8 +1209,12 @@ impl TreatXrefsAsEquivalentClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.idspace)
+        self.richcmp_py(other, op)
     }
 
     /// `str`: the ID prefix to select some Xrefs with.
@@ -1261,14 +1222,6 @@ impl TreatXrefsAsEquivalentClause {
     fn get_idspace(&self) -> &str {
         self.idspace.as_str()
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "treat-xrefs-as-equivalent").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.idspace.to_string()
-    }
 }
 
 // --- TreatXrefsAsGenusDifferentiaClause ------------------------------------
@@ -1278,7 +1231,7 @@ impl TreatXrefsAsEquivalentClause {
 ///
 /// A macro to treats xrefs from an ID space as genus-differentia definitions.
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Debug, EqPy, FinalClass)]
+#[derive(Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct TreatXrefsAsGenusDifferentiaClause {
     idspace: ast::IdentPrefix,
@@ -1306,12 +1259,7 @@ impl ClonePy for TreatXrefsAsGenusDifferentiaClause {
     }
 }
 
-impl Display for TreatXrefsAsGenusDifferentiaClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::HeaderClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(TreatXrefsAsGenusDifferentiaClause, idspace, relation, filler);
 
 impl IntoPy<obo::HeaderClause> for TreatXrefsAsGenusDifferentiaClause {
     fn into_py(self, py: Python) -> obo::HeaderClause {
@@ -1323,6 +1271,7 @@ impl IntoPy<obo::HeaderClause> for TreatXrefsAsGenusDifferentiaClause {
     }
 }
 
+#[obo_clause(tag = "treat-xrefs-as-genus-differentia")]
 #[pymethods]
 impl TreatXrefsAsGenusDifferentiaClause {
     #[new]
@@ -1341,13 +1290,12 @@ impl TreatXrefsAsGenusDifferentiaClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(
-            self,
-            other,
-            op,
-            self.idspace && self.relation && self.filler
-        )
+        self.richcmp_py(other, op)
     }
 
     /// `str`: the ID prefix to select some Xrefs with.
@@ -1355,14 +1303,6 @@ impl TreatXrefsAsGenusDifferentiaClause {
     fn get_idspace(&self) -> &str {
         self.idspace.as_str()
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "treat-xrefs-as-genus-differentia").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        format!("{} {} {}", self.idspace, self.relation, self.filler)
-    }
 }
 
 // --- TreatXrefsAsReverseGenusDifferentiaClause -----------------------------
@@ -1372,7 +1312,7 @@ impl TreatXrefsAsGenusDifferentiaClause {
 ///
 /// A macro to treats xrefs from an ID space as reverse genus-differentia definitions.
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Debug, EqPy, FinalClass)]
+#[derive(Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct TreatXrefsAsReverseGenusDifferentiaClause {
     idspace: ast::IdentPrefix,
@@ -1400,12 +1340,7 @@ impl ClonePy for TreatXrefsAsReverseGenusDifferentiaClause {
     }
 }
 
-impl Display for TreatXrefsAsReverseGenusDifferentiaClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::HeaderClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(TreatXrefsAsReverseGenusDifferentiaClause, idspace, relation, filler);
 
 impl IntoPy<obo::HeaderClause> for TreatXrefsAsReverseGenusDifferentiaClause {
     fn into_py(self, py: Python) -> obo::HeaderClause {
@@ -1417,6 +1352,7 @@ impl IntoPy<obo::HeaderClause> for TreatXrefsAsReverseGenusDifferentiaClause {
     }
 }
 
+#[obo_clause(tag = "treat-xrefs-as-reverse-genus-differentia")]
 #[pymethods]
 impl TreatXrefsAsReverseGenusDifferentiaClause {
     #[new]
@@ -1439,13 +1375,12 @@ impl TreatXrefsAsReverseGenusDifferentiaClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(
-            self,
-            other,
-            op,
-            self.idspace && self.relation && self.filler
-        )
+        self.richcmp_py(other, op)
     }
 
     /// `str`: the ID prefix to select some Xrefs with.
@@ -1453,14 +1388,6 @@ impl TreatXrefsAsReverseGenusDifferentiaClause {
     fn get_idspace(&self) -> &str {
         self.idspace.as_str()
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "treat-xrefs-as-reverse-genus-differentia").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        format!("{} {} {}", self.idspace, self.relation, self.filler)
-    }
 }
 
 // --- TreatXrefsAsRelationshipClause ----------------------------------------
@@ -1470,7 +1397,7 @@ impl TreatXrefsAsReverseGenusDifferentiaClause {
 ///
 /// A macro to treats xrefs from an ID space as being relationships.
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Debug, EqPy, FinalClass)]
+#[derive(Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct TreatXrefsAsRelationshipClause {
     idspace: ast::IdentPrefix,
@@ -1492,12 +1419,7 @@ impl ClonePy for TreatXrefsAsRelationshipClause {
     }
 }
 
-impl Display for TreatXrefsAsRelationshipClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::HeaderClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(TreatXrefsAsRelationshipClause, idspace, relation);
 
 impl IntoPy<obo::HeaderClause> for TreatXrefsAsRelationshipClause {
     fn into_py(self, py: Python) -> obo::HeaderClause {
@@ -1508,6 +1430,7 @@ impl IntoPy<obo::HeaderClause> for TreatXrefsAsRelationshipClause {
     }
 }
 
+#[obo_clause(tag = "treat-xrefs-as-relationship")]
 #[pymethods]
 impl TreatXrefsAsRelationshipClause {
     #[new]
@@ -1526,8 +1449,12 @@ impl TreatXrefsAsRelationshipClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.idspace && self.relation)
+        self.richcmp_py(other, op)
     }
 
     /// `str`: the ID prefix to select some Xrefs with.
@@ -1535,14 +1462,6 @@ impl TreatXrefsAsRelationshipClause {
     fn get_idspace(&self) -> &str {
         self.idspace.as_str()
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "treat-xrefs-as-relationship").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        format!("{} {}", self.idspace, self.relation)
-    }
 }
 
 // --- TreatXrefsAsIsA -------------------------------------------------------
@@ -1552,7 +1471,7 @@ impl TreatXrefsAsRelationshipClause {
 ///
 /// A macro to treats xrefs from an ID space as being subclassing relations.
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Clone, ClonePy, Debug, EqPy, FinalClass)]
+#[derive(Clone, ClonePy, Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct TreatXrefsAsIsAClause {
     idspace: ast::IdentPrefix,
@@ -1564,12 +1483,7 @@ impl TreatXrefsAsIsAClause {
     }
 }
 
-impl Display for TreatXrefsAsIsAClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::HeaderClause = self.clone().into();
-        clause.fmt(f)
-    }
-}
+impl_display_py!(TreatXrefsAsIsAClause, idspace);
 
 impl From<TreatXrefsAsIsAClause> for obo::HeaderClause {
     fn from(clause: TreatXrefsAsIsAClause) -> obo::HeaderClause {
@@ -1583,6 +1497,7 @@ impl IntoPy<obo::HeaderClause> for TreatXrefsAsIsAClause {
     }
 }
 
+#[obo_clause(tag = "treat-xrefs-as-is_a")]
 #[pymethods]
 impl TreatXrefsAsIsAClause {
     #[new]
@@ -1598,8 +1513,12 @@ impl TreatXrefsAsIsAClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.idspace)
+        self.richcmp_py(other, op)
     }
 
     /// `str`: the ID prefix to select some Xrefs with.
@@ -1607,14 +1526,6 @@ impl TreatXrefsAsIsAClause {
     fn get_idspace(&self) -> &str {
         self.idspace.as_str()
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "treat-xrefs-as-is_a").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.idspace.to_string()
-    }
 }
 
 // --- TreatXrefsAsHasSubclassClause -----------------------------------------
@@ -1624,7 +1535,7 @@ impl TreatXrefsAsIsAClause {
 ///
 /// A macro to treats xrefs from an ID space as being superclassing relations.
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Clone, ClonePy, Debug, EqPy, FinalClass)]
+#[derive(Clone, ClonePy, Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct TreatXrefsAsHasSubclassClause {
     idspace: ast::IdentPrefix,
@@ -1636,11 +1547,7 @@ impl TreatXrefsAsHasSubclassClause {
     }
 }
 
-impl Display for TreatXrefsAsHasSubclassClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::HeaderClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(TreatXrefsAsHasSubclassClause, idspace);
 
 impl From<TreatXrefsAsHasSubclassClause> for obo::HeaderClause {
     fn from(clause: TreatXrefsAsHasSubclassClause) -> Self {
@@ -1654,6 +1561,7 @@ impl IntoPy<obo::HeaderClause> for TreatXrefsAsHasSubclassClause {
     }
 }
 
+#[obo_clause(tag = "treat-xrefs-as-has-subclass")]
 #[pymethods]
 impl TreatXrefsAsHasSubclassClause {
     #[new]
@@ -1669,8 +1577,12 @@ impl TreatXrefsAsHasSubclassClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.idspace)
+        self.richcmp_py(other, op)
     }
 
     /// `str`: the ID prefix to select some Xrefs with.
@@ -1678,14 +1590,6 @@ impl TreatXrefsAsHasSubclassClause {
     fn get_idspace(&self) -> &str {
         self.idspace.as_str()
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "treat-xrefs-as-has-subclass").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.idspace.to_string()
-    }
 }
 
 // --- PropertyValue ---------------------------------------------------------
@@ -1700,7 +1604,7 @@ impl TreatXrefsAsHasSubclassClause {
 ///         to annotate the current OBO document with.
 ///
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Debug, EqPy, FinalClass)]
+#[derive(Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct PropertyValueClause {
     #[pyo3(set)]
@@ -1723,12 +1627,7 @@ impl ClonePy for PropertyValueClause {
     }
 }
 
-impl Display for PropertyValueClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::HeaderClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(PropertyValueClause, inner);
 
 impl IntoPy<ast::HeaderClause> for PropertyValueClause {
     fn into_py(self, py: Python) -> ast::HeaderClause {
@@ -1736,6 +1635,7 @@ impl IntoPy<ast::HeaderClause> for PropertyValueClause {
     }
 }
 
+#[obo_clause(tag = "property_value")]
 #[pymethods]
 impl PropertyValueClause {
     #[new]
@@ -1751,8 +1651,12 @@ impl PropertyValueClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.inner)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -1760,14 +1664,6 @@ impl PropertyValueClause {
     fn get_property_value(&self) -> PyResult<&PropertyValue> {
         Ok(&self.inner)
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "property_value").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.inner.to_string()
-    }
 }
 
 // --- Remark ----------------------------------------------------------------
@@ -1777,7 +1673,7 @@ impl PropertyValueClause {
 ///
 /// A header clause storing general comments for the current OBO file.
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Clone, ClonePy, Debug, EqPy, FinalClass)]
+#[derive(Clone, ClonePy, Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct RemarkClause {
     remark: UnquotedString,
@@ -1789,12 +1685,7 @@ impl RemarkClause {
     }
 }
 
-impl Display for RemarkClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: obo::HeaderClause = self.clone().into();
-        clause.fmt(f)
-    }
-}
+impl_display_py!(RemarkClause, remark);
 
 impl From<RemarkClause> for obo::HeaderClause {
     fn from(clause: RemarkClause) -> Self {
@@ -1808,6 +1699,7 @@ impl IntoPy<obo::HeaderClause> for RemarkClause {
     }
 }
 
+#[obo_clause(tag = "remark")]
 #[pymethods]
 impl RemarkClause {
     #[new]
@@ -1823,8 +1715,12 @@ impl RemarkClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.remark)
+        self.richcmp_py(other, op)
     }
 
     /// `str`: a remark about the ontology.
@@ -1837,14 +1733,6 @@ impl RemarkClause {
     fn set_remark(&mut self, remark: String) {
         self.remark = fastobo::ast::UnquotedString::new(remark);
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "remark").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.remark.to_string()
-    }
 }
 
 // --- Ontology --------------------------------------------------------------
@@ -1854,7 +1742,7 @@ impl RemarkClause {
 ///
 /// The ontology ID of the current OBO file.
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Clone, ClonePy, Debug, EqPy, FinalClass)]
+#[derive(Clone, ClonePy, Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct OntologyClause {
     ontology: UnquotedString,
@@ -1864,14 +1752,15 @@ impl OntologyClause {
     pub fn new(ontology: UnquotedString) -> Self {
         Self { ontology }
     }
-}
 
-impl Display for OntologyClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        obo::HeaderClause::from(self.clone()).fmt(f)
+    /// Get a reference to the ID of the ontology.
+    pub fn ontology(&self) -> &UnquotedString {
+        &self.ontology
     }
 }
 
+impl_display_py!(OntologyClause, ontology);
+
 impl From<OntologyClause> for obo::HeaderClause {
     fn from(clause: OntologyClause) -> Self {
         obo::HeaderClause::Ontology(Box::new(clause.ontology))
@@ -1884,6 +1773,7 @@ impl IntoPy<obo::HeaderClause> for OntologyClause {
     }
 }
 
+#[obo_clause(tag = "ontology")]
 #[pymethods]
 impl OntologyClause {
     #[new]
@@ -1899,8 +1789,12 @@ impl OntologyClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.ontology)
+        self.richcmp_py(other, op)
     }
 
     /// `str`: the ID of the ontology described in the OBO document.
@@ -1913,14 +1807,6 @@ impl OntologyClause {
     fn set_ontology(&mut self, ontology: String) {
         self.ontology = fastobo::ast::UnquotedString::new(ontology);
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "ontology").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.ontology.to_string()
-    }
 }
 
 // --- OwlAxioms -------------------------------------------------------------
@@ -1930,7 +1816,7 @@ impl OntologyClause {
 ///
 /// A header clause containing untranslatable OWL axioms.
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Clone, ClonePy, Debug, EqPy, FinalClass)]
+#[derive(Clone, ClonePy, Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct OwlAxiomsClause {
     axioms: UnquotedString,
@@ -1942,11 +1828,7 @@ impl OwlAxiomsClause {
     }
 }
 
-impl Display for OwlAxiomsClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::HeaderClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(OwlAxiomsClause, axioms);
 
 impl From<OwlAxiomsClause> for obo::HeaderClause {
     fn from(clause: OwlAxiomsClause) -> Self {
@@ -1960,6 +1842,7 @@ impl IntoPy<obo::HeaderClause> for OwlAxiomsClause {
     }
 }
 
+#[obo_clause(tag = "owl-axioms")]
 #[pymethods]
 impl OwlAxiomsClause {
     #[new]
@@ -1976,8 +1859,12 @@ impl OwlAxiomsClause {
         impl_repr!(self, OwlAxiomsClause(self.axioms.as_str()))
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.axioms)
+        self.richcmp_py(other, op)
     }
 
     /// `str`: raw OWL axioms that have no equivalent in the OBO language.
@@ -1990,24 +1877,74 @@ impl OwlAxiomsClause {
     fn set_axioms(&mut self, axioms: String) {
         self.axioms = fastobo::ast::UnquotedString::new(axioms);
     }
+}
 
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "owl-axioms").to_object(slf.py())
-    }
+// --- UnreservedClause ------------------------------------------------------
 
-    fn raw_value(&self) -> String {
-        self.axioms.to_string()
+/// The tags of the header clauses reserved by the OBO specification.
+///
+/// Tags are listed in the order they should appear in a header frame.
+pub(crate) const RESERVED_TAGS: &[&str] = &[
+    "format-version",
+    "data-version",
+    "date",
+    "saved-by",
+    "auto-generated-by",
+    "import",
+    "subsetdef",
+    "synonymtypedef",
+    "default-namespace",
+    "namespace-id-rule",
+    "idspace",
+    "treat-xrefs-as-equivalent",
+    "treat-xrefs-as-genus-differentia",
+    "treat-xrefs-as-reverse-genus-differentia",
+    "treat-xrefs-as-relationship",
+    "treat-xrefs-as-is_a",
+    "treat-xrefs-as-has-subclass",
+    "property_value",
+    "remark",
+    "ontology",
+    "owl-axioms",
+];
+
+/// Check a tag can be used in an `UnreservedClause` and round-trip.
+fn check_unreserved_tag(tag: &str) -> PyResult<()> {
+    if tag.is_empty() || tag.contains(|c: char| c == ':' || c.is_whitespace()) {
+        let msg = format!("invalid header clause tag: {:?}", tag);
+        Err(PyValueError::new_err(msg))
+    } else if RESERVED_TAGS.contains(&tag) {
+        let msg = format!(
+            "{:?} is a reserved tag, use `fastobo.header.parse_clause` instead",
+            tag
+        );
+        Err(PyValueError::new_err(msg))
+    } else {
+        Ok(())
     }
 }
 
-// --- UnreservedClause ------------------------------------------------------
-
 /// UnreservedClause(tag, value)
 /// --
 ///
 /// A tag/value pair not reserved in the OBO specification.
+///
+/// Use this clause to add custom header tags to a document, such as the
+/// ``exported-from`` tag added by some ontology editors.
+///
+/// Raises:
+///     ValueError: When the tag is invalid, or reserved by the OBO
+///         specification.
+///
+/// Example:
+///     >>> clause = fastobo.header.UnreservedClause(
+///     ...     "exported-from", "http://example.com/ont.owl"
+///     ... )
+///     >>> print(clause)
+///     exported-from: http://example.com/ont.owl
+///
 #[pyclass(extends=BaseHeaderClause, module="fastobo.header")]
-#[derive(Clone, ClonePy, Debug, EqPy, FinalClass)]
+#[derive(Clone, ClonePy, Debug, EqPy, HashPy, RichCmpPy, FinalClass)]
 #[base(BaseHeaderClause)]
 pub struct UnreservedClause {
     tag: UnquotedString,
@@ -2032,17 +1969,26 @@ impl IntoPy<obo::HeaderClause> for UnreservedClause {
     }
 }
 
+impl DisplayPy for UnreservedClause {
+    fn fmt_py(&self, _py: Python, f: &mut Formatter) -> FmtResult {
+        self.fmt(f)
+    }
+}
+
 impl Display for UnreservedClause {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        obo::HeaderClause::from(self.clone()).fmt(f)
+        self.tag.fmt(f)?;
+        f.write_str(": ")?;
+        self.value.fmt(f)
     }
 }
 
 #[pymethods]
 impl UnreservedClause {
     #[new]
-    fn __init__(tag: String, value: String) -> PyClassInitializer<Self> {
-        Self::new(UnquotedString::new(tag), UnquotedString::new(value)).into()
+    fn __init__(tag: String, value: String) -> PyResult<PyClassInitializer<Self>> {
+        check_unreserved_tag(&tag)?;
+        Ok(Self::new(UnquotedString::new(tag), UnquotedString::new(value)).into())
     }
 
     fn __repr__(&self) -> PyResult<PyObject> {
@@ -2056,8 +2002,12 @@ impl UnreservedClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.tag && self.value)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -2067,14 +2017,16 @@ impl UnreservedClause {
     }
 
     #[setter]
-    fn set_tag(&mut self, tag: String) {
+    fn set_tag(&mut self, tag: String) -> PyResult<()> {
+        check_unreserved_tag(&tag)?;
         self.tag = UnquotedString::new(tag);
+        Ok(())
     }
 
     #[getter]
     /// `str`: The value of the clause.
     fn get_value(&self) -> PyResult<&str> {
-        Ok(self.tag.as_str())
+        Ok(self.value.as_str())
     }
 
     #[setter]
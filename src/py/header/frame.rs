@@ -1,12 +1,19 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::fmt::Write;
 use std::iter::FromIterator;
 use std::iter::IntoIterator;
+use std::mem::discriminant;
 
 use fastobo::ast as obo;
+use pyo3::class::basic::CompareOp;
 use pyo3::class::gc::PyVisit;
-use pyo3::exceptions::PyIndexError;
+use pyo3::exceptions::PyTypeError;
 use pyo3::gc::PyTraverseError;
 use pyo3::prelude::*;
 use pyo3::types::PyAny;
+use pyo3::types::PyDict;
 use pyo3::types::PyIterator;
 use pyo3::types::PyList;
 use pyo3::types::PyString;
@@ -16,17 +23,30 @@ use pyo3::PyTypeInfo;
 
 use super::super::abc::AbstractFrame;
 use super::clause::BaseHeaderClause;
+use super::clause::DataVersionClause;
+use super::clause::DateClause;
+use super::clause::DefaultNamespaceClause;
+use super::clause::FormatVersionClause;
 use super::clause::HeaderClause;
+use super::clause::OntologyClause;
+use super::clause::SavedByClause;
+use super::clause::RESERVED_TAGS;
 use crate::utils::AbstractClass;
+use crate::utils::AssignPy;
 use crate::utils::ClonePy;
+use crate::utils::DisplayPy;
 use crate::utils::EqPy;
 use crate::utils::FinalClass;
+use crate::utils::FreezePy;
+use crate::utils::RichCmpPy;
 
 #[pyclass(extends=AbstractFrame, module="fastobo.header")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, RichCmpPy)]
 #[base(AbstractFrame)]
 pub struct HeaderFrame {
     clauses: Vec<HeaderClause>,
+    #[eqpy(ignore)]
+    frozen: bool,
 }
 
 impl HeaderFrame {
@@ -35,7 +55,77 @@ impl HeaderFrame {
     }
 
     pub fn new(clauses: Vec<HeaderClause>) -> Self {
-        Self { clauses }
+        Self {
+            clauses,
+            frozen: false,
+        }
+    }
+
+    /// Get a reference to the clauses of the frame.
+    pub fn clauses(&self) -> &Vec<HeaderClause> {
+        &self.clauses
+    }
+
+    /// Update the frame in place to match an edited AST frame.
+    ///
+    /// Unchanged clauses are kept as they are, and changed clauses are
+    /// updated in place whenever possible, so that the clause objects
+    /// referenced from Python (and their comments) remain valid.
+    pub fn update(&mut self, py: Python, frame: obo::HeaderFrame) {
+        crate::utils::update_items(
+            &mut self.clauses,
+            frame.into_iter().collect(),
+            |clause| IntoPy::<obo::HeaderClause>::into_py(clause, py),
+            |a, b| discriminant(a) == discriminant(b),
+            |clause, new| clause.assign_py(&new.clone().into_py(py), py),
+            |clause| clause.into_py(py),
+        );
+    }
+
+    /// Get an attribute of the first clause with the given raw tag, if any.
+    fn unique_attr(&self, py: Python, tag: &str, attr: &str) -> PyResult<Option<PyObject>> {
+        for clause in self.clauses.iter() {
+            if crate::utils::raw_tag(clause, py)? == tag {
+                return clause.to_object(py).getattr(py, attr).map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Replace all the clauses with the given raw tag by a single new clause.
+    ///
+    /// The new clause is created by calling the `T` type with `value`, and
+    /// takes the place of the first clause with the same tag, or is inserted
+    /// following the standard header tag order. Passing `None` as the value
+    /// only removes the existing clauses.
+    fn set_unique<T: PyTypeInfo>(&mut self, py: Python, tag: &str, value: Option<&PyAny>) -> PyResult<()> {
+        crate::utils::check_mutable(self.frozen, "HeaderFrame")?;
+        let mut new = match value {
+            Some(v) => Some(HeaderClause::extract(py.get_type::<T>().call1((v,))?)?),
+            None => None,
+        };
+
+        let rank = |t: &str| RESERVED_TAGS.iter().position(|&r| r == t).unwrap_or(RESERVED_TAGS.len());
+        let tags = self
+            .clauses
+            .iter()
+            .map(|clause| crate::utils::raw_tag(clause, py))
+            .collect::<PyResult<Vec<String>>>()?;
+        let position = tags
+            .iter()
+            .position(|t| t == tag)
+            .or_else(|| tags.iter().position(|t| rank(t) > rank(tag)));
+
+        for (i, (clause, t)) in std::mem::take(&mut self.clauses).into_iter().zip(tags).enumerate() {
+            if Some(i) == position {
+                self.clauses.extend(new.take());
+            }
+            if t != tag {
+                self.clauses.push(clause);
+            }
+        }
+        self.clauses.extend(new);
+        Ok(())
     }
 }
 
@@ -43,7 +133,24 @@ impl ClonePy for HeaderFrame {
     fn clone_py(&self, py: Python) -> Self {
         Self {
             clauses: self.clauses.clone_py(py),
+            frozen: false,
+        }
+    }
+}
+
+impl DisplayPy for HeaderFrame {
+    fn fmt_py(&self, py: Python, f: &mut Formatter) -> FmtResult {
+        for clause in self.clauses.iter() {
+            clause.fmt_py(py, f)?;
+            f.write_char('\n')?;
         }
+        Ok(())
+    }
+}
+
+impl Display for HeaderFrame {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Python::with_gil(|py| self.fmt_py(py, f))
     }
 }
 
@@ -77,7 +184,7 @@ impl ToPyObject for HeaderFrame {
     }
 }
 
-#[listlike(field = "clauses", type = "HeaderClause")]
+#[listlike(field = "clauses", type = "HeaderClause", frozen = "frozen")]
 #[pymethods]
 impl HeaderFrame {
     #[new]
@@ -95,41 +202,19 @@ impl HeaderFrame {
         impl_repr!(self, HeaderFrame(self))
     }
 
-    fn __str__(&self) -> PyResult<String> {
-        let frame: obo::HeaderFrame = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        Ok(frame.to_string())
+    fn __str__(slf: &PyCell<Self>) -> PyResult<String> {
+        AbstractFrame::render(slf)
     }
 
     fn __len__(&self) -> PyResult<usize> {
         Ok(self.clauses.len())
     }
 
-    fn __getitem__(&self, index: isize) -> PyResult<PyObject> {
-        if index < self.clauses.len() as isize {
-            Python::with_gil(|py| {
-                let item = &self.clauses[index as usize];
-                Ok(item.to_object(py))
-            })
-        } else {
-            Err(PyIndexError::new_err("list index out of range"))
-        }
-    }
-
-    fn __setitem__(&mut self, index: isize, elem: &PyAny) -> PyResult<()> {
-        if index as usize > self.clauses.len() {
-            return Err(PyIndexError::new_err("list index out of range"));
-        }
-        let clause = HeaderClause::extract(elem)?;
-        self.clauses[index as usize] = clause;
-        Ok(())
-    }
-
-    fn __delitem__(&mut self, index: isize) -> PyResult<()> {
-        if index as usize > self.clauses.len() {
-            return Err(PyIndexError::new_err("list index out of range"));
-        }
-        self.clauses.remove(index as usize);
-        Ok(())
+    /// Get the size of the frame in memory, in bytes.
+    ///
+    /// The size includes the clauses of the frame.
+    fn __sizeof__(&self, py: Python) -> PyResult<usize> {
+        Ok(std::mem::size_of::<PyCell<Self>>() + crate::utils::sizeof_vec(&self.clauses, py)?)
     }
 
     fn __concat__(&self, other: &PyAny) -> PyResult<Py<Self>> {
@@ -141,7 +226,213 @@ impl HeaderFrame {
             new_clauses.push(HeaderClause::extract(item?)?);
         }
 
-        let init = PyClassInitializer::from(AbstractFrame {}).add_subclass(Self::new(new_clauses));
+        let init = PyClassInitializer::from(AbstractFrame::default()).add_subclass(Self::new(new_clauses));
         Py::new(py, init)
     }
+
+    fn __hash__(&self) -> PyResult<u64> {
+        if self.frozen {
+            let frame: obo::HeaderFrame = Python::with_gil(|py| self.clone_py(py).into_py(py));
+            Ok(impl_hash!(frame.to_string()))
+        } else {
+            Err(PyTypeError::new_err("unhashable type: 'HeaderFrame'"))
+        }
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+
+    /// `str` or `None`: the version of the OBO format used in the document.
+    ///
+    /// This property, like `data_version`, `date`, `saved_by`,
+    /// `default_namespace` and `ontology`, reads the value of the first
+    /// clause with the corresponding tag. Setting it replaces all these
+    /// clauses with a single new clause, and setting it to `None` removes
+    /// them.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.header.HeaderFrame([
+    ///     ...     fastobo.header.OntologyClause("test"),
+    ///     ... ])
+    ///     >>> frame.format_version is None
+    ///     True
+    ///     >>> frame.format_version = "1.4"
+    ///     >>> frame[0]
+    ///     FormatVersionClause('1.4')
+    ///
+    #[getter]
+    fn get_format_version(&self, py: Python) -> PyResult<Option<PyObject>> {
+        self.unique_attr(py, "format-version", "version")
+    }
+
+    #[setter]
+    fn set_format_version(&mut self, py: Python, value: Option<&PyAny>) -> PyResult<()> {
+        self.set_unique::<FormatVersionClause>(py, "format-version", value)
+    }
+
+    /// `str` or `None`: the version of the data in the document.
+    #[getter]
+    fn get_data_version(&self, py: Python) -> PyResult<Option<PyObject>> {
+        self.unique_attr(py, "data-version", "version")
+    }
+
+    #[setter]
+    fn set_data_version(&mut self, py: Python, value: Option<&PyAny>) -> PyResult<()> {
+        self.set_unique::<DataVersionClause>(py, "data-version", value)
+    }
+
+    /// `~datetime.datetime` or `None`: the date the document was last modified.
+    #[getter]
+    fn get_date(&self, py: Python) -> PyResult<Option<PyObject>> {
+        self.unique_attr(py, "date", "date")
+    }
+
+    #[setter]
+    fn set_date(&mut self, py: Python, value: Option<&PyAny>) -> PyResult<()> {
+        self.set_unique::<DateClause>(py, "date", value)
+    }
+
+    /// `str` or `None`: the name of the user who last saved the document.
+    #[getter]
+    fn get_saved_by(&self, py: Python) -> PyResult<Option<PyObject>> {
+        self.unique_attr(py, "saved-by", "name")
+    }
+
+    #[setter]
+    fn set_saved_by(&mut self, py: Python, value: Option<&PyAny>) -> PyResult<()> {
+        self.set_unique::<SavedByClause>(py, "saved-by", value)
+    }
+
+    /// `~fastobo.id.Ident` or `None`: the default namespace of the entities of the document.
+    #[getter]
+    fn get_default_namespace(&self, py: Python) -> PyResult<Option<PyObject>> {
+        self.unique_attr(py, "default-namespace", "namespace")
+    }
+
+    #[setter]
+    fn set_default_namespace(&mut self, py: Python, value: Option<&PyAny>) -> PyResult<()> {
+        self.set_unique::<DefaultNamespaceClause>(py, "default-namespace", value)
+    }
+
+    /// `str` or `None`: the identifier of the ontology.
+    #[getter]
+    fn get_ontology(&self, py: Python) -> PyResult<Option<PyObject>> {
+        self.unique_attr(py, "ontology", "ontology")
+    }
+
+    #[setter]
+    fn set_ontology(&mut self, py: Python, value: Option<&PyAny>) -> PyResult<()> {
+        self.set_unique::<OntologyClause>(py, "ontology", value)
+    }
+
+    /// `dict`: the ``subsetdef`` clauses of the frame, by subset identifier.
+    ///
+    /// Keys are the subset identifiers as `str`, so that the ``subset``
+    /// clauses of entity frames can be validated against the header. Values
+    /// are the `SubsetdefClause` of the frame, which are not copied. Only the
+    /// first declaration of a subset is included.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.header.HeaderFrame([
+    ///     ...     fastobo.header.SubsetdefClause(
+    ///     ...         fastobo.id.UnprefixedIdent("goslim_generic"),
+    ///     ...         "Generic GO slim",
+    ///     ...     )
+    ///     ... ])
+    ///     >>> frame.subsetdefs["goslim_generic"].description
+    ///     'Generic GO slim'
+    ///
+    #[getter]
+    fn get_subsetdefs<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let dict = PyDict::new(py);
+        for clause in self.clauses.iter() {
+            if let HeaderClause::Subsetdef(c) = clause {
+                let id = c.as_ref(py).getattr("subset")?.str()?;
+                if !dict.contains(id)? {
+                    dict.set_item(id, c)?;
+                }
+            }
+        }
+        Ok(dict)
+    }
+
+    /// `dict`: the ``synonymtypedef`` clauses of the frame, by synonym type.
+    ///
+    /// Keys are the synonym type identifiers as `str`, so that the
+    /// synonyms of entity frames can be resolved against the header. Values
+    /// are the `SynonymTypedefClause` of the frame, which give access to
+    /// the description and the optional scope of each synonym type. Only
+    /// the first declaration of a synonym type is included.
+    ///
+    #[getter]
+    fn get_synonymtypedefs<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let dict = PyDict::new(py);
+        for clause in self.clauses.iter() {
+            if let HeaderClause::SynonymTypedef(c) = clause {
+                let id = c.as_ref(py).getattr("typedef")?.str()?;
+                if !dict.contains(id)? {
+                    dict.set_item(id, c)?;
+                }
+            }
+        }
+        Ok(dict)
+    }
+
+    /// `bool`: Whether the frame is frozen.
+    #[getter]
+    fn get_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Make the frame read-only.
+    ///
+    /// See `OboDoc.freeze` for the modifications rejected by a frozen frame.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.header.HeaderFrame()
+    ///     >>> frame.freeze()
+    ///     >>> frame.append(fastobo.header.FormatVersionClause("1.4"))
+    ///     Traceback (most recent call last):
+    ///       ...
+    ///     TypeError: cannot modify frozen HeaderFrame instance
+    ///
+    #[pyo3(text_signature = "(self)")]
+    pub fn freeze(&mut self, py: Python) -> PyResult<()> {
+        self.clauses.freeze_py(py)?;
+        self.frozen = true;
+        Ok(())
+    }
+
+    /// Convert the frame to a `dict` of builtin Python types.
+    ///
+    /// Returns:
+    ///     `dict`: A dictionary with the ``type`` of the frame (``"Header"``)
+    ///     and its ``clauses`` as a `list` of `dict` with the raw ``tag``
+    ///     and ``value`` of each clause.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.header.HeaderFrame([
+    ///     ...     fastobo.header.FormatVersionClause("1.4"),
+    ///     ... ])
+    ///     >>> pprint(frame.to_dict())
+    ///     {'clauses': [{'tag': 'format-version', 'value': '1.4'}], 'type': 'Header'}
+    ///
+    #[pyo3(text_signature = "(self)")]
+    fn to_dict(&self, py: Python) -> PyResult<PyObject> {
+        crate::dict::header_to_dict(py, self.clone_py(py).into_py(py))
+    }
+
+    /// Create a new frame from a `dict` created with `HeaderFrame.to_dict`.
+    ///
+    /// Raises:
+    ///     ValueError: When the dictionary does not describe a header frame.
+    ///     SyntaxError: When a clause cannot be parsed.
+    ///
+    #[staticmethod]
+    #[pyo3(text_signature = "(d)")]
+    fn from_dict(py: Python, d: &PyAny) -> PyResult<Py<Self>> {
+        let frame: HeaderFrame = crate::dict::header_from_dict(d)?.into_py(py);
+        Py::new(py, frame)
+    }
 }
@@ -1,8 +1,15 @@
 pub mod clause;
 pub mod frame;
 
+use std::str::FromStr;
+
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
+use self::clause::HeaderClause;
+use crate::error::Error;
+use crate::utils::ParseClause;
+
 #[pymodule]
 #[pyo3(name = "header")]
 pub fn init(py: Python, m: &PyModule) -> PyResult<()> {
@@ -31,9 +38,57 @@ pub fn init(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<self::clause::UnreservedClause>()?;
     m.add_class::<self::clause::NamespaceIdRuleClause>()?;
 
+    m.add_function(wrap_pyfunction!(self::parse_clause, m)?)?;
+
     register!(py, m, HeaderFrame, "collections.abc", MutableSequence);
 
     m.add("__name__", "fastobo.header")?;
 
     Ok(())
 }
+
+/// Parse a single header clause from a line of text, or from a tag/value pair.
+///
+/// Trailing qualifiers and comments are supported, so that a clause line
+/// can be extracted verbatim from an OBO document. Tags not reserved by
+/// the OBO specification are parsed into a `UnreservedClause`.
+///
+/// Arguments:
+///     text (str): The serialized clause, such as ``"format-version: 1.4"``,
+///         or the tag of the clause when ``value`` is given.
+///     value (str, optional): The raw value of the clause, serialized as
+///         it would be in an OBO document.
+///
+/// Returns:
+///     `~fastobo.header.BaseHeaderClause`: The deserialized clause.
+///
+/// Raises:
+///     SyntaxError: When the text is not a valid header clause.
+///     ValueError: When the text does not contain exactly one clause.
+///
+/// Example:
+///     >>> fastobo.header.parse_clause("format-version: 1.4")
+///     FormatVersionClause('1.4')
+///     >>> fastobo.header.parse_clause("exported-from", "http://example.com")
+///     UnreservedClause('exported-from', 'http://example.com')
+///
+#[pyfunction]
+#[pyo3(text_signature = "(text, value=None)", signature = (text, value=None))]
+pub fn parse_clause(py: Python, text: &str, value: Option<&str>) -> PyResult<HeaderClause> {
+    let source = match value {
+        Some(v) => format!("{}: {}\n", text.trim(), v.trim_end()),
+        None => format!("{}\n", text.trim_end()),
+    };
+    let ast = fastobo::ast::HeaderFrame::from_str(&source).map_err(|e| PyErr::from(Error::from(e)))?;
+    let mut clauses = ast.into_iter().collect::<Vec<_>>();
+    match clauses.len() {
+        1 => Ok(clauses.pop().unwrap().into_py(py)),
+        n => Err(PyValueError::new_err(format!("expected a single clause, found {}", n))),
+    }
+}
+
+impl ParseClause for HeaderClause {
+    fn parse_clause(py: Python, text: &str) -> PyResult<Self> {
+        self::parse_clause(py, text, None)
+    }
+}
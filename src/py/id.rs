@@ -1,14 +1,23 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
 use std::fmt::Write;
 use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+use std::thread::LocalKey;
 
 use pyo3::class::basic::CompareOp;
 use pyo3::exceptions::PyTypeError;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::PyAny;
+use pyo3::types::PyDict;
 use pyo3::types::PyString;
 use pyo3::AsPyPointer;
 use pyo3::PyNativeType;
@@ -21,7 +30,9 @@ use crate::error::Error;
 use crate::raise;
 use crate::utils::AbstractClass;
 use crate::utils::ClonePy;
+use crate::utils::DisplayPy;
 use crate::utils::EqPy;
+use crate::utils::HashPy;
 use crate::utils::FinalClass;
 
 // --- Module export ----------------------------------------------------------
@@ -52,7 +63,7 @@ use crate::utils::FinalClass;
     ///
     #[pyfunction]
     #[pyo3(name = "parse")]
-    fn parse(py: Python, s: &str) -> PyResult<Ident> {
+    pub fn parse(py: Python, s: &str) -> PyResult<Ident> {
         match fastobo::ast::Ident::from_str(s) {
             Ok(id) => Ok(id.into_py(py)),
             Err(e) => {
@@ -92,6 +103,216 @@ use crate::utils::FinalClass;
         }
     }
 
+    /// check(s)
+    /// --
+    ///
+    /// Check whether a string is a valid OBO identifier, with diagnostics.
+    ///
+    /// Arguments:
+    ///     s (`str`): the identifier to validate.
+    ///
+    /// Returns:
+    ///     `dict`: a dictionary with a ``valid`` boolean key. For a valid
+    ///     identifier, the ``kind`` key contains the identifier category,
+    ///     either ``"prefixed"``, ``"unprefixed"`` or ``"url"``. Otherwise,
+    ///     the ``position`` key contains the index of the first invalid
+    ///     character, the ``expected`` key a list of the grammar rules
+    ///     expected at that position, and the ``message`` key a
+    ///     human-readable explanation.
+    ///
+    /// Example:
+    ///     >>> fastobo.id.check("MS:1000031")
+    ///     {'valid': True, 'kind': 'prefixed'}
+    ///     >>> diagnostic = fastobo.id.check("MS:1000031 extra")
+    ///     >>> diagnostic['valid'], diagnostic['position']
+    ///     (False, 10)
+    ///
+    #[pyfunction]
+    #[pyo3(name = "check")]
+    fn check<'py>(py: Python<'py>, s: &str) -> PyResult<&'py PyDict> {
+        use fastobo::syntax::pest::error::ErrorVariant;
+        use fastobo::syntax::pest::error::InputLocation;
+
+        let dict = PyDict::new(py);
+        let (offset, expected, message) = match fastobo::syntax::Lexer::tokenize(fastobo::syntax::Rule::Id, s) {
+            Ok(pairs) if pairs.as_str().len() == s.len() => {
+                let kind = match ast::Ident::from_str(s) {
+                    Ok(ast::Ident::Prefixed(_)) => "prefixed",
+                    Ok(ast::Ident::Unprefixed(_)) => "unprefixed",
+                    Ok(ast::Ident::Url(_)) => "url",
+                    Err(e) => return Err(PyErr::from(Error::from(e))),
+                };
+                dict.set_item("valid", true)?;
+                dict.set_item("kind", kind)?;
+                return Ok(dict);
+            }
+            Ok(pairs) => {
+                let offset = pairs.as_str().len();
+                let c = s[offset..].chars().next().unwrap_or_default();
+                let message = format!("unexpected character {:?}", c);
+                (offset, Vec::new(), message)
+            }
+            Err(e) => {
+                let offset = match e.location {
+                    InputLocation::Pos(p) => p,
+                    InputLocation::Span((p, _)) => p,
+                };
+                let expected = match &e.variant {
+                    ErrorVariant::ParsingError { positives, .. } => {
+                        positives.iter().map(|r| format!("{:?}", r)).collect()
+                    }
+                    ErrorVariant::CustomError { .. } => Vec::new(),
+                };
+                (offset, expected, e.variant.message().into_owned())
+            }
+        };
+
+        dict.set_item("valid", false)?;
+        dict.set_item("position", s[..offset].chars().count())?;
+        dict.set_item("expected", expected)?;
+        dict.set_item("message", message)?;
+        Ok(dict)
+    }
+
+    /// compact(url, prefix_map=None)
+    /// --
+    ///
+    /// Compact an IRI into a prefixed identifier.
+    ///
+    /// The longest IRI prefix of ``prefix_map`` matching the IRI is used
+    /// to build the prefixed identifier. Otherwise, the default OBO rule is
+    /// used for IRIs in the ``http://purl.obolibrary.org/obo/`` namespace.
+    ///
+    /// Arguments:
+    ///     url (`~fastobo.id.Url` or `str`): the IRI to compact.
+    ///     prefix_map (`dict` or `~fastobo.doc.OboDoc`, optional): a mapping
+    ///         of CURIE prefixes to IRI prefixes, or a document to use the
+    ///         prefix map returned by `~fastobo.doc.OboDoc.prefix_map`.
+    ///
+    /// Returns:
+    ///     `~fastobo.id.BaseIdent`: a `PrefixedIdent` for the given IRI, or
+    ///     the IRI itself if it cannot be compacted.
+    ///
+    /// Example:
+    ///     >>> fastobo.id.compact("http://purl.obolibrary.org/obo/GO_0005623")
+    ///     PrefixedIdent('GO', '0005623')
+    ///     >>> fastobo.id.compact(
+    ///     ...     "http://example.com/tst/001",
+    ///     ...     {"TST": "http://example.com/tst/"},
+    ///     ... )
+    ///     PrefixedIdent('TST', '001')
+    ///
+    #[pyfunction]
+    #[pyo3(name = "compact", signature = (url, prefix_map = None))]
+    fn compact(py: Python, url: &PyAny, prefix_map: Option<&PyAny>) -> PyResult<Ident> {
+        let id = extract_ident(py, url)?;
+        let url = match &id {
+            ast::Ident::Url(url) => url.as_str().to_string(),
+            _ => return Ok(id.into_py(py)),
+        };
+
+        let prefixes = extract_prefix_map(prefix_map)?;
+        match compact_url(&url, &prefixes) {
+            Some(ident) => Ok(ast::Ident::from(ident).into_py(py)),
+            None => Ok(id.into_py(py)),
+        }
+    }
+
+    /// expand(ident, prefix_map=None)
+    /// --
+    ///
+    /// Expand a prefixed identifier into an IRI.
+    ///
+    /// The IRI prefix of ``prefix_map`` declared for the identifier prefix
+    /// is used if any, otherwise the default OBO rule is used.
+    ///
+    /// Arguments:
+    ///     ident (`~fastobo.id.PrefixedIdent` or `str`): the identifier to
+    ///         expand.
+    ///     prefix_map (`dict` or `~fastobo.doc.OboDoc`, optional): a mapping
+    ///         of CURIE prefixes to IRI prefixes, or a document to use the
+    ///         prefix map returned by `~fastobo.doc.OboDoc.prefix_map`.
+    ///
+    /// Returns:
+    ///     `~fastobo.id.BaseIdent`: a `Url` for the given identifier, or the
+    ///     identifier itself if it is not a prefixed identifier.
+    ///
+    /// Raises:
+    ///     ValueError: when the expanded IRI is not a valid URL.
+    ///
+    /// Example:
+    ///     >>> fastobo.id.expand("GO:0005623")
+    ///     Url('http://purl.obolibrary.org/obo/GO_0005623')
+    ///     >>> fastobo.id.expand("TST:001", {"TST": "http://example.com/tst/"})
+    ///     Url('http://example.com/tst/001')
+    ///
+    #[pyfunction]
+    #[pyo3(name = "expand", signature = (ident, prefix_map = None))]
+    fn expand(py: Python, ident: &PyAny, prefix_map: Option<&PyAny>) -> PyResult<Ident> {
+        let id = extract_ident(py, ident)?;
+        let iri = match &id {
+            ast::Ident::Prefixed(p) => {
+                let prefixes = extract_prefix_map(prefix_map)?;
+                match prefixes.get(p.prefix()) {
+                    Some(iri) => format!("{}{}", iri, p.local()),
+                    None => format!("{}{}_{}", OBO_PURL, p.prefix(), p.local()),
+                }
+            }
+            _ => return Ok(id.into_py(py)),
+        };
+        match ast::Url::from_str(&iri) {
+            Ok(url) => Ok(ast::Ident::from(url).into_py(py)),
+            Err(e) => {
+                let err = PyErr::from(Error::from(e));
+                raise!(py, PyValueError("could not expand identifier") from err)
+            }
+        }
+    }
+
+/// The IRI prefix of the default OBO identifier translation rule.
+const OBO_PURL: &str = "http://purl.obolibrary.org/obo/";
+
+/// Compact an IRI using the longest matching prefix, or the default OBO rule.
+pub fn compact_url(url: &str, prefixes: &HashMap<String, String>) -> Option<ast::PrefixedIdent> {
+    let (prefix, local) = prefixes
+        .iter()
+        .filter(|(_, iri)| url.starts_with(iri.as_str()))
+        .max_by_key(|(_, iri)| iri.len())
+        .map(|(prefix, iri)| (prefix.as_str(), &url[iri.len()..]))
+        .or_else(|| url.strip_prefix(OBO_PURL)?.split_once('_'))?;
+    if prefix.is_empty() || local.is_empty() {
+        None
+    } else {
+        Some(ast::PrefixedIdent::new(prefix, local))
+    }
+}
+
+/// Extract an identifier from a `str` or a `BaseIdent` instance.
+fn extract_ident(py: Python, object: &PyAny) -> PyResult<ast::Ident> {
+    if let Ok(s) = object.downcast::<PyString>() {
+        ast::Ident::from_str(s.to_str()?).map_err(|e| PyErr::from(Error::from(e)))
+    } else {
+        Ok(object.extract::<Ident>()?.into_py(py))
+    }
+}
+
+/// Extract an identifier from a `BaseIdent` instance, or parse it from a `str`.
+pub fn extract_or_parse(py: Python, object: &PyAny) -> PyResult<Ident> {
+    if let Ok(s) = object.downcast::<PyString>() {
+        self::parse(py, s.to_str()?)
+    } else {
+        object.extract()
+    }
+}
+
+/// Extract a prefix map from a `dict`, or from an object with a `prefix_map` method.
+fn extract_prefix_map(prefix_map: Option<&PyAny>) -> PyResult<HashMap<String, String>> {
+    match prefix_map {
+        None => Ok(HashMap::new()),
+        Some(obj) if obj.hasattr("prefix_map")? => obj.call_method0("prefix_map")?.extract(),
+        Some(obj) => obj.extract(),
+    }
+}
 
 #[pymodule]
 #[pyo3(name = "id")]
@@ -104,6 +325,9 @@ pub fn init(_py: Python, m: &PyModule) -> PyResult<()> {
 
     m.add_function(wrap_pyfunction!(self::parse, m)?)?;
     m.add_function(wrap_pyfunction!(self::is_valid, m)?)?;
+    m.add_function(wrap_pyfunction!(self::check, m)?)?;
+    m.add_function(wrap_pyfunction!(self::compact, m)?)?;
+    m.add_function(wrap_pyfunction!(self::expand, m)?)?;
 
     Ok(())
 }
@@ -118,7 +342,18 @@ macro_rules! impl_convert {
                 ident.into_py(py)
             }
         }
-
+        impl_convert!(@back $base, $cls);
+    };
+    ($base:ident, $cls:ident, pooled) => {
+        impl IntoPy<$cls> for $crate::fastobo::ast::$base {
+            fn into_py(self, py: Python) -> $cls {
+                let ident: $crate::fastobo::ast::Ident = self.into();
+                cached(&POOLED, ident, py, |id, py| id.into_py(py))
+            }
+        }
+        impl_convert!(@back $base, $cls);
+    };
+    (@back $base:ident, $cls:ident) => {
         impl IntoPy<$crate::fastobo::ast::$base> for $cls {
             fn into_py(self, py: Python) -> $crate::fastobo::ast::$base {
                 let ident: $crate::fastobo::ast::Ident = self.into_py(py);
@@ -128,7 +363,7 @@ macro_rules! impl_convert {
     };
 }
 
-#[derive(ClonePy, Debug, EqPy, PyWrapper)]
+#[derive(ClonePy, Debug, DisplayPy, EqPy, HashPy, PyWrapper)]
 #[wraps(BaseIdent)]
 pub enum Ident {
     Unprefixed(Py<UnprefixedIdent>),
@@ -136,26 +371,142 @@ pub enum Ident {
     Url(Py<Url>),
 }
 
-impl Display for Ident {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        Python::with_gil(|py| {
-            match self {
-                Ident::Unprefixed(id) => id.as_ref(py).borrow().fmt(f),
-                Ident::Prefixed(id) => id.as_ref(py).borrow().fmt(f),
-                Ident::Url(id) => id.as_ref(py).borrow().fmt(f),
+impl IntoPy<Ident> for fastobo::ast::Ident {
+    fn into_py(self, py: Python) -> Ident {
+        cached(&INTERNED, self, py, |id, py| {
+            match id {
+                ast::Ident::Unprefixed(id) => Py::new(py, id.into_py(py)).map(Ident::Unprefixed),
+                ast::Ident::Prefixed(id) => Py::new(py, id.into_py(py)).map(Ident::Prefixed),
+                ast::Ident::Url(id) => Py::new(py, id.into_py(py)).map(Ident::Url),
             }
+            .expect("could not allocate on Python heap")
         })
     }
 }
 
-impl IntoPy<Ident> for fastobo::ast::Ident {
-    fn into_py(self, py: Python) -> Ident {
-        match self {
-            ast::Ident::Unprefixed(id) => Py::new(py, id.into_py(py)).map(Ident::Unprefixed),
-            ast::Ident::Prefixed(id) => Py::new(py, id.into_py(py)).map(Ident::Prefixed),
-            ast::Ident::Url(id) => Py::new(py, id.into_py(py)).map(Ident::Url),
+/// A pool of Python identifiers and strings, shared between the frames
+/// converted from the same document.
+#[derive(Debug, Default)]
+struct Pool {
+    idents: HashMap<String, Ident>,
+    strings: HashSet<Arc<str>>,
+}
+
+/// A pool enabled on the current thread, if any.
+type PoolCell = RefCell<Option<Arc<Mutex<Pool>>>>;
+
+thread_local! {
+    /// The pool of all identifiers, used when interning is enabled.
+    static INTERNED: PoolCell = const { RefCell::new(None) };
+    /// The pool of namespace, subset and synonym type identifiers, and of
+    /// the strings of `created_by` clauses.
+    static POOLED: PoolCell = const { RefCell::new(None) };
+}
+
+/// Lock a pool, ignoring poisoning since a pool is always left consistent.
+fn lock(pool: &Mutex<Pool>) -> MutexGuard<'_, Pool> {
+    pool.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Convert an identifier with `convert`, or get it from `cache` if enabled.
+fn cached<F>(cache: &'static LocalKey<PoolCell>, id: ast::Ident, py: Python, convert: F) -> Ident
+where
+    F: FnOnce(ast::Ident, Python) -> Ident,
+{
+    let pool = match cache.with(|c| c.borrow().clone()) {
+        None => return convert(id, py),
+        Some(pool) => pool,
+    };
+    let key = id.to_string();
+    if let Some(ident) = lock(&pool).idents.get(&key) {
+        return ident.clone_py(py);
+    }
+    // convert without holding the lock, since `convert` may use a pool too
+    let ident = convert(id, py);
+    lock(&pool).idents.insert(key, ident.clone_py(py));
+    ident
+}
+
+/// Get a string from the pool enabled on the current thread, if any.
+///
+/// Strings are immutable, so sharing them never makes a change to one
+/// clause visible from another clause.
+pub fn pooled_str(s: &str) -> Arc<str> {
+    let pool = match POOLED.with(|c| c.borrow().clone()) {
+        None => return Arc::from(s),
+        Some(pool) => pool,
+    };
+    let mut pool = lock(&pool);
+    if let Some(string) = pool.strings.get(s) {
+        return string.clone();
+    }
+    let string = Arc::<str>::from(s);
+    pool.strings.insert(string.clone());
+    string
+}
+
+/// A guard enabling an identifier cache on the current thread.
+///
+/// While the guard is alive, converting identical `fastobo::ast::Ident`
+/// values with `IntoPy` returns references to the same Python object
+/// instead of allocating a new one for each occurrence. The cache is
+/// dropped, and the previous state restored, when the guard is dropped.
+pub struct InternGuard {
+    cache: &'static LocalKey<PoolCell>,
+    previous: Option<Arc<Mutex<Pool>>>,
+}
+
+impl InternGuard {
+    fn enable(cache: &'static LocalKey<PoolCell>, pool: Option<Arc<Mutex<Pool>>>) -> Self {
+        let previous = cache.with(|c| c.replace(pool));
+        Self { cache, previous }
+    }
+
+    /// Share all the identifiers converted while the guard is alive.
+    pub fn idents() -> Self {
+        Self::enable(&INTERNED, Some(Default::default()))
+    }
+
+    /// Share the namespaces, subsets, synonym types and creators converted
+    /// while the guard is alive.
+    pub fn strings() -> Self {
+        Self::enable(&POOLED, Some(Default::default()))
+    }
+}
+
+impl Drop for InternGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        self.cache.with(|c| *c.borrow_mut() = previous);
+    }
+}
+
+/// The pools enabled on the current thread.
+///
+/// Frames keep the pools enabled when they were created, so that their
+/// clauses share the same objects when they are converted on first access,
+/// after the document was loaded.
+#[derive(Clone, Debug, Default)]
+pub struct InternContext {
+    interned: Option<Arc<Mutex<Pool>>>,
+    pooled: Option<Arc<Mutex<Pool>>>,
+}
+
+impl InternContext {
+    /// Get the pools enabled on the current thread.
+    pub fn current() -> Self {
+        Self {
+            interned: INTERNED.with(|c| c.borrow().clone()),
+            pooled: POOLED.with(|c| c.borrow().clone()),
         }
-        .expect("could not allocate on Python heap")
+    }
+
+    /// Enable these pools on the current thread while the guards are alive.
+    pub fn enter(&self) -> [InternGuard; 2] {
+        [
+            InternGuard::enable(&INTERNED, self.interned.clone()),
+            InternGuard::enable(&POOLED, self.pooled.clone()),
+        ]
     }
 }
 
@@ -181,9 +532,9 @@ impl IntoPy<fastobo::ast::Ident> for Ident {
 impl_convert!(ClassIdent, Ident);
 impl_convert!(RelationIdent, Ident);
 impl_convert!(InstanceIdent, Ident);
-impl_convert!(SubsetIdent, Ident);
-impl_convert!(SynonymTypeIdent, Ident);
-impl_convert!(NamespaceIdent, Ident);
+impl_convert!(SubsetIdent, Ident, pooled);
+impl_convert!(SynonymTypeIdent, Ident, pooled);
+impl_convert!(NamespaceIdent, Ident, pooled);
 
 // --- Base -------------------------------------------------------------------
 
@@ -198,6 +549,29 @@ impl AbstractClass for BaseIdent {
     }
 }
 
+#[pymethods]
+impl BaseIdent {
+    /// Create a copy of the identifier.
+    ///
+    /// Example:
+    ///     >>> ident = fastobo.id.PrefixedIdent('GO', '0009637')
+    ///     >>> copy = ident.__copy__()
+    ///     >>> copy == ident and copy is not ident
+    ///     True
+    ///
+    fn __copy__(slf: &PyCell<Self>) -> PyResult<Ident> {
+        let py = slf.py();
+        let ident = Ident::extract(slf)?;
+        let ast: ast::Ident = (&ident).into_py(py);
+        Ok(ast.into_py(py))
+    }
+
+    /// Create a deep copy of the identifier.
+    fn __deepcopy__(slf: &PyCell<Self>, _memo: &PyAny) -> PyResult<Ident> {
+        Self::__copy__(slf)
+    }
+}
+
 // --- PrefixedIdent ----------------------------------------------------------
 
 /// An identifier with a prefix.
@@ -212,7 +586,7 @@ impl AbstractClass for BaseIdent {
 ///     'GO:0009637'
 ///
 #[pyclass(extends=BaseIdent, module="fastobo.id")]
-#[derive(Debug, FinalClass, Clone, PartialEq, Eq, EqPy)]
+#[derive(Debug, FinalClass, Clone, PartialEq, Eq, EqPy, HashPy)]
 #[base(BaseIdent)]
 pub struct PrefixedIdent {
     inner: ast::PrefixedIdent,
@@ -238,6 +612,12 @@ impl Display for PrefixedIdent {
     }
 }
 
+impl DisplayPy for PrefixedIdent {
+    fn fmt_py(&self, _py: Python, f: &mut Formatter) -> FmtResult {
+        self.inner.fmt(f)
+    }
+}
+
 impl From<ast::PrefixedIdent> for PrefixedIdent {
     fn from(id: ast::PrefixedIdent) -> Self {
         Self { inner: id }
@@ -278,9 +658,26 @@ impl PrefixedIdent {
     /// Arguments:
     ///     prefix (str): the idspace of the identifier.
     ///     local (str): the local part of the identifier.
+    ///     validate (bool): whether or not to check the prefix and the local
+    ///         part against the OBO identifier grammar.
+    ///
+    /// Raises:
+    ///     ValueError: when ``validate`` is `True` and the prefix or the
+    ///         local part contains a character not allowed by the grammar.
+    ///
+    /// Example:
+    ///     >>> fastobo.id.PrefixedIdent("GO ", "0001", validate=True)
+    ///     Traceback (most recent call last):
+    ///       ...
+    ///     ValueError: invalid character ' ' at position 2 in identifier prefix
     ///
     #[new]
-    fn __init__(prefix: &str, local: &str) -> PyResult<PyClassInitializer<Self>> {
+    #[pyo3(signature = (prefix, local, *, validate = false))]
+    fn __init__(prefix: &str, local: &str, validate: bool) -> PyResult<PyClassInitializer<Self>> {
+        if validate {
+            check_component(fastobo::syntax::Rule::IdPrefix, prefix, "prefix")?;
+            check_component(fastobo::syntax::Rule::IdLocal, local, "local part")?;
+        }
         Ok(PyClassInitializer::from(BaseIdent {}).add_subclass(Self::new(prefix, local)))
     }
 
@@ -333,6 +730,7 @@ impl PrefixedIdent {
 
     #[setter]
     fn set_prefix(&mut self, prefix: &str) {
+        crate::index::invalidate();
         self.inner = ast::PrefixedIdent::new(prefix, self.inner.local());
     }
 
@@ -344,10 +742,31 @@ impl PrefixedIdent {
 
     #[setter]
     fn set_local(&mut self, local: &str) {
+        crate::index::invalidate();
         self.inner = ast::PrefixedIdent::new(self.inner.prefix(), local);
     }
 }
 
+/// Check a component of a prefixed identifier against the OBO grammar.
+fn check_component(rule: fastobo::syntax::Rule, value: &str, name: &str) -> PyResult<()> {
+    let matched = match fastobo::syntax::Lexer::tokenize(rule, value) {
+        Ok(pairs) => pairs.as_str().len(),
+        Err(_) => 0,
+    };
+    match value[matched..].chars().next() {
+        None if matched > 0 => Ok(()),
+        None => Err(PyValueError::new_err(format!("empty identifier {}", name))),
+        Some(c) => {
+            let position = value[..matched].chars().count();
+            let msg = format!(
+                "invalid character {:?} at position {} in identifier {}",
+                c, position, name
+            );
+            Err(PyValueError::new_err(msg))
+        }
+    }
+}
+
 // --- UnprefixedIdent --------------------------------------------------------
 
 /// An identifier without a prefix.
@@ -361,7 +780,7 @@ impl PrefixedIdent {
 ///     hello world
 ///
 #[pyclass(extends=BaseIdent, module="fastobo.id")]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, EqPy, FinalClass)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, EqPy, HashPy, FinalClass)]
 #[base(BaseIdent)]
 pub struct UnprefixedIdent {
     inner: ast::UnprefixedIdent,
@@ -391,6 +810,12 @@ impl Display for UnprefixedIdent {
     }
 }
 
+impl DisplayPy for UnprefixedIdent {
+    fn fmt_py(&self, _py: Python, f: &mut Formatter) -> FmtResult {
+        self.inner.fmt(f)
+    }
+}
+
 impl From<UnprefixedIdent> for ast::UnprefixedIdent {
     fn from(id: UnprefixedIdent) -> Self {
         id.inner
@@ -505,7 +930,7 @@ impl UnprefixedIdent {
 ///     ValueError: invalid url: ...
 ///
 #[pyclass(extends=BaseIdent, module="fastobo.id")]
-#[derive(Clone, ClonePy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, EqPy, FinalClass)]
+#[derive(Clone, ClonePy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, EqPy, HashPy, FinalClass)]
 #[base(BaseIdent)]
 pub struct Url {
     inner: ast::Url,
@@ -517,12 +942,58 @@ impl Url {
     }
 }
 
+/// The components of a URL, following the generic syntax of RFC 3986.
+struct UrlComponents<'a> {
+    scheme: &'a str,
+    host: Option<&'a str>,
+    path: &'a str,
+    fragment: Option<&'a str>,
+}
+
+impl Url {
+    /// Split the URL into its components.
+    fn components(&self) -> UrlComponents<'_> {
+        let url = self.inner.as_str();
+        let (scheme, rest) = url.split_once(':').unwrap_or(("", url));
+        let (rest, fragment) = match rest.split_once('#') {
+            Some((rest, fragment)) => (rest, Some(fragment)),
+            None => (rest, None),
+        };
+        let rest = rest.split_once('?').map(|(rest, _)| rest).unwrap_or(rest);
+        let (host, path) = match rest.strip_prefix("//") {
+            Some(hier) => {
+                let end = hier.find('/').unwrap_or(hier.len());
+                let authority = &hier[..end];
+                let host = authority.rsplit_once('@').map(|(_, h)| h).unwrap_or(authority);
+                let host = match host.rfind(':') {
+                    Some(i) if !host[i..].contains(']') => &host[..i],
+                    _ => host,
+                };
+                (Some(host), &hier[end..])
+            }
+            None => (None, rest),
+        };
+        UrlComponents {
+            scheme,
+            host,
+            path,
+            fragment,
+        }
+    }
+}
+
 impl Display for Url {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         self.inner.fmt(f)
     }
 }
 
+impl DisplayPy for Url {
+    fn fmt_py(&self, _py: Python, f: &mut Formatter) -> FmtResult {
+        self.inner.fmt(f)
+    }
+}
+
 impl From<ast::Url> for Url {
     fn from(url: ast::Url) -> Self {
         Self::new(url)
@@ -615,4 +1086,70 @@ impl Url {
             }
         }
     }
+
+    /// `str`: the scheme of the URL, such as ``"http"``.
+    ///
+    /// Example:
+    ///     >>> url = fastobo.id.Url("http://purl.obolibrary.org/obo/go.owl#GO_0070412")
+    ///     >>> url.scheme
+    ///     'http'
+    ///     >>> url.host
+    ///     'purl.obolibrary.org'
+    ///     >>> url.path
+    ///     '/obo/go.owl'
+    ///     >>> url.fragment
+    ///     'GO_0070412'
+    ///
+    #[getter]
+    fn get_scheme(&self) -> &str {
+        self.components().scheme
+    }
+
+    /// `str` or `None`: the host of the URL, if it has an authority.
+    #[getter]
+    fn get_host(&self) -> Option<&str> {
+        self.components().host
+    }
+
+    /// `str`: the path of the URL, possibly empty.
+    #[getter]
+    fn get_path(&self) -> &str {
+        self.components().path
+    }
+
+    /// `str` or `None`: the fragment of the URL, without the leading ``#``.
+    #[getter]
+    fn get_fragment(&self) -> Option<&str> {
+        self.components().fragment
+    }
+
+    /// Convert the URL to a prefixed identifier.
+    ///
+    /// Arguments:
+    ///     prefix_map (`dict` or `~fastobo.doc.OboDoc`, optional): a mapping
+    ///         of CURIE prefixes to IRI prefixes, or a document to use the
+    ///         prefix map returned by `~fastobo.doc.OboDoc.prefix_map`.
+    ///
+    /// Returns:
+    ///     `~fastobo.id.PrefixedIdent`: the compact form of the URL.
+    ///
+    /// Raises:
+    ///     ValueError: when the URL cannot be compacted with the prefix map
+    ///         or the default OBO rule.
+    ///
+    /// See Also:
+    ///     `fastobo.id.compact`, which returns the URL unchanged instead of
+    ///     raising an error.
+    ///
+    #[pyo3(signature = (prefix_map = None), text_signature = "(self, prefix_map=None)")]
+    fn to_prefixed(&self, py: Python, prefix_map: Option<&PyAny>) -> PyResult<Ident> {
+        let prefixes = extract_prefix_map(prefix_map)?;
+        match compact_url(self.inner.as_str(), &prefixes) {
+            Some(ident) => Ok(ast::Ident::from(ident).into_py(py)),
+            None => {
+                let msg = format!("cannot compact url: {}", self.inner.as_str());
+                Err(PyValueError::new_err(msg))
+            }
+        }
+    }
 }
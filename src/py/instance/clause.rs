@@ -1 +1,1459 @@
+use std::cmp::Ord;
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::fmt::Write;
+use std::str::FromStr;
 
+use pyo3::class::basic::CompareOp;
+use pyo3::prelude::*;
+use pyo3::types::PyAny;
+use pyo3::types::PyDateAccess;
+use pyo3::types::PyDict;
+use pyo3::types::PyString;
+use pyo3::types::PyTimeAccess;
+use pyo3::types::PyTuple;
+use pyo3::types::PyTzInfo;
+use pyo3::AsPyPointer;
+use pyo3::PyNativeType;
+use pyo3::PyTypeInfo;
+
+use fastobo::ast;
+
+use super::super::abc::AbstractClause;
+use super::super::abc::AbstractEntityClause;
+use super::super::id::Ident;
+use super::super::pv::PropertyValue;
+use super::super::syn::Synonym;
+use super::super::xref::Xref;
+use super::super::xref::XrefList;
+use crate::date::extract_creation_date;
+use crate::date::isodate_to_date;
+use crate::date::isodatetime_to_datetime;
+use crate::date::NaivePolicy;
+use crate::utils::AbstractClass;
+use crate::utils::ClonePy;
+use crate::utils::DisplayPy;
+use crate::utils::EqPy;
+use crate::utils::FinalClass;
+use crate::utils::FreezePy;
+use crate::utils::HashPy;
+use crate::utils::PooledString;
+use crate::utils::RichCmpPy;
+
+// --- Conversion Wrapper ----------------------------------------------------
+
+#[derive(ClonePy, Debug, DisplayPy, PyWrapper, EqPy, HashPy)]
+#[wraps(BaseInstanceClause, parse)]
+#[eqpy(with = "AbstractEntityClause::eq_metadata_of")]
+pub enum InstanceClause {
+    IsAnonymous(Py<IsAnonymousClause>),
+    Name(Py<NameClause>),
+    Namespace(Py<NamespaceClause>),
+    AltId(Py<AltIdClause>),
+    Def(Py<DefClause>),
+    Comment(Py<CommentClause>),
+    Subset(Py<SubsetClause>),
+    Synonym(Py<SynonymClause>),
+    Xref(Py<XrefClause>),
+    PropertyValue(Py<PropertyValueClause>),
+    InstanceOf(Py<InstanceOfClause>),
+    Relationship(Py<RelationshipClause>),
+    CreatedBy(Py<CreatedByClause>),
+    CreationDate(Py<CreationDateClause>),
+    IsObsolete(Py<IsObsoleteClause>),
+    ReplacedBy(Py<ReplacedByClause>),
+    Consider(Py<ConsiderClause>),
+}
+
+impl InstanceClause {
+    /// Get the raw tag of the clause, without converting it.
+    pub fn raw_tag(&self) -> &'static str {
+        match self {
+            InstanceClause::IsAnonymous(_) => IsAnonymousClause::TAG,
+            InstanceClause::Name(_) => NameClause::TAG,
+            InstanceClause::Namespace(_) => NamespaceClause::TAG,
+            InstanceClause::AltId(_) => AltIdClause::TAG,
+            InstanceClause::Def(_) => DefClause::TAG,
+            InstanceClause::Comment(_) => CommentClause::TAG,
+            InstanceClause::Subset(_) => SubsetClause::TAG,
+            InstanceClause::Synonym(_) => SynonymClause::TAG,
+            InstanceClause::Xref(_) => XrefClause::TAG,
+            InstanceClause::PropertyValue(_) => PropertyValueClause::TAG,
+            InstanceClause::InstanceOf(_) => InstanceOfClause::TAG,
+            InstanceClause::Relationship(_) => RelationshipClause::TAG,
+            InstanceClause::CreatedBy(_) => CreatedByClause::TAG,
+            InstanceClause::CreationDate(_) => CreationDateClause::TAG,
+            InstanceClause::IsObsolete(_) => IsObsoleteClause::TAG,
+            InstanceClause::ReplacedBy(_) => ReplacedByClause::TAG,
+            InstanceClause::Consider(_) => ConsiderClause::TAG,
+        }
+    }
+}
+
+impl FreezePy for InstanceClause {
+    fn freeze_py(&self, py: Python) -> PyResult<()> {
+        AbstractClause::freeze_object(self.to_object(py).as_ref(py))?;
+        match self {
+            InstanceClause::Def(c) => c.try_borrow(py)?.xrefs.freeze_py(py),
+            InstanceClause::Synonym(c) => c.try_borrow(py)?.synonym.freeze_py(py),
+            InstanceClause::Xref(c) => c.try_borrow(py)?.xref.freeze_py(py),
+            InstanceClause::PropertyValue(c) => c.try_borrow(py)?.inner.freeze_py(py),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl IntoPy<InstanceClause> for fastobo::ast::InstanceClause {
+    fn into_py(self, py: Python) -> InstanceClause {
+        use fastobo::ast::InstanceClause::*;
+        match self {
+            IsAnonymous(b) => {
+                Py::new(py, IsAnonymousClause::new(b)).map(InstanceClause::IsAnonymous)
+            }
+            Name(n) => Py::new(py, NameClause::new(*n)).map(InstanceClause::Name),
+            Namespace(ns) => {
+                Py::new(py, NamespaceClause::new(ns.into_py(py))).map(InstanceClause::Namespace)
+            }
+            AltId(id) => Py::new(py, AltIdClause::new(id.into_py(py))).map(InstanceClause::AltId),
+            Def(mut def) => {
+                let text = std::mem::take(def.text_mut());
+                let xrefs = std::mem::take(def.xrefs_mut()).into_py(py);
+                Py::new(py, DefClause::new(text, xrefs)).map(InstanceClause::Def)
+            }
+            Comment(c) => Py::new(py, CommentClause::new(*c)).map(InstanceClause::Comment),
+            Subset(s) => Py::new(py, SubsetClause::new(s.into_py(py))).map(InstanceClause::Subset),
+            Synonym(s) => Py::new(py, s.into_py(py))
+                .map(SynonymClause::new)
+                .and_then(|clause| Py::new(py, clause))
+                .map(InstanceClause::Synonym),
+            Xref(x) => Py::new(py, x.into_py(py))
+                .map(XrefClause::new)
+                .and_then(|clause| Py::new(py, clause))
+                .map(InstanceClause::Xref),
+            PropertyValue(pv) => Py::new(py, PropertyValueClause::new(pv.into_py(py)))
+                .map(InstanceClause::PropertyValue),
+            InstanceOf(id) => {
+                Py::new(py, InstanceOfClause::new(id.into_py(py))).map(InstanceClause::InstanceOf)
+            }
+            Relationship(r, id) => {
+                Py::new(py, RelationshipClause::new(r.into_py(py), id.into_py(py)))
+                    .map(InstanceClause::Relationship)
+            }
+            CreatedBy(name) => {
+                Py::new(py, CreatedByClause::new(*name)).map(InstanceClause::CreatedBy)
+            }
+            CreationDate(dt) => {
+                Py::new(py, CreationDateClause::new(*dt)).map(InstanceClause::CreationDate)
+            }
+            IsObsolete(b) => Py::new(py, IsObsoleteClause::new(b)).map(InstanceClause::IsObsolete),
+            ReplacedBy(id) => {
+                Py::new(py, ReplacedByClause::new(id.into_py(py))).map(InstanceClause::ReplacedBy)
+            }
+            Consider(id) => {
+                Py::new(py, ConsiderClause::new(id.into_py(py))).map(InstanceClause::Consider)
+            }
+        }
+        .expect("could not allocate memory for `InstanceClause` in Python heap")
+    }
+}
+
+// --- Base ------------------------------------------------------------------
+
+/// An instance clause, appearing in an OBO instance frame.
+#[pyclass(subclass, extends=AbstractEntityClause, module="fastobo.instance")]
+#[derive(AbstractClass)]
+#[base(AbstractEntityClause)]
+pub struct BaseInstanceClause {}
+
+#[pymethods]
+impl BaseInstanceClause {
+    // Allow user-defined subclasses, which are converted through their
+    // `raw_tag` and `raw_value` when they are added to a frame.
+    #[new]
+    #[pyo3(signature = (*_args, **_kwargs))]
+    fn __new__(_args: &PyTuple, _kwargs: Option<&PyDict>) -> PyClassInitializer<Self> {
+        Self::initializer()
+    }
+}
+
+// --- IsAnonymous -----------------------------------------------------------
+
+/// IsAnonymousClause(anonymous)
+/// --
+///
+/// A clause declaring whether or not the current instance has an anonymous id.
+#[pyclass(extends=BaseInstanceClause, module="fastobo.instance")]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
+#[base(BaseInstanceClause)]
+pub struct IsAnonymousClause {
+    #[pyo3(get, set)]
+    anonymous: bool,
+}
+
+impl IsAnonymousClause {
+    pub fn new(anonymous: bool) -> Self {
+        Self { anonymous }
+    }
+}
+
+impl_display_py!(IsAnonymousClause, anonymous);
+
+impl From<IsAnonymousClause> for fastobo::ast::InstanceClause {
+    fn from(clause: IsAnonymousClause) -> Self {
+        fastobo::ast::InstanceClause::IsAnonymous(clause.anonymous)
+    }
+}
+
+impl IntoPy<fastobo::ast::InstanceClause> for IsAnonymousClause {
+    fn into_py(self, _py: Python) -> fastobo::ast::InstanceClause {
+        fastobo::ast::InstanceClause::from(self)
+    }
+}
+
+#[obo_clause(tag = "is_anonymous")]
+#[pymethods]
+impl IsAnonymousClause {
+    #[new]
+    fn __init__(anonymous: bool) -> PyClassInitializer<Self> {
+        Self::new(anonymous).into()
+    }
+
+    fn __repr__(&self) -> PyResult<PyObject> {
+        impl_repr!(self, IsAnonymousClause(self.anonymous))
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+}
+
+// --- Name ------------------------------------------------------------------
+
+/// NameClause(name)
+/// --
+///
+/// An instance clause declaring the human-readable name of this instance.
+#[pyclass(extends=BaseInstanceClause, module="fastobo.instance")]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
+#[base(BaseInstanceClause)]
+pub struct NameClause {
+    name: fastobo::ast::UnquotedString,
+}
+
+impl NameClause {
+    pub fn new(name: fastobo::ast::UnquotedString) -> Self {
+        Self { name }
+    }
+
+    /// Get a reference to the name of the clause.
+    pub fn name(&self) -> &fastobo::ast::UnquotedString {
+        &self.name
+    }
+}
+
+impl_display_py!(NameClause, name);
+
+impl From<NameClause> for fastobo::ast::InstanceClause {
+    fn from(clause: NameClause) -> Self {
+        fastobo::ast::InstanceClause::Name(Box::new(clause.name))
+    }
+}
+
+impl IntoPy<fastobo::ast::InstanceClause> for NameClause {
+    fn into_py(self, _py: Python) -> fastobo::ast::InstanceClause {
+        fastobo::ast::InstanceClause::from(self)
+    }
+}
+
+#[obo_clause(tag = "name")]
+#[pymethods]
+impl NameClause {
+    #[new]
+    fn __init__(name: String) -> PyClassInitializer<Self> {
+        Self::new(fastobo::ast::UnquotedString::new(name)).into()
+    }
+
+    fn __repr__(&self) -> PyResult<PyObject> {
+        impl_repr!(self, NameClause(self.name))
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+
+    /// `str`: the name of the current instance.
+    #[getter]
+    fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    #[setter]
+    fn set_name(&mut self, name: String) {
+        self.name = fastobo::ast::UnquotedString::new(name);
+    }
+}
+
+// --- Namespace -------------------------------------------------------------
+
+/// NamespaceClause(namespace)
+/// --
+///
+/// An instance clause declaring the namespace of this instance.
+#[pyclass(extends=BaseInstanceClause, module="fastobo.instance")]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
+#[base(BaseInstanceClause)]
+pub struct NamespaceClause {
+    #[pyo3(set)]
+    namespace: Ident,
+}
+
+impl NamespaceClause {
+    pub fn new(namespace: Ident) -> Self {
+        Self { namespace }
+    }
+}
+
+impl ClonePy for NamespaceClause {
+    fn clone_py(&self, py: Python) -> Self {
+        Self {
+            namespace: self.namespace.clone_py(py),
+        }
+    }
+}
+
+impl_display_py!(NamespaceClause, namespace);
+
+impl IntoPy<fastobo::ast::InstanceClause> for NamespaceClause {
+    fn into_py(self, py: Python) -> fastobo::ast::InstanceClause {
+        let ns: fastobo::ast::NamespaceIdent = self.namespace.into_py(py);
+        fastobo::ast::InstanceClause::Namespace(Box::new(ns))
+    }
+}
+
+#[obo_clause(tag = "namespace")]
+#[pymethods]
+impl NamespaceClause {
+    #[new]
+    fn __init__(namespace: Ident) -> PyClassInitializer<Self> {
+        Self::new(namespace).into()
+    }
+
+    fn __repr__(&self) -> PyResult<PyObject> {
+        impl_repr!(self, NamespaceClause(self.namespace))
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+
+    #[getter]
+    /// `~fastobo.id.Ident`: the ID of the namespace this instance is part of.
+    fn get_namespace(&self) -> &Ident {
+        &self.namespace
+    }
+}
+
+// --- AltId -----------------------------------------------------------------
+
+/// AltIdClause(alt_id)
+/// --
+///
+/// A clause defines an alternate id for this instance.
+#[pyclass(extends=BaseInstanceClause, module="fastobo.instance")]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
+#[base(BaseInstanceClause)]
+pub struct AltIdClause {
+    #[pyo3(set)]
+    alt_id: Ident,
+}
+
+impl AltIdClause {
+    pub fn new(alt_id: Ident) -> Self {
+        Self { alt_id }
+    }
+}
+
+impl ClonePy for AltIdClause {
+    fn clone_py(&self, py: Python) -> Self {
+        Self {
+            alt_id: self.alt_id.clone_py(py),
+        }
+    }
+}
+
+impl_display_py!(AltIdClause, alt_id);
+
+impl IntoPy<fastobo::ast::InstanceClause> for AltIdClause {
+    fn into_py(self, py: Python) -> fastobo::ast::InstanceClause {
+        fastobo::ast::InstanceClause::AltId(Box::new(self.alt_id.into_py(py)))
+    }
+}
+
+#[obo_clause(tag = "alt_id")]
+#[pymethods]
+impl AltIdClause {
+    #[new]
+    fn __init__(alt_id: Ident) -> PyClassInitializer<Self> {
+        Self::new(alt_id).into()
+    }
+
+    fn __repr__(&self) -> PyResult<PyObject> {
+        impl_repr!(self, AltIdClause(self.alt_id))
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+
+    #[getter]
+    /// `~fastobo.id.Ident`: an alternative ID used to refer to this instance.
+    fn get_alt_id(&self) -> &Ident {
+        &self.alt_id
+    }
+}
+
+// --- Def -------------------------------------------------------------------
+
+/// DefClause(definition, xrefs=None)
+/// --
+///
+/// A clause giving a human-readable definition of the instance.
+///
+/// Arguments:
+///     definition (str): The human-readable textual definition of the
+///         current instance.
+///     xrefs (~typing.Iterable[~fastobo.xref.Xref], optional): An iterable
+///         of database cross-references describing the origin of the
+///         definition, or `None`.
+///
+#[pyclass(extends=BaseInstanceClause, module="fastobo.instance")]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
+#[base(BaseInstanceClause)]
+pub struct DefClause {
+    definition: fastobo::ast::QuotedString,
+    xrefs: XrefList,
+}
+
+impl DefClause {
+    pub fn new(definition: fastobo::ast::QuotedString, xrefs: XrefList) -> Self {
+        Self { definition, xrefs }
+    }
+
+    /// Get a reference to the textual definition of the clause.
+    pub fn definition(&self) -> &fastobo::ast::QuotedString {
+        &self.definition
+    }
+
+    /// Get a reference to the cross-references supporting the definition.
+    pub fn xrefs(&self) -> &XrefList {
+        &self.xrefs
+    }
+}
+
+impl ClonePy for DefClause {
+    fn clone_py(&self, py: Python) -> Self {
+        Self {
+            definition: self.definition.clone(),
+            xrefs: self.xrefs.clone_py(py),
+        }
+    }
+}
+
+impl_display_py!(DefClause, definition, xrefs);
+
+impl IntoPy<fastobo::ast::InstanceClause> for DefClause {
+    fn into_py(self, py: Python) -> fastobo::ast::InstanceClause {
+        let xrefs: fastobo::ast::XrefList = self.xrefs.into_py(py);
+        let def = fastobo::ast::Definition::with_xrefs(self.definition, xrefs);
+        fastobo::ast::InstanceClause::Def(Box::new(def))
+    }
+}
+
+#[obo_clause(tag = "def")]
+#[pymethods]
+impl DefClause {
+    #[new]
+    fn __init__(definition: &PyString, xrefs: Option<&PyAny>) -> PyResult<PyClassInitializer<Self>> {
+        let py = definition.py();
+        let def = fastobo::ast::QuotedString::new(definition.to_str()?);
+        let list = match xrefs {
+            Some(x) => XrefList::collect(py, x)?,
+            None => XrefList::new(Vec::new()),
+        };
+        Ok(Self::new(def, list).into())
+    }
+
+    fn __repr__(&self) -> PyResult<PyObject> {
+        if self.xrefs.is_empty() {
+            impl_repr!(self, DefClause(self.definition))
+        } else {
+            impl_repr!(self, DefClause(self.definition, self.xrefs))
+        }
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+
+    #[getter]
+    /// `str`: a textual definition for this instance.
+    fn get_definition(&self) -> &str {
+        &self.definition.as_str()
+    }
+
+    #[setter]
+    fn set_definition(&mut self, definition: String) {
+        self.definition = fastobo::ast::QuotedString::new(definition);
+    }
+
+    #[getter]
+    /// `~fastobo.xrefs.XrefList`: a list of xrefs supporting the definition.
+    fn get_xrefs<'py>(&self, py: Python<'py>) -> XrefList {
+        self.xrefs.clone_py(py)
+    }
+
+    fn raw_value(&self) -> String {
+        self.definition.to_string()
+    }
+}
+
+// --- Comment ---------------------------------------------------------------
+
+/// CommentClause(comment)
+/// --
+///
+/// A clause storing a comment for this instance.
+#[pyclass(extends=BaseInstanceClause, module="fastobo.instance")]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
+#[base(BaseInstanceClause)]
+pub struct CommentClause {
+    comment: fastobo::ast::UnquotedString,
+}
+
+impl CommentClause {
+    pub fn new(comment: fastobo::ast::UnquotedString) -> Self {
+        Self { comment }
+    }
+}
+
+impl_display_py!(CommentClause, comment);
+
+impl From<CommentClause> for fastobo::ast::InstanceClause {
+    fn from(clause: CommentClause) -> Self {
+        fastobo::ast::InstanceClause::Comment(Box::new(clause.comment))
+    }
+}
+
+impl IntoPy<fastobo::ast::InstanceClause> for CommentClause {
+    fn into_py(self, _py: Python) -> fastobo::ast::InstanceClause {
+        fastobo::ast::InstanceClause::from(self)
+    }
+}
+
+#[obo_clause(tag = "comment")]
+#[pymethods]
+impl CommentClause {
+    #[new]
+    fn __init__(comment: String) -> PyClassInitializer<Self> {
+        Self::new(fastobo::ast::UnquotedString::new(comment)).into()
+    }
+
+    fn __repr__(&self) -> PyResult<PyObject> {
+        impl_repr!(self, CommentClause(self.comment))
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+
+    #[getter]
+    /// `str`: a comment relevant to this instance.
+    fn get_comment(&self) -> &str {
+        self.comment.as_str()
+    }
+
+    #[setter]
+    fn set_comment(&mut self, comment: String) {
+        self.comment = fastobo::ast::UnquotedString::new(comment);
+    }
+}
+
+// --- Subset ----------------------------------------------------------------
+
+/// SubsetClause(subset)
+/// --
+///
+/// A clause declaring a subset to which this instance belongs.
+#[pyclass(extends=BaseInstanceClause, module="fastobo.instance")]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
+#[base(BaseInstanceClause)]
+pub struct SubsetClause {
+    #[pyo3(set)]
+    subset: Ident,
+}
+
+impl SubsetClause {
+    pub fn new(subset: Ident) -> Self {
+        Self { subset }
+    }
+}
+
+impl ClonePy for SubsetClause {
+    fn clone_py(&self, py: Python) -> Self {
+        Self {
+            subset: self.subset.clone_py(py),
+        }
+    }
+}
+
+impl_display_py!(SubsetClause, subset);
+
+impl IntoPy<fastobo::ast::InstanceClause> for SubsetClause {
+    fn into_py(self, py: Python) -> fastobo::ast::InstanceClause {
+        fastobo::ast::InstanceClause::Subset(Box::new(self.subset.into_py(py)))
+    }
+}
+
+#[obo_clause(tag = "subset")]
+#[pymethods]
+impl SubsetClause {
+    #[new]
+    fn __init__(subset: Ident) -> PyClassInitializer<Self> {
+        Self::new(subset).into()
+    }
+
+    fn __repr__(&self) -> PyResult<PyObject> {
+        impl_repr!(self, SubsetClause(self.subset))
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+
+    #[getter]
+    /// `~fastobo.id.Ident`: the ID of the subset this instance is part of.
+    fn get_subset(&self) -> &Ident {
+        &self.subset
+    }
+}
+
+// --- Synonym ---------------------------------------------------------------
+
+/// SynonymClause(synonym)
+/// --
+///
+/// A clause giving a synonym for this instance, with some cross-references.
+#[pyclass(extends=BaseInstanceClause, module="fastobo.instance")]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
+#[base(BaseInstanceClause)]
+pub struct SynonymClause {
+    #[pyo3(set)]
+    synonym: Py<Synonym>,
+}
+
+impl SynonymClause {
+    pub fn new(synonym: Py<Synonym>) -> Self {
+        Self { synonym }
+    }
+
+    /// Get a reference to the synonym of the clause.
+    pub fn synonym(&self) -> &Py<Synonym> {
+        &self.synonym
+    }
+}
+
+impl ClonePy for SynonymClause {
+    fn clone_py(&self, py: Python) -> Self {
+        Self {
+            synonym: self.synonym.clone_py(py),
+        }
+    }
+}
+
+impl_display_py!(SynonymClause, synonym);
+
+impl IntoPy<fastobo::ast::InstanceClause> for SynonymClause {
+    fn into_py(self, py: Python) -> fastobo::ast::InstanceClause {
+        fastobo::ast::InstanceClause::Synonym(Box::new(
+            self.synonym.as_ref(py).borrow().clone_py(py).into_py(py),
+        ))
+    }
+}
+
+#[obo_clause(tag = "synonym")]
+#[pymethods]
+impl SynonymClause {
+    #[new]
+    fn __init__(synonym: Py<Synonym>) -> PyClassInitializer<Self> {
+        Self::new(Python::with_gil(|py| synonym.clone_ref(py))).into()
+    }
+
+    fn __repr__(&self) -> PyResult<PyObject> {
+        impl_repr!(self, SynonymClause(self.synonym))
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+
+    #[getter]
+    /// `~fastobo.syn.Synonym`: a possible synonym for this instance.
+    fn get_synonym<'py>(&self, py: Python<'py>) -> Py<Synonym> {
+        self.synonym.clone_py(py)
+    }
+}
+
+// --- Xref ------------------------------------------------------------------
+
+/// XrefClause(xref)
+/// --
+///
+/// A cross-reference that describes an analogous instance in another vocabulary.
+#[pyclass(extends=BaseInstanceClause, module="fastobo.instance")]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
+#[base(BaseInstanceClause)]
+pub struct XrefClause {
+    #[pyo3(get, set)]
+    /// `~fastobo.xref.Xref`: a cross-reference relevant to this instance.
+    xref: Py<Xref>,
+}
+
+impl XrefClause {
+    pub fn new(xref: Py<Xref>) -> Self {
+        Self { xref }
+    }
+
+    /// Get a reference to the cross-reference of the clause.
+    pub fn xref(&self) -> &Py<Xref> {
+        &self.xref
+    }
+}
+
+impl ClonePy for XrefClause {
+    fn clone_py(&self, py: Python) -> Self {
+        Self {
+            xref: self.xref.clone_py(py),
+        }
+    }
+}
+
+impl_display_py!(XrefClause, xref);
+
+impl From<Py<Xref>> for XrefClause {
+    fn from(xref: Py<Xref>) -> Self {
+        Self { xref }
+    }
+}
+
+impl IntoPy<fastobo::ast::InstanceClause> for XrefClause {
+    fn into_py(self, py: Python) -> fastobo::ast::InstanceClause {
+        fastobo::ast::InstanceClause::Xref(Box::new(
+            self.xref.as_ref(py).borrow().clone_py(py).into_py(py),
+        ))
+    }
+}
+
+impl IntoPy<XrefClause> for Xref {
+    fn into_py(self, py: Python) -> XrefClause {
+        XrefClause {
+            xref: Py::new(py, self)
+                .expect("could not allocate memory on Python heap for XrefClause"),
+        }
+    }
+}
+
+#[obo_clause(tag = "xref")]
+#[pymethods]
+impl XrefClause {
+    #[new]
+    fn __init__(xref: Py<Xref>) -> PyClassInitializer<Self> {
+        Self::from(xref).into()
+    }
+
+    fn __repr__(&self) -> PyResult<PyObject> {
+        impl_repr!(self, XrefClause(self.xref))
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+}
+
+// --- PropertyValue ---------------------------------------------------------
+
+/// PropertyValueClause(property_value)
+/// --
+///
+/// A clause that binds a property to a value in the instance.
+///
+/// Arguments:
+///     property_value (~fastobo.pv.AbstractPropertyValue): the property value
+///         to annotate the current instance.
+///
+#[pyclass(extends=BaseInstanceClause, module="fastobo.instance")]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
+#[base(BaseInstanceClause)]
+pub struct PropertyValueClause {
+    #[pyo3(set)]
+    inner: PropertyValue,
+}
+
+impl PropertyValueClause {
+    pub fn new(property_value: PropertyValue) -> Self {
+        Self {
+            inner: property_value,
+        }
+    }
+}
+
+impl ClonePy for PropertyValueClause {
+    fn clone_py(&self, py: Python) -> Self {
+        Self {
+            inner: self.inner.clone_py(py),
+        }
+    }
+}
+
+impl_display_py!(PropertyValueClause, inner);
+
+impl IntoPy<fastobo::ast::InstanceClause> for PropertyValueClause {
+    fn into_py(self, py: Python) -> ast::InstanceClause {
+        ast::InstanceClause::PropertyValue(Box::new(self.inner.into_py(py)))
+    }
+}
+
+#[obo_clause(tag = "property_value")]
+#[pymethods]
+impl PropertyValueClause {
+    #[new]
+    fn __init__(property_value: PropertyValue) -> PyClassInitializer<Self> {
+        Self::new(property_value).into()
+    }
+
+    fn __repr__(&self) -> PyResult<PyObject> {
+        impl_repr!(self, PropertyValueClause(self.inner))
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+
+    #[getter]
+    /// `~fastobo.pv.AbstractPropertyValue`: an annotation of the instance.
+    fn get_property_value(&self) -> &PropertyValue {
+        &self.inner
+    }
+}
+
+// --- InstanceOf ------------------------------------------------------------
+
+/// InstanceOfClause(term)
+/// --
+///
+/// A clause declaring the class this instance is an instance of.
+#[pyclass(extends=BaseInstanceClause, module="fastobo.instance")]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
+#[base(BaseInstanceClause)]
+pub struct InstanceOfClause {
+    #[pyo3(set)]
+    term: Ident,
+}
+
+impl InstanceOfClause {
+    pub fn new(term: Ident) -> Self {
+        Self { term }
+    }
+
+    /// Get a reference to the identifier of the class of the instance.
+    pub fn term(&self) -> &Ident {
+        &self.term
+    }
+}
+
+impl ClonePy for InstanceOfClause {
+    fn clone_py(&self, py: Python) -> Self {
+        Self {
+            term: self.term.clone_py(py),
+        }
+    }
+}
+
+impl_display_py!(InstanceOfClause, term);
+
+impl IntoPy<fastobo::ast::InstanceClause> for InstanceOfClause {
+    fn into_py(self, py: Python) -> fastobo::ast::InstanceClause {
+        ast::InstanceClause::InstanceOf(Box::new(self.term.into_py(py)))
+    }
+}
+
+#[obo_clause(tag = "instance_of")]
+#[pymethods]
+impl InstanceOfClause {
+    #[new]
+    fn __init__(term: Ident) -> PyClassInitializer<Self> {
+        Self::new(term).into()
+    }
+
+    fn __repr__(&self) -> PyResult<PyObject> {
+        impl_repr!(self, InstanceOfClause(self.term))
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+
+    #[getter]
+    /// `~fastobo.id.Ident`: the identifier of the class of the instance.
+    fn get_term(&self) -> &Ident {
+        &self.term
+    }
+}
+
+// --- Relationship ----------------------------------------------------------
+
+/// RelationshipClause(typedef, target)
+/// --
+///
+/// A clause describing a typed relationship between this instance and another entity.
+#[pyclass(extends=BaseInstanceClause, module="fastobo.instance")]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
+#[base(BaseInstanceClause)]
+pub struct RelationshipClause {
+    #[pyo3(set)]
+    typedef: Ident,
+    #[pyo3(set)]
+    target: Ident,
+}
+
+impl RelationshipClause {
+    pub fn new(typedef: Ident, target: Ident) -> Self {
+        Self { typedef, target }
+    }
+
+    /// Get a reference to the identifier of the relationship.
+    pub fn typedef(&self) -> &Ident {
+        &self.typedef
+    }
+
+    /// Get a reference to the identifier of the target entity.
+    pub fn target(&self) -> &Ident {
+        &self.target
+    }
+}
+
+impl ClonePy for RelationshipClause {
+    fn clone_py(&self, py: Python) -> Self {
+        Self {
+            typedef: self.typedef.clone_py(py),
+            target: self.target.clone_py(py),
+        }
+    }
+}
+
+impl_display_py!(RelationshipClause, typedef, target);
+
+impl IntoPy<fastobo::ast::InstanceClause> for RelationshipClause {
+    fn into_py(self, py: Python) -> fastobo::ast::InstanceClause {
+        ast::InstanceClause::Relationship(
+            Box::new(self.typedef.into_py(py)),
+            Box::new(self.target.into_py(py)),
+        )
+    }
+}
+
+#[obo_clause(tag = "relationship")]
+#[pymethods]
+impl RelationshipClause {
+    #[new]
+    fn __init__(typedef: Ident, target: Ident) -> PyClassInitializer<Self> {
+        Self::new(typedef, target).into()
+    }
+
+    fn __repr__(&self) -> PyResult<PyObject> {
+        impl_repr!(self, RelationshipClause(self.typedef, self.target))
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+
+    #[getter]
+    fn get_typedef<'py>(&self, py: Python<'py>) -> PyResult<Ident> {
+        Ok(self.typedef.clone_py(py))
+    }
+
+    #[getter]
+    fn get_target<'py>(&self, py: Python<'py>) -> PyResult<Ident> {
+        Ok(self.target.clone_py(py))
+    }
+}
+
+// --- IsObsolete ------------------------------------------------------------
+
+/// IsObsoleteClause(obsolete)
+/// --
+///
+/// A clause indicating whether or not this instance is obsolete.
+#[pyclass(extends=BaseInstanceClause, module="fastobo.instance")]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
+#[base(BaseInstanceClause)]
+pub struct IsObsoleteClause {
+    #[pyo3(get, set)]
+    obsolete: bool,
+}
+
+impl IsObsoleteClause {
+    pub fn new(obsolete: bool) -> Self {
+        Self { obsolete }
+    }
+}
+
+impl_display_py!(IsObsoleteClause, obsolete);
+
+impl From<IsObsoleteClause> for fastobo::ast::InstanceClause {
+    fn from(clause: IsObsoleteClause) -> Self {
+        fastobo::ast::InstanceClause::IsObsolete(clause.obsolete)
+    }
+}
+
+impl IntoPy<fastobo::ast::InstanceClause> for IsObsoleteClause {
+    fn into_py(self, _py: Python) -> fastobo::ast::InstanceClause {
+        fastobo::ast::InstanceClause::from(self)
+    }
+}
+
+#[obo_clause(tag = "is_obsolete")]
+#[pymethods]
+impl IsObsoleteClause {
+    #[new]
+    fn __init__(obsolete: bool) -> PyClassInitializer<Self> {
+        Self::new(obsolete).into()
+    }
+
+    fn __repr__(&self) -> PyResult<PyObject> {
+        impl_repr!(self, IsObsoleteClause(self.obsolete))
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+}
+
+// --- ReplacedBy ------------------------------------------------------------
+
+/// ReplacedByClause(instance)
+/// --
+///
+/// A clause giving an instance which replaces this obsolete instance.
+#[pyclass(extends=BaseInstanceClause, module="fastobo.instance")]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
+#[base(BaseInstanceClause)]
+pub struct ReplacedByClause {
+    #[pyo3(set)]
+    instance: Ident,
+}
+
+impl ReplacedByClause {
+    pub fn new(instance: Ident) -> Self {
+        Self { instance }
+    }
+}
+
+impl ClonePy for ReplacedByClause {
+    fn clone_py(&self, py: Python) -> Self {
+        Self {
+            instance: self.instance.clone_py(py),
+        }
+    }
+}
+
+impl_display_py!(ReplacedByClause, instance);
+
+impl IntoPy<fastobo::ast::InstanceClause> for ReplacedByClause {
+    fn into_py(self, py: Python) -> fastobo::ast::InstanceClause {
+        fastobo::ast::InstanceClause::ReplacedBy(Box::new(self.instance.into_py(py)))
+    }
+}
+
+#[obo_clause(tag = "replaced_by")]
+#[pymethods]
+impl ReplacedByClause {
+    #[new]
+    fn __init__(instance: Ident) -> PyClassInitializer<Self> {
+        Self::new(instance).into()
+    }
+
+    fn __repr__(&self) -> PyResult<PyObject> {
+        impl_repr!(self, ReplacedByClause(self.instance))
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+
+    #[getter]
+    /// `~fastobo.id.Ident`: the identifier of the replacement instance.
+    fn get_instance(&self) -> &Ident {
+        &self.instance
+    }
+}
+
+// --- Consider --------------------------------------------------------------
+
+/// ConsiderClause(instance)
+/// --
+///
+/// A clause giving a potential substitute for an obsolete instance.
+#[pyclass(extends=BaseInstanceClause, module="fastobo.instance")]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
+#[base(BaseInstanceClause)]
+pub struct ConsiderClause {
+    instance: Ident,
+}
+
+impl ConsiderClause {
+    pub fn new(instance: Ident) -> Self {
+        Self { instance }
+    }
+}
+
+impl ClonePy for ConsiderClause {
+    fn clone_py(&self, py: Python) -> Self {
+        Self {
+            instance: self.instance.clone_py(py),
+        }
+    }
+}
+
+impl_display_py!(ConsiderClause, instance);
+
+impl IntoPy<fastobo::ast::InstanceClause> for ConsiderClause {
+    fn into_py(self, py: Python) -> fastobo::ast::InstanceClause {
+        ast::InstanceClause::Consider(Box::new(self.instance.into_py(py)))
+    }
+}
+
+#[obo_clause(tag = "consider")]
+#[pymethods]
+impl ConsiderClause {
+    #[new]
+    fn __init__(instance: Ident) -> PyClassInitializer<Self> {
+        Self::new(instance).into()
+    }
+
+    fn __repr__(&self) -> PyResult<PyObject> {
+        impl_repr!(self, ConsiderClause(self.instance))
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+
+    #[getter]
+    /// `~fastobo.id.Ident`: the identifier of the substitute instance.
+    fn get_instance(&self) -> &Ident {
+        &self.instance
+    }
+}
+
+// --- CreatedBy -------------------------------------------------------------
+
+/// CreatedByClause(creator)
+/// --
+///
+/// An instance clause stating the name of the creator of this instance.
+#[pyclass(extends=BaseInstanceClause, module="fastobo.instance")]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
+#[base(BaseInstanceClause)]
+pub struct CreatedByClause {
+    creator: PooledString,
+}
+
+impl CreatedByClause {
+    pub fn new(creator: fastobo::ast::UnquotedString) -> Self {
+        Self {
+            creator: PooledString::from(creator),
+        }
+    }
+}
+
+impl_display_py!(CreatedByClause, creator);
+
+impl From<CreatedByClause> for fastobo::ast::InstanceClause {
+    fn from(clause: CreatedByClause) -> Self {
+        fastobo::ast::InstanceClause::CreatedBy(Box::new(clause.creator.into()))
+    }
+}
+
+impl IntoPy<fastobo::ast::InstanceClause> for CreatedByClause {
+    fn into_py(self, _py: Python) -> fastobo::ast::InstanceClause {
+        fastobo::ast::InstanceClause::from(self)
+    }
+}
+
+#[obo_clause(tag = "created_by")]
+#[pymethods]
+impl CreatedByClause {
+    #[new]
+    fn __init__(creator: String) -> PyClassInitializer<Self> {
+        Self::new(fastobo::ast::UnquotedString::new(creator)).into()
+    }
+
+    fn __repr__(&self) -> PyResult<PyObject> {
+        impl_repr!(self, CreatedByClause(self.creator))
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+
+    #[getter]
+    /// `str`: the name of the creator of this instance.
+    fn get_creator(&self) -> &str {
+        self.creator.as_str()
+    }
+
+    #[setter]
+    fn set_creator(&mut self, creator: String) {
+        self.creator = PooledString::new(&creator);
+    }
+}
+
+// --- CreationDate ----------------------------------------------------------
+
+/// CreationDateClause(date, *, naive="keep")
+/// --
+///
+/// A clause declaring the date (and optionally time) an instance was created.
+///
+/// Arguments:
+///     date (`datetime.date` or `str`): The date this instance was created.
+///         If a `datetime.datime` object is given, then the serialized value
+///         will also include the serialized time. If a `str` is given, it
+///         is parsed as an ISO-8601 date or datetime, and kept as written.
+///     naive (`str`): The policy for a naive `datetime.datetime`: either
+///         ``"keep"`` to serialize it without a timezone, ``"utc"`` to
+///         assume it is in UTC, ``"local"`` to assume it is in the local
+///         timezone of the system, or ``"error"`` to raise a `ValueError`.
+///
+/// Warning:
+///     The timezone of the `datetime` will only be extracted down to the
+///     minutes, seconds and smaller durations will be ignored. A timezone
+///     of `datetime.timezone.utc` is serialized as ``Z``, and any other
+///     timezone with its ``±HH:MM`` offset, so that ``+00:00`` is kept
+///     as written in the source document.
+///
+/// Example:
+///     >>> d1 = datetime.date(2021, 1, 23)
+///     >>> print(fastobo.instance.CreationDateClause(d1))
+///     creation_date: 2021-01-23
+///     >>> d2 = datetime.datetime(2021, 1, 23, tzinfo=datetime.timezone.utc)
+///     >>> print(fastobo.instance.CreationDateClause(d2))
+///     creation_date: 2021-01-23T00:00:00Z
+///     >>> print(fastobo.instance.CreationDateClause("2021-01-23T08:00:00+01:00"))
+///     creation_date: 2021-01-23T08:00:00+01:00
+///
+#[pyclass(extends=BaseInstanceClause, module="fastobo.instance")]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
+#[base(BaseInstanceClause)]
+pub struct CreationDateClause {
+    date: fastobo::ast::CreationDate,
+}
+
+impl CreationDateClause {
+    pub fn new(date: fastobo::ast::CreationDate) -> Self {
+        Self { date }
+    }
+}
+
+impl_display_py!(CreationDateClause, date);
+
+impl From<CreationDateClause> for fastobo::ast::InstanceClause {
+    fn from(clause: CreationDateClause) -> fastobo::ast::InstanceClause {
+        fastobo::ast::InstanceClause::CreationDate(Box::new(clause.date))
+    }
+}
+
+impl IntoPy<fastobo::ast::InstanceClause> for CreationDateClause {
+    fn into_py(self, py: Python) -> fastobo::ast::InstanceClause {
+        fastobo::ast::InstanceClause::from(self)
+    }
+}
+
+#[obo_clause(tag = "creation_date")]
+#[pymethods]
+impl CreationDateClause {
+    #[new]
+    #[pyo3(signature = (date, *, naive = "keep"))]
+    fn __init__(date: &PyAny, naive: &str) -> PyResult<PyClassInitializer<Self>> {
+        let policy = NaivePolicy::from_str(naive)?;
+        let date = extract_creation_date(date.py(), date, policy)?;
+        Ok(CreationDateClause::new(date).into())
+    }
+
+    fn __repr__(&self) -> PyResult<PyObject> {
+        Python::with_gil(|py| {
+            let fmt = PyString::new(py, "CreationDateClause({!r})").to_object(py);
+            self.get_date(py)
+                .and_then(|dt| fmt.call_method1(py, "format", (dt,)))
+        })
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+
+    #[getter]
+    /// `datetime.datetime`: the date and time this instance was created.
+    fn get_date<'py>(&self, py: Python<'py>) -> PyResult<PyObject> {
+        use fastobo::ast::CreationDate::*;
+        match &self.date {
+            DateTime(dt) => Ok(isodatetime_to_datetime(py, dt)?.to_object(py)),
+            Date(d) => Ok(isodate_to_date(py, d)?.to_object(py)),
+        }
+    }
+
+    #[setter]
+    fn set_date(&mut self, datetime: &PyAny) -> PyResult<()> {
+        self.date = extract_creation_date(datetime.py(), datetime, NaivePolicy::Keep)?;
+        Ok(())
+    }
+}
@@ -1,9 +1,14 @@
+use std::collections::HashSet;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
 use std::fmt::Write;
+use std::mem::discriminant;
 use std::str::FromStr;
 
+use pyo3::class::basic::CompareOp;
+use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::PyAny;
 use pyo3::types::PyIterator;
@@ -14,58 +19,192 @@ use pyo3::PyTypeInfo;
 
 use fastobo::ast;
 
+use super::super::abc::AbstractEntityClause;
 use super::super::abc::AbstractEntityFrame;
+use super::super::abc::AbstractFrame;
 use super::super::id::Ident;
+use super::clause::InstanceClause;
+use crate::error::Error;
 use crate::utils::AbstractClass;
+use crate::utils::AssignPy;
 use crate::utils::ClonePy;
+use crate::utils::DisplayPy;
 use crate::utils::EqPy;
 use crate::utils::FinalClass;
+use crate::utils::FreezePy;
+use crate::utils::LazyVec;
+use crate::utils::RichCmpPy;
 
 #[pyclass(extends=AbstractEntityFrame, module="fastobo.instance")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, RichCmpPy)]
 #[base(AbstractEntityFrame)]
 pub struct InstanceFrame {
     id: Ident,
-    //clauses: Vec<InstanceClause>,
+    clauses: LazyVec<ast::Line<ast::InstanceClause>, InstanceClause>,
+    #[eqpy(ignore)]
+    frozen: bool,
 }
 
 impl InstanceFrame {
     pub fn new(id: Ident) -> Self {
-        // Self::with_clauses(id, Vec::new())
-        Self { id }
+        Self::with_clauses(id, Vec::new())
     }
 
-    // pub fn with_clauses(id: Ident, clauses: Vec<InstanceClause>) -> Self {
-    //     Self { id, clauses }
-    // }
+    pub fn with_clauses(id: Ident, clauses: Vec<InstanceClause>) -> Self {
+        Self {
+            id,
+            clauses: LazyVec::from(clauses),
+            frozen: false,
+        }
+    }
+
+    /// Get a reference to the identifier of the frame.
+    pub fn id(&self) -> &Ident {
+        &self.id
+    }
+
+    /// Get a reference to the clauses of the frame.
+    pub fn clauses(&self) -> &Vec<InstanceClause> {
+        &self.clauses
+    }
+
+    /// Get a mutable reference to the clauses of the frame.
+    pub fn clauses_mut(&mut self) -> &mut Vec<InstanceClause> {
+        &mut self.clauses
+    }
+
+    /// Convert the frame to an AST frame.
+    ///
+    /// Clauses that have not been converted to Python objects yet are
+    /// copied from the source lines directly.
+    pub fn to_ast(&self, py: Python) -> ast::InstanceFrame {
+        let lines = match self.clauses.source() {
+            Some(lines) => lines.to_vec(),
+            None => self.clauses.iter().map(|c| Self::clause_line(c, py)).collect(),
+        };
+        ast::InstanceFrame::with_clauses(
+            ast::InstanceIdent::new((&self.id).into_py(py)),
+            lines,
+        )
+    }
+
+    /// Get the size of the frame in memory, skipping the objects in `seen`.
+    pub fn deep_sizeof(&self, py: Python, seen: &mut HashSet<usize>) -> PyResult<usize> {
+        Ok(std::mem::size_of::<PyCell<Self>>()
+            + crate::utils::sizeof_shared(&self.id, py, seen)?
+            + crate::utils::sizeof_lazy_vec(&self.clauses, py, seen)?)
+    }
+
+    /// Update the frame in place to match an edited AST frame.
+    ///
+    /// Unchanged clauses are kept as they are, and changed clauses are
+    /// updated in place whenever possible, so that the clause objects
+    /// referenced from Python (and their comments) remain valid.
+    pub fn update(&mut self, py: Python, frame: ast::InstanceFrame) {
+        let id: ast::Ident = frame.id().as_ref().clone().into();
+        if IntoPy::<ast::Ident>::into_py(&self.id, py) != id {
+            self.id = id.into_py(py);
+        }
+        let lines = frame.into_iter().collect::<Vec<_>>();
+        if self.clauses.source().is_some() {
+            self.clauses = LazyVec::new(lines);
+            return;
+        }
+        crate::utils::update_items(
+            &mut self.clauses,
+            lines,
+            |clause| Self::clause_line(clause, py),
+            |a, b| discriminant(a.as_ref()) == discriminant(b.as_ref()),
+            |clause, line| Self::update_clause(clause, line, py),
+            |line| line.into_py(py),
+        );
+    }
+
+    /// Update a clause in place to match an edited AST line.
+    fn update_clause(clause: &InstanceClause, line: &ast::Line<ast::InstanceClause>, py: Python) -> bool {
+        let new: InstanceClause = line.as_ref().clone().into_py(py);
+        if !clause.assign_py(&new, py) {
+            return false;
+        }
+        let qualifiers = AbstractEntityClause::qualifiers_of(clause, py).ok().flatten();
+        qualifiers.as_ref() == line.qualifiers()
+            || AbstractEntityClause::set_qualifiers_of(clause, py, line.qualifiers().cloned()).is_ok()
+    }
+
+    /// Convert a clause to an AST line, with its qualifiers.
+    fn clause_line(clause: &InstanceClause, py: Python) -> ast::Line<ast::InstanceClause> {
+        let qualifiers = AbstractEntityClause::qualifiers_of(clause, py).ok().flatten();
+        let inner: ast::InstanceClause = clause.into_py(py);
+        ast::Line::new().and_inner(inner).and_qualifiers(qualifiers)
+    }
 }
 
 impl ClonePy for InstanceFrame {
     fn clone_py(&self, py: Python) -> Self {
         Self {
             id: self.id.clone_py(py),
-            // clauses: self.clauses.clone_py(py),
+            clauses: self.clauses.clone_py(py),
+            frozen: false,
         }
     }
 }
 
+impl DisplayPy for InstanceFrame {
+    fn fmt_py(&self, py: Python, f: &mut Formatter) -> FmtResult {
+        f.write_str("[Instance]\nid: ")?;
+        self.id.fmt_py(py, f)?;
+        f.write_char('\n')?;
+        if let Some(lines) = self.clauses.source() {
+            for line in lines.iter() {
+                line.as_ref().fmt(f)?;
+                match line.qualifiers() {
+                    Some(q) if !q.is_empty() => write!(f, " {}", q)?,
+                    _ => (),
+                }
+                f.write_char('\n')?;
+            }
+            return Ok(());
+        }
+        for clause in self.clauses.iter() {
+            clause.fmt_py(py, f)?;
+            AbstractEntityClause::fmt_qualifiers_of(clause, py, f)?;
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
 impl Display for InstanceFrame {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let frame: fastobo::ast::InstanceFrame = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        frame.fmt(f)
+        Python::with_gil(|py| self.fmt_py(py, f))
+    }
+}
+
+impl IntoPy<InstanceClause> for fastobo::ast::Line<fastobo::ast::InstanceClause> {
+    fn into_py(self, py: Python) -> InstanceClause {
+        let qualifiers = self.qualifiers().cloned();
+        let clause: InstanceClause = self.into_inner().into_py(py);
+        if qualifiers.is_some() {
+            AbstractEntityClause::set_qualifiers_of(&clause, py, qualifiers)
+                .expect("could not set qualifiers of a new `InstanceClause`");
+        }
+        clause
     }
 }
 
 impl IntoPy<InstanceFrame> for fastobo::ast::InstanceFrame {
     fn into_py(self, py: Python) -> InstanceFrame {
-        let id: Ident = self.id().as_ref().clone().into_py(py);
-        InstanceFrame::new(id)
+        InstanceFrame {
+            id: self.id().as_ref().clone().into_py(py),
+            clauses: LazyVec::new(self.into_iter().collect()),
+            frozen: false,
+        }
     }
 }
 
 impl IntoPy<fastobo::ast::InstanceFrame> for InstanceFrame {
     fn into_py(self, py: Python) -> fastobo::ast::InstanceFrame {
-        fastobo::ast::InstanceFrame::new(fastobo::ast::InstanceIdent::new(self.id.into_py(py)))
+        self.to_ast(py)
     }
 }
 
@@ -75,3 +214,265 @@ impl IntoPy<fastobo::ast::EntityFrame> for InstanceFrame {
         fastobo::ast::EntityFrame::from(frame)
     }
 }
+
+#[listlike(field = "clauses", type = "InstanceClause", frozen = "frozen")]
+#[pymethods]
+impl InstanceFrame {
+    #[new]
+    fn __init__(id: Ident, clauses: Option<&PyAny>) -> PyResult<PyClassInitializer<Self>> {
+        if let Some(clauses) = clauses {
+            match clauses.extract() {
+                Ok(c) => Ok(Self::with_clauses(id, c).into()),
+                Err(_) => Err(PyTypeError::new_err("Expected list of `InstanceClause`")),
+            }
+        } else {
+            Ok(Self::new(id).into())
+        }
+    }
+
+    fn __repr__(&self) -> PyResult<PyObject> {
+        impl_repr!(self, InstanceFrame(self.id))
+    }
+
+    fn __str__(slf: &PyCell<Self>) -> PyResult<String> {
+        AbstractFrame::render(slf)
+    }
+
+    fn __len__(&self) -> PyResult<usize> {
+        match self.clauses.source() {
+            Some(lines) => Ok(lines.len()),
+            None => Ok(self.clauses.len()),
+        }
+    }
+
+    fn __contains__(&self, item: &PyAny) -> PyResult<bool> {
+        let py = item.py();
+        let clause = match InstanceClause::extract(item) {
+            Ok(clause) => clause,
+            Err(_) => return Ok(false),
+        };
+        if let Some(lines) = self.clauses.source() {
+            let clause: ast::InstanceClause = (&clause).into_py(py);
+            return Ok(lines.iter().any(|line| line.as_ref() == &clause));
+        }
+        Ok(self.clauses.iter().any(|c| c.eq_py(&clause, py)))
+    }
+
+    /// Get the size of the frame in memory, in bytes.
+    ///
+    /// The size includes the identifier and the clauses of the frame.
+    fn __sizeof__(&self, py: Python) -> PyResult<usize> {
+        self.deep_sizeof(py, &mut HashSet::new())
+    }
+
+    fn __concat__(&self, other: &PyAny) -> PyResult<Py<Self>> {
+        let py = other.py();
+
+        let mut new_clauses = Vec::new();
+        for item in PyIterator::from_object(py, other)? {
+            new_clauses.push(InstanceClause::extract(item?)?);
+        }
+
+        let clauses = match self.clauses.source() {
+            Some(lines) => {
+                let mut lines = lines.to_vec();
+                lines.extend(new_clauses.iter().map(|c| Self::clause_line(c, py)));
+                LazyVec::new(lines)
+            }
+            None => self.clauses.clone_py(py).into_iter().chain(new_clauses).collect(),
+        };
+
+        Py::new(
+            py,
+            Self {
+                id: self.id.clone_py(py),
+                clauses,
+                frozen: false,
+            },
+        )
+    }
+
+    #[getter]
+    /// `~fastobo.id.Ident`: the identifier of the instance frame.
+    fn get_id(&self) -> PyResult<&Ident> {
+        Ok(&self.id)
+    }
+
+    #[setter]
+    fn set_id(&mut self, id: Ident) -> PyResult<()> {
+        crate::utils::check_mutable(self.frozen, "InstanceFrame")?;
+        self.id = id;
+        Ok(())
+    }
+
+    /// `str` or `None`: the name of the instance, from its first ``name`` clause.
+    #[getter]
+    fn get_name(&self, py: Python) -> Option<String> {
+        if let Some(lines) = self.clauses.source() {
+            return lines.iter().find_map(|line| match line.as_ref() {
+                ast::InstanceClause::Name(name) => Some(name.as_str().to_string()),
+                _ => None,
+            });
+        }
+        self.clauses.iter().find_map(|clause| match clause {
+            InstanceClause::Name(name) => Some(name.borrow(py).name().as_str().to_string()),
+            _ => None,
+        })
+    }
+
+    fn __hash__(&self) -> PyResult<u64> {
+        if self.frozen {
+            Ok(impl_hash!(self.to_string()))
+        } else {
+            Err(PyTypeError::new_err("unhashable type: 'InstanceFrame'"))
+        }
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+
+    /// `bool`: Whether the frame is frozen.
+    #[getter]
+    fn get_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Make the frame read-only.
+    ///
+    /// See `OboDoc.freeze` for the modifications rejected by a frozen frame.
+    ///
+    #[pyo3(text_signature = "(self)")]
+    pub fn freeze(&mut self, py: Python) -> PyResult<()> {
+        self.clauses.freeze_py(py)?;
+        self.frozen = true;
+        Ok(())
+    }
+
+    /// Get all the clauses of the frame with the given raw tag.
+    ///
+    /// Arguments:
+    ///     tag (str): The raw tag of the clauses to retrieve, as returned
+    ///         by the `raw_tag` method of each clause.
+    ///
+    /// Returns:
+    ///     `list`: The clauses with the given tag, in frame order. The
+    ///     clauses are not copied, so they can be edited in place.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.instance.InstanceFrame(
+    ///     ...     fastobo.id.PrefixedIdent("ex", "john"),
+    ///     ...     [
+    ///     ...         fastobo.instance.NameClause("John"),
+    ///     ...         fastobo.instance.InstanceOfClause(fastobo.id.PrefixedIdent("ex", "Person")),
+    ///     ...     ]
+    ///     ... )
+    ///     >>> frame.get("instance_of")
+    ///     [InstanceOfClause(PrefixedIdent('ex', 'Person'))]
+    ///
+    #[pyo3(text_signature = "(self, tag)")]
+    fn get(&self, py: Python, tag: &str) -> Vec<InstanceClause> {
+        self.clauses
+            .iter()
+            .filter(|clause| clause.raw_tag() == tag)
+            .map(|clause| clause.clone_py(py))
+            .collect()
+    }
+
+    /// Remove and return all the clauses of the frame with the given raw tag.
+    ///
+    /// Arguments:
+    ///     tag (str): The raw tag of the clauses to remove, as returned
+    ///         by the `raw_tag` method of each clause.
+    ///
+    /// Returns:
+    ///     `list`: The removed clauses, in frame order.
+    ///
+    #[pyo3(text_signature = "(self, tag)")]
+    fn pop_clauses(&mut self, tag: &str) -> PyResult<Vec<InstanceClause>> {
+        crate::utils::check_mutable(self.frozen, "InstanceFrame")?;
+        let mut popped = Vec::new();
+        for clause in std::mem::take(&mut self.clauses) {
+            if clause.raw_tag() == tag {
+                popped.push(clause);
+            } else {
+                self.clauses.push(clause);
+            }
+        }
+        Ok(popped)
+    }
+
+    /// Get the set of raw tags of the clauses of the frame.
+    #[pyo3(text_signature = "(self)")]
+    fn tags(&self) -> HashSet<&'static str> {
+        self.clauses.iter().map(|clause| clause.raw_tag()).collect()
+    }
+
+    /// Convert the frame to a `dict` of builtin Python types.
+    ///
+    /// Arguments:
+    ///     grouped (`bool`): Whether to group the raw values of the clauses
+    ///         by raw tag in a `dict`, instead of listing the clauses in
+    ///         frame order.
+    ///
+    /// Returns:
+    ///     `dict`: A dictionary with the ``type`` of the frame
+    ///     (``"Instance"``), its ``id`` as a `str`, and its ``clauses`` as a
+    ///     `list` of `dict` with the raw ``tag`` and ``value`` of each clause,
+    ///     or as a `dict` mapping each raw tag to a `list` of raw values if
+    ///     ``grouped`` is `True`. The qualifiers of a clause, if any, are given
+    ///     as a `list` of `dict` with the ``key`` and ``value`` of each
+    ///     qualifier, under the ``qualifiers`` key of the clause `dict`, or of
+    ///     a `dict` with the raw ``value`` replacing the raw value of a grouped
+    ///     clause.
+    ///
+    #[pyo3(signature = (grouped = false), text_signature = "(self, grouped=False)")]
+    fn to_dict(&self, py: Python, grouped: bool) -> PyResult<PyObject> {
+        let frame: ast::InstanceFrame = self.to_ast(py);
+        crate::dict::entity_to_dict(py, ast::EntityFrame::from(frame), grouped)
+    }
+
+    /// Create a new frame from a `dict` created with `InstanceFrame.to_dict`.
+    ///
+    /// Raises:
+    ///     ValueError: When the dictionary does not describe an instance frame.
+    ///     SyntaxError: When the identifier or a clause cannot be parsed.
+    ///
+    #[staticmethod]
+    #[pyo3(text_signature = "(d)")]
+    fn from_dict(py: Python, d: &PyAny) -> PyResult<Py<Self>> {
+        match crate::dict::entity_from_dict(d)? {
+            ast::EntityFrame::Instance(frame) => {
+                let frame: InstanceFrame = frame.into_py(py);
+                Py::new(py, frame)
+            }
+            _ => Err(PyValueError::new_err("expected Instance frame")),
+        }
+    }
+
+    /// Parse a single instance frame from a string.
+    ///
+    /// Arguments:
+    ///     text (str): The serialized frame, starting with a ``[Instance]``
+    ///         frame header.
+    ///
+    /// Raises:
+    ///     SyntaxError: When the text is not a valid instance frame.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.instance.InstanceFrame.loads(
+    ///     ...     "[Instance]\nid: ex:john\ninstance_of: ex:Person\n"
+    ///     ... )
+    ///     >>> frame.id
+    ///     PrefixedIdent('ex', 'john')
+    ///
+    #[staticmethod]
+    #[pyo3(text_signature = "(text)")]
+    fn loads(py: Python, text: &str) -> PyResult<Py<Self>> {
+        let source = format!("{}\n", text.trim());
+        match ast::InstanceFrame::from_str(&source) {
+            Ok(frame) => Py::new(py, IntoPy::<InstanceFrame>::into_py(frame, py)),
+            Err(e) => Err(PyErr::from(Error::from(e))),
+        }
+    }
+}
@@ -1,12 +1,40 @@
 pub mod clause;
 pub mod frame;
 
+use std::str::FromStr;
+
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
+use self::clause::InstanceClause;
+use self::frame::InstanceFrame;
+use crate::error::Error;
+use crate::utils::ParseClause;
+
 #[pymodule]
 #[pyo3(name = "instance")]
 pub fn init(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<self::frame::InstanceFrame>()?;
+    m.add_class::<self::clause::BaseInstanceClause>()?;
+    m.add_class::<self::clause::IsAnonymousClause>()?;
+    m.add_class::<self::clause::NameClause>()?;
+    m.add_class::<self::clause::NamespaceClause>()?;
+    m.add_class::<self::clause::AltIdClause>()?;
+    m.add_class::<self::clause::DefClause>()?;
+    m.add_class::<self::clause::CommentClause>()?;
+    m.add_class::<self::clause::SubsetClause>()?;
+    m.add_class::<self::clause::SynonymClause>()?;
+    m.add_class::<self::clause::XrefClause>()?;
+    m.add_class::<self::clause::PropertyValueClause>()?;
+    m.add_class::<self::clause::InstanceOfClause>()?;
+    m.add_class::<self::clause::RelationshipClause>()?;
+    m.add_class::<self::clause::CreatedByClause>()?;
+    m.add_class::<self::clause::CreationDateClause>()?;
+    m.add_class::<self::clause::IsObsoleteClause>()?;
+    m.add_class::<self::clause::ReplacedByClause>()?;
+    m.add_class::<self::clause::ConsiderClause>()?;
+
+    m.add_function(wrap_pyfunction!(self::parse_clause, m)?)?;
 
     register!(py, m, InstanceFrame, "collections.abc", MutableSequence);
 
@@ -14,3 +42,40 @@ pub fn init(py: Python, m: &PyModule) -> PyResult<()> {
 
     Ok(())
 }
+
+/// Parse a single instance clause from a line of text.
+///
+/// Trailing qualifiers and comments are supported, so that a clause line
+/// can be extracted verbatim from an OBO document.
+///
+/// Arguments:
+///     text (str): The serialized clause, such as ``"instance_of: ex:Person"``.
+///
+/// Returns:
+///     `~fastobo.instance.BaseInstanceClause`: The deserialized clause.
+///
+/// Raises:
+///     SyntaxError: When the text is not a valid instance clause.
+///     ValueError: When the text does not contain exactly one clause.
+///
+/// Example:
+///     >>> fastobo.instance.parse_clause("instance_of: ex:Person")
+///     InstanceOfClause(PrefixedIdent('ex', 'Person'))
+///
+#[pyfunction]
+#[pyo3(text_signature = "(text)")]
+pub fn parse_clause(py: Python, text: &str) -> PyResult<InstanceClause> {
+    let source = format!("[Instance]\nid: x\n{}\n", text.trim_end());
+    let ast = fastobo::ast::InstanceFrame::from_str(&source).map_err(|e| PyErr::from(Error::from(e)))?;
+    let mut frame: InstanceFrame = ast.into_py(py);
+    match frame.clauses().len() {
+        1 => Ok(frame.clauses_mut().pop().unwrap()),
+        n => Err(PyValueError::new_err(format!("expected a single clause, found {}", n))),
+    }
+}
+
+impl ParseClause for InstanceClause {
+    fn parse_clause(py: Python, text: &str) -> PyResult<Self> {
+        self::parse_clause(py, text)
+    }
+}
@@ -5,15 +5,22 @@ use std::convert::TryInto;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::io::BufRead;
 use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::Read;
 use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::str::FromStr;
 use std::string::ToString;
 
 use pyo3::class::gc::PyVisit;
 use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::PyValueError;
 use pyo3::gc::PyTraverseError;
 use pyo3::prelude::*;
 use pyo3::types::PyAny;
@@ -27,20 +34,24 @@ use fastobo::ast as obo;
 use fastobo::parser::Parser;
 use fastobo::visit::VisitMut;
 use fastobo_graphs::model::GraphDocument;
+use fastobo_graphs::model::Meta;
 use fastobo_graphs::FromGraph;
 use fastobo_graphs::IntoGraph;
 use fastobo_owl::IntoOwl;
-use horned_owl::ontology::axiom_mapped::AxiomMappedOntology;
-use horned_functional::AsFunctional;
-use horned_functional::Context;
 
+use crate::compression::Compression;
+use crate::compression::Decoder;
+use crate::compression::Encoder;
+use crate::convert::Format;
 use crate::error::Error;
 use crate::error::GraphError;
-use crate::error::OwlError;
 use crate::iter::FrameReader;
+use crate::iter::GraphReader;
 use crate::iter::InternalParser;
+use crate::iter::check_buffer_size;
+use crate::iter::DEFAULT_BUFFER_SIZE;
 use crate::pyfile::PyFileRead;
-use crate::pyfile::PyFileWrite;
+use crate::pyfile::PyFileBufWrite;
 use crate::raise;
 use crate::utils::ClonePy;
 
@@ -52,14 +63,20 @@ pub mod header;
 pub mod id;
 pub mod instance;
 pub mod pv;
+pub mod qual;
 pub mod syn;
 pub mod term;
 pub mod typedef;
+pub mod visit;
 pub mod xref;
 pub mod exceptions;
 
 use self::doc::EntityFrame;
+use self::doc::FrameDiff;
 use self::doc::OboDoc;
+use self::header::clause::HeaderClause;
+use self::header::frame::HeaderFrame;
+use self::id::InternGuard;
 use super::built;
 
 // --- Module export ---------------------------------------------------------
@@ -74,11 +91,29 @@ use super::built;
     ///     fh (str or file-handle): The path to an OBO file, or a **binary**
     ///         stream that contains a serialized OBO document. *A binary
     ///         stream needs a* ``read(x)`` *method returning* ``x`` *bytes*.
+    ///         Text streams are decoded by Python and accepted as well.
     ///     ordered (bool): Whether or not to yield the frames in the same
     ///         order they are declared in the source document.
     ///     threads (int): The number of threads to use for parsing. Set to
     ///         **0** to detect the number of logical cores, **1** to use the
     ///         single threadeded parser, or to any positive integer value.
+    ///     prefixes (`collections.abc.Iterable` of `str`, optional): The
+    ///         identifier prefixes of the entity frames to yield. Frames
+    ///         with an identifier using another prefix are skipped before
+    ///         being parsed. Use an empty string to keep the frames with
+    ///         an unprefixed identifier. If `None` given, all frames are
+    ///         yielded.
+    ///     tags (`collections.abc.Iterable` of `str`, optional): The tags
+    ///         of the clauses to keep in the entity frames, such as ``name``
+    ///         or ``is_a``. Other clauses are dropped before being converted
+    ///         to Python objects. If `None` given, all clauses are kept.
+    ///     buffer_size (int): The size of the buffer used to read the
+    ///         document, in bytes. Increase to reduce the number of reads
+    ///         from slow streams, or decrease to limit memory usage.
+    ///     queue_size (int, optional): The number of frames to parse ahead
+    ///         of the iteration in a background thread, so that parsing a
+    ///         slow stream overlaps with processing the frames. If `None`
+    ///         given, frames are parsed on demand while iterating.
     ///
     /// Yields:
     ///     `~fastobo.abc.AbstractFrame`: The individual frames contained
@@ -101,36 +136,142 @@ use super::built;
     ///     >>> list(reader)
     ///     [TermFrame(PrefixedIdent('MS', '1000001')), ...]
     ///
+    ///     Use the ``prefixes`` argument to extract the frames of a
+    ///     single ontology from a document merging several ontologies:
+    ///
+    ///     >>> reader = fastobo.iter('ms.obo', prefixes=["PEFF"])
+    ///     >>> next(reader)
+    ///     TermFrame(PrefixedIdent('PEFF', '0000001'))
+    ///
+    /// Caution:
+    ///     When ``prefixes`` is given, the line numbers reported in syntax
+    ///     errors are relative to the frames that were not skipped.
+    ///
     #[pyfunction]
-    #[pyo3(name = "iter", text_signature = "(fh, ordered=True, threads=0)", signature = (fh, ordered=true, threads=0))]
-    fn iter(py: Python, fh: &PyAny, ordered: bool, threads: i16) -> PyResult<FrameReader> {
+    #[pyo3(
+        name = "iter",
+        text_signature = "(fh, ordered=True, threads=0, prefixes=None, tags=None, buffer_size=8192, queue_size=None)",
+        signature = (fh, ordered=true, threads=0, prefixes=None, tags=None, buffer_size=DEFAULT_BUFFER_SIZE, queue_size=None)
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn iter(
+        py: Python,
+        fh: &PyAny,
+        ordered: bool,
+        threads: i16,
+        prefixes: Option<&PyAny>,
+        tags: Option<&PyAny>,
+        buffer_size: usize,
+        queue_size: Option<usize>,
+    ) -> PyResult<FrameReader> {
+        let prefixes = prefixes.map(crate::utils::str_set).transpose()?;
+        let tags = tags.map(crate::utils::str_set).transpose()?;
         if let Ok(s) = fh.downcast::<PyString>() {
             let path = s.to_str()?;
-            FrameReader::from_path(path, ordered, threads)
+            FrameReader::from_path(
+                path,
+                ordered,
+                threads,
+                prefixes,
+                tags,
+                buffer_size,
+                queue_size,
+            )
         } else {
-            match FrameReader::from_handle(fh, ordered, threads) {
+            match FrameReader::from_handle(
+                fh,
+                ordered,
+                threads,
+                prefixes,
+                tags,
+                buffer_size,
+                queue_size,
+            ) {
                 Ok(r) => Ok(r),
                 Err(inner) if inner.is_instance_of::<pyo3::exceptions::PySyntaxError>(py) => {
                     Err(inner)
                 }
                 Err(inner) => {
-                    raise!(py, PyTypeError("expected path or binary file handle") from inner);
+                    raise!(py, PyTypeError("expected path or file handle") from inner);
                 }
             }
         }
     }
 
+    /// The options used to load an OBO document with `load_with`.
+    ///
+    /// The default options are the default arguments of `fastobo.load`.
+    #[derive(Clone, Debug)]
+    pub struct LoadOptions {
+        pub ordered: bool,
+        pub threads: i16,
+        pub preserve_comments: bool,
+        pub locations: bool,
+        pub intern_ids: bool,
+        pub string_pool: bool,
+        pub tags: Option<HashSet<String>>,
+        pub buffer_size: usize,
+    }
+
+    impl Default for LoadOptions {
+        fn default() -> Self {
+            Self {
+                ordered: true,
+                threads: 0,
+                preserve_comments: false,
+                locations: false,
+                intern_ids: false,
+                string_pool: false,
+                tags: None,
+                buffer_size: DEFAULT_BUFFER_SIZE,
+            }
+        }
+    }
+
     /// Load an OBO document from the given path or file handle.
     ///
     /// Arguments:
     ///     fh (str or file-handle): The path to an OBO file, or a **binary**
     ///         stream that contains a serialized OBO document. *A binary
     ///         stream needs a* ``read(x)`` *method returning* ``x`` *bytes*.
+    ///         Text streams are decoded by Python and accepted as well.
     ///     ordered (bool): Whether or not to yield the frames in the same
     ///         order they are declared in the source document.
     ///     threads (int): The number of threads to use for parsing. Set to
     ///         **0** to detect the number of logical cores, **1** to use the
     ///         single threadeded parser, or to any positive integer value.
+    ///     preserve_comments (bool): Whether or not to attach the comment
+    ///         lines of the document to the following frame or clause (or
+    ///         to the `~fastobo.doc.OboDoc.footer` at the end of the file),
+    ///         so that they are written back on serialization. This requires
+    ///         the frames to be parsed in order.
+    ///     locations (bool): Whether or not to record the location of each
+    ///         frame and the line of each clause, available through the
+    ///         ``location`` and ``line`` properties. This requires the frames
+    ///         to be parsed in order.
+    ///     intern_ids (bool): Whether or not to share a single object between
+    ///         all the occurrences of an identifier, which reduces memory
+    ///         usage on large documents. Editing an interned identifier in
+    ///         place affects all of its occurrences in the document.
+    ///     string_pool (bool): Whether or not to share a single object between
+    ///         all the occurrences of a namespace, a subset or a synonym type,
+    ///         and a single string between all the occurrences of a
+    ///         ``created_by`` value, which reduces memory usage on large
+    ///         documents. Editing a pooled identifier in place affects all
+    ///         of its occurrences in the document.
+    ///     tags (`collections.abc.Iterable` of `str`, optional): The tags
+    ///         of the clauses to keep in the entity frames, such as ``name``
+    ///         or ``is_a``. Other clauses are dropped before being converted
+    ///         to Python objects. If `None` given, all clauses are kept.
+    ///         Cannot be combined with ``preserve_comments`` or ``locations``.
+    ///     buffer_size (int): The size of the buffer used to read the
+    ///         document, in bytes. Increase to reduce the number of reads
+    ///         from slow streams, or decrease to limit memory usage.
+    ///     resolve_imports (bool): Whether or not to merge the documents
+    ///         referenced by ``import`` clauses, as `fastobo.resolve_imports`
+    ///         does. Imported documents are loaded with the same options,
+    ///         and relative paths are resolved against the path of the
+    ///         importing document.
     ///
     /// Returns:
     ///     `~fastobo.doc.OboDoc`: The OBO document deserialized into an
@@ -152,14 +293,75 @@ use super::built;
     ///     SubsetdefClause(UnprefixedIdent('Angiosperm'), 'Term for angiosperms')
     ///
     #[pyfunction]
-    #[pyo3(name = "load", text_signature = "(fh, ordered=True, threads=0)", signature=(fh, ordered=true, threads=0))]
-    fn load(py: Python, fh: &PyAny, ordered: bool, threads: i16) -> PyResult<OboDoc> {
+    #[pyo3(
+        name = "load",
+        text_signature = "(fh, ordered=True, threads=0, preserve_comments=False, locations=False, intern_ids=False, string_pool=False, tags=None, buffer_size=8192, resolve_imports=False)",
+        signature = (fh, ordered=true, threads=0, preserve_comments=false, locations=false, intern_ids=false, string_pool=false, tags=None, buffer_size=DEFAULT_BUFFER_SIZE, resolve_imports=false)
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn load(
+        py: Python,
+        fh: &PyAny,
+        ordered: bool,
+        threads: i16,
+        preserve_comments: bool,
+        locations: bool,
+        intern_ids: bool,
+        string_pool: bool,
+        tags: Option<&PyAny>,
+        buffer_size: usize,
+        resolve_imports: bool,
+    ) -> PyResult<OboDoc> {
+        let tags = tags.map(crate::utils::str_set).transpose()?;
+        let options = LoadOptions {
+            ordered,
+            threads,
+            preserve_comments,
+            locations,
+            intern_ids,
+            string_pool,
+            tags,
+            buffer_size,
+        };
+        let doc = load_with(py, fh, &options)?;
+        if !resolve_imports {
+            return Ok(doc);
+        }
+        // resolve relative imports against the path of the document, if any
+        let path = match fh.downcast::<PyString>() {
+            Ok(s) => Some(s.to_str()?),
+            Err(_) => fh.getattr("name").and_then(|n| n.extract::<&str>()).ok(),
+        };
+        resolve_root_imports(py, &doc, None, &options, path.map(Path::new))
+    }
+
+    /// Load an OBO document from a path or a file handle with the given options.
+    pub fn load_with(py: Python, fh: &PyAny, options: &LoadOptions) -> PyResult<OboDoc> {
+        let LoadOptions {
+            ordered,
+            threads,
+            preserve_comments,
+            locations,
+            intern_ids,
+            string_pool,
+            ref tags,
+            buffer_size,
+        } = *options;
+        let capacity = check_buffer_size(buffer_size)?;
+
+        // comments and locations are attached to clauses by position
+        if tags.is_some() && (preserve_comments || locations) {
+            return Err(PyValueError::new_err(
+                "cannot use `tags` with `preserve_comments` or `locations`",
+            ));
+        }
+
         // extract either a path or a file-handle from the arguments
         let path: Option<String>;
-        let boxed: Box<dyn BufRead> = if let Ok(s) = fh.downcast::<PyString>() {
+        let mut boxed: Box<dyn BufRead> = if let Ok(s) = fh.downcast::<PyString>() {
             // get a buffered reader to the resources pointed by `path`
             let bf = match std::fs::File::open(s.to_str()?) {
-                Ok(f) => std::io::BufReader::new(f),
+                Ok(f) => std::io::BufReader::with_capacity(capacity, f),
                 Err(e) => return Err(PyErr::from(Error::from(e))),
             };
             // store the path for later
@@ -171,11 +373,11 @@ use super::built;
             let bf = match PyFileRead::from_ref(fh) {
                 // Object is a binary file-handle: attempt to parse the
                 // document and return an `OboDoc` object.
-                Ok(f) => std::io::BufReader::new(f),
+                Ok(f) => std::io::BufReader::with_capacity(capacity, f),
                 // Object is not a binary file-handle: wrap the inner error
                 // into a `TypeError` and raise that error.
                 Err(e) => {
-                    raise!(py, PyTypeError("expected path or binary file handle") from e)
+                    raise!(py, PyTypeError("expected path or file handle") from e)
                 }
             };
             // extract the path from the `name` attribute
@@ -189,9 +391,24 @@ use super::built;
             Box::new(bf)
         };
 
+        // extract the comment lines and locations from the whole document if needed
+        let mut comments = None;
+        let mut lines = None;
+        if preserve_comments || locations {
+            let mut text = String::new();
+            boxed.read_to_string(&mut text)?;
+            comments = preserve_comments.then(|| crate::comments::scan(&text));
+            lines = locations.then(|| crate::location::scan(&text));
+            boxed = Box::new(std::io::Cursor::new(crate::comments::blank(&text).into_owned()));
+        }
+
         // create the reader and set the `ordered` flag
         let mut reader = InternalParser::with_thread_count(boxed, threads)?;
-        reader.ordered(ordered);
+        reader.ordered(ordered || preserve_comments || locations);
+
+        // share identical identifiers while converting the frames if needed
+        let _interning = intern_ids.then(InternGuard::idents);
+        let _pooling = string_pool.then(InternGuard::strings);
 
         // read the header and check it did not error
         let header = match reader.next().unwrap() {
@@ -206,12 +423,29 @@ use super::built;
         // read the rest while transforming it to Python
         let frames = reader
             .map(|res| res.map(|frame| frame.into_entity().unwrap()))
+            .map(|res| {
+                res.map(|mut entity| {
+                    if let Some(tags) = tags {
+                        crate::transform::retain_tags(&mut entity, tags);
+                    }
+                    entity
+                })
+            })
             .map(|res| res.map(|entity| entity.into_py(py)))
             .collect::<fastobo::error::Result<Vec<EntityFrame>>>();
 
         // propagate the Python error if any error occurred
         match frames {
-            Ok(entities) => Ok(OboDoc::with_entities(Py::new(py, header)?, entities)),
+            Ok(entities) => {
+                let mut doc = OboDoc::with_entities(Py::new(py, header)?, entities);
+                if let Some(c) = comments {
+                    doc.attach_comments(py, c)?;
+                }
+                if let Some(l) = lines {
+                    doc.attach_locations(py, path.as_deref(), l)?;
+                }
+                Ok(doc)
+            }
             Err(e) if PyErr::occurred(py) => Err(PyErr::fetch(py)),
             Err(e) => match &path {
                 Some(p) => Err(Error::from(e).with_path(p).into()),
@@ -229,6 +463,25 @@ use super::built;
     ///     threads (int): The number of threads to use for parsing. Set to
     ///         **0** to detect the number of logical cores, **1** to use the
     ///         single threadeded parser, or to any positive integer value.
+    ///     preserve_comments (bool): Whether or not to attach the comment
+    ///         lines of the document to the following frame or clause (or
+    ///         to the `~fastobo.doc.OboDoc.footer` at the end of the file),
+    ///         so that they are written back on serialization. This requires
+    ///         the frames to be parsed in order.
+    ///     locations (bool): Whether or not to record the location of each
+    ///         frame and the line of each clause, available through the
+    ///         ``location`` and ``line`` properties. This requires the frames
+    ///         to be parsed in order.
+    ///     intern_ids (bool): Whether or not to share a single object between
+    ///         all the occurrences of an identifier, which reduces memory
+    ///         usage on large documents. Editing an interned identifier in
+    ///         place affects all of its occurrences in the document.
+    ///     string_pool (bool): Whether or not to share a single object between
+    ///         all the occurrences of a namespace, a subset or a synonym type,
+    ///         and a single string between all the occurrences of a
+    ///         ``created_by`` value, which reduces memory usage on large
+    ///         documents. Editing a pooled identifier in place affects all
+    ///         of its occurrences in the document.
     ///
     /// Returns:
     ///     `~fastobo.doc.OboDoc`: The OBO document deserialized into an
@@ -254,19 +507,261 @@ use super::built;
     ///     >>> doc[0][0]
     ///     NameClause('test item')
     ///
+    ///     Use ``preserve_comments=True`` to keep the comment lines of the
+    ///     document when editing it:
+    ///
+    ///     >>> doc = fastobo.loads(textwrap.dedent(
+    ///     ...     """
+    ///     ...     [Term]
+    ///     ...     id: TST:001
+    ///     ...     ! to be reviewed
+    ///     ...     name: test item
+    ///     ...     """
+    ///     ... ), preserve_comments=True)
+    ///     >>> doc[0][0].comments
+    ///     ['to be reviewed']
+    ///     >>> print(doc)
+    ///     [Term]
+    ///     id: TST:001
+    ///     ! to be reviewed
+    ///     name: test item
+    ///     <BLANKLINE>
+    ///
     #[pyfunction]
-    #[pyo3(name = "loads", text_signature = "(document, ordered=True, threads=0)", signature=(document, ordered=true, threads=0))]
-    fn loads(py: Python, document: &PyString, ordered: bool, threads: i16) -> PyResult<OboDoc> {
-        let cursor = std::io::Cursor::new(document.to_str()?);
+    #[pyo3(
+        name = "loads",
+        text_signature = "(document, ordered=True, threads=0, preserve_comments=False, locations=False, intern_ids=False, string_pool=False)",
+        signature = (document, ordered=true, threads=0, preserve_comments=false, locations=false, intern_ids=false, string_pool=false)
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn loads(
+        py: Python,
+        document: &PyString,
+        ordered: bool,
+        threads: i16,
+        preserve_comments: bool,
+        locations: bool,
+        intern_ids: bool,
+        string_pool: bool,
+    ) -> PyResult<OboDoc> {
+        let text = document.to_str()?;
+        let blanked = crate::comments::blank(text);
+        let cursor = std::io::Cursor::new(blanked.as_ref());
         let mut reader = InternalParser::with_thread_count(cursor, threads)?;
-        reader.ordered(ordered);
+        reader.ordered(ordered || preserve_comments || locations);
         // match py.allow_threads(|| reader.try_into_doc()) {
         match reader.try_into_doc() {
-            Ok(doc) => Ok(doc.into_py(py)),
+            Ok(ast) => {
+                let _interning = intern_ids.then(InternGuard::idents);
+                let _pooling = string_pool.then(InternGuard::strings);
+                let mut doc: OboDoc = ast.into_py(py);
+                if preserve_comments {
+                    doc.attach_comments(py, crate::comments::scan(text))?;
+                }
+                if locations {
+                    doc.attach_locations(py, None, crate::location::scan(text))?;
+                }
+                Ok(doc)
+            }
             Err(e) => Error::from(e).into(),
         }
     }
 
+    /// Resolve the imports of an OBO document.
+    ///
+    /// Every document referenced by an ``import`` clause in the header is
+    /// loaded, has its own imports resolved recursively, and is merged into
+    /// a copy of ``doc``: frames with a new identifier are appended to the
+    /// document, while frames with an identifier already declared have their
+    /// clauses merged into the existing frame. The resolved ``import``
+    /// clauses are removed from the header of the returned document. Each
+    /// document is imported at most once, and imports referring back to
+    /// the ``ontology`` of ``doc`` itself are ignored.
+    ///
+    /// Arguments:
+    ///     doc (`~fastobo.doc.OboDoc`): The OBO document to resolve the
+    ///         imports of.
+    ///     loader (callable, optional): A callable taking the reference of
+    ///         an ``import`` clause as a `str`, and returning the imported
+    ///         document as an `~fastobo.doc.OboDoc`. If `None` given, only
+    ///         local paths and ``file://`` URLs can be resolved. Pass
+    ///         `~fastobo.load_url` to download remote imports.
+    ///     path (str, optional): The path to the file ``doc`` was loaded
+    ///         from, used to resolve relative ``import`` paths. If `None`
+    ///         given, relative paths are resolved against the current
+    ///         working directory.
+    ///
+    /// Returns:
+    ///     `~fastobo.doc.OboDoc`: The OBO document with all of its imports
+    ///     merged in.
+    ///
+    /// Raises:
+    ///     TypeError: When ``loader`` does not return an `~fastobo.doc.OboDoc`.
+    ///     ValueError: When a remote import is found and no ``loader`` was
+    ///         given.
+    ///     SyntaxError: When an imported document is not in valid OBO syntax.
+    ///     OSError: When an underlying OS error occurs.
+    ///
+    /// Example:
+    ///     Use a custom loader to resolve imports from memory, or to fetch
+    ///     them from a remote location with `~fastobo.load_url`:
+    ///
+    ///     >>> imported = fastobo.loads("[Term]\nid: TST:001\n")
+    ///     >>> doc = fastobo.loads("import: tst\n\n[Term]\nid: TST:002\n")
+    ///     >>> resolved = fastobo.resolve_imports(doc, {"tst": imported}.get)
+    ///     >>> [frame.id for frame in resolved]
+    ///     [PrefixedIdent('TST', '002'), PrefixedIdent('TST', '001')]
+    ///     >>> len(resolved.header)
+    ///     0
+    ///
+    #[pyfunction]
+    #[pyo3(
+        name = "resolve_imports",
+        text_signature = "(doc, loader=None, path=None)",
+        signature = (doc, loader=None, path=None)
+    )]
+    fn resolve_imports(
+        py: Python,
+        doc: &OboDoc,
+        loader: Option<&PyAny>,
+        path: Option<&str>,
+    ) -> PyResult<OboDoc> {
+        resolve_root_imports(py, doc, loader, &LoadOptions::default(), path.map(Path::new))
+    }
+
+    /// Resolve the imports of `doc`, loading local imports with `options`.
+    ///
+    /// `path` is the path to the file `doc` was loaded from, if any.
+    fn resolve_root_imports(
+        py: Python,
+        doc: &OboDoc,
+        loader: Option<&PyAny>,
+        options: &LoadOptions,
+        path: Option<&Path>,
+    ) -> PyResult<OboDoc> {
+        // mark the root document as seen, using either its ontology ID or
+        // its OBO Library PURL, so that an import cycle leading back to it
+        // does not load it a second time
+        let mut seen = HashSet::new();
+        for clause in doc.header().borrow(py).clauses() {
+            if let HeaderClause::Ontology(ontology) = clause {
+                let id = ontology.borrow(py).ontology().as_str().to_string();
+                seen.insert(format!("http://purl.obolibrary.org/obo/{}.obo", id));
+                seen.insert(id);
+            }
+        }
+        resolve_imports_with(py, doc, loader, options, path, &mut seen)
+    }
+
+    /// Resolve the imports of `doc`, ignoring the references in `seen`.
+    fn resolve_imports_with(
+        py: Python,
+        doc: &OboDoc,
+        loader: Option<&PyAny>,
+        options: &LoadOptions,
+        path: Option<&Path>,
+        seen: &mut HashSet<String>,
+    ) -> PyResult<OboDoc> {
+        // split import clauses from the rest of the header
+        let mut references = Vec::new();
+        let mut clauses = Vec::new();
+        for clause in doc.header().borrow(py).clauses() {
+            match clause {
+                HeaderClause::Import(import) => {
+                    references.push(import.borrow(py).reference().to_string())
+                }
+                other => clauses.push(other.clone_py(py)),
+            }
+        }
+
+        // create a new document with the remaining header clauses
+        let header = Py::new(py, HeaderFrame::new(clauses))?;
+        let mut resolved = OboDoc::with_entities(header, doc.entities().clone_py(py));
+
+        // load every imported document and merge it into the new document,
+        // making sure each document is only imported once to avoid cycles
+        for reference in references {
+            if seen.insert(reference.clone()) {
+                let (imported, imported_path) = match loader {
+                    Some(f) => {
+                        let imported = f.call1((reference.as_str(),))?.extract::<PyRef<OboDoc>>()?;
+                        (imported.clone_py(py), None)
+                    }
+                    None => {
+                        let (imported, imported_path) = load_import(py, &reference, options, path)?;
+                        (imported, Some(imported_path))
+                    }
+                };
+                let imported_path = imported_path.as_deref();
+                let imported = resolve_imports_with(py, &imported, loader, options, imported_path, seen)?;
+                resolved.merge_entities(py, &imported)?;
+            }
+        }
+
+        Ok(resolved)
+    }
+
+    /// Load an imported document from a local path or a ``file://`` URL.
+    ///
+    /// Relative paths are resolved against the directory of `path`, the
+    /// path to the importing document. The path to the imported document
+    /// is returned along with the document.
+    fn load_import(
+        py: Python,
+        reference: &str,
+        options: &LoadOptions,
+        path: Option<&Path>,
+    ) -> PyResult<(OboDoc, PathBuf)> {
+        let target = match reference.strip_prefix("file://") {
+            Some(target) => target,
+            None if reference.contains("://") => {
+                let msg = format!("cannot resolve remote import without a loader: {}", reference);
+                return Err(PyValueError::new_err(msg));
+            }
+            None => reference,
+        };
+        // joining an absolute path replaces the directory entirely
+        let target = match path.and_then(Path::parent) {
+            Some(dir) => dir.join(target),
+            None => PathBuf::from(target),
+        };
+        let doc = load_with(py, PyString::new(py, &target.to_string_lossy()), options)?;
+        Ok((doc, target))
+    }
+
+    /// Compute the differences between two OBO documents.
+    ///
+    /// Frames are matched between both documents using their identifier,
+    /// and compared clause by clause. Frames with no changes are not
+    /// reported.
+    ///
+    /// Arguments:
+    ///     old (`~fastobo.doc.OboDoc`): The old version of the document.
+    ///     new (`~fastobo.doc.OboDoc`): The new version of the document.
+    ///
+    /// Returns:
+    ///     `list` of `~fastobo.doc.FrameDiff`: The changes made to each frame
+    ///     of the document, starting with the header frame if it changed.
+    ///
+    /// Example:
+    ///     >>> old = fastobo.loads("[Term]\nid: TST:001\nname: old name\n")
+    ///     >>> new = fastobo.loads(
+    ///     ...     "[Term]\nid: TST:001\nname: new name\n\n[Term]\nid: TST:002\n"
+    ///     ... )
+    ///     >>> changes = fastobo.diff(old, new)
+    ///     >>> changes
+    ///     [FrameDiff(PrefixedIdent('TST', '001'), 'modified'), FrameDiff(PrefixedIdent('TST', '002'), 'added')]
+    ///     >>> changes[0].modified
+    ///     [(NameClause('old name'), NameClause('new name'))]
+    ///
+    #[pyfunction]
+    #[pyo3(name = "diff", text_signature = "(old, new)")]
+    fn diff(py: Python, old: &OboDoc, new: &OboDoc) -> PyResult<Vec<FrameDiff>> {
+        let old: obo::OboDoc = old.to_ast(py);
+        let new: obo::OboDoc = new.to_ast(py);
+        Ok(FrameDiff::compute(py, old, new))
+    }
+
     /// Load an OBO graph from the given path or file handle.
     ///
     /// Both JSON and YAML formats are supported. *Actually, since YAML is a
@@ -276,7 +771,11 @@ use super::built;
     ///     fh (str or file-handle): The path to an OBO graph file, or a
     ///         **binary** stream that contains a serialized OBO document.
     ///         *A binary stream needs a* ``read(x)`` *method returning*
-    ///         ``x`` *bytes*.
+    ///         ``x`` *bytes*. Text streams are accepted as well.
+    ///     compression (`str`, optional): The compression of the file,
+    ///         either ``"gzip"``, or ``"infer"`` to detect gzip compressed
+    ///         files from their magic bytes. Pass `None` to read the file
+    ///         as-is.
     ///
     /// Returns:
     ///     `~fastobo.doc.OboDoc`: The first graph of the OBO graph
@@ -303,23 +802,32 @@ use super::built;
     ///     TermFrame(PrefixedIdent('PATO', '0000000'))
     ///
     #[pyfunction]
-    #[pyo3(name = "load_graph", text_signature = "(fh)")]
-    fn load_graph(py: Python, fh: &PyAny) -> PyResult<OboDoc> {
+    #[pyo3(
+        name = "load_graph",
+        signature = (fh, compression = "infer"),
+        text_signature = "(fh, compression=\"infer\")"
+    )]
+    fn load_graph(py: Python, fh: &PyAny, compression: Option<&str>) -> PyResult<OboDoc> {
+        let compression = Compression::from_arg(compression).map_err(PyValueError::new_err)?;
         let doc: GraphDocument = if let Ok(s) = fh.downcast::<PyString>() {
             // Argument is a string, assumed to be a path: open the file.
             // and extract the graph
-            let path = s.to_str()?;
-            // py.allow_threads(|| fastobo_graphs::from_file(path))
-            fastobo_graphs::from_file(path)
+            let file = std::fs::File::open(s.to_str()?)?;
+            let reader = Decoder::new(BufReader::new(file), compression)?;
+            // py.allow_threads(|| fastobo_graphs::from_reader(reader))
+            fastobo_graphs::from_reader(reader)
                 .map_err(|e| PyErr::from(GraphError::from(e)))?
         } else {
             // Argument is not a string, check if it is a file-handle.
-            let mut f = match PyFileRead::from_ref(fh) {
+            let f = match PyFileRead::from_ref(fh) {
                 Ok(f) => f,
-                Err(e) => raise!(py, PyTypeError("expected path or binary file handle") from e),
+                Err(e) => raise!(py, PyTypeError("expected path or file handle") from e),
             };
             // Extract the graph
-            match fastobo_graphs::from_reader(&mut f) {
+            let result = Decoder::new(BufReader::new(f), compression)
+                .map_err(fastobo_graphs::error::Error::from)
+                .and_then(fastobo_graphs::from_reader);
+            match result {
                 Ok(doc) => doc,
                 Err(e) if PyErr::occurred(py) => return Err(PyErr::fetch(py)),
                 Err(e) => return Err(GraphError::from(e).into()),
@@ -327,13 +835,76 @@ use super::built;
         };
 
         // Convert the graph to an OBO document
-        let graph = doc.graphs.into_iter().next().unwrap();
+        let mut graph = doc.graphs.into_iter().next().unwrap();
+        let meta = graph.meta.clone();
+        let nodes = crate::graph::collect_node_meta(&mut graph);
         // let doc = py.allow_threads(|| obo::OboDoc::from_graph(graph))
-        let doc = obo::OboDoc::from_graph(graph)
+        let mut doc = obo::OboDoc::from_graph(graph)
             .map_err(GraphError::from)?;
+        crate::graph::add_doc_clauses(&mut doc, &nodes);
 
-        // Convert the OBO document to a Python `OboDoc` class
-        Ok(doc.into_py(py))
+        // Convert the OBO document to a Python `OboDoc` class, keeping
+        // the graph metadata to restore it in `dump_graph`.
+        let mut doc: OboDoc = doc.into_py(py);
+        doc.replace_graph_meta(meta);
+        Ok(doc)
+    }
+
+    /// Iterate over the entity frames contained in an OBO graph.
+    ///
+    /// Contrary to `~fastobo.load_graph`, the graph is never loaded
+    /// entirely in memory: the nodes of the graph are read one at a time
+    /// in a background thread, and converted to entity frames as they
+    /// are yielded.
+    ///
+    /// Arguments:
+    ///     fh (str or file-handle): The path to an OBO graph file, or a
+    ///         **binary** stream that contains a serialized OBO graph.
+    ///         *A binary stream needs a* ``read(x)`` *method returning*
+    ///         ``x`` *bytes*. Text streams are accepted as well.
+    ///     compression (`str`, optional): The compression of the file,
+    ///         either ``"gzip"``, or ``"infer"`` to detect gzip compressed
+    ///         files from their magic bytes. Pass `None` to read the file
+    ///         as-is.
+    ///
+    /// Yields:
+    ///     `~fastobo.abc.AbstractEntityFrame`: The entity frames converted
+    ///     from the nodes of the graph, in file order.
+    ///
+    /// Raises:
+    ///     TypeError: When the argument is not a `str` or a binary stream.
+    ///     ValueError: When the file is not a valid OBO graph in JSON format.
+    ///     OSError: When an underlying OS error occurs.
+    ///
+    /// Caution:
+    ///     Only the nodes of the graph are converted, so clauses derived
+    ///     from the edges and axioms of the graph, such as ``is_a`` or
+    ///     ``relationship`` clauses, are missing from the yielded frames.
+    ///     Unlike `~fastobo.load_graph`, only the JSON format is supported.
+    ///
+    /// Example:
+    ///     >>> reader = fastobo.iter_graph("pato.json")
+    ///     >>> next(reader)
+    ///     TermFrame(PrefixedIdent(...))
+    ///
+    #[pyfunction]
+    #[pyo3(
+        name = "iter_graph",
+        signature = (fh, compression = "infer"),
+        text_signature = "(fh, compression=\"infer\")"
+    )]
+    fn iter_graph(py: Python, fh: &PyAny, compression: Option<&str>) -> PyResult<GraphReader> {
+        let compression = Compression::from_arg(compression).map_err(PyValueError::new_err)?;
+        if let Ok(s) = fh.downcast::<PyString>() {
+            GraphReader::from_path(s.to_str()?, compression)
+        } else {
+            match GraphReader::from_handle(fh, compression) {
+                Ok(r) => Ok(r),
+                Err(inner) => {
+                    raise!(py, PyTypeError("expected path or file handle") from inner);
+                }
+            }
+        }
     }
 
     /// Dump an OBO graph into the given writer or file handle, serialized
@@ -343,9 +914,16 @@ use super::built;
     ///     fh (str or file-handle): The path to a file, or a writable
     ///         **binary** stream to write the serialized graph into.
     ///         *A binary stream needs a* ``write(b)`` *method that accepts
-    ///         binary strings*.
+    ///         binary strings*. Text streams are accepted as well.
     ///     doc (`~fastobo.doc.OboDoc`): The OBO document to be converted
     ///         into an OBO Graph.
+    ///     compression (`str`, optional): The compression of the file,
+    ///         either ``"gzip"``, or ``"infer"`` to compress the file only
+    ///         when given a path ending with ``.gz``. Pass `None` to write
+    ///         the file uncompressed.
+    ///     buffer_size (int): The size of the buffer used to write the
+    ///         document, in bytes. The buffer is always flushed, along with
+    ///         the file-handle, before the function returns.
     ///
     /// Raises:
     ///     TypeError: When the argument have invalid types.
@@ -359,54 +937,514 @@ use super::built;
     ///     >>> fastobo.dump_graph(doc, "plana.json")
     ///
     #[pyfunction]
-    #[pyo3(name = "dump_graph", text_signature = "(doc, fh)")]
-    fn dump_graph(py: Python, obj: &OboDoc, fh: &PyAny) -> PyResult<()> {
+    #[pyo3(
+        name = "dump_graph",
+        signature = (obj, fh, compression = "infer", buffer_size = DEFAULT_BUFFER_SIZE),
+        text_signature = "(doc, fh, compression=\"infer\", buffer_size=8192)"
+    )]
+    fn dump_graph(
+        py: Python,
+        obj: &OboDoc,
+        fh: &PyAny,
+        compression: Option<&str>,
+        buffer_size: usize,
+    ) -> PyResult<()> {
+        let compression = Compression::from_arg(compression).map_err(PyValueError::new_err)?;
+        let capacity = check_buffer_size(buffer_size)?;
+
         // Convert OBO document to an OBO Graph document.
-        let doc: obo::OboDoc = obj.clone_py(py).into_py(py);
+        let doc: obo::OboDoc = obj.to_ast(py);
+        let nodes = crate::graph::collect_doc_meta(&doc);
         // FIXME: let graph = py.allow_threads(|| doc.into_graph())
-        let graph = doc.into_graph()
+        let mut graph = doc.into_graph()
             .map_err(|e| PyErr::from(GraphError::from(e)))?;
+        if let Some(g) = graph.graphs.first_mut() {
+            crate::graph::apply_node_meta(g, &nodes);
+            if let Some(stored) = obj.graph_meta() {
+                crate::graph::merge_meta(g.meta.get_or_insert_with(Default::default), stored);
+            }
+        }
 
         // Write the document
         if let Ok(s) = fh.downcast::<PyString>() {
             // Write into a file if given a path as a string.
             let path = s.to_str()?;
-            // py.allow_threads(|| fastobo_graphs::to_file(path, &graph))
-            fastobo_graphs::to_file(path, &graph)
-                .map_err(|e| PyErr::from(GraphError::from(e)))
+            let file = BufWriter::with_capacity(capacity, std::fs::File::create(path)?);
+            let mut f = Encoder::new(file, compression, Some(path));
+            // py.allow_threads(|| fastobo_graphs::to_writer(&mut f, &graph))
+            fastobo_graphs::to_writer(&mut f, &graph)
+                .map_err(|e| PyErr::from(GraphError::from(e)))?;
+            f.finish()?;
+            Ok(())
         } else {
             // Write into the handle if given a writable file.
-            let mut f = match PyFileWrite::from_ref(fh) {
-                Ok(f) => f,
+            let mut f = match PyFileBufWrite::from_ref(fh, capacity) {
+                Ok(f) => Encoder::new(f, compression, None),
                 Err(e) => {
-                    raise!(py, PyTypeError("expected path or binary file handle") from e)
+                    raise!(py, PyTypeError("expected path or file handle") from e)
                 }
             };
             // Write the graph
             match fastobo_graphs::to_writer(&mut f, &graph) {
+                Ok(()) => (),
+                Err(_) if PyErr::occurred(py) => return Err(PyErr::fetch(py)),
+                Err(e) => return Err(PyErr::from(GraphError::from(e))),
+            }
+            match f.finish().and_then(PyFileBufWrite::finish) {
+                Ok(()) => Ok(()),
+                Err(_) if PyErr::occurred(py) => Err(PyErr::fetch(py)),
+                Err(e) => Err(PyErr::from(e)),
+            }
+        }
+    }
+
+    /// Read a document in a readable format from `reader`.
+    ///
+    /// The graph metadata of OBO graphs is returned as well, since it has
+    /// no equivalent in OBO documents.
+    fn read_document<B: BufRead>(
+        py: Python,
+        reader: B,
+        format: Format,
+        path: Option<&str>,
+    ) -> PyResult<(obo::OboDoc, Option<Box<Meta>>)> {
+        match format {
+            Format::Obo => {
+                let mut parser = InternalParser::with_thread_count(reader, 0)?;
+                parser.ordered(true);
+                match parser.try_into_doc() {
+                    Ok(doc) => Ok((doc, None)),
+                    Err(e) if PyErr::occurred(py) => Err(PyErr::fetch(py)),
+                    Err(e) => match path {
+                        Some(p) => Err(Error::from(e).with_path(p).into()),
+                        None => Err(Error::from(e).into()),
+                    },
+                }
+            }
+            Format::OboJson => {
+                let doc: GraphDocument = match fastobo_graphs::from_reader(reader) {
+                    Ok(doc) => doc,
+                    Err(_) if PyErr::occurred(py) => return Err(PyErr::fetch(py)),
+                    Err(e) => return Err(GraphError::from(e).into()),
+                };
+                let mut graph = doc.graphs.into_iter().next().unwrap();
+                let meta = graph.meta.clone();
+                let nodes = crate::graph::collect_node_meta(&mut graph);
+                let mut doc = obo::OboDoc::from_graph(graph).map_err(GraphError::from)?;
+                crate::graph::add_doc_clauses(&mut doc, &nodes);
+                Ok((doc, meta))
+            }
+            other => {
+                let msg = format!("cannot read {:?} documents", other);
+                Err(PyValueError::new_err(msg))
+            }
+        }
+    }
+
+    /// Convert an ontology file to another format.
+    ///
+    /// The document is loaded and written without creating any Python
+    /// object for its frames, so that simple conversion scripts only need
+    /// a single call and benefit from the Rust implementation of each step.
+    /// Gzip-compressed input is detected automatically, and the output is
+    /// compressed when ``dst`` is a path ending with ``.gz``.
+    ///
+    /// Arguments:
+    ///     src (str or file-handle): The path to the file to convert, or
+    ///         a **binary** stream to read the document from.
+    ///     dst (str or file-handle): The path to the file to write, or a
+    ///         writable **binary** stream to write the document into.
+    ///     from_format (`str`, optional): The format of ``src``, either
+    ///         ``"obo"`` or ``"obojson"``. If `None` given, the format is
+    ///         detected from the extension of ``src``, or from its content.
+    ///     to_format (`str`, optional): The format of ``dst``, either
+    ///         ``"obo"``, ``"obojson"``, ``"ofn"``, ``"owx"`` or ``"rdf"``.
+    ///         If `None` given, the format is detected from the extension
+    ///         of ``dst``.
+    ///
+    /// Raises:
+    ///     TypeError: When the arguments have invalid types.
+    ///     ValueError: When a format is invalid or cannot be detected, or
+    ///         when the source document cannot be parsed or converted.
+    ///     OSError: When an underlying OS error occurs.
+    ///
+    /// Example:
+    ///     >>> fastobo.convert("ms.obo", "ms.json")
+    ///     >>> import json
+    ///     >>> with open("ms.json") as f:
+    ///     ...     json.load(f)["graphs"][0]["id"]
+    ///     'http://purl.obolibrary.org/obo/ms.owl'
+    ///
+    /// Caution:
+    ///     OWL documents can be written but not read, since there is no
+    ///     OWL to OBO translation available.
+    ///
+    #[pyfunction]
+    #[pyo3(
+        name = "convert",
+        signature = (src, dst, from_format = None, to_format = None),
+        text_signature = "(src, dst, from_format=None, to_format=None)"
+    )]
+    fn convert(
+        py: Python,
+        src: &PyAny,
+        dst: &PyAny,
+        from_format: Option<&str>,
+        to_format: Option<&str>,
+    ) -> PyResult<()> {
+        let from_format = from_format
+            .map(Format::from_str)
+            .transpose()
+            .map_err(PyValueError::new_err)?;
+        let to_format = to_format
+            .map(Format::from_str)
+            .transpose()
+            .map_err(PyValueError::new_err)?;
+
+        // Open the source, and detect its format if needed.
+        let src_path: Option<String>;
+        let file: Box<dyn Read> = if let Ok(s) = src.downcast::<PyString>() {
+            src_path = Some(s.to_str()?.to_string());
+            Box::new(std::fs::File::open(s.to_str()?).map_err(Error::from)?)
+        } else {
+            let f = match PyFileRead::from_ref(src) {
+                Ok(f) => f,
+                Err(e) => raise!(py, PyTypeError("expected path or file handle") from e),
+            };
+            src_path = src
+                .getattr("name")
+                .and_then(|n| n.downcast::<PyString>().map_err(PyErr::from))
+                .and_then(|s| s.to_str())
+                .map(|s| s.to_string())
+                .ok();
+            Box::new(f)
+        };
+        let decoder = Decoder::new(BufReader::new(file), Compression::Infer)?;
+        let mut reader = BufReader::new(decoder);
+        let from_format = match from_format.or_else(|| src_path.as_deref().and_then(Format::from_path)) {
+            Some(format) => format,
+            None => Format::from_content(reader.fill_buf()?),
+        };
+        if !from_format.is_readable() {
+            let msg = format!("cannot convert from {:?} documents", from_format);
+            return Err(PyValueError::new_err(msg));
+        }
+
+        // Detect the format of the destination.
+        let dst_path = dst.downcast::<PyString>().ok().map(|s| s.to_str()).transpose()?;
+        let to_format = match to_format.or_else(|| dst_path.and_then(Format::from_path)) {
+            Some(format) => format,
+            None => return Err(PyValueError::new_err("could not detect output format")),
+        };
+
+        // Read the source document.
+        let (doc, graph_meta) = read_document(py, reader, from_format, src_path.as_deref())?;
+
+        // Open the destination.
+        let file: Box<dyn Write> = match dst_path {
+            Some(path) => Box::new(BufWriter::new(std::fs::File::create(path)?)),
+            None => match PyFileBufWrite::from_ref(dst, DEFAULT_BUFFER_SIZE) {
+                Ok(f) => Box::new(f),
+                Err(e) => raise!(py, PyTypeError("expected path or file handle") from e),
+            },
+        };
+        let mut writer = Encoder::new(file, Compression::Infer, dst_path);
+
+        // Write the document in the destination format.
+        let result = match to_format {
+            Format::Obo => write!(writer, "{}", doc).map_err(PyErr::from),
+            Format::OboJson => {
+                let nodes = crate::graph::collect_doc_meta(&doc);
+                let mut graph = doc.into_graph().map_err(GraphError::from)?;
+                if let Some(g) = graph.graphs.first_mut() {
+                    crate::graph::apply_node_meta(g, &nodes);
+                    if let Some(stored) = &graph_meta {
+                        let meta = g.meta.get_or_insert_with(Default::default);
+                        crate::graph::merge_meta(meta, stored);
+                    }
+                }
+                fastobo_graphs::to_writer(&mut writer, &graph)
+                    .map_err(|e| PyErr::from(GraphError::from(e)))
+            }
+            Format::Ofn => {
+                let ofn = crate::owl::to_ofn(doc, None, None, None)?;
+                writer.write_all(ofn.as_bytes()).map_err(PyErr::from)
+            }
+            Format::Owx => {
+                let mapping = doc.prefixes();
+                let ont = crate::owl::to_owl(doc, None, None)?;
+                horned_owl::io::owx::writer::write(&mut writer, &ont, Some(&mapping))
+                    .map_err(|e| PyValueError::new_err(e.to_string()))
+            }
+            Format::Rdf => {
+                let ont = crate::owl::to_owl(doc, None, None)?;
+                horned_owl::io::rdf::writer::write(&mut writer, &ont)
+                    .map_err(|e| PyValueError::new_err(e.to_string()))
+            }
+        };
+
+        // Flush the destination and check for Python errors.
+        match result.and_then(|_| writer.finish().map(|_| ()).map_err(PyErr::from)) {
+            Ok(()) => Ok(()),
+            Err(_) if PyErr::occurred(py) => Err(PyErr::fetch(py)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Load an ontology from a URL.
+    ///
+    /// The document is downloaded with ``fetcher``, or with `urllib.request`
+    /// if no fetcher is given, and loaded like with `~fastobo.load` or
+    /// `~fastobo.load_graph` depending on its format. Gzip-compressed
+    /// content is decompressed automatically. Since it takes a single URL
+    /// argument, this function can be used as the loader of
+    /// `~fastobo.resolve_imports` to resolve remote imports.
+    ///
+    /// Arguments:
+    ///     url (str): The URL of the document to load.
+    ///     fetcher (callable, optional): A callable taking a URL and
+    ///         returning the content of the document, either as a bytes-like
+    ///         object or as a file-handle. If `None` given, the document is
+    ///         downloaded with `urllib.request.urlopen`.
+    ///
+    /// Returns:
+    ///     `~fastobo.doc.OboDoc`: The document obtained from the URL.
+    ///
+    /// Raises:
+    ///     TypeError: When the fetcher does not return bytes or a file-handle.
+    ///     ValueError: When the document is not in a readable format.
+    ///     SyntaxError: When the document is not in valid OBO syntax.
+    ///     OSError: When an underlying OS error occurs, such as a network
+    ///         error raised by `urllib.request.urlopen`.
+    ///
+    /// Example:
+    ///     >>> def fetcher(url):
+    ///     ...     return b"[Term]\nid: TST:001\n"
+    ///     >>> doc = fastobo.load_url("http://example.com/tst.obo", fetcher)
+    ///     >>> doc[0]
+    ///     TermFrame(PrefixedIdent('TST', '001'))
+    ///
+    /// Note:
+    ///     The format of the document is detected from the ``Content-Type``
+    ///     header of the response when the returned file-handle has a
+    ///     ``headers`` attribute, like the responses of `urllib.request`,
+    ///     then from the extension of the URL, and finally from the content
+    ///     of the document itself.
+    ///
+    #[pyfunction]
+    #[pyo3(
+        name = "load_url",
+        signature = (url, fetcher = None),
+        text_signature = "(url, fetcher=None)"
+    )]
+    fn load_url(py: Python, url: &str, fetcher: Option<&PyAny>) -> PyResult<OboDoc> {
+        // Fetch the document, using `urllib` if no fetcher was given.
+        let response = match fetcher {
+            Some(f) => f.call1((url,))?,
+            None => py
+                .import(pyo3::intern!(py, "urllib.request"))?
+                .call_method1(pyo3::intern!(py, "urlopen"), (url,))?,
+        };
+
+        // Detect the format from the response headers, if any.
+        let content_type = response
+            .getattr(pyo3::intern!(py, "headers"))
+            .and_then(|h| h.call_method1(pyo3::intern!(py, "get"), ("Content-Type",)))
+            .and_then(|t| t.extract::<Option<String>>())
+            .ok()
+            .flatten();
+        let format = content_type
+            .as_deref()
+            .and_then(Format::from_content_type)
+            .or_else(|| Format::from_path(url.split(['?', '#']).next().unwrap_or(url)));
+
+        // Read the content, either from bytes or from a file-handle.
+        let result = if let Ok(buffer) = pyo3::buffer::PyBuffer::<u8>::get(response) {
+            let data = buffer.to_vec(py)?;
+            let decoder = Decoder::new(BufReader::new(std::io::Cursor::new(data)), Compression::Infer)?;
+            let mut reader = BufReader::new(decoder);
+            let format = match format {
+                Some(format) => format,
+                None => Format::from_content(reader.fill_buf()?),
+            };
+            read_document(py, reader, format, Some(url))
+        } else {
+            let f = match PyFileRead::from_ref(response) {
+                Ok(f) => f,
+                Err(e) => raise!(py, PyTypeError("expected bytes or file handle") from e),
+            };
+            let decoder = Decoder::new(BufReader::new(f), Compression::Infer)?;
+            let mut reader = BufReader::new(decoder);
+            let format = match format {
+                Some(format) => format,
+                None => Format::from_content(reader.fill_buf()?),
+            };
+            let result = read_document(py, reader, format, Some(url));
+            if fetcher.is_none() {
+                response.call_method0(pyo3::intern!(py, "close"))?;
+            }
+            result
+        };
+
+        // Convert the document, keeping the graph metadata if any.
+        let (doc, meta) = result?;
+        let mut doc: OboDoc = doc.into_py(py);
+        doc.replace_graph_meta(meta);
+        Ok(doc)
+    }
+
+    /// Write the metadata of the entity frames of a document to a TSV file.
+    ///
+    /// The file contains a header line with the column names, followed by
+    /// one line per entity frame. Lines are written as they are built from
+    /// the syntax tree, without creating Python objects for the frames.
+    ///
+    /// Arguments:
+    ///     doc (`~fastobo.doc.OboDoc`): The OBO document to write.
+    ///     fh (str or file-handle): The path to a file, or a writable
+    ///         **binary** stream to write the table into.
+    ///     columns (`list` of `str`, optional): The columns to write, among
+    ///         ``id``, ``type``, ``name``, ``def``, ``namespace``, ``parents``
+    ///         (the targets of ``is_a`` clauses, separated by ``|``) and
+    ///         ``obsolete``. Defaults to all columns but ``type``.
+    ///     sep (`str`): The field delimiter, e.g. ``","`` to write a CSV file.
+    ///
+    /// Raises:
+    ///     TypeError: When the argument have invalid types.
+    ///     ValueError: When a column name is unknown.
+    ///     OSError: When an underlying OS error occurs.
+    ///
+    /// Example:
+    ///     >>> fastobo.dump_tsv(ms, "ms.tsv", columns=["id", "name"])
+    ///     >>> with open("ms.tsv") as f:
+    ///     ...     f.readline().rstrip().split("\t")
+    ///     ['id', 'name']
+    ///
+    #[pyfunction]
+    #[pyo3(
+        name = "dump_tsv",
+        signature = (doc, fh, columns = None, sep = '\t'),
+        text_signature = "(doc, fh, columns=None, sep=\"\\t\")"
+    )]
+    fn dump_tsv(
+        py: Python,
+        doc: &OboDoc,
+        fh: &PyAny,
+        columns: Option<Vec<String>>,
+        sep: char,
+    ) -> PyResult<()> {
+        let columns = columns.unwrap_or_else(|| {
+            ["id", "name", "def", "namespace", "parents", "obsolete"]
+                .iter()
+                .map(|c| c.to_string())
+                .collect()
+        });
+        for column in columns.iter() {
+            if !crate::table::DELIMITED_COLUMNS.contains(&column.as_str()) {
+                return Err(PyValueError::new_err(format!("invalid column: {:?}", column)));
+            }
+        }
+
+        let doc: obo::OboDoc = doc.to_ast(py);
+        let rows = crate::table::entity_rows(&doc);
+        if let Ok(s) = fh.downcast::<PyString>() {
+            // Write into a file if given a path as a string.
+            let file = std::fs::File::create(s.to_str()?)?;
+            crate::table::write_delimited(std::io::BufWriter::new(file), rows, &columns, sep)?;
+            Ok(())
+        } else {
+            // Write into the handle if given a writable file.
+            let mut f = match PyFileBufWrite::from_ref(fh, DEFAULT_BUFFER_SIZE) {
+                Ok(f) => f,
+                Err(e) => raise!(py, PyTypeError("expected path or file handle") from e),
+            };
+            let result = crate::table::write_delimited(&mut f, rows, &columns, sep);
+            match result.and_then(|_| f.finish()) {
                 Ok(()) => Ok(()),
                 Err(_) if PyErr::occurred(py) => Err(PyErr::fetch(py)),
-                Err(e) => Err(PyErr::from(GraphError::from(e))),
+                Err(e) => Err(PyErr::from(e)),
             }
         }
     }
 
+    /// Write an OBO document into a new SQLite database.
+    ///
+    /// The database follows the layout of `semsql <https://github.com/INCATools/semantic-sql>`_,
+    /// so that ontologies can be queried with SQL without any OWL tooling:
+    /// every clause of an entity frame is stored as a row of the
+    /// ``statements`` table, and the ``rdfs_label_statement``,
+    /// ``has_oio_synonym_statement`` and ``has_dbxref_statement`` views
+    /// give access to the labels, synonyms and cross-references of the
+    /// entities. Identifiers are stored as CURIEs.
+    ///
+    /// Arguments:
+    ///     doc (`~fastobo.doc.OboDoc`): The OBO document to write.
+    ///     path (`str`): The path to the database file. An existing file
+    ///         is overwritten.
+    ///     entailed_edges (`bool`): Whether or not to fill the
+    ///         ``entailed_edge`` table with the reflexive transitive closure
+    ///         of the ``is_a`` clauses of the term frames.
+    ///
+    /// Raises:
+    ///     OSError: When the database cannot be written.
+    ///
+    /// Example:
+    ///     >>> import sqlite3
+    ///     >>> fastobo.dump_sqlite(ms, "ms.db")
+    ///     >>> db = sqlite3.connect("ms.db")
+    ///     >>> db.execute(
+    ///     ...     "SELECT value FROM rdfs_label_statement WHERE subject = 'MS:1000001'"
+    ///     ... ).fetchone()
+    ///     ('sample number',)
+    ///
+    #[pyfunction]
+    #[pyo3(
+        name = "dump_sqlite",
+        signature = (doc, path, entailed_edges = false),
+        text_signature = "(doc, path, entailed_edges=False)"
+    )]
+    fn dump_sqlite(py: Python, doc: &OboDoc, path: &str, entailed_edges: bool) -> PyResult<()> {
+        let doc: obo::OboDoc = doc.to_ast(py);
+        if std::path::Path::new(path).exists() {
+            std::fs::remove_file(path)?;
+        }
+        py.allow_threads(|| crate::sqlite::dump(&doc, path, entailed_edges))
+            .map_err(|e| pyo3::exceptions::PyOSError::new_err(e.to_string()))
+    }
+
     /// Convert an OBO ontology to OWL and write it to the given handle.
     ///
     /// Arguments:
     ///     fh (str or file-handle): The path to a file, or a writable
     ///         **binary** stream to write the serialized graph into.
     ///         *A binary stream needs a* ``write(b)`` *method that accepts*
-    ///         ``bytes``.
+    ///         ``bytes``. Text streams are accepted as well.
     ///     doc (`~fastobo.doc.OboDoc`): The OBO document to be converted
     ///         into an OWL Ontology.
     ///     format (`str`): The OWL format to serialize the converted OWL
     ///         document into. Supported values are: ``ofn`` for
     ///         `Functional-style syntax <https://w3.org/TR/owl2-syntax/>`_.
+    ///     prefixes (`dict`, optional): A mapping of prefixes to IRIs to
+    ///         declare in the OWL document, merged over the prefixes derived
+    ///         from the document. Use an empty string key to set the default
+    ///         prefix.
+    ///     ontology_iri (`str`, optional): The IRI of the OWL ontology, used
+    ///         instead of the IRI derived from the ``ontology`` clause. When
+    ///         the header has no ``ontology`` clause, the ontology name is
+    ///         derived from the last segment of this IRI.
+    ///     version_iri (`str`, optional): The version IRI of the OWL
+    ///         ontology, e.g. to export a dated release.
+    ///     buffer_size (int): The size of the buffer used to write the
+    ///         document, in bytes. The buffer is always flushed, along with
+    ///         the file-handle, before the function returns.
     ///
     /// Raises:
     ///     TypeError: When the argument have invalid types.
     ///     ValueError: When the conversion to OWL fails.
+    ///     MissingOntologyClauseError: When the header of ``doc`` has no
+    ///         ``ontology`` clause and no ``ontology_iri`` is given.
+    ///     MissingNamespaceError: When the header of ``doc`` has no
+    ///         ``default-namespace`` clause.
     ///     OSError: When an underlying OS error occurs.
     ///
     /// Example:
@@ -423,6 +1461,11 @@ use super::built;
     ///     consider opening an issue directly on the ``fastobo-owl``
     ///     `issue tracker <https://github.com/fastobo/fastobo-owl/issues>`_.
     ///
+    /// Note:
+    ///     ``relationship`` clauses with ``gci_relation`` and ``gci_filler``
+    ///     qualifiers are translated to general class inclusion axioms, as
+    ///     described in the OBO 1.4 semantics.
+    ///
     /// Hint:
     ///     To support serialization to OWL, an OBO document is required to
     ///     declare an ``ontology`` clause in the header. Furthermore, every
@@ -431,34 +1474,87 @@ use super::built;
     ///     Failure to do both will result in a `ValueError` being thrown.
     ///
     #[pyfunction]
-    #[pyo3(name = "dump_owl", text_signature = r#"(doc, fh, format="ofn")"#, signature=(obj, fh, format="ofn"))]
-    fn dump_owl(py: Python, obj: &OboDoc, fh: &PyAny, format: &str) -> PyResult<()> {
+    #[pyo3(
+        name = "dump_owl",
+        text_signature = r#"(doc, fh, format="ofn", prefixes=None, ontology_iri=None, version_iri=None, buffer_size=8192)"#,
+        signature = (obj, fh, format="ofn", prefixes=None, ontology_iri=None, version_iri=None, buffer_size=DEFAULT_BUFFER_SIZE)
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn dump_owl(
+        py: Python,
+        obj: &OboDoc,
+        fh: &PyAny,
+        format: &str,
+        prefixes: Option<HashMap<String, String>>,
+        ontology_iri: Option<&str>,
+        version_iri: Option<&str>,
+        buffer_size: usize,
+    ) -> PyResult<()> {
+        let capacity = check_buffer_size(buffer_size)?;
+
         // Convert OBO document to an OWL document.
-        let doc: obo::OboDoc = obj.clone_py(py).into_py(py);
-        let prefixes = doc.prefixes();
-        let ont = doc.into_owl::<AxiomMappedOntology>().map_err(OwlError::from)?;
-        let ctx = horned_functional::Context::from(&prefixes);
+        let doc: obo::OboDoc = obj.to_ast(py);
+        let ofn = crate::owl::to_ofn(doc, prefixes, ontology_iri, version_iri)?;
 
         // Write the document
-        let mut file: Box<dyn Write> = if let Ok(s) = fh.downcast::<PyString>() {
+        if let Ok(s) = fh.downcast::<PyString>() {
             // Write into a file if given a path as a string.
-            Box::new(std::fs::File::create(s.to_str()?)?)
+            let mut file = BufWriter::with_capacity(capacity, std::fs::File::create(s.to_str()?)?);
+            file.write_all(ofn.as_bytes())?;
+            file.flush()?;
+            Ok(())
         } else {
             // Write into the handle if given a writable file.
-            match PyFileWrite::from_ref(fh) {
-                Ok(f) => Box::new(f),
+            let mut file = match PyFileBufWrite::from_ref(fh, capacity) {
+                Ok(f) => f,
                 Err(e) => {
-                    raise!(py, PyTypeError("expected path or binary file handle") from e)
+                    raise!(py, PyTypeError("expected path or file handle") from e)
                 }
+            };
+            match file.write_all(ofn.as_bytes()).and_then(|_| file.finish()) {
+                Ok(()) => Ok(()),
+                Err(_) if PyErr::occurred(py) => Err(PyErr::fetch(py)),
+                Err(e) => Err(PyErr::from(e)),
             }
-        };
-
-        write!(file, "{}", prefixes.as_ofn())?;
-        write!(file, "{}", ont.as_ofn_ctx(&ctx))?;
-
-        Ok(())
+        }
     }
 
+    /// Convert a `pyhornedowl` ontology to an OBO document.
+    ///
+    /// The ontology is passed from `py-horned-owl <https://github.com/ontology-tools/py-horned-owl>`_
+    /// in OWL/XML syntax, without going through the filesystem, and
+    /// translated back to OBO: declared classes, object properties and
+    /// named individuals become entity frames, and the axioms produced by
+    /// the OBO to OWL translation (labels, definitions, synonyms, subclass
+    /// and existential restrictions, ...) become the matching clauses.
+    /// Axioms without an OBO equivalent are ignored.
+    ///
+    /// Arguments:
+    ///     ontology (`pyhornedowl.PyIndexedOntology`): The OWL ontology to
+    ///         convert.
+    ///
+    /// Returns:
+    ///     `~fastobo.doc.OboDoc`: The OBO document translated from the
+    ///     ontology.
+    ///
+    /// Raises:
+    ///     ValueError: When the ontology could not be read back from its
+    ///         OWL/XML serialization.
+    ///
+    /// Example:
+    ///     >>> doc = fastobo.from_pyhornedowl(ms.to_pyhornedowl())
+    ///     >>> fastobo.id.PrefixedIdent("MS", "1000031") in doc
+    ///     True
+    ///
+    #[pyfunction]
+    #[pyo3(name = "from_pyhornedowl", text_signature = "(ontology)")]
+    fn from_pyhornedowl(py: Python, ontology: &PyAny) -> PyResult<OboDoc> {
+        let owx = ontology
+            .call_method1(pyo3::intern!(py, "save_to_string"), ("owx",))?
+            .extract::<String>()?;
+        let doc = crate::owl::from_owx(&owx)?;
+        Ok(doc.into_py(py))
+    }
 
 /// The Faultless AST for Open Biomedical Ontologies.
 ///
@@ -478,17 +1574,27 @@ pub fn init(py: Python, m: &PyModule) -> PyResult<()> {
     add_submodule!(py, m, id);
     add_submodule!(py, m, instance);
     add_submodule!(py, m, pv);
+    add_submodule!(py, m, qual);
     add_submodule!(py, m, syn);
     add_submodule!(py, m, term);
     add_submodule!(py, m, typedef);
+    add_submodule!(py, m, visit);
     add_submodule!(py, m, xref);
 
     m.add_function(wrap_pyfunction!(self::iter, m)?)?;
     m.add_function(wrap_pyfunction!(self::load, m)?)?;
     m.add_function(wrap_pyfunction!(self::loads, m)?)?;
+    m.add_function(wrap_pyfunction!(self::resolve_imports, m)?)?;
+    m.add_function(wrap_pyfunction!(self::diff, m)?)?;
     m.add_function(wrap_pyfunction!(self::load_graph, m)?)?;
+    m.add_function(wrap_pyfunction!(self::iter_graph, m)?)?;
     m.add_function(wrap_pyfunction!(self::dump_graph, m)?)?;
     m.add_function(wrap_pyfunction!(self::dump_owl, m)?)?;
+    m.add_function(wrap_pyfunction!(self::from_pyhornedowl, m)?)?;
+    m.add_function(wrap_pyfunction!(self::dump_sqlite, m)?)?;
+    m.add_function(wrap_pyfunction!(self::dump_tsv, m)?)?;
+    m.add_function(wrap_pyfunction!(self::convert, m)?)?;
+    m.add_function(wrap_pyfunction!(self::load_url, m)?)?;
 
     Ok(())
 }
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
+use std::fmt::Write;
 use std::ops::Deref;
 use std::str::FromStr;
 
@@ -14,43 +15,68 @@ use pyo3::PyTypeInfo;
 use fastobo::ast;
 
 use super::id::Ident;
+use crate::error::Error;
 use crate::utils::AbstractClass;
 use crate::utils::ClonePy;
+use crate::utils::DisplayPy;
 use crate::utils::EqPy;
+use crate::utils::FreezePy;
+use crate::utils::HashPy;
 use crate::utils::FinalClass;
 
 // --- Module export ---------------------------------------------------------
 
 #[pymodule]
 #[pyo3(name = "pv")]
-pub fn init(_py: Python, m: &PyModule) -> PyResult<()> {
+pub fn init(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<self::AbstractPropertyValue>()?;
     m.add_class::<self::LiteralPropertyValue>()?;
     m.add_class::<self::ResourcePropertyValue>()?;
+    m.add_class::<self::PropertyValueList>()?;
+    register!(py, m, PropertyValueList, "collections.abc", MutableSequence);
+    m.add_function(wrap_pyfunction!(self::parse, m)?)?;
     m.add("__name__", "fastobo.pv")?;
     Ok(())
 }
 
+/// Parse a property value from a string.
+///
+/// Arguments:
+///     text (str): The serialized property value, as written after the
+///         ``property_value`` tag of a clause.
+///
+/// Returns:
+///     `~fastobo.pv.AbstractPropertyValue`: A `LiteralPropertyValue` if
+///     the text contains a quoted value and a datatype, or a
+///     `ResourcePropertyValue` otherwise.
+///
+/// Raises:
+///     SyntaxError: When the text is not a valid property value.
+///
+/// Example:
+///     >>> fastobo.pv.parse("IAO:0000412 http://purl.obolibrary.org/obo/go.owl")
+///     ResourcePropertyValue(PrefixedIdent('IAO', '0000412'), Url('http://purl.obolibrary.org/obo/go.owl'))
+///     >>> fastobo.pv.parse('dc:title "Gene Ontology" xsd:string')
+///     LiteralPropertyValue(PrefixedIdent('dc', 'title'), 'Gene Ontology', PrefixedIdent('xsd', 'string'))
+///
+#[pyfunction]
+#[pyo3(text_signature = "(text)")]
+pub fn parse(py: Python, text: &str) -> PyResult<PropertyValue> {
+    match ast::PropertyValue::from_str(text.trim()) {
+        Ok(pv) => Ok(pv.into_py(py)),
+        Err(e) => Err(PyErr::from(Error::from(e))),
+    }
+}
+
 // --- Conversion Wrapper ----------------------------------------------------
 
-#[derive(ClonePy, Debug, PyWrapper, EqPy)]
+#[derive(ClonePy, Debug, DisplayPy, PyWrapper, EqPy, HashPy)]
 #[wraps(AbstractPropertyValue)]
 pub enum PropertyValue {
     Literal(Py<LiteralPropertyValue>),
     Resource(Py<ResourcePropertyValue>),
 }
 
-impl Display for PropertyValue {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        Python::with_gil(|py| {
-            match self {
-                PropertyValue::Literal(lpv) => lpv.as_ref(py).borrow().fmt(f),
-                PropertyValue::Resource(rpv) => rpv.as_ref(py).borrow().fmt(f),
-            }
-        })
-    }
-}
-
 impl IntoPy<PropertyValue> for fastobo::ast::PropertyValue {
     fn into_py(self, py: Python) -> PropertyValue {
         match self {
@@ -65,6 +91,16 @@ impl IntoPy<PropertyValue> for fastobo::ast::PropertyValue {
     }
 }
 
+impl FreezePy for PropertyValue {
+    fn freeze_py(&self, py: Python) -> PyResult<()> {
+        match self {
+            PropertyValue::Literal(l) => l.try_borrow_mut(py)?.frozen = true,
+            PropertyValue::Resource(r) => r.try_borrow_mut(py)?.frozen = true,
+        }
+        Ok(())
+    }
+}
+
 impl IntoPy<fastobo::ast::PropertyValue> for PropertyValue {
     fn into_py(self, py: Python) -> fastobo::ast::PropertyValue {
         match self {
@@ -89,12 +125,14 @@ impl AbstractClass for AbstractPropertyValue {
 // --- Literal -----------------------------------------------------------------
 
 #[pyclass(extends=AbstractPropertyValue, module="fastobo.pv")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy)]
 #[base(AbstractPropertyValue)]
 pub struct LiteralPropertyValue {
     relation: Ident,
     value: ast::QuotedString,
     datatype: Ident,
+    #[eqpy(ignore)]
+    frozen: bool,
 }
 
 impl LiteralPropertyValue {
@@ -103,6 +141,7 @@ impl LiteralPropertyValue {
             relation,
             value,
             datatype,
+            frozen: false,
         }
     }
 }
@@ -113,14 +152,24 @@ impl ClonePy for LiteralPropertyValue {
             relation: self.relation.clone_py(py),
             value: self.value.clone(),
             datatype: self.datatype.clone_py(py),
+            frozen: false,
         }
     }
 }
 
+impl DisplayPy for LiteralPropertyValue {
+    fn fmt_py(&self, py: Python, f: &mut Formatter) -> FmtResult {
+        self.relation.fmt_py(py, f)?;
+        f.write_char(' ')?;
+        self.value.fmt(f)?;
+        f.write_char(' ')?;
+        self.datatype.fmt_py(py, f)
+    }
+}
+
 impl Display for LiteralPropertyValue {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let pv: fastobo::ast::PropertyValue = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        pv.fmt(f)
+        Python::with_gil(|py| self.fmt_py(py, f))
     }
 }
 
@@ -151,13 +200,30 @@ impl IntoPy<LiteralPropertyValue> for fastobo::ast::LiteralPropertyValue {
 
 #[pymethods]
 impl LiteralPropertyValue {
+    /// Create a new literal property value.
+    ///
+    /// Arguments:
+    ///     relation (~fastobo.id.Ident or str): the identifier of the
+    ///         property, parsed with `fastobo.id.parse` if given as a `str`.
+    ///     value (str): the literal value of the property.
+    ///     datatype (~fastobo.id.Ident or str, optional): the datatype of
+    ///         the value, parsed with `fastobo.id.parse` if given as a `str`.
+    ///         Defaults to ``xsd:string``.
+    ///
+    /// Example:
+    ///     >>> pv = fastobo.pv.LiteralPropertyValue("dc:title", "Gene Ontology")
+    ///     >>> print(pv)
+    ///     dc:title "Gene Ontology" xsd:string
+    ///
     #[new]
+    #[pyo3(signature = (relation, value, datatype = None))]
     fn __init__(
         relation: &PyAny,
         value: &PyAny,
-        datatype: &PyAny,
+        datatype: Option<&PyAny>,
     ) -> PyResult<PyClassInitializer<Self>> {
-        let r = relation.extract::<Ident>()?;
+        let py = relation.py();
+        let r = super::id::extract_or_parse(py, relation)?;
         let v = if let Ok(s) = value.extract::<&PyString>() {
             ast::QuotedString::new(s.to_str()?.to_string())
         } else {
@@ -165,7 +231,10 @@ impl LiteralPropertyValue {
             let msg = format!("expected str for value, found {}", n);
             return Err(PyTypeError::new_err(msg));
         };
-        let dt = datatype.extract::<Ident>()?;
+        let dt = match datatype {
+            Some(dt) => super::id::extract_or_parse(py, dt)?,
+            None => ast::Ident::from(ast::PrefixedIdent::new("xsd", "string")).into_py(py),
+        };
         Ok(Self::new(r, v, dt).into())
     }
 
@@ -185,8 +254,7 @@ impl LiteralPropertyValue {
     }
 
     fn __str__(&self) -> PyResult<String> {
-        let pv: fastobo::ast::PropertyValue = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        Ok(pv.to_string())
+        Ok(self.to_string())
     }
 
     #[getter]
@@ -196,6 +264,7 @@ impl LiteralPropertyValue {
 
     #[setter]
     fn set_relation(&mut self, relation: Ident) -> PyResult<()> {
+        crate::utils::check_mutable(self.frozen, "LiteralPropertyValue")?;
         self.relation = relation;
         Ok(())
     }
@@ -207,6 +276,7 @@ impl LiteralPropertyValue {
 
     #[setter]
     fn set_value(&mut self, value: String) -> PyResult<()> {
+        crate::utils::check_mutable(self.frozen, "LiteralPropertyValue")?;
         self.value = fastobo::ast::QuotedString::new(value);
         Ok(())
     }
@@ -218,6 +288,7 @@ impl LiteralPropertyValue {
 
     #[setter]
     fn set_datatype(&mut self, datatype: Ident) -> PyResult<()> {
+        crate::utils::check_mutable(self.frozen, "LiteralPropertyValue")?;
         self.datatype = datatype;
         Ok(())
     }
@@ -226,16 +297,22 @@ impl LiteralPropertyValue {
 // --- Resource ------------------------------------------------------------
 
 #[pyclass(extends=AbstractPropertyValue, module="fastobo.pv")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy)]
 #[base(AbstractPropertyValue)]
 pub struct ResourcePropertyValue {
     relation: Ident,
     value: Ident,
+    #[eqpy(ignore)]
+    frozen: bool,
 }
 
 impl ResourcePropertyValue {
     pub fn new(relation: Ident, value: Ident) -> Self {
-        ResourcePropertyValue { relation, value }
+        ResourcePropertyValue {
+            relation,
+            value,
+            frozen: false,
+        }
     }
 }
 
@@ -244,14 +321,22 @@ impl ClonePy for ResourcePropertyValue {
         Self {
             relation: self.relation.clone_py(py),
             value: self.value.clone_py(py),
+            frozen: false,
         }
     }
 }
 
+impl DisplayPy for ResourcePropertyValue {
+    fn fmt_py(&self, py: Python, f: &mut Formatter) -> FmtResult {
+        self.relation.fmt_py(py, f)?;
+        f.write_char(' ')?;
+        self.value.fmt_py(py, f)
+    }
+}
+
 impl Display for ResourcePropertyValue {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let pv: fastobo::ast::PropertyValue = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        pv.fmt(f)
+        Python::with_gil(|py| self.fmt_py(py, f))
     }
 }
 
@@ -287,8 +372,7 @@ impl ResourcePropertyValue {
     }
 
     fn __str__(&self) -> PyResult<String> {
-        let pv: fastobo::ast::PropertyValue = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        Ok(pv.to_string())
+        Ok(self.to_string())
     }
 
     #[getter]
@@ -298,6 +382,7 @@ impl ResourcePropertyValue {
 
     #[setter]
     fn set_relation(&mut self, relation: Ident) -> PyResult<()> {
+        crate::utils::check_mutable(self.frozen, "ResourcePropertyValue")?;
         self.relation = relation;
         Ok(())
     }
@@ -309,7 +394,25 @@ impl ResourcePropertyValue {
 
     #[setter]
     fn set_value(&mut self, value: Ident) -> PyResult<()> {
+        crate::utils::check_mutable(self.frozen, "ResourcePropertyValue")?;
         self.value = value;
         Ok(())
     }
 }
+
+// --- PropertyValueList -----------------------------------------------------
+
+/// A list of property values.
+///
+/// Example:
+///     >>> pvs = fastobo.pv.PropertyValueList([
+///     ...     fastobo.pv.parse('IAO:0000112 "an example" xsd:string'),
+///     ... ])
+///     >>> print(pvs)
+///     [IAO:0000112 "an example" xsd:string]
+///     >>> pvs.append(fastobo.pv.parse("RO:0002161 NCBITaxon:9606"))
+///     >>> len(pvs)
+///     2
+///
+#[pylist(type = "PropertyValue", module = "fastobo.pv")]
+pub struct PropertyValueList;
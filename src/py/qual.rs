@@ -0,0 +1,360 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::fmt::Write;
+
+use pyo3::class::basic::CompareOp;
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::PyAny;
+use pyo3::types::PyIterator;
+use pyo3::types::PyString;
+use pyo3::AsPyPointer;
+use pyo3::PyNativeType;
+
+use super::id::Ident;
+use crate::utils::ClonePy;
+use crate::utils::DisplayPy;
+use crate::utils::EqPy;
+use crate::utils::FreezePy;
+use crate::utils::HashPy;
+use crate::utils::RichCmpPy;
+
+// --- Module export ---------------------------------------------------------
+
+#[pymodule]
+#[pyo3(name = "qual")]
+pub fn init(py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<self::Qualifier>()?;
+    m.add_class::<self::QualifierList>()?;
+    register!(py, m, QualifierList, "collections.abc", MutableSequence);
+    m.add("__name__", "fastobo.qual")?;
+    Ok(())
+}
+
+// --- Qualifier -------------------------------------------------------------
+
+/// A trailing qualifier, annotating an entity clause with a key/value pair.
+///
+/// Qualifiers are written between braces at the end of a clause line, and
+/// can be accessed through the ``qualifiers`` property of any entity clause.
+///
+/// Example:
+///     >>> qualifier = fastobo.qual.Qualifier(
+///     ...     fastobo.id.UnprefixedIdent("cardinality"),
+///     ...     "1",
+///     ... )
+///     >>> print(qualifier)
+///     cardinality="1"
+///
+#[pyclass(module = "fastobo.qual")]
+#[derive(Debug, EqPy, HashPy, RichCmpPy)]
+pub struct Qualifier {
+    key: Ident,
+    value: fastobo::ast::QuotedString,
+    #[eqpy(ignore)]
+    frozen: bool,
+}
+
+impl Qualifier {
+    pub fn new(key: Ident, value: fastobo::ast::QuotedString) -> Self {
+        Self {
+            key,
+            value,
+            frozen: false,
+        }
+    }
+
+    /// Get a reference to the key of the qualifier.
+    pub fn key(&self) -> &Ident {
+        &self.key
+    }
+
+    /// Get a reference to the value of the qualifier.
+    pub fn value(&self) -> &fastobo::ast::QuotedString {
+        &self.value
+    }
+}
+
+impl ClonePy for Qualifier {
+    fn clone_py(&self, py: Python) -> Self {
+        Qualifier {
+            key: self.key.clone_py(py),
+            value: self.value.clone(),
+            frozen: false,
+        }
+    }
+}
+
+impl FreezePy for Py<Qualifier> {
+    fn freeze_py(&self, py: Python) -> PyResult<()> {
+        self.try_borrow_mut(py)?.frozen = true;
+        Ok(())
+    }
+}
+
+impl DisplayPy for Qualifier {
+    fn fmt_py(&self, py: Python, f: &mut Formatter) -> FmtResult {
+        self.key.fmt_py(py, f)?;
+        f.write_char('=')?;
+        self.value.fmt(f)
+    }
+}
+
+impl Display for Qualifier {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Python::with_gil(|py| self.fmt_py(py, f))
+    }
+}
+
+impl IntoPy<Qualifier> for fastobo::ast::Qualifier {
+    fn into_py(self, py: Python) -> Qualifier {
+        Qualifier::new(self.key().clone().into_py(py), self.value().clone())
+    }
+}
+
+impl IntoPy<fastobo::ast::Qualifier> for Qualifier {
+    fn into_py(self, py: Python) -> fastobo::ast::Qualifier {
+        fastobo::ast::Qualifier::new(self.key.into_py(py), self.value)
+    }
+}
+
+#[pymethods]
+impl Qualifier {
+    /// Create a new `Qualifier` from a key and a value.
+    ///
+    /// Arguments:
+    ///     key (~fastobo.id.Ident): the relation identifier of the qualifier.
+    ///     value (str): the value of the qualifier.
+    #[new]
+    fn __init__(key: Ident, value: String) -> Self {
+        Self::new(key, fastobo::ast::QuotedString::new(value))
+    }
+
+    fn __repr__(&self) -> PyResult<PyObject> {
+        Python::with_gil(|py| {
+            PyString::new(py, "Qualifier({!r}, {!r})")
+                .to_object(py)
+                .call_method1(py, "format", (&self.key, self.value.as_str()))
+        })
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+
+    /// `~fastobo.id.Ident`: the relation identifier of the qualifier.
+    #[getter]
+    fn get_key(&self) -> PyResult<&Ident> {
+        Ok(&self.key)
+    }
+
+    #[setter]
+    fn set_key(&mut self, key: Ident) -> PyResult<()> {
+        crate::utils::check_mutable(self.frozen, "Qualifier")?;
+        self.key = key;
+        Ok(())
+    }
+
+    /// `str`: the value of the qualifier.
+    #[getter]
+    fn get_value(&self) -> PyResult<&str> {
+        Ok(self.value.as_str())
+    }
+
+    #[setter]
+    fn set_value(&mut self, value: String) -> PyResult<()> {
+        crate::utils::check_mutable(self.frozen, "Qualifier")?;
+        self.value = fastobo::ast::QuotedString::new(value);
+        Ok(())
+    }
+}
+
+// --- QualifierList ---------------------------------------------------------
+
+/// A list of trailing qualifiers.
+///
+/// Example:
+///     >>> frame = fastobo.loads(
+///     ...     '[Term]\nid: TST:001\nis_a: TST:002 {source="TST:003"}\n'
+///     ... )[0]
+///     >>> frame[0].qualifiers
+///     QualifierList([Qualifier(UnprefixedIdent('source'), 'TST:003')])
+///
+#[pyclass(module = "fastobo.qual")]
+#[derive(Debug, Default, EqPy, HashPy)]
+pub struct QualifierList {
+    qualifiers: Vec<Py<Qualifier>>,
+    #[eqpy(ignore)]
+    frozen: bool,
+}
+
+impl QualifierList {
+    /// Create a new `QualifierList` from a vector of qualifiers.
+    pub fn new(qualifiers: Vec<Py<Qualifier>>) -> Self {
+        Self {
+            qualifiers,
+            frozen: false,
+        }
+    }
+
+    /// Create a new `QualifierList` from a `PyIterator`.
+    pub fn collect(py: Python, qualifiers: &PyAny) -> PyResult<Self> {
+        let mut vec = Vec::new();
+        for item in PyIterator::from_object(py, qualifiers)? {
+            let i = item?;
+            if let Ok(qualifier) = i.extract::<Py<Qualifier>>() {
+                vec.push(qualifier.clone_ref(py));
+            } else {
+                let ty = i.get_type().name()?;
+                let msg = format!("expected Qualifier, found {}", ty);
+                return Err(PyTypeError::new_err(msg));
+            }
+        }
+        Ok(Self::new(vec))
+    }
+
+    /// Check whether the `QualifierList` is empty.
+    pub fn is_empty(&self) -> bool {
+        self.qualifiers.is_empty()
+    }
+
+    /// Iterate over the qualifiers of the `QualifierList`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Py<Qualifier>> {
+        self.qualifiers.iter()
+    }
+
+    /// Get the value of the first qualifier with the given key, if any.
+    pub fn get(&self, py: Python, key: &str) -> Option<String> {
+        for qualifier in self.qualifiers.iter() {
+            let q = qualifier.as_ref(py).borrow();
+            if q.key.to_string() == key {
+                return Some(q.value.as_str().to_string());
+            }
+        }
+        None
+    }
+
+    /// Replace the qualifiers with the given key by a single new qualifier.
+    ///
+    /// Passing `None` as the value only removes the existing qualifiers.
+    pub fn set(&mut self, py: Python, key: &str, value: Option<String>) -> PyResult<()> {
+        crate::utils::check_mutable(self.frozen, "QualifierList")?;
+        self.qualifiers
+            .retain(|q| q.as_ref(py).borrow().key.to_string() != key);
+        if let Some(v) = value {
+            let id = fastobo::ast::Ident::from(fastobo::ast::UnprefixedIdent::new(key));
+            let qualifier = Qualifier::new(id.into_py(py), fastobo::ast::QuotedString::new(v));
+            self.qualifiers.push(Py::new(py, qualifier)?);
+        }
+        Ok(())
+    }
+}
+
+impl DisplayPy for QualifierList {
+    fn fmt_py(&self, py: Python, f: &mut Formatter) -> FmtResult {
+        f.write_char('{')?;
+        for (i, qualifier) in self.qualifiers.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            qualifier.fmt_py(py, f)?;
+        }
+        f.write_char('}')
+    }
+}
+
+impl Display for QualifierList {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Python::with_gil(|py| self.fmt_py(py, f))
+    }
+}
+
+impl ClonePy for QualifierList {
+    fn clone_py(&self, py: Python) -> Self {
+        QualifierList::new(self.qualifiers.clone_py(py))
+    }
+}
+
+impl FreezePy for Py<QualifierList> {
+    fn freeze_py(&self, py: Python) -> PyResult<()> {
+        let mut list = self.try_borrow_mut(py)?;
+        list.frozen = true;
+        list.qualifiers.freeze_py(py)
+    }
+}
+
+impl IntoPy<QualifierList> for fastobo::ast::QualifierList {
+    fn into_py(self, py: Python) -> QualifierList {
+        let mut qualifiers = Vec::with_capacity((&self).len());
+        for qualifier in self.into_iter() {
+            qualifiers.push(Py::new(py, qualifier.into_py(py)).unwrap())
+        }
+        QualifierList::new(qualifiers)
+    }
+}
+
+impl IntoPy<fastobo::ast::QualifierList> for &QualifierList {
+    fn into_py(self, py: Python) -> fastobo::ast::QualifierList {
+        self.qualifiers
+            .iter()
+            .map(|q| -> fastobo::ast::Qualifier { q.as_ref(py).borrow().clone_py(py).into_py(py) })
+            .collect()
+    }
+}
+
+#[listlike(field = "qualifiers", type = "Py<Qualifier>", frozen = "frozen")]
+#[pymethods]
+impl QualifierList {
+    #[new]
+    fn __init__(qualifiers: Option<&PyAny>) -> PyResult<Self> {
+        if let Some(q) = qualifiers {
+            Python::with_gil(|py| Self::collect(py, q))
+        } else {
+            Ok(Self::new(Vec::new()))
+        }
+    }
+
+    fn __repr__(&self) -> PyResult<PyObject> {
+        Python::with_gil(|py| {
+            if self.qualifiers.is_empty() {
+                Ok("QualifierList()".to_object(py))
+            } else {
+                let fmt = PyString::new(py, "QualifierList({!r})").to_object(py);
+                fmt.call_method1(py, "format", (&self.qualifiers.to_object(py),))
+            }
+        })
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+
+    fn __len__(&self) -> PyResult<usize> {
+        Ok(self.qualifiers.len())
+    }
+
+    fn __sizeof__(&self, py: Python) -> PyResult<usize> {
+        Ok(std::mem::size_of::<PyCell<Self>>() + crate::utils::sizeof_vec(&self.qualifiers, py)?)
+    }
+
+    fn __contains__(&self, item: &PyAny) -> PyResult<bool> {
+        if let Ok(qualifier) = item.extract::<Py<Qualifier>>() {
+            let py = item.py();
+            Ok(self.qualifiers.iter().any(|q| {
+                (*q.as_ref(py).borrow()).eq_py(&qualifier.as_ref(py).borrow(), py)
+            }))
+        } else {
+            let ty = item.get_type().name()?;
+            let msg = format!(
+                "'in <QualifierList>' requires Qualifier as left operand, not {}",
+                ty
+            );
+            Err(PyTypeError::new_err(msg))
+        }
+    }
+}
@@ -1,16 +1,19 @@
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
+use std::fmt::Write;
 use std::rc::Rc;
 use std::str::FromStr;
 use std::string::ToString;
 
 use pyo3::class::basic::CompareOp;
 use pyo3::class::gc::PyVisit;
+use pyo3::exceptions::PyTypeError;
 use pyo3::exceptions::PyValueError;
 use pyo3::gc::PyTraverseError;
 use pyo3::prelude::*;
 use pyo3::types::PyAny;
+use pyo3::types::PyDict;
 use pyo3::types::PyIterator;
 use pyo3::types::PyList;
 use pyo3::types::PyString;
@@ -18,39 +21,103 @@ use pyo3::AsPyPointer;
 use pyo3::PyNativeType;
 use pyo3::PyTypeInfo;
 
+use super::header::frame::HeaderFrame;
 use super::id::Ident;
 use super::xref::XrefList;
 use crate::utils::EqPy;
+use crate::utils::FreezePy;
+use crate::utils::HashPy;
+use crate::utils::RichCmpPy;
 use crate::utils::ClonePy;
+use crate::utils::DisplayPy;
 
 // --- Module export ---------------------------------------------------------
 
 #[pymodule]
 #[pyo3(name = "syn")]
-pub fn init(_py: Python, m: &PyModule) -> PyResult<()> {
+pub fn init(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<self::Synonym>()?;
+    m.add_class::<self::SynonymList>()?;
     m.add_class::<self::SynonymScope>()?;
+    register!(py, m, SynonymList, "collections.abc", MutableSequence);
     m.add("__name__", "fastobo.syn")?;
     Ok(())
 }
 
 // --- SynonymScope ----------------------------------------------------------
 
-#[pyclass(module = "fastobo.syn")] // FIXME(@althonos): probably not needed since it is not exposed.
-#[derive(Clone, ClonePy, Debug, Eq, PartialEq, EqPy)]
-pub struct SynonymScope {
-    inner: fastobo::ast::SynonymScope,
+/// The scope of a synonym, relative to the entity it is attached to.
+///
+/// Synonym scopes are serialized using the keywords of the OBO 1.4
+/// specification. The `Synonym` constructor and setters accept either
+/// a `SynonymScope` variant, or the corresponding keyword as a `str`.
+///
+/// Example:
+///     >>> scope = fastobo.syn.SynonymScope.EXACT
+///     >>> str(scope)
+///     'EXACT'
+///     >>> synonym = fastobo.syn.Synonym("assay", scope)
+///     >>> print(synonym)
+///     "assay" EXACT []
+///
+#[pyclass(module = "fastobo.syn")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SynonymScope {
+    #[pyo3(name = "EXACT")]
+    Exact,
+    #[pyo3(name = "BROAD")]
+    Broad,
+    #[pyo3(name = "NARROW")]
+    Narrow,
+    #[pyo3(name = "RELATED")]
+    Related,
 }
 
 impl SynonymScope {
     pub fn new(scope: fastobo::ast::SynonymScope) -> Self {
-        Self { inner: scope }
+        match scope {
+            fastobo::ast::SynonymScope::Exact => SynonymScope::Exact,
+            fastobo::ast::SynonymScope::Broad => SynonymScope::Broad,
+            fastobo::ast::SynonymScope::Narrow => SynonymScope::Narrow,
+            fastobo::ast::SynonymScope::Related => SynonymScope::Related,
+        }
+    }
+
+    /// Extract a synonym scope from a `SynonymScope` variant or a `str`.
+    pub fn extract_scope(object: &PyAny) -> PyResult<Self> {
+        if let Ok(scope) = object.extract::<SynonymScope>() {
+            Ok(scope)
+        } else if let Ok(s) = object.downcast::<PyString>() {
+            Self::from_str(s.to_str()?)
+        } else {
+            let ty = object.get_type().name()?;
+            let msg = format!("expected SynonymScope or str, found {}", ty);
+            Err(PyTypeError::new_err(msg))
+        }
+    }
+}
+
+impl ClonePy for SynonymScope {
+    fn clone_py(&self, _py: Python) -> Self {
+        *self
+    }
+}
+
+impl EqPy for SynonymScope {
+    fn eq_py(&self, other: &Self, _py: Python) -> bool {
+        self == other
+    }
+}
+
+impl HashPy for SynonymScope {
+    fn hash_py<H: std::hash::Hasher>(&self, state: &mut H, _py: Python) {
+        std::hash::Hash::hash(self, state)
     }
 }
 
 impl Display for SynonymScope {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        self.inner.fmt(f)
+        fastobo::ast::SynonymScope::from(*self).fmt(f)
     }
 }
 
@@ -62,7 +129,12 @@ impl From<fastobo::ast::SynonymScope> for SynonymScope {
 
 impl From<SynonymScope> for fastobo::ast::SynonymScope {
     fn from(scope: SynonymScope) -> Self {
-        scope.inner
+        match scope {
+            SynonymScope::Exact => fastobo::ast::SynonymScope::Exact,
+            SynonymScope::Broad => fastobo::ast::SynonymScope::Broad,
+            SynonymScope::Narrow => fastobo::ast::SynonymScope::Narrow,
+            SynonymScope::Related => fastobo::ast::SynonymScope::Related,
+        }
     }
 }
 
@@ -70,10 +142,10 @@ impl FromStr for SynonymScope {
     type Err = PyErr;
     fn from_str(s: &str) -> PyResult<Self> {
         match s {
-            "EXACT" => Ok(Self::new(fastobo::ast::SynonymScope::Exact)),
-            "BROAD" => Ok(Self::new(fastobo::ast::SynonymScope::Broad)),
-            "NARROW" => Ok(Self::new(fastobo::ast::SynonymScope::Narrow)),
-            "RELATED" => Ok(Self::new(fastobo::ast::SynonymScope::Related)),
+            "EXACT" => Ok(SynonymScope::Exact),
+            "BROAD" => Ok(SynonymScope::Broad),
+            "NARROW" => Ok(SynonymScope::Narrow),
+            "RELATED" => Ok(SynonymScope::Related),
             invalid => Err(PyValueError::new_err(format!(
                 "expected 'EXACT', 'BROAD', 'NARROW' or 'RELATED', found {:?}",
                 invalid
@@ -90,7 +162,7 @@ impl IntoPy<SynonymScope> for fastobo::ast::SynonymScope {
 
 impl IntoPy<fastobo::ast::SynonymScope> for SynonymScope {
     fn into_py(self, _py: Python) -> fastobo::ast::SynonymScope {
-        self.inner
+        fastobo::ast::SynonymScope::from(self)
     }
 }
 
@@ -100,33 +172,63 @@ impl ToPyObject for SynonymScope {
     }
 }
 
+#[pymethods]
+impl SynonymScope {
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+}
+
 // --- Synonym ---------------------------------------------------------------
 
 #[pyclass(module = "fastobo.syn")]
-#[derive(Debug, EqPy)]
+#[derive(Debug, EqPy, HashPy, RichCmpPy)]
 pub struct Synonym {
     desc: fastobo::ast::QuotedString,
     scope: SynonymScope,
     ty: Option<Ident>,
-    #[pyo3(get, set)]
     xrefs: Py<XrefList>,
+    #[eqpy(ignore)]
+    frozen: bool,
 }
 
 impl ClonePy for Synonym {
     fn clone_py(&self, py: Python) -> Self {
         Self {
             desc: self.desc.clone(),
-            scope: self.scope.clone_py(py),
+            scope: self.scope,
             ty: self.ty.clone_py(py),
             xrefs: self.xrefs.clone_py(py),
+            frozen: false,
+        }
+    }
+}
+
+impl FreezePy for Py<Synonym> {
+    fn freeze_py(&self, py: Python) -> PyResult<()> {
+        let mut synonym = self.try_borrow_mut(py)?;
+        synonym.frozen = true;
+        synonym.xrefs.freeze_py(py)
+    }
+}
+
+impl DisplayPy for Synonym {
+    fn fmt_py(&self, py: Python, f: &mut Formatter) -> FmtResult {
+        self.desc.fmt(f)?;
+        f.write_char(' ')?;
+        self.scope.fmt(f)?;
+        if let Some(ty) = &self.ty {
+            f.write_char(' ')?;
+            ty.fmt_py(py, f)?;
         }
+        f.write_char(' ')?;
+        self.xrefs.fmt_py(py, f)
     }
 }
 
 impl Display for Synonym {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let syn: fastobo::ast::Synonym = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        syn.fmt(f)
+        Python::with_gil(|py| self.fmt_py(py, f))
     }
 }
 
@@ -138,6 +240,7 @@ impl IntoPy<Synonym> for fastobo::ast::Synonym {
             ty: self.ty().map(|id| id.clone().into_py(py)),
             xrefs: Py::new(py, std::mem::take(self.xrefs_mut()).into_py(py))
                 .expect("failed allocating memory on Python heap"),
+            frozen: false,
         }
     }
 }
@@ -146,7 +249,7 @@ impl IntoPy<fastobo::ast::Synonym> for Synonym {
     fn into_py(self, py: Python) -> fastobo::ast::Synonym {
         fastobo::ast::Synonym::with_type_and_xrefs(
             self.desc,
-            self.scope.inner,
+            self.scope.into(),
             self.ty.map(|ty| ty.into_py(py)),
             (&*self.xrefs.as_ref(py).borrow()).into_py(py),
         )
@@ -158,7 +261,7 @@ impl Synonym {
     #[new]
     pub fn __init__(
         desc: String,
-        scope: &str,
+        scope: &PyAny,
         ty: Option<Ident>,
         xrefs: Option<&PyAny>,
     ) -> PyResult<Self> {
@@ -171,9 +274,10 @@ impl Synonym {
         })?;
         Ok(Self {
             desc: fastobo::ast::QuotedString::new(desc),
-            scope: SynonymScope::from_str(scope)?,
+            scope: SynonymScope::extract_scope(scope)?,
             xrefs,
             ty,
+            frozen: false,
         })
     }
 
@@ -186,12 +290,11 @@ impl Synonym {
     }
 
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(
-            self,
-            other,
-            op,
-            self.desc && self.scope && self.ty && self.xrefs
-        )
+        self.richcmp_py(other, op)
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
     #[getter]
@@ -201,6 +304,7 @@ impl Synonym {
 
     #[setter]
     pub fn set_desc(&mut self, desc: String) -> PyResult<()> {
+        crate::utils::check_mutable(self.frozen, "Synonym")?;
         self.desc = fastobo::ast::QuotedString::new(desc);
         Ok(())
     }
@@ -211,8 +315,9 @@ impl Synonym {
     }
 
     #[setter]
-    pub fn set_scope(&mut self, scope: &str) -> PyResult<()> {
-        self.scope = scope.parse()?;
+    pub fn set_scope(&mut self, scope: &PyAny) -> PyResult<()> {
+        crate::utils::check_mutable(self.frozen, "Synonym")?;
+        self.scope = SynonymScope::extract_scope(scope)?;
         Ok(())
     }
 
@@ -223,7 +328,109 @@ impl Synonym {
 
     #[setter]
     pub fn set_type(&mut self, ty: Option<Ident>) -> PyResult<()> {
+        crate::utils::check_mutable(self.frozen, "Synonym")?;
         self.ty = ty;
         Ok(())
     }
+
+    /// `~fastobo.xref.XrefList`: the cross-references supporting the synonym.
+    ///
+    /// The list is not copied, so it can be edited in place, e.g. to add
+    /// new evidence to an existing synonym. It can also be replaced by any
+    /// iterable of `~fastobo.xref.Xref`.
+    ///
+    /// Example:
+    ///     >>> synonym = fastobo.syn.Synonym("assay", "EXACT")
+    ///     >>> synonym.xrefs.append(
+    ///     ...     fastobo.xref.Xref(fastobo.id.PrefixedIdent("PMID", "123"))
+    ///     ... )
+    ///     >>> print(synonym)
+    ///     "assay" EXACT [PMID:123]
+    ///
+    #[getter]
+    pub fn get_xrefs(&self, py: Python) -> Py<XrefList> {
+        self.xrefs.clone_ref(py)
+    }
+
+    #[setter]
+    pub fn set_xrefs(&mut self, xrefs: &PyAny) -> PyResult<()> {
+        crate::utils::check_mutable(self.frozen, "Synonym")?;
+        let py = xrefs.py();
+        self.xrefs = match xrefs.extract::<Py<XrefList>>() {
+            Ok(list) => list,
+            Err(_) => Py::new(py, XrefList::collect(py, xrefs)?)?,
+        };
+        Ok(())
+    }
+
+    /// Resolve the type of the synonym against the declarations of a header.
+    ///
+    /// Arguments:
+    ///     header (~fastobo.header.HeaderFrame): The header frame declaring
+    ///         the synonym types, usually the header of the document the
+    ///         synonym belongs to.
+    ///
+    /// Returns:
+    ///     `tuple` or `None`: The description and the scope (or `None`)
+    ///     declared for the synonym type, or `None` if the synonym does
+    ///     not have a type.
+    ///
+    /// Raises:
+    ///     ValueError: When the synonym type is not declared in the header
+    ///         with a ``synonymtypedef`` clause.
+    ///
+    /// Example:
+    ///     >>> header = fastobo.header.HeaderFrame([
+    ///     ...     fastobo.header.SynonymTypedefClause(
+    ///     ...         fastobo.id.UnprefixedIdent("systematic_synonym"),
+    ///     ...         "Systematic synonym",
+    ///     ...         "EXACT",
+    ///     ...     )
+    ///     ... ])
+    ///     >>> synonym = fastobo.syn.Synonym(
+    ///     ...     "C6H12O6",
+    ///     ...     "EXACT",
+    ///     ...     fastobo.id.UnprefixedIdent("systematic_synonym"),
+    ///     ... )
+    ///     >>> synonym.resolve_type(header)
+    ///     ('Systematic synonym', 'EXACT')
+    ///
+    #[pyo3(text_signature = "(self, header)")]
+    pub fn resolve_type(
+        &self,
+        header: &PyCell<HeaderFrame>,
+    ) -> PyResult<Option<(String, Option<String>)>> {
+        let ty = match &self.ty {
+            Some(ty) => ty.to_string(),
+            None => return Ok(None),
+        };
+        let typedefs = header.getattr("synonymtypedefs")?.downcast::<PyDict>()?;
+        match typedefs.get_item(ty.as_str()) {
+            Some(clause) => Ok(Some((
+                clause.getattr("description")?.extract()?,
+                clause.getattr("scope")?.extract()?,
+            ))),
+            None => Err(PyValueError::new_err(format!(
+                "undeclared synonym type: {}",
+                ty
+            ))),
+        }
+    }
 }
+
+// --- SynonymList -----------------------------------------------------------
+
+/// A list of synonyms.
+///
+/// Example:
+///     >>> synonyms = fastobo.syn.SynonymList([
+///     ...     fastobo.syn.Synonym("assay", "EXACT"),
+///     ...     fastobo.syn.Synonym("test", "BROAD"),
+///     ... ])
+///     >>> print(synonyms)
+///     ["assay" EXACT [], "test" BROAD []]
+///     >>> synonyms[1].desc
+///     'test'
+///
+#[pylist(type = "Py<Synonym>", module = "fastobo.syn")]
+pub struct SynonymList;
@@ -7,14 +7,14 @@ use std::fmt::Write;
 use std::str::FromStr;
 
 use pyo3::class::basic::CompareOp;
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::PyAny;
-use pyo3::types::PyDate;
 use pyo3::types::PyDateAccess;
-use pyo3::types::PyDateTime;
+use pyo3::types::PyDict;
 use pyo3::types::PyString;
 use pyo3::types::PyTimeAccess;
+use pyo3::types::PyTuple;
 use pyo3::types::PyTzInfo;
 use pyo3::AsPyPointer;
 use pyo3::PyNativeType;
@@ -22,37 +22,44 @@ use pyo3::PyTypeInfo;
 
 use fastobo::ast;
 
+use super::super::abc::AbstractClause;
 use super::super::abc::AbstractEntityClause;
 use super::super::id::Ident;
 use super::super::pv::PropertyValue;
 use super::super::syn::Synonym;
 use super::super::xref::Xref;
 use super::super::xref::XrefList;
-use crate::date::date_to_isodate;
-use crate::date::datetime_to_isodatetime;
+use crate::date::extract_creation_date;
 use crate::date::isodate_to_date;
 use crate::date::isodatetime_to_datetime;
-use crate::raise;
+use crate::date::NaivePolicy;
 use crate::utils::AbstractClass;
 use crate::utils::ClonePy;
+use crate::utils::DisplayPy;
 use crate::utils::EqPy;
 use crate::utils::FinalClass;
+use crate::utils::FreezePy;
+use crate::utils::HashPy;
+use crate::utils::Lazy;
+use crate::utils::PooledString;
+use crate::utils::RichCmpPy;
 
 // --- Conversion Wrapper ----------------------------------------------------
 
-#[derive(ClonePy, Debug, PyWrapper, EqPy)]
-#[wraps(BaseTermClause)]
+#[derive(ClonePy, Debug, DisplayPy, PyWrapper, EqPy, HashPy)]
+#[wraps(BaseTermClause, parse)]
+#[eqpy(with = "AbstractEntityClause::eq_metadata_of")]
 pub enum TermClause {
-    IsAnonymous(Py<IsAnonymousClause>),
-    Name(Py<NameClause>),
+    IsAnonymous(Lazy<IsAnonymousClause>),
+    Name(Lazy<NameClause>),
     Namespace(Py<NamespaceClause>),
     AltId(Py<AltIdClause>),
     Def(Py<DefClause>),
-    Comment(Py<CommentClause>),
+    Comment(Lazy<CommentClause>),
     Subset(Py<SubsetClause>),
     Synonym(Py<SynonymClause>),
     Xref(Py<XrefClause>),
-    Builtin(Py<BuiltinClause>),
+    Builtin(Lazy<BuiltinClause>),
     PropertyValue(Py<PropertyValueClause>),
     IsA(Py<IsAClause>),
     IntersectionOf(Py<IntersectionOfClause>),
@@ -60,19 +67,62 @@ pub enum TermClause {
     EquivalentTo(Py<EquivalentToClause>),
     DisjointFrom(Py<DisjointFromClause>),
     Relationship(Py<RelationshipClause>),
-    IsObsolete(Py<IsObsoleteClause>),
+    IsObsolete(Lazy<IsObsoleteClause>),
     ReplacedBy(Py<ReplacedByClause>),
     Consider(Py<ConsiderClause>),
-    CreatedBy(Py<CreatedByClause>),
+    CreatedBy(Lazy<CreatedByClause>),
     CreationDate(Py<CreationDateClause>),
 }
 
+impl TermClause {
+    /// Get the raw tag of the clause, without converting it.
+    pub fn raw_tag(&self) -> &'static str {
+        match self {
+            TermClause::IsAnonymous(_) => IsAnonymousClause::TAG,
+            TermClause::Name(_) => NameClause::TAG,
+            TermClause::Namespace(_) => NamespaceClause::TAG,
+            TermClause::AltId(_) => AltIdClause::TAG,
+            TermClause::Def(_) => DefClause::TAG,
+            TermClause::Comment(_) => CommentClause::TAG,
+            TermClause::Subset(_) => SubsetClause::TAG,
+            TermClause::Synonym(_) => SynonymClause::TAG,
+            TermClause::Xref(_) => XrefClause::TAG,
+            TermClause::Builtin(_) => BuiltinClause::TAG,
+            TermClause::PropertyValue(_) => PropertyValueClause::TAG,
+            TermClause::IsA(_) => IsAClause::TAG,
+            TermClause::IntersectionOf(_) => IntersectionOfClause::TAG,
+            TermClause::UnionOf(_) => UnionOfClause::TAG,
+            TermClause::EquivalentTo(_) => EquivalentToClause::TAG,
+            TermClause::DisjointFrom(_) => DisjointFromClause::TAG,
+            TermClause::Relationship(_) => RelationshipClause::TAG,
+            TermClause::IsObsolete(_) => IsObsoleteClause::TAG,
+            TermClause::ReplacedBy(_) => ReplacedByClause::TAG,
+            TermClause::Consider(_) => ConsiderClause::TAG,
+            TermClause::CreatedBy(_) => CreatedByClause::TAG,
+            TermClause::CreationDate(_) => CreationDateClause::TAG,
+        }
+    }
+}
+
+impl FreezePy for TermClause {
+    fn freeze_py(&self, py: Python) -> PyResult<()> {
+        AbstractClause::freeze_object(self.to_object(py).as_ref(py))?;
+        match self {
+            TermClause::Def(c) => c.try_borrow(py)?.xrefs.freeze_py(py),
+            TermClause::Synonym(c) => c.try_borrow(py)?.synonym.freeze_py(py),
+            TermClause::Xref(c) => c.try_borrow(py)?.xref.freeze_py(py),
+            TermClause::PropertyValue(c) => c.try_borrow(py)?.inner.freeze_py(py),
+            _ => Ok(()),
+        }
+    }
+}
+
 impl IntoPy<TermClause> for fastobo::ast::TermClause {
     fn into_py(self, py: Python) -> TermClause {
         use fastobo::ast::TermClause::*;
         match self {
-            IsAnonymous(b) => Py::new(py, IsAnonymousClause::new(b)).map(TermClause::IsAnonymous),
-            Name(n) => Py::new(py, NameClause::new(*n)).map(TermClause::Name),
+            IsAnonymous(b) => Ok(TermClause::IsAnonymous(Lazy::new(IsAnonymousClause::new(b)))),
+            Name(n) => Ok(TermClause::Name(Lazy::new(NameClause::new(*n)))),
             Namespace(ns) => {
                 Py::new(py, NamespaceClause::new(ns.into_py(py))).map(TermClause::Namespace)
             }
@@ -82,7 +132,7 @@ impl IntoPy<TermClause> for fastobo::ast::TermClause {
                 let xrefs = std::mem::take(def.xrefs_mut()).into_py(py);
                 Py::new(py, DefClause::new(text, xrefs)).map(TermClause::Def)
             }
-            Comment(c) => Py::new(py, CommentClause::new(*c)).map(TermClause::Comment),
+            Comment(c) => Ok(TermClause::Comment(Lazy::new(CommentClause::new(*c)))),
             Subset(s) => Py::new(py, SubsetClause::new(s.into_py(py))).map(TermClause::Subset),
             Synonym(s) => Py::new(py, s.into_py(py))
                 .map(SynonymClause::new)
@@ -92,7 +142,7 @@ impl IntoPy<TermClause> for fastobo::ast::TermClause {
                 .map(XrefClause::new)
                 .and_then(|clause| Py::new(py, clause))
                 .map(TermClause::Xref),
-            Builtin(b) => Py::new(py, BuiltinClause::new(b)).map(TermClause::Builtin),
+            Builtin(b) => Ok(TermClause::Builtin(Lazy::new(BuiltinClause::new(b)))),
             PropertyValue(pv) => {
                 Py::new(py, PropertyValueClause::new(pv.into_py(py))).map(TermClause::PropertyValue)
             }
@@ -115,14 +165,14 @@ impl IntoPy<TermClause> for fastobo::ast::TermClause {
                 Py::new(py, RelationshipClause::new(r.into_py(py), id.into_py(py)))
                     .map(TermClause::Relationship)
             }
-            IsObsolete(b) => Py::new(py, IsObsoleteClause::new(b)).map(TermClause::IsObsolete),
+            IsObsolete(b) => Ok(TermClause::IsObsolete(Lazy::new(IsObsoleteClause::new(b)))),
             ReplacedBy(id) => {
                 Py::new(py, ReplacedByClause::new(id.into_py(py))).map(TermClause::ReplacedBy)
             }
             Consider(id) => {
                 Py::new(py, ConsiderClause::new(id.into_py(py))).map(TermClause::Consider)
             }
-            CreatedBy(name) => Py::new(py, CreatedByClause::new(*name)).map(TermClause::CreatedBy),
+            CreatedBy(name) => Ok(TermClause::CreatedBy(Lazy::new(CreatedByClause::new(*name)))),
             CreationDate(dt) => {
                 Py::new(py, CreationDateClause::new(*dt)).map(TermClause::CreationDate)
             }
@@ -139,6 +189,17 @@ impl IntoPy<TermClause> for fastobo::ast::TermClause {
 #[base(AbstractEntityClause)]
 pub struct BaseTermClause {}
 
+#[pymethods]
+impl BaseTermClause {
+    // Allow user-defined subclasses, which are converted through their
+    // `raw_tag` and `raw_value` when they are added to a frame.
+    #[new]
+    #[pyo3(signature = (*_args, **_kwargs))]
+    fn __new__(_args: &PyTuple, _kwargs: Option<&PyDict>) -> PyClassInitializer<Self> {
+        Self::initializer()
+    }
+}
+
 // --- IsAnonymous -----------------------------------------------------------
 
 /// IsAnonymousClause(anonymous)
@@ -146,7 +207,7 @@ pub struct BaseTermClause {}
 ///
 /// A clause declaring whether or not the current term has an anonymous id.
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct IsAnonymousClause {
     #[pyo3(get, set)]
@@ -159,11 +220,7 @@ impl IsAnonymousClause {
     }
 }
 
-impl Display for IsAnonymousClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TermClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(IsAnonymousClause, anonymous);
 
 impl From<IsAnonymousClause> for fastobo::ast::TermClause {
     fn from(clause: IsAnonymousClause) -> Self {
@@ -177,6 +234,7 @@ impl IntoPy<fastobo::ast::TermClause> for IsAnonymousClause {
     }
 }
 
+#[obo_clause(tag = "is_anonymous")]
 #[pymethods]
 impl IsAnonymousClause {
     #[new]
@@ -192,16 +250,12 @@ impl IsAnonymousClause {
         Ok(self.to_string())
     }
 
-    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.anonymous)
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "is_anonymous").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.anonymous.to_string()
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
     }
 }
 
@@ -212,7 +266,7 @@ impl IsAnonymousClause {
 ///
 /// A term clause declaring the human-readable name of this term.
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct NameClause {
     name: fastobo::ast::UnquotedString,
@@ -222,14 +276,15 @@ impl NameClause {
     pub fn new(name: fastobo::ast::UnquotedString) -> Self {
         Self { name }
     }
-}
 
-impl Display for NameClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TermClause::from(self.clone()).fmt(f)
+    /// Get a reference to the name of the clause.
+    pub fn name(&self) -> &fastobo::ast::UnquotedString {
+        &self.name
     }
 }
 
+impl_display_py!(NameClause, name);
+
 impl From<NameClause> for fastobo::ast::TermClause {
     fn from(clause: NameClause) -> Self {
         fastobo::ast::TermClause::Name(Box::new(clause.name))
@@ -242,6 +297,7 @@ impl IntoPy<fastobo::ast::TermClause> for NameClause {
     }
 }
 
+#[obo_clause(tag = "name")]
 #[pymethods]
 impl NameClause {
     #[new]
@@ -257,8 +313,12 @@ impl NameClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.name)
+        self.richcmp_py(other, op)
     }
 
     /// `str`: the name of the current term.
@@ -271,14 +331,6 @@ impl NameClause {
     fn set_name(&mut self, name: String) {
         self.name = fastobo::ast::UnquotedString::new(name);
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "name").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.name.to_string()
-    }
 }
 
 // --- Namespace -------------------------------------------------------------
@@ -288,7 +340,7 @@ impl NameClause {
 ///
 /// A term clause declaring the namespace of this term.
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct NamespaceClause {
     #[pyo3(set)]
@@ -309,12 +361,7 @@ impl ClonePy for NamespaceClause {
     }
 }
 
-impl Display for NamespaceClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TermClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(NamespaceClause, namespace);
 
 impl IntoPy<fastobo::ast::TermClause> for NamespaceClause {
     fn into_py(self, py: Python) -> fastobo::ast::TermClause {
@@ -323,6 +370,7 @@ impl IntoPy<fastobo::ast::TermClause> for NamespaceClause {
     }
 }
 
+#[obo_clause(tag = "namespace")]
 #[pymethods]
 impl NamespaceClause {
     #[new]
@@ -338,8 +386,12 @@ impl NamespaceClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.namespace)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -347,14 +399,6 @@ impl NamespaceClause {
     fn get_namespace(&self) -> &Ident {
         &self.namespace
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "namespace").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.namespace.to_string()
-    }
 }
 
 // --- AltId -----------------------------------------------------------------
@@ -364,7 +408,7 @@ impl NamespaceClause {
 ///
 /// A clause defines an alternate id for this term.
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct AltIdClause {
     #[pyo3(set)]
@@ -385,12 +429,7 @@ impl ClonePy for AltIdClause {
     }
 }
 
-impl Display for AltIdClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TermClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(AltIdClause, alt_id);
 
 impl IntoPy<fastobo::ast::TermClause> for AltIdClause {
     fn into_py(self, py: Python) -> fastobo::ast::TermClause {
@@ -398,6 +437,7 @@ impl IntoPy<fastobo::ast::TermClause> for AltIdClause {
     }
 }
 
+#[obo_clause(tag = "alt_id")]
 #[pymethods]
 impl AltIdClause {
     #[new]
@@ -413,8 +453,12 @@ impl AltIdClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.alt_id)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -422,14 +466,6 @@ impl AltIdClause {
     fn get_alt_id(&self) -> &Ident {
         &self.alt_id
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "alt_id").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.alt_id.to_string()
-    }
 }
 
 // --- Def -------------------------------------------------------------------
@@ -447,7 +483,7 @@ impl AltIdClause {
 ///         definition, or `None`.
 ///
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct DefClause {
     definition: fastobo::ast::QuotedString,
@@ -458,6 +494,16 @@ impl DefClause {
     pub fn new(definition: fastobo::ast::QuotedString, xrefs: XrefList) -> Self {
         Self { definition, xrefs }
     }
+
+    /// Get a reference to the textual definition of the clause.
+    pub fn definition(&self) -> &fastobo::ast::QuotedString {
+        &self.definition
+    }
+
+    /// Get a reference to the cross-references supporting the definition.
+    pub fn xrefs(&self) -> &XrefList {
+        &self.xrefs
+    }
 }
 
 impl ClonePy for DefClause {
@@ -469,12 +515,7 @@ impl ClonePy for DefClause {
     }
 }
 
-impl Display for DefClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TermClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(DefClause, definition, xrefs);
 
 impl IntoPy<fastobo::ast::TermClause> for DefClause {
     fn into_py(self, py: Python) -> fastobo::ast::TermClause {
@@ -484,6 +525,7 @@ impl IntoPy<fastobo::ast::TermClause> for DefClause {
     }
 }
 
+#[obo_clause(tag = "def")]
 #[pymethods]
 impl DefClause {
     #[new]
@@ -509,8 +551,12 @@ impl DefClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.definition && self.xrefs)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -530,10 +576,6 @@ impl DefClause {
         self.xrefs.clone_py(py)
     }
 
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "def").to_object(slf.py())
-    }
-
     fn raw_value(&self) -> String {
         self.definition.to_string()
     }
@@ -546,7 +588,7 @@ impl DefClause {
 ///
 /// A clause storing a comment for this term.
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct CommentClause {
     comment: fastobo::ast::UnquotedString,
@@ -558,11 +600,7 @@ impl CommentClause {
     }
 }
 
-impl Display for CommentClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TermClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(CommentClause, comment);
 
 impl From<CommentClause> for fastobo::ast::TermClause {
     fn from(clause: CommentClause) -> Self {
@@ -576,6 +614,7 @@ impl IntoPy<fastobo::ast::TermClause> for CommentClause {
     }
 }
 
+#[obo_clause(tag = "comment")]
 #[pymethods]
 impl CommentClause {
     #[new]
@@ -591,8 +630,12 @@ impl CommentClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.comment)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -605,14 +648,6 @@ impl CommentClause {
     fn set_comment(&mut self, comment: String) {
         self.comment = fastobo::ast::UnquotedString::new(comment);
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "comment").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.comment.to_string()
-    }
 }
 
 // --- Subset ----------------------------------------------------------------
@@ -622,7 +657,7 @@ impl CommentClause {
 ///
 /// A clause declaring a subset to which this term belongs.
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct SubsetClause {
     #[pyo3(set)]
@@ -643,12 +678,7 @@ impl ClonePy for SubsetClause {
     }
 }
 
-impl Display for SubsetClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TermClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(SubsetClause, subset);
 
 impl IntoPy<fastobo::ast::TermClause> for SubsetClause {
     fn into_py(self, py: Python) -> fastobo::ast::TermClause {
@@ -656,6 +686,7 @@ impl IntoPy<fastobo::ast::TermClause> for SubsetClause {
     }
 }
 
+#[obo_clause(tag = "subset")]
 #[pymethods]
 impl SubsetClause {
     #[new]
@@ -671,8 +702,12 @@ impl SubsetClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.subset)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -680,14 +715,6 @@ impl SubsetClause {
     fn get_subset(&self) -> &Ident {
         &self.subset
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "subset").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.subset.to_string()
-    }
 }
 
 // --- Synonym ---------------------------------------------------------------
@@ -697,7 +724,7 @@ impl SubsetClause {
 ///
 /// A clause giving a synonym for this term, with some cross-references.
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct SynonymClause {
     #[pyo3(set)]
@@ -708,6 +735,11 @@ impl SynonymClause {
     pub fn new(synonym: Py<Synonym>) -> Self {
         Self { synonym }
     }
+
+    /// Get a reference to the synonym of the clause.
+    pub fn synonym(&self) -> &Py<Synonym> {
+        &self.synonym
+    }
 }
 
 impl ClonePy for SynonymClause {
@@ -718,12 +750,7 @@ impl ClonePy for SynonymClause {
     }
 }
 
-impl Display for SynonymClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TermClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(SynonymClause, synonym);
 
 impl IntoPy<fastobo::ast::TermClause> for SynonymClause {
     fn into_py(self, py: Python) -> fastobo::ast::TermClause {
@@ -733,6 +760,7 @@ impl IntoPy<fastobo::ast::TermClause> for SynonymClause {
     }
 }
 
+#[obo_clause(tag = "synonym")]
 #[pymethods]
 impl SynonymClause {
     #[new]
@@ -748,8 +776,12 @@ impl SynonymClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.synonym)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -757,16 +789,6 @@ impl SynonymClause {
     fn get_synonym<'py>(&self, py: Python<'py>) -> Py<Synonym> {
         self.synonym.clone_py(py)
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "synonym").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        Python::with_gil(|py| {
-            format!("{}", &*self.synonym.as_ref(py).borrow())
-        })
-    }
 }
 
 // --- Xref ------------------------------------------------------------------
@@ -776,7 +798,7 @@ impl SynonymClause {
 ///
 /// A cross-reference that describes an analogous term in another vocabulary.
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct XrefClause {
     #[pyo3(get, set)]
@@ -788,6 +810,11 @@ impl XrefClause {
     pub fn new(xref: Py<Xref>) -> Self {
         Self { xref }
     }
+
+    /// Get a reference to the cross-reference of the clause.
+    pub fn xref(&self) -> &Py<Xref> {
+        &self.xref
+    }
 }
 
 impl ClonePy for XrefClause {
@@ -798,12 +825,7 @@ impl ClonePy for XrefClause {
     }
 }
 
-impl Display for XrefClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TermClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(XrefClause, xref);
 
 impl From<Py<Xref>> for XrefClause {
     fn from(xref: Py<Xref>) -> Self {
@@ -828,6 +850,7 @@ impl IntoPy<XrefClause> for Xref {
     }
 }
 
+#[obo_clause(tag = "xref")]
 #[pymethods]
 impl XrefClause {
     #[new]
@@ -843,16 +866,12 @@ impl XrefClause {
         Ok(self.to_string())
     }
 
-    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.xref)
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "xref").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        Python::with_gil(|py| self.xref.as_ref(py).to_string())
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
     }
 }
 
@@ -863,7 +882,7 @@ impl XrefClause {
 ///
 /// A clause declaring whether or not this term is built-in to the OBO format.
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct BuiltinClause {
     #[pyo3(set)]
@@ -876,11 +895,7 @@ impl BuiltinClause {
     }
 }
 
-impl Display for BuiltinClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TermClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(BuiltinClause, builtin);
 
 impl From<BuiltinClause> for fastobo::ast::TermClause {
     fn from(clause: BuiltinClause) -> Self {
@@ -894,6 +909,7 @@ impl IntoPy<fastobo::ast::TermClause> for BuiltinClause {
     }
 }
 
+#[obo_clause(tag = "builtin")]
 #[pymethods]
 impl BuiltinClause {
     #[new]
@@ -909,8 +925,12 @@ impl BuiltinClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.builtin)
+        self.richcmp_py(other, op)
     }
 
     /// `bool`: ``True`` if the term is built in the OBO format.
@@ -918,14 +938,6 @@ impl BuiltinClause {
     fn get_builtin(&self) -> bool {
         self.builtin
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "builtin").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.builtin.to_string()
-    }
 }
 
 // --- PropertyValue ---------------------------------------------------------
@@ -940,7 +952,7 @@ impl BuiltinClause {
 ///         to annotate the current term.
 ///
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct PropertyValueClause {
     #[pyo3(set)]
@@ -963,12 +975,7 @@ impl ClonePy for PropertyValueClause {
     }
 }
 
-impl Display for PropertyValueClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TermClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(PropertyValueClause, inner);
 
 impl IntoPy<fastobo::ast::TermClause> for PropertyValueClause {
     fn into_py(self, py: Python) -> ast::TermClause {
@@ -976,6 +983,7 @@ impl IntoPy<fastobo::ast::TermClause> for PropertyValueClause {
     }
 }
 
+#[obo_clause(tag = "property_value")]
 #[pymethods]
 impl PropertyValueClause {
     #[new]
@@ -991,8 +999,12 @@ impl PropertyValueClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.inner)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -1000,14 +1012,6 @@ impl PropertyValueClause {
     fn get_property_value(&self) -> &PropertyValue {
         &self.inner
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "property_value").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.inner.to_string()
-    }
 }
 
 // --- IsA -------------------------------------------------------------------
@@ -1017,10 +1021,9 @@ impl PropertyValueClause {
 ///
 /// A clause declaring this term is a subclass of another term.
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct IsAClause {
-    #[pyo3(set)]
     term: Ident,
 }
 
@@ -1028,6 +1031,11 @@ impl IsAClause {
     pub fn new(term: Ident) -> Self {
         Self { term }
     }
+
+    /// Get a reference to the identifier of the parent term.
+    pub fn term(&self) -> &Ident {
+        &self.term
+    }
 }
 
 impl ClonePy for IsAClause {
@@ -1038,12 +1046,7 @@ impl ClonePy for IsAClause {
     }
 }
 
-impl Display for IsAClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TermClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(IsAClause, term);
 
 impl IntoPy<fastobo::ast::TermClause> for IsAClause {
     fn into_py(self, py: Python) -> fastobo::ast::TermClause {
@@ -1051,6 +1054,7 @@ impl IntoPy<fastobo::ast::TermClause> for IsAClause {
     }
 }
 
+#[obo_clause(tag = "is_a")]
 #[pymethods]
 impl IsAClause {
     #[new]
@@ -1066,8 +1070,12 @@ impl IsAClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.term)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -1076,12 +1084,10 @@ impl IsAClause {
         &self.term
     }
 
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "is_a").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.term.to_string()
+    #[setter]
+    fn set_term(&mut self, term: Ident) {
+        crate::index::invalidate();
+        self.term = term;
     }
 }
 
@@ -1116,7 +1122,7 @@ impl IsAClause {
 ///     ... ))
 ///
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct IntersectionOfClause {
     typedef: Option<Ident>,
@@ -1138,10 +1144,21 @@ impl ClonePy for IntersectionOfClause {
     }
 }
 
+impl DisplayPy for IntersectionOfClause {
+    fn fmt_py(&self, py: Python, f: &mut Formatter) -> FmtResult {
+        f.write_str(Self::TAG)?;
+        f.write_str(": ")?;
+        if let Some(typedef) = &self.typedef {
+            typedef.fmt_py(py, f)?;
+            f.write_char(' ')?;
+        }
+        self.term.fmt_py(py, f)
+    }
+}
+
 impl Display for IntersectionOfClause {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TermClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
+        Python::with_gil(|py| self.fmt_py(py, f))
     }
 }
 
@@ -1154,6 +1171,7 @@ impl IntoPy<fastobo::ast::TermClause> for IntersectionOfClause {
     }
 }
 
+#[obo_clause(tag = "intersection_of")]
 #[pymethods]
 impl IntersectionOfClause {
     #[new]
@@ -1170,8 +1188,12 @@ impl IntersectionOfClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.typedef && self.term)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -1185,18 +1207,6 @@ impl IntersectionOfClause {
     fn get_typedef(&self) -> Option<&Ident> {
         self.typedef.as_ref()
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "intersection_of").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        if let Some(ref rel) = self.typedef {
-            format!("{} {}", rel, &self.term)
-        } else {
-            format!("{}", &self.term)
-        }
-    }
 }
 
 // --- UnionOf ---------------------------------------------------------------
@@ -1206,7 +1216,7 @@ impl IntersectionOfClause {
 ///
 /// A clause indicating the term represents the union of several other terms.
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct UnionOfClause {
     term: Ident,
@@ -1226,12 +1236,7 @@ impl ClonePy for UnionOfClause {
     }
 }
 
-impl Display for UnionOfClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TermClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(UnionOfClause, term);
 
 impl IntoPy<fastobo::ast::TermClause> for UnionOfClause {
     fn into_py(self, py: Python) -> fastobo::ast::TermClause {
@@ -1239,11 +1244,12 @@ impl IntoPy<fastobo::ast::TermClause> for UnionOfClause {
     }
 }
 
+#[obo_clause(tag = "union_of")]
 #[pymethods]
 impl UnionOfClause {
     #[new]
-    fn __init__(id: Ident) -> PyClassInitializer<Self> {
-        Self::new(id).into()
+    fn __init__(term: Ident) -> PyClassInitializer<Self> {
+        Self::new(term).into()
     }
 
     fn __repr__(&self) -> PyResult<PyObject> {
@@ -1254,8 +1260,12 @@ impl UnionOfClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.term)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -1263,14 +1273,6 @@ impl UnionOfClause {
     fn get_term(&self) -> &Ident {
         &self.term
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "union_of").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.term.to_string()
-    }
 }
 
 // --- EquivalentTo ----------------------------------------------------------
@@ -1280,7 +1282,7 @@ impl UnionOfClause {
 ///
 /// A clause indicating the term is exactly equivalent to another term.
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct EquivalentToClause {
     term: Ident,
@@ -1300,12 +1302,7 @@ impl ClonePy for EquivalentToClause {
     }
 }
 
-impl Display for EquivalentToClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TermClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(EquivalentToClause, term);
 
 impl IntoPy<fastobo::ast::TermClause> for EquivalentToClause {
     fn into_py(self, py: Python) -> fastobo::ast::TermClause {
@@ -1313,6 +1310,7 @@ impl IntoPy<fastobo::ast::TermClause> for EquivalentToClause {
     }
 }
 
+#[obo_clause(tag = "equivalent_to")]
 #[pymethods]
 impl EquivalentToClause {
     #[new]
@@ -1328,8 +1326,12 @@ impl EquivalentToClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.term)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -1337,14 +1339,6 @@ impl EquivalentToClause {
     fn get_term(&self) -> &Ident {
         &self.term
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "equivalent_to").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.term.to_string()
-    }
 }
 
 // --- DisjointFrom ----------------------------------------------------------
@@ -1354,7 +1348,7 @@ impl EquivalentToClause {
 ///
 /// A clause stating this term has no instances in common with another term.
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct DisjointFromClause {
     #[pyo3(set)]
@@ -1375,12 +1369,7 @@ impl ClonePy for DisjointFromClause {
     }
 }
 
-impl Display for DisjointFromClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TermClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(DisjointFromClause, term);
 
 impl IntoPy<fastobo::ast::TermClause> for DisjointFromClause {
     fn into_py(self, py: Python) -> fastobo::ast::TermClause {
@@ -1388,6 +1377,7 @@ impl IntoPy<fastobo::ast::TermClause> for DisjointFromClause {
     }
 }
 
+#[obo_clause(tag = "disjoint_from")]
 #[pymethods]
 impl DisjointFromClause {
     #[new]
@@ -1403,8 +1393,12 @@ impl DisjointFromClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.term)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -1412,14 +1406,6 @@ impl DisjointFromClause {
     fn get_term(&self) -> &Ident {
         &self.term
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "disjoint_from").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.term.to_string()
-    }
 }
 
 // --- Relationship ----------------------------------------------------------
@@ -1429,12 +1415,10 @@ impl DisjointFromClause {
 ///
 /// A clause describing a typed relationship between this term and another term.
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct RelationshipClause {
-    #[pyo3(set)]
     typedef: Ident,
-    #[pyo3(set)]
     term: Ident,
 }
 
@@ -1442,6 +1426,27 @@ impl RelationshipClause {
     pub fn new(typedef: Ident, term: Ident) -> Self {
         Self { typedef, term }
     }
+
+    /// Get a reference to the identifier of the relationship.
+    pub fn typedef(&self) -> &Ident {
+        &self.typedef
+    }
+
+    /// Get a reference to the identifier of the target term.
+    pub fn term(&self) -> &Ident {
+        &self.term
+    }
+
+    /// Parse the identifier stored in a GCI qualifier, if any.
+    fn parse_qualifier(py: Python, value: Option<String>) -> PyResult<Option<Ident>> {
+        match value {
+            None => Ok(None),
+            Some(v) => match ast::Ident::from_str(&v) {
+                Ok(id) => Ok(Some(id.into_py(py))),
+                Err(_) => Err(PyValueError::new_err(format!("invalid identifier: {:?}", v))),
+            },
+        }
+    }
 }
 
 impl ClonePy for RelationshipClause {
@@ -1453,12 +1458,7 @@ impl ClonePy for RelationshipClause {
     }
 }
 
-impl Display for RelationshipClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TermClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(RelationshipClause, typedef, term);
 
 impl IntoPy<fastobo::ast::TermClause> for RelationshipClause {
     fn into_py(self, py: Python) -> fastobo::ast::TermClause {
@@ -1469,6 +1469,7 @@ impl IntoPy<fastobo::ast::TermClause> for RelationshipClause {
     }
 }
 
+#[obo_clause(tag = "relationship")]
 #[pymethods]
 impl RelationshipClause {
     #[new]
@@ -1484,8 +1485,12 @@ impl RelationshipClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.typedef && self.term)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -1493,17 +1498,68 @@ impl RelationshipClause {
         Ok(self.typedef.clone_py(py))
     }
 
+    #[setter]
+    fn set_typedef(&mut self, typedef: Ident) {
+        crate::index::invalidate();
+        self.typedef = typedef;
+    }
+
     #[getter]
     fn get_term<'py>(&self, py: Python<'py>) -> PyResult<Ident> {
         Ok(self.term.clone_py(py))
     }
 
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "relationship").to_object(slf.py())
+    #[setter]
+    fn set_term(&mut self, term: Ident) {
+        crate::index::invalidate();
+        self.term = term;
+    }
+
+    /// `~fastobo.id.Ident` or `None`: the relation of the GCI condition.
+    ///
+    /// General class inclusion axioms are declared in OBO with the
+    /// ``gci_relation`` and ``gci_filler`` qualifiers of a ``relationship``
+    /// clause, and restrict the relationship to the instances of the term
+    /// that are related to the filler through the GCI relation.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.loads(
+    ///     ...     '[Term]\nid: UBERON:0002107\n'
+    ///     ...     'relationship: part_of UBERON:0000062 '
+    ///     ...     '{gci_relation="part_of", gci_filler="NCBITaxon:7955"}\n'
+    ///     ... )[0]
+    ///     >>> frame[0].gci_relation
+    ///     UnprefixedIdent('part_of')
+    ///     >>> frame[0].gci_filler
+    ///     PrefixedIdent('NCBITaxon', '7955')
+    ///
+    #[getter]
+    fn get_gci_relation(slf: PyRef<'_, Self>) -> PyResult<Option<Ident>> {
+        let py = slf.py();
+        let base = slf.into_super().into_super();
+        Self::parse_qualifier(py, base.qualifier(py, "gci_relation"))
     }
 
-    fn raw_value(&self) -> String {
-        format!("{} {}", self.typedef, self.term)
+    #[setter]
+    fn set_gci_relation(slf: PyRefMut<'_, Self>, relation: Option<Ident>) -> PyResult<()> {
+        let py = slf.py();
+        let mut base = slf.into_super().into_super();
+        base.set_qualifier(py, "gci_relation", relation.map(|id| id.to_string()))
+    }
+
+    /// `~fastobo.id.Ident` or `None`: the filler of the GCI condition.
+    #[getter]
+    fn get_gci_filler(slf: PyRef<'_, Self>) -> PyResult<Option<Ident>> {
+        let py = slf.py();
+        let base = slf.into_super().into_super();
+        Self::parse_qualifier(py, base.qualifier(py, "gci_filler"))
+    }
+
+    #[setter]
+    fn set_gci_filler(slf: PyRefMut<'_, Self>, filler: Option<Ident>) -> PyResult<()> {
+        let py = slf.py();
+        let mut base = slf.into_super().into_super();
+        base.set_qualifier(py, "gci_filler", filler.map(|id| id.to_string()))
     }
 }
 
@@ -1514,7 +1570,7 @@ impl RelationshipClause {
 ///
 /// A clause indicating whether or not this term is obsolete.
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct IsObsoleteClause {
     #[pyo3(get, set)]
@@ -1527,11 +1583,7 @@ impl IsObsoleteClause {
     }
 }
 
-impl Display for IsObsoleteClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TermClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(IsObsoleteClause, obsolete);
 
 impl From<IsObsoleteClause> for fastobo::ast::TermClause {
     fn from(clause: IsObsoleteClause) -> Self {
@@ -1545,6 +1597,7 @@ impl IntoPy<fastobo::ast::TermClause> for IsObsoleteClause {
     }
 }
 
+#[obo_clause(tag = "is_obsolete")]
 #[pymethods]
 impl IsObsoleteClause {
     #[new]
@@ -1560,16 +1613,12 @@ impl IsObsoleteClause {
         Ok(self.to_string())
     }
 
-    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.obsolete)
-    }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "is_obsolete").to_object(slf.py())
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
-    fn raw_value(&self) -> String {
-        self.obsolete.to_string()
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
     }
 }
 
@@ -1580,7 +1629,7 @@ impl IsObsoleteClause {
 ///
 /// A clause giving a term which replaces this obsolete term.
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct ReplacedByClause {
     #[pyo3(set)]
@@ -1601,12 +1650,7 @@ impl ClonePy for ReplacedByClause {
     }
 }
 
-impl Display for ReplacedByClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TermClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(ReplacedByClause, term);
 
 impl IntoPy<fastobo::ast::TermClause> for ReplacedByClause {
     fn into_py(self, py: Python) -> fastobo::ast::TermClause {
@@ -1614,6 +1658,7 @@ impl IntoPy<fastobo::ast::TermClause> for ReplacedByClause {
     }
 }
 
+#[obo_clause(tag = "replaced_by")]
 #[pymethods]
 impl ReplacedByClause {
     #[new]
@@ -1629,8 +1674,12 @@ impl ReplacedByClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.term)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -1638,14 +1687,6 @@ impl ReplacedByClause {
     fn get_term(&self) -> &Ident {
         &self.term
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "replaced_by").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.term.to_string()
-    }
 }
 
 // --- Consider --------------------------------------------------------------
@@ -1655,7 +1696,7 @@ impl ReplacedByClause {
 ///
 /// A clause giving a potential substitute for an obsolete term.
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct ConsiderClause {
     term: Ident,
@@ -1675,12 +1716,7 @@ impl ClonePy for ConsiderClause {
     }
 }
 
-impl Display for ConsiderClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TermClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(ConsiderClause, term);
 
 impl IntoPy<fastobo::ast::TermClause> for ConsiderClause {
     fn into_py(self, py: Python) -> fastobo::ast::TermClause {
@@ -1688,6 +1724,7 @@ impl IntoPy<fastobo::ast::TermClause> for ConsiderClause {
     }
 }
 
+#[obo_clause(tag = "consider")]
 #[pymethods]
 impl ConsiderClause {
     #[new]
@@ -1703,22 +1740,18 @@ impl ConsiderClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.term)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
     fn get_term(&self) -> &Ident {
         &self.term
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "consider").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.term.to_string()
-    }
 }
 
 // --- CreatedBy -------------------------------------------------------------
@@ -1728,28 +1761,25 @@ impl ConsiderClause {
 ///
 /// A term clause stating the name of the creator of this term.
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct CreatedByClause {
-    creator: fastobo::ast::UnquotedString,
+    creator: PooledString,
 }
 
 impl CreatedByClause {
     pub fn new(creator: fastobo::ast::UnquotedString) -> Self {
-        Self { creator }
+        Self {
+            creator: PooledString::from(creator),
+        }
     }
 }
 
-impl Display for CreatedByClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TermClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(CreatedByClause, creator);
 
 impl From<CreatedByClause> for fastobo::ast::TermClause {
     fn from(clause: CreatedByClause) -> Self {
-        fastobo::ast::TermClause::CreatedBy(Box::new(clause.creator))
+        fastobo::ast::TermClause::CreatedBy(Box::new(clause.creator.into()))
     }
 }
 
@@ -1759,6 +1789,7 @@ impl IntoPy<fastobo::ast::TermClause> for CreatedByClause {
     }
 }
 
+#[obo_clause(tag = "created_by")]
 #[pymethods]
 impl CreatedByClause {
     #[new]
@@ -1774,8 +1805,12 @@ impl CreatedByClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.creator)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -1786,35 +1821,33 @@ impl CreatedByClause {
 
     #[setter]
     fn set_creator(&mut self, creator: String) {
-        self.creator = fastobo::ast::UnquotedString::new(creator);
-    }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "created_by").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.creator.to_string()
+        self.creator = PooledString::new(&creator);
     }
 }
 
 // --- CreationDate ----------------------------------------------------------
 
-/// CreationDateClause(date)
+/// CreationDateClause(date, *, naive="keep")
 /// --
 ///
 /// A clause declaring the date (and optionally time) a term was created.
 ///
 /// Arguments:
-///     date (`datetime.date`): The date this term was created. If a
-///         `datetime.datime` object is given, then the serialized value
-///         will also include the serialized time.
+///     date (`datetime.date` or `str`): The date this term was created.
+///         If a `datetime.datime` object is given, then the serialized value
+///         will also include the serialized time. If a `str` is given, it
+///         is parsed as an ISO-8601 date or datetime, and kept as written.
+///     naive (`str`): The policy for a naive `datetime.datetime`: either
+///         ``"keep"`` to serialize it without a timezone, ``"utc"`` to
+///         assume it is in UTC, ``"local"`` to assume it is in the local
+///         timezone of the system, or ``"error"`` to raise a `ValueError`.
 ///
 /// Warning:
 ///     The timezone of the `datetime` will only be extracted down to the
-///     minutes, seconds and smaller durations will be ignored. It is advised
-///     to use `datetime.timezone.utc` whenever possible to preserve the
-///     date and time properly.
+///     minutes, seconds and smaller durations will be ignored. A timezone
+///     of `datetime.timezone.utc` is serialized as ``Z``, and any other
+///     timezone with its ``±HH:MM`` offset, so that ``+00:00`` is kept
+///     as written in the source document.
 ///
 /// Example:
 ///     >>> d1 = datetime.date(2021, 1, 23)
@@ -1823,9 +1856,11 @@ impl CreatedByClause {
 ///     >>> d2 = datetime.datetime(2021, 1, 23, tzinfo=datetime.timezone.utc)
 ///     >>> print(fastobo.term.CreationDateClause(d2))
 ///     creation_date: 2021-01-23T00:00:00Z
+///     >>> print(fastobo.term.CreationDateClause("2021-01-23T08:00:00+01:00"))
+///     creation_date: 2021-01-23T08:00:00+01:00
 ///
 #[pyclass(extends=BaseTermClause, module="fastobo.term")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTermClause)]
 pub struct CreationDateClause {
     date: fastobo::ast::CreationDate,
@@ -1837,11 +1872,7 @@ impl CreationDateClause {
     }
 }
 
-impl Display for CreationDateClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TermClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(CreationDateClause, date);
 
 impl From<CreationDateClause> for fastobo::ast::TermClause {
     fn from(clause: CreationDateClause) -> fastobo::ast::TermClause {
@@ -1855,25 +1886,15 @@ impl IntoPy<fastobo::ast::TermClause> for CreationDateClause {
     }
 }
 
+#[obo_clause(tag = "creation_date")]
 #[pymethods]
 impl CreationDateClause {
     #[new]
-    fn __init__(date: &PyAny) -> PyResult<PyClassInitializer<Self>> {
-        let py = date.py();
-        if let Ok(dt) = date.downcast::<PyDateTime>() {
-            let date = datetime_to_isodatetime(py, dt).map(From::from)?;
-            Ok(CreationDateClause::new(date).into())
-        } else {
-            match date.downcast::<PyDate>() {
-                Err(e) => {
-                    raise!(py, PyTypeError("expected datetime.date or datetime.datetime") from PyErr::from(e))
-                }
-                Ok(d) => {
-                    let date = date_to_isodate(py, d).map(From::from)?;
-                    Ok(CreationDateClause::new(date).into())
-                }
-            }
-        }
+    #[pyo3(signature = (date, *, naive = "keep"))]
+    fn __init__(date: &PyAny, naive: &str) -> PyResult<PyClassInitializer<Self>> {
+        let policy = NaivePolicy::from_str(naive)?;
+        let date = extract_creation_date(date.py(), date, policy)?;
+        Ok(CreationDateClause::new(date).into())
     }
 
     fn __repr__(&self) -> PyResult<PyObject> {
@@ -1888,8 +1909,12 @@ impl CreationDateClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.date)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -1904,27 +1929,7 @@ impl CreationDateClause {
 
     #[setter]
     fn set_date(&mut self, datetime: &PyAny) -> PyResult<()> {
-        let py = datetime.py();
-        if let Ok(dt) = datetime.downcast::<PyDateTime>() {
-            self.date = From::from(datetime_to_isodatetime(py, dt)?);
-        } else {
-            match datetime.downcast::<PyDate>() {
-                Err(e) => {
-                    raise!(py, PyTypeError("expected datetime.date or datetime.datetime") from PyErr::from(e))
-                }
-                Ok(d) => {
-                    self.date = From::from(date_to_isodate(py, d)?);
-                }
-            }
-        }
+        self.date = extract_creation_date(datetime.py(), datetime, NaivePolicy::Keep)?;
         Ok(())
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "creation_date").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.date.to_string()
-    }
 }
@@ -1,36 +1,55 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
 use std::fmt::Write;
+use std::mem::discriminant;
 use std::str::FromStr;
 
-use pyo3::exceptions::PyIndexError;
+use pyo3::class::basic::CompareOp;
 use pyo3::prelude::*;
 use pyo3::types::PyAny;
+use pyo3::types::PyDict;
 use pyo3::types::PyIterator;
 use pyo3::types::PyString;
 use pyo3::AsPyPointer;
 use pyo3::PyNativeType;
 use pyo3::PyTypeInfo;
 use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::PyValueError;
 
 use fastobo::ast;
 
+use super::super::abc::AbstractEntityClause;
 use super::super::abc::AbstractEntityFrame;
+use super::super::abc::AbstractFrame;
+use super::super::header::frame::HeaderFrame;
 use super::super::id::Ident;
+use super::super::syn::Synonym;
+use super::super::xref::XrefList;
+use super::clause::DefClause;
 use super::clause::TermClause;
+use crate::error::Error;
+use crate::error::OwlError;
 use crate::utils::AbstractClass;
+use crate::utils::AssignPy;
 use crate::utils::ClonePy;
+use crate::utils::DisplayPy;
 use crate::utils::EqPy;
 use crate::utils::FinalClass;
+use crate::utils::FreezePy;
+use crate::utils::LazyVec;
+use crate::utils::RichCmpPy;
 
 #[pyclass(extends=AbstractEntityFrame, module="fastobo.term")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, RichCmpPy)]
 #[base(AbstractEntityFrame)]
 pub struct TermFrame {
-    #[pyo3(set)]
     id: Ident,
-    clauses: Vec<TermClause>,
+    clauses: LazyVec<ast::Line<ast::TermClause>, TermClause>,
+    #[eqpy(ignore)]
+    frozen: bool,
 }
 
 impl TermFrame {
@@ -39,7 +58,99 @@ impl TermFrame {
     }
 
     pub fn with_clauses(id: Ident, clauses: Vec<TermClause>) -> Self {
-        Self { id, clauses }
+        Self {
+            id,
+            clauses: LazyVec::from(clauses),
+            frozen: false,
+        }
+    }
+
+    /// Get a reference to the identifier of the frame.
+    pub fn id(&self) -> &Ident {
+        &self.id
+    }
+
+    /// Get a reference to the clauses of the frame.
+    pub fn clauses(&self) -> &Vec<TermClause> {
+        &self.clauses
+    }
+
+    /// Get a mutable reference to the clauses of the frame.
+    pub fn clauses_mut(&mut self) -> &mut Vec<TermClause> {
+        &mut self.clauses
+    }
+
+    /// Convert the frame to an AST frame.
+    ///
+    /// Clauses that have not been converted to Python objects yet are
+    /// copied from the source lines directly.
+    pub fn to_ast(&self, py: Python) -> ast::TermFrame {
+        let lines = match self.clauses.source() {
+            Some(lines) => lines.to_vec(),
+            None => self.clauses.iter().map(|c| Self::clause_line(c, py)).collect(),
+        };
+        ast::TermFrame::with_clauses(
+            ast::ClassIdent::new((&self.id).into_py(py)),
+            lines,
+        )
+    }
+
+    /// Get the size of the frame in memory, skipping the objects in `seen`.
+    pub fn deep_sizeof(&self, py: Python, seen: &mut HashSet<usize>) -> PyResult<usize> {
+        Ok(std::mem::size_of::<PyCell<Self>>()
+            + crate::utils::sizeof_shared(&self.id, py, seen)?
+            + crate::utils::sizeof_lazy_vec(&self.clauses, py, seen)?)
+    }
+
+    /// Update the frame in place to match an edited AST frame.
+    ///
+    /// Unchanged clauses are kept as they are, and changed clauses are
+    /// updated in place whenever possible, so that the clause objects
+    /// referenced from Python (and their comments) remain valid.
+    pub fn update(&mut self, py: Python, frame: ast::TermFrame) {
+        let id: ast::Ident = frame.id().as_ref().clone().into();
+        if IntoPy::<ast::Ident>::into_py(&self.id, py) != id {
+            self.id = id.into_py(py);
+        }
+        let lines = frame.into_iter().collect::<Vec<_>>();
+        if self.clauses.source().is_some() {
+            self.clauses = LazyVec::new(lines);
+            return;
+        }
+        crate::utils::update_items(
+            &mut self.clauses,
+            lines,
+            |clause| Self::clause_line(clause, py),
+            |a, b| discriminant(a.as_ref()) == discriminant(b.as_ref()),
+            |clause, line| Self::update_clause(clause, line, py),
+            |line| line.into_py(py),
+        );
+    }
+
+    /// Update a clause in place to match an edited AST line.
+    fn update_clause(clause: &TermClause, line: &ast::Line<ast::TermClause>, py: Python) -> bool {
+        let new: TermClause = line.as_ref().clone().into_py(py);
+        if !clause.assign_py(&new, py) {
+            return false;
+        }
+        let qualifiers = AbstractEntityClause::qualifiers_of(clause, py).ok().flatten();
+        qualifiers.as_ref() == line.qualifiers()
+            || AbstractEntityClause::set_qualifiers_of(clause, py, line.qualifiers().cloned()).is_ok()
+    }
+
+    /// Convert a clause to an AST line, with its qualifiers.
+    fn clause_line(clause: &TermClause, py: Python) -> ast::Line<ast::TermClause> {
+        let qualifiers = AbstractEntityClause::qualifiers_of(clause, py).ok().flatten();
+        let inner: ast::TermClause = clause.into_py(py);
+        ast::Line::new().and_inner(inner).and_qualifiers(qualifiers)
+    }
+
+    /// Get the `def` clause of the frame, if any.
+    fn def_clause(&self) -> Option<&Py<DefClause>> {
+        self.clauses.iter().find_map(|clause| match clause {
+            TermClause::Def(def) => Some(def),
+            _ => None,
+        })
     }
 }
 
@@ -48,39 +159,67 @@ impl ClonePy for TermFrame {
         Self {
             id: self.id.clone_py(py),
             clauses: self.clauses.clone_py(py),
+            frozen: false,
+        }
+    }
+}
+
+impl DisplayPy for TermFrame {
+    fn fmt_py(&self, py: Python, f: &mut Formatter) -> FmtResult {
+        f.write_str("[Term]\nid: ")?;
+        self.id.fmt_py(py, f)?;
+        f.write_char('\n')?;
+        if let Some(lines) = self.clauses.source() {
+            for line in lines.iter() {
+                line.as_ref().fmt(f)?;
+                match line.qualifiers() {
+                    Some(q) if !q.is_empty() => write!(f, " {}", q)?,
+                    _ => (),
+                }
+                f.write_char('\n')?;
+            }
+            return Ok(());
         }
+        for clause in self.clauses.iter() {
+            clause.fmt_py(py, f)?;
+            AbstractEntityClause::fmt_qualifiers_of(clause, py, f)?;
+            f.write_char('\n')?;
+        }
+        Ok(())
     }
 }
 
 impl Display for TermFrame {
-    // FIXME: no clone
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let frame: fastobo::ast::TermFrame = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        frame.fmt(f)
+        Python::with_gil(|py| self.fmt_py(py, f))
+    }
+}
+
+impl IntoPy<TermClause> for fastobo::ast::Line<fastobo::ast::TermClause> {
+    fn into_py(self, py: Python) -> TermClause {
+        let qualifiers = self.qualifiers().cloned();
+        let clause: TermClause = self.into_inner().into_py(py);
+        if qualifiers.is_some() {
+            AbstractEntityClause::set_qualifiers_of(&clause, py, qualifiers)
+                .expect("could not set qualifiers of a new `TermClause`");
+        }
+        clause
     }
 }
 
 impl IntoPy<TermFrame> for fastobo::ast::TermFrame {
     fn into_py(self, py: Python) -> TermFrame {
-        TermFrame::with_clauses(
-            self.id().as_ref().clone().into_py(py),
-            self.into_iter()
-                .map(|line| line.into_inner().into_py(py))
-                .collect(),
-        )
+        TermFrame {
+            id: self.id().as_ref().clone().into_py(py),
+            clauses: LazyVec::new(self.into_iter().collect()),
+            frozen: false,
+        }
     }
 }
 
 impl IntoPy<fastobo::ast::TermFrame> for TermFrame {
     fn into_py(self, py: Python) -> fastobo::ast::TermFrame {
-        fastobo::ast::TermFrame::with_clauses(
-            fastobo::ast::ClassIdent::new(self.id.into_py(py)),
-            self.clauses
-                .iter()
-                .map(|f| f.into_py(py))
-                .map(|c| fastobo::ast::Line::new().and_inner(c))
-                .collect(),
-        )
+        self.to_ast(py)
     }
 }
 
@@ -91,7 +230,7 @@ impl IntoPy<fastobo::ast::EntityFrame> for TermFrame {
     }
 }
 
-#[listlike(field = "clauses", type = "TermClause")]
+#[listlike(field = "clauses", type = "TermClause", frozen = "frozen")]
 #[pymethods]
 impl TermFrame {
     // FIXME: should accept any iterable.
@@ -111,50 +250,62 @@ impl TermFrame {
         impl_repr!(self, TermFrame(self.id))
     }
 
-    fn __str__(&self) -> PyResult<String> {
-        Ok(self.to_string())
+    fn __str__(slf: &PyCell<Self>) -> PyResult<String> {
+        AbstractFrame::render(slf)
     }
 
     fn __len__(&self) -> PyResult<usize> {
-        Ok(self.clauses.len())
-    }
-
-    fn __getitem__(&self, index: isize) -> PyResult<PyObject> {
-        if index < self.clauses.len() as isize {
-            let item = &self.clauses[index as usize];
-            Ok(Python::with_gil(|py| item.to_object(py)))
-        } else {
-            Err(PyIndexError::new_err("list index out of range"))
+        match self.clauses.source() {
+            Some(lines) => Ok(lines.len()),
+            None => Ok(self.clauses.len()),
         }
     }
 
-    fn __setitem__(&mut self, index: isize, elem: &PyAny) -> PyResult<()> {
-        if index as usize > self.clauses.len() {
-            return Err(PyIndexError::new_err("list index out of range"));
+    fn __contains__(&self, item: &PyAny) -> PyResult<bool> {
+        let py = item.py();
+        let clause = match TermClause::extract(item) {
+            Ok(clause) => clause,
+            Err(_) => return Ok(false),
+        };
+        if let Some(lines) = self.clauses.source() {
+            let clause: ast::TermClause = (&clause).into_py(py);
+            return Ok(lines.iter().any(|line| line.as_ref() == &clause));
         }
-        let clause = TermClause::extract(elem)?;
-        self.clauses[index as usize] = clause;
-        Ok(())
+        Ok(self.clauses.iter().any(|c| c.eq_py(&clause, py)))
     }
 
-    fn __delitem__(&mut self, index: isize) -> PyResult<()> {
-        if index as usize > self.clauses.len() {
-            return Err(PyIndexError::new_err("list index out of range"));
-        }
-        self.clauses.remove(index as usize);
-        Ok(())
+    /// Get the size of the frame in memory, in bytes.
+    ///
+    /// The size includes the identifier and the clauses of the frame.
+    fn __sizeof__(&self, py: Python) -> PyResult<usize> {
+        self.deep_sizeof(py, &mut HashSet::new())
     }
 
     fn __concat__(&self, other: &PyAny) -> PyResult<Py<Self>> {
         let py = other.py();
 
-        let iterator = PyIterator::from_object(py, other)?;
-        let mut new_clauses = self.clauses.clone_py(py);
-        for item in iterator {
+        let mut new_clauses = Vec::new();
+        for item in PyIterator::from_object(py, other)? {
             new_clauses.push(TermClause::extract(item?)?);
         }
 
-        Py::new(py, Self::with_clauses(self.id.clone_py(py), new_clauses))
+        let clauses = match self.clauses.source() {
+            Some(lines) => {
+                let mut lines = lines.to_vec();
+                lines.extend(new_clauses.iter().map(|c| Self::clause_line(c, py)));
+                LazyVec::new(lines)
+            }
+            None => self.clauses.clone_py(py).into_iter().chain(new_clauses).collect(),
+        };
+
+        Py::new(
+            py,
+            Self {
+                id: self.id.clone_py(py),
+                clauses,
+                frozen: false,
+            },
+        )
     }
 
     #[getter]
@@ -162,4 +313,481 @@ impl TermFrame {
     fn get_id(&self) -> PyResult<&Ident> {
         Ok(&self.id)
     }
+
+    #[setter]
+    fn set_id(&mut self, id: Ident) -> PyResult<()> {
+        crate::utils::check_mutable(self.frozen, "TermFrame")?;
+        self.id = id;
+        Ok(())
+    }
+
+    /// `str` or `None`: the name of the term, from its first ``name`` clause.
+    #[getter]
+    fn get_name(&self, py: Python) -> Option<String> {
+        if let Some(lines) = self.clauses.source() {
+            return lines.iter().find_map(|line| match line.as_ref() {
+                ast::TermClause::Name(name) => Some(name.as_str().to_string()),
+                _ => None,
+            });
+        }
+        self.clauses.iter().find_map(|clause| match clause {
+            TermClause::Name(name) => Some(name.borrow(py).name().as_str().to_string()),
+            _ => None,
+        })
+    }
+
+    /// `str` or `None`: the textual definition of the term, if any.
+    ///
+    /// Setting a definition replaces the ``def`` clause of the frame, or
+    /// adds a new one after the ``name``, ``namespace`` and ``alt_id``
+    /// clauses if the frame has no definition yet. The cross-references of
+    /// a replaced definition are discarded. Setting the definition to
+    /// `None` removes the ``def`` clause from the frame.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.term.TermFrame(
+    ///     ...     fastobo.id.PrefixedIdent("MS", "1000031"),
+    ///     ...     [fastobo.term.NameClause("instrument model")],
+    ///     ... )
+    ///     >>> frame.definition = "Instrument model name not including the vendor's name."
+    ///     >>> print(frame)
+    ///     [Term]
+    ///     id: MS:1000031
+    ///     name: instrument model
+    ///     def: "Instrument model name not including the vendor's name." []
+    ///     <BLANKLINE>
+    ///
+    #[getter]
+    fn get_definition(&self, py: Python) -> Option<String> {
+        self.def_clause().map(|def| def.borrow(py).definition().as_str().to_string())
+    }
+
+    #[setter]
+    fn set_definition(&mut self, py: Python, definition: Option<String>) -> PyResult<()> {
+        crate::utils::check_mutable(self.frozen, "TermFrame")?;
+        let index = self
+            .clauses
+            .iter()
+            .position(|clause| matches!(clause, TermClause::Def(_)))
+            .unwrap_or_else(|| {
+                self.clauses
+                    .iter()
+                    .position(|clause| {
+                        !matches!(
+                            clause,
+                            TermClause::IsAnonymous(_)
+                                | TermClause::Name(_)
+                                | TermClause::Namespace(_)
+                                | TermClause::AltId(_)
+                        )
+                    })
+                    .unwrap_or(self.clauses.len())
+            });
+        self.clauses.retain(|clause| !matches!(clause, TermClause::Def(_)));
+        if let Some(text) = definition {
+            let def = DefClause::new(ast::QuotedString::new(text), XrefList::new(Vec::new()));
+            let clause = TermClause::Def(Py::new(py, def)?);
+            let len = self.clauses.len();
+            self.clauses.insert(index.min(len), clause);
+        }
+        Ok(())
+    }
+
+    /// `~fastobo.xref.XrefList` or `None`: the xrefs supporting the definition.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.term.TermFrame(
+    ///     ...     fastobo.id.PrefixedIdent("MS", "1000031"),
+    ///     ...     [fastobo.term.DefClause("Instrument model name.", [
+    ///     ...         fastobo.xref.Xref(fastobo.id.PrefixedIdent("PSI", "MS"))
+    ///     ...     ])],
+    ///     ... )
+    ///     >>> frame.definition_xrefs
+    ///     XrefList([Xref(PrefixedIdent('PSI', 'MS'))])
+    ///
+    #[getter]
+    fn get_definition_xrefs(&self, py: Python) -> Option<XrefList> {
+        self.def_clause().map(|def| def.borrow(py).xrefs().clone_py(py))
+    }
+
+    fn __hash__(&self) -> PyResult<u64> {
+        if self.frozen {
+            Ok(impl_hash!(self.to_string()))
+        } else {
+            Err(PyTypeError::new_err("unhashable type: 'TermFrame'"))
+        }
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+
+    /// `bool`: Whether the frame is frozen.
+    #[getter]
+    fn get_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Make the frame read-only.
+    ///
+    /// See `OboDoc.freeze` for the modifications rejected by a frozen frame.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.term.TermFrame(fastobo.id.PrefixedIdent("MS", "1000031"))
+    ///     >>> frame.freeze()
+    ///     >>> frame.append(fastobo.term.NameClause("model"))
+    ///     Traceback (most recent call last):
+    ///       ...
+    ///     TypeError: cannot modify frozen TermFrame instance
+    ///
+    #[pyo3(text_signature = "(self)")]
+    pub fn freeze(&mut self, py: Python) -> PyResult<()> {
+        self.clauses.freeze_py(py)?;
+        self.frozen = true;
+        Ok(())
+    }
+
+    /// `list` of `~fastobo.syn.Synonym`: the synonyms of the term.
+    ///
+    /// The synonyms are collected from all the ``synonym`` clauses of the
+    /// frame into a new `list`, so adding or removing synonyms must be
+    /// done through the clauses of the frame. The `Synonym` objects are
+    /// not copied, and can be edited in place.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.term.TermFrame(
+    ///     ...     fastobo.id.PrefixedIdent("MS", "1000031"),
+    ///     ...     [
+    ///     ...         fastobo.term.SynonymClause(fastobo.syn.Synonym("model", "EXACT")),
+    ///     ...         fastobo.term.SynonymClause(fastobo.syn.Synonym("instrument", "BROAD")),
+    ///     ...     ]
+    ///     ... )
+    ///     >>> [syn.desc for syn in frame.synonyms if syn.scope == "EXACT"]
+    ///     ['model']
+    ///
+    #[getter]
+    fn get_synonyms(&self, py: Python) -> Vec<Py<Synonym>> {
+        self.clauses
+            .iter()
+            .filter_map(|clause| match clause {
+                TermClause::Synonym(s) => Some(s.borrow(py).synonym().clone_ref(py)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Collect the cross-references of the term into an `XrefList`.
+    ///
+    /// Arguments:
+    ///     include_definition (bool): Whether to also include the xrefs
+    ///         supporting the definition of the term, in addition to the
+    ///         xrefs of the ``xref`` clauses.
+    ///
+    /// Returns:
+    ///     `~fastobo.xref.XrefList`: A new list with the xrefs of the frame,
+    ///     in frame order. The `Xref` objects are not copied.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.term.TermFrame(
+    ///     ...     fastobo.id.PrefixedIdent("MS", "1000031"),
+    ///     ...     [
+    ///     ...         fastobo.term.DefClause("Instrument model name.", [
+    ///     ...             fastobo.xref.Xref(fastobo.id.PrefixedIdent("PSI", "MS"))
+    ///     ...         ]),
+    ///     ...         fastobo.term.XrefClause(
+    ///     ...             fastobo.xref.Xref(fastobo.id.PrefixedIdent("MOD", "00000"))
+    ///     ...         ),
+    ///     ...     ]
+    ///     ... )
+    ///     >>> frame.xrefs()
+    ///     XrefList([Xref(PrefixedIdent('MOD', '00000'))])
+    ///     >>> len(frame.xrefs(include_definition=True))
+    ///     2
+    ///
+    #[pyo3(
+        text_signature = "(self, include_definition=False)",
+        signature = (include_definition = false)
+    )]
+    fn xrefs(&self, py: Python, include_definition: bool) -> XrefList {
+        let mut xrefs = Vec::new();
+        for clause in self.clauses.iter() {
+            match clause {
+                TermClause::Def(def) if include_definition => {
+                    xrefs.extend(def.borrow(py).xrefs().iter().map(|x| x.clone_ref(py)));
+                }
+                TermClause::Xref(x) => xrefs.push(x.borrow(py).xref().clone_ref(py)),
+                _ => (),
+            }
+        }
+        XrefList::new(xrefs)
+    }
+
+    /// Group the targets of the relationships of the term by relation.
+    ///
+    /// Targets of ``is_a`` clauses are grouped under the ``is_a`` key,
+    /// given as an `~fastobo.id.UnprefixedIdent`, and targets of
+    /// ``relationship`` clauses are grouped under the identifier of their
+    /// relation, in frame order.
+    ///
+    /// Returns:
+    ///     `dict`: A mapping of relation identifiers to a `list` of target
+    ///     identifiers.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.term.TermFrame(
+    ///     ...     fastobo.id.PrefixedIdent("GO", "0005739"),
+    ///     ...     [
+    ///     ...         fastobo.term.IsAClause(fastobo.id.PrefixedIdent("GO", "0043231")),
+    ///     ...         fastobo.term.RelationshipClause(
+    ///     ...             fastobo.id.UnprefixedIdent("part_of"),
+    ///     ...             fastobo.id.PrefixedIdent("GO", "0005737"),
+    ///     ...         ),
+    ///     ...     ]
+    ///     ... )
+    ///     >>> pprint(frame.relationships())
+    ///     {UnprefixedIdent('is_a'): [PrefixedIdent('GO', '0043231')],
+    ///      UnprefixedIdent('part_of'): [PrefixedIdent('GO', '0005737')]}
+    ///
+    #[pyo3(text_signature = "(self)")]
+    fn relationships(&self, py: Python) -> PyResult<PyObject> {
+        let mut groups: Vec<(Ident, Vec<Ident>)> = Vec::new();
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        for clause in self.clauses.iter() {
+            let (relation, target) = match clause {
+                TermClause::IsA(c) => {
+                    let is_a = ast::Ident::from(ast::UnprefixedIdent::new("is_a"));
+                    let relation: Ident = is_a.into_py(py);
+                    (relation, c.borrow(py).term().clone_py(py))
+                }
+                TermClause::Relationship(c) => {
+                    let c = c.borrow(py);
+                    (c.typedef().clone_py(py), c.term().clone_py(py))
+                }
+                _ => continue,
+            };
+            match positions.get(&relation.to_string()) {
+                Some(&i) => groups[i].1.push(target),
+                None => {
+                    positions.insert(relation.to_string(), groups.len());
+                    groups.push((relation, vec![target]));
+                }
+            }
+        }
+
+        let dict = PyDict::new(py);
+        for (relation, targets) in groups {
+            dict.set_item(relation, targets)?;
+        }
+        Ok(dict.to_object(py))
+    }
+
+    /// Get all the clauses of the frame with the given raw tag.
+    ///
+    /// Arguments:
+    ///     tag (str): The raw tag of the clauses to retrieve, as returned
+    ///         by the `raw_tag` method of each clause.
+    ///
+    /// Returns:
+    ///     `list`: The clauses with the given tag, in frame order. The
+    ///     clauses are not copied, so they can be edited in place.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.term.TermFrame(
+    ///     ...     fastobo.id.PrefixedIdent("MS", "1000031"),
+    ///     ...     [
+    ///     ...         fastobo.term.NameClause("instrument model"),
+    ///     ...         fastobo.term.IsAClause(fastobo.id.PrefixedIdent("MS", "1000496")),
+    ///     ...         fastobo.term.IsAClause(fastobo.id.PrefixedIdent("MS", "1000031")),
+    ///     ...     ]
+    ///     ... )
+    ///     >>> frame.get("is_a")
+    ///     [IsAClause(PrefixedIdent('MS', '1000496')), IsAClause(PrefixedIdent('MS', '1000031'))]
+    ///
+    #[pyo3(text_signature = "(self, tag)")]
+    fn get(&self, py: Python, tag: &str) -> Vec<TermClause> {
+        self.clauses
+            .iter()
+            .filter(|clause| clause.raw_tag() == tag)
+            .map(|clause| clause.clone_py(py))
+            .collect()
+    }
+
+    /// Remove and return all the clauses of the frame with the given raw tag.
+    ///
+    /// Arguments:
+    ///     tag (str): The raw tag of the clauses to remove, as returned
+    ///         by the `raw_tag` method of each clause.
+    ///
+    /// Returns:
+    ///     `list`: The removed clauses, in frame order.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.term.TermFrame(
+    ///     ...     fastobo.id.PrefixedIdent("MS", "1000031"),
+    ///     ...     [
+    ///     ...         fastobo.term.NameClause("instrument model"),
+    ///     ...         fastobo.term.XrefClause(
+    ///     ...             fastobo.xref.Xref(fastobo.id.PrefixedIdent("PSI", "MS"))
+    ///     ...         ),
+    ///     ...     ]
+    ///     ... )
+    ///     >>> frame.pop_clauses("xref")
+    ///     [XrefClause(Xref(PrefixedIdent('PSI', 'MS')))]
+    ///     >>> len(frame)
+    ///     1
+    ///
+    #[pyo3(text_signature = "(self, tag)")]
+    fn pop_clauses(&mut self, tag: &str) -> PyResult<Vec<TermClause>> {
+        crate::utils::check_mutable(self.frozen, "TermFrame")?;
+        let mut popped = Vec::new();
+        for clause in std::mem::take(&mut self.clauses) {
+            if clause.raw_tag() == tag {
+                popped.push(clause);
+            } else {
+                self.clauses.push(clause);
+            }
+        }
+        Ok(popped)
+    }
+
+    /// Get the set of raw tags of the clauses of the frame.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.term.TermFrame(
+    ///     ...     fastobo.id.PrefixedIdent("MS", "1000031"),
+    ///     ...     [
+    ///     ...         fastobo.term.NameClause("instrument model"),
+    ///     ...         fastobo.term.IsAClause(fastobo.id.PrefixedIdent("MS", "1000496")),
+    ///     ...         fastobo.term.IsAClause(fastobo.id.PrefixedIdent("MS", "1000031")),
+    ///     ...     ]
+    ///     ... )
+    ///     >>> sorted(frame.tags())
+    ///     ['is_a', 'name']
+    ///
+    #[pyo3(text_signature = "(self)")]
+    fn tags(&self) -> HashSet<&'static str> {
+        self.clauses.iter().map(|clause| clause.raw_tag()).collect()
+    }
+
+    /// Convert the frame to a `dict` of builtin Python types.
+    ///
+    /// Arguments:
+    ///     grouped (`bool`): Whether to group the raw values of the clauses
+    ///         by raw tag in a `dict`, instead of listing the clauses in
+    ///         frame order.
+    ///
+    /// Returns:
+    ///     `dict`: A dictionary with the ``type`` of the frame (``"Term"``),
+    ///     its ``id`` as a `str`, and its ``clauses`` as a `list` of `dict`
+    ///     with the raw ``tag`` and ``value`` of each clause, or as a `dict`
+    ///     mapping each raw tag to a `list` of raw values if ``grouped`` is
+    ///     `True`. The qualifiers of a clause, if any, are given as a `list` of
+    ///     `dict` with the ``key`` and ``value`` of each qualifier, under the
+    ///     ``qualifiers`` key of the clause `dict`, or of a `dict` with the raw
+    ///     ``value`` replacing the raw value of a grouped clause.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.term.TermFrame(
+    ///     ...     fastobo.id.PrefixedIdent("MS", "1000031"),
+    ///     ...     [fastobo.term.NameClause("model")],
+    ///     ... )
+    ///     >>> pprint(frame.to_dict())
+    ///     {'clauses': [{'tag': 'name', 'value': 'model'}],
+    ///      'id': 'MS:1000031',
+    ///      'type': 'Term'}
+    ///
+    ///     >>> pprint(frame.to_dict(grouped=True))
+    ///     {'clauses': {'name': ['model']}, 'id': 'MS:1000031', 'type': 'Term'}
+    ///
+    #[pyo3(signature = (grouped = false), text_signature = "(self, grouped=False)")]
+    fn to_dict(&self, py: Python, grouped: bool) -> PyResult<PyObject> {
+        let frame: ast::TermFrame = self.to_ast(py);
+        crate::dict::entity_to_dict(py, ast::EntityFrame::from(frame), grouped)
+    }
+
+    /// Create a new frame from a `dict` created with `TermFrame.to_dict`.
+    ///
+    /// Raises:
+    ///     ValueError: When the dictionary does not describe a term frame.
+    ///     SyntaxError: When the identifier or a clause cannot be parsed.
+    ///
+    #[staticmethod]
+    #[pyo3(text_signature = "(d)")]
+    fn from_dict(py: Python, d: &PyAny) -> PyResult<Py<Self>> {
+        match crate::dict::entity_from_dict(d)? {
+            ast::EntityFrame::Term(frame) => {
+                let frame: TermFrame = frame.into_py(py);
+                Py::new(py, frame)
+            }
+            _ => Err(PyValueError::new_err("expected Term frame")),
+        }
+    }
+
+    /// Convert the frame to OWL and render the resulting axioms.
+    ///
+    /// This is useful to check how a single frame is translated without
+    /// exporting the whole document with `~fastobo.dump_owl`.
+    ///
+    /// Arguments:
+    ///     header (`~fastobo.header.HeaderFrame`, optional): The header of
+    ///         the document declaring the frame, used to resolve the ID
+    ///         spaces and unprefixed identifiers. If `None` given, use a
+    ///         header declaring an ``obo`` ontology and default namespace.
+    ///
+    /// Returns:
+    ///     `list` of `str`: The OWL axioms translated from the frame, in
+    ///     `Functional-style syntax <https://w3.org/TR/owl2-syntax/>`_.
+    ///
+    /// Raises:
+    ///     ValueError: When the conversion to OWL fails, e.g. because
+    ///         ``header`` lacks a required clause.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.term.TermFrame.loads(
+    ///     ...     "[Term]\nid: TST:001\nis_a: TST:002\n"
+    ///     ... )
+    ///     >>> axioms = frame.as_owl_functional()
+    ///     >>> "SubClassOf(obo:TST_001 obo:TST_002)" in axioms
+    ///     True
+    ///
+    #[pyo3(signature = (header = None), text_signature = "(self, header=None)")]
+    fn as_owl_functional(
+        &self,
+        py: Python,
+        header: Option<&PyCell<HeaderFrame>>,
+    ) -> PyResult<Vec<String>> {
+        let header: ast::HeaderFrame = match header {
+            Some(h) => h.borrow().clone_py(py).into_py(py),
+            None => crate::owl::default_header(),
+        };
+        let frame: ast::TermFrame = self.to_ast(py);
+        crate::owl::frame_to_ofn(header, ast::EntityFrame::from(frame))
+            .map_err(|e| PyErr::from(OwlError::from(e)))
+    }
+
+    /// Parse a single term frame from a string.
+    ///
+    /// Arguments:
+    ///     text (str): The serialized frame, starting with a ``[Term]``
+    ///         frame header.
+    ///
+    /// Raises:
+    ///     SyntaxError: When the text is not a valid term frame.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.term.TermFrame.loads(
+    ///     ...     "[Term]\nid: TST:001\nname: test item\n"
+    ///     ... )
+    ///     >>> frame.id
+    ///     PrefixedIdent('TST', '001')
+    ///
+    #[staticmethod]
+    #[pyo3(text_signature = "(text)")]
+    fn loads(py: Python, text: &str) -> PyResult<Py<Self>> {
+        let source = format!("{}\n", text.trim());
+        match ast::TermFrame::from_str(&source) {
+            Ok(frame) => Py::new(py, IntoPy::<TermFrame>::into_py(frame, py)),
+            Err(e) => Err(PyErr::from(Error::from(e))),
+        }
+    }
 }
@@ -1,8 +1,16 @@
 pub mod clause;
 pub mod frame;
 
+use std::str::FromStr;
+
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
+use self::clause::TermClause;
+use self::frame::TermFrame;
+use crate::error::Error;
+use crate::utils::ParseClause;
+
 #[pymodule]
 #[pyo3(name = "term")]
 pub fn init(py: Python, m: &PyModule) -> PyResult<()> {
@@ -31,9 +39,48 @@ pub fn init(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<self::clause::CreatedByClause>()?;
     m.add_class::<self::clause::CreationDateClause>()?;
 
+    m.add_function(wrap_pyfunction!(self::parse_clause, m)?)?;
+
     register!(py, m, TermFrame, "collections.abc", MutableSequence);
 
     m.add("__name__", "fastobo.term")?;
 
     Ok(())
 }
+
+/// Parse a single term clause from a line of text.
+///
+/// Trailing qualifiers and comments are supported, so that a clause line
+/// can be extracted verbatim from an OBO document.
+///
+/// Arguments:
+///     text (str): The serialized clause, such as ``"is_a: GO:0000001 ! parent"``.
+///
+/// Returns:
+///     `~fastobo.term.BaseTermClause`: The deserialized clause.
+///
+/// Raises:
+///     SyntaxError: When the text is not a valid term clause.
+///     ValueError: When the text does not contain exactly one clause.
+///
+/// Example:
+///     >>> fastobo.term.parse_clause("is_a: GO:0000001 ! parent")
+///     IsAClause(PrefixedIdent('GO', '0000001'))
+///
+#[pyfunction]
+#[pyo3(text_signature = "(text)")]
+pub fn parse_clause(py: Python, text: &str) -> PyResult<TermClause> {
+    let source = format!("[Term]\nid: x\n{}\n", text.trim_end());
+    let ast = fastobo::ast::TermFrame::from_str(&source).map_err(|e| PyErr::from(Error::from(e)))?;
+    let mut frame: TermFrame = ast.into_py(py);
+    match frame.clauses().len() {
+        1 => Ok(frame.clauses_mut().pop().unwrap()),
+        n => Err(PyValueError::new_err(format!("expected a single clause, found {}", n))),
+    }
+}
+
+impl ParseClause for TermClause {
+    fn parse_clause(py: Python, text: &str) -> PyResult<Self> {
+        self::parse_clause(py, text)
+    }
+}
@@ -5,61 +5,67 @@ use std::fmt::Write;
 use std::str::FromStr;
 
 use pyo3::class::basic::CompareOp;
-use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
 use pyo3::types::PyAny;
-use pyo3::types::PyDate;
-use pyo3::types::PyDateTime;
+use pyo3::types::PyDict;
 use pyo3::types::PyString;
+use pyo3::types::PyTuple;
 use pyo3::AsPyPointer;
 use pyo3::PyNativeType;
 use pyo3::PyTypeInfo;
 
 use fastobo::ast;
 
+use super::super::abc::AbstractClause;
 use super::super::abc::AbstractEntityClause;
 use super::super::id::Ident;
 use super::super::pv::PropertyValue;
 use super::super::syn::Synonym;
 use super::super::xref::Xref;
 use super::super::xref::XrefList;
-use crate::date::date_to_isodate;
-use crate::date::datetime_to_isodatetime;
+use crate::date::extract_creation_date;
 use crate::date::isodate_to_date;
 use crate::date::isodatetime_to_datetime;
-use crate::raise;
+use crate::date::NaivePolicy;
 use crate::utils::AbstractClass;
 use crate::utils::ClonePy;
+use crate::utils::DisplayPy;
 use crate::utils::EqPy;
 use crate::utils::FinalClass;
+use crate::utils::FreezePy;
+use crate::utils::HashPy;
+use crate::utils::Lazy;
+use crate::utils::PooledString;
+use crate::utils::RichCmpPy;
 
 // --- Conversion Wrapper ----------------------------------------------------
 
-#[derive(ClonePy, Debug, PyWrapper, EqPy)]
-#[wraps(BaseTypedefClause)]
+#[derive(ClonePy, Debug, DisplayPy, PyWrapper, EqPy, HashPy)]
+#[wraps(BaseTypedefClause, parse)]
+#[eqpy(with = "AbstractEntityClause::eq_metadata_of")]
 pub enum TypedefClause {
-    IsAnonymous(Py<IsAnonymousClause>),
-    Name(Py<NameClause>),
+    IsAnonymous(Lazy<IsAnonymousClause>),
+    Name(Lazy<NameClause>),
     Namespace(Py<NamespaceClause>),
     AltId(Py<AltIdClause>),
     Def(Py<DefClause>),
-    Comment(Py<CommentClause>),
+    Comment(Lazy<CommentClause>),
     Subset(Py<SubsetClause>),
     Synonym(Py<SynonymClause>),
     Xref(Py<XrefClause>),
     PropertyValue(Py<PropertyValueClause>),
     Domain(Py<DomainClause>),
     Range(Py<RangeClause>),
-    Builtin(Py<BuiltinClause>),
+    Builtin(Lazy<BuiltinClause>),
     HoldsOverChain(Py<HoldsOverChainClause>),
-    IsAntiSymmetric(Py<IsAntiSymmetricClause>),
-    IsCyclic(Py<IsCyclicClause>),
-    IsReflexive(Py<IsReflexiveClause>),
-    IsSymmetric(Py<IsSymmetricClause>),
-    IsAsymmetric(Py<IsAsymmetricClause>),
-    IsTransitive(Py<IsTransitiveClause>),
-    IsFunctional(Py<IsFunctionalClause>),
-    IsInverseFunctional(Py<IsInverseFunctionalClause>),
+    IsAntiSymmetric(Lazy<IsAntiSymmetricClause>),
+    IsCyclic(Lazy<IsCyclicClause>),
+    IsReflexive(Lazy<IsReflexiveClause>),
+    IsSymmetric(Lazy<IsSymmetricClause>),
+    IsAsymmetric(Lazy<IsAsymmetricClause>),
+    IsTransitive(Lazy<IsTransitiveClause>),
+    IsFunctional(Lazy<IsFunctionalClause>),
+    IsInverseFunctional(Lazy<IsInverseFunctionalClause>),
     IsA(Py<IsAClause>),
     IntersectionOf(Py<IntersectionOfClause>),
     UnionOf(Py<UnionOfClause>),
@@ -70,26 +76,88 @@ pub enum TypedefClause {
     EquivalentToChain(Py<EquivalentToChainClause>),
     DisjointOver(Py<DisjointOverClause>),
     Relationship(Py<RelationshipClause>),
-    IsObsolete(Py<IsObsoleteClause>),
+    IsObsolete(Lazy<IsObsoleteClause>),
     ReplacedBy(Py<ReplacedByClause>),
     Consider(Py<ConsiderClause>),
-    CreatedBy(Py<CreatedByClause>),
+    CreatedBy(Lazy<CreatedByClause>),
     CreationDate(Py<CreationDateClause>),
     ExpandAssertionTo(Py<ExpandAssertionToClause>),
     ExpandExpressionTo(Py<ExpandExpressionToClause>),
-    IsMetadataTag(Py<IsMetadataTagClause>),
-    IsClassLevel(Py<IsClassLevelClause>),
+    IsMetadataTag(Lazy<IsMetadataTagClause>),
+    IsClassLevel(Lazy<IsClassLevelClause>),
+}
+
+impl TypedefClause {
+    /// Get the raw tag of the clause, without converting it.
+    pub fn raw_tag(&self) -> &'static str {
+        match self {
+            TypedefClause::IsAnonymous(_) => IsAnonymousClause::TAG,
+            TypedefClause::Name(_) => NameClause::TAG,
+            TypedefClause::Namespace(_) => NamespaceClause::TAG,
+            TypedefClause::AltId(_) => AltIdClause::TAG,
+            TypedefClause::Def(_) => DefClause::TAG,
+            TypedefClause::Comment(_) => CommentClause::TAG,
+            TypedefClause::Subset(_) => SubsetClause::TAG,
+            TypedefClause::Synonym(_) => SynonymClause::TAG,
+            TypedefClause::Xref(_) => XrefClause::TAG,
+            TypedefClause::PropertyValue(_) => PropertyValueClause::TAG,
+            TypedefClause::Domain(_) => DomainClause::TAG,
+            TypedefClause::Range(_) => RangeClause::TAG,
+            TypedefClause::Builtin(_) => BuiltinClause::TAG,
+            TypedefClause::HoldsOverChain(_) => HoldsOverChainClause::TAG,
+            TypedefClause::IsAntiSymmetric(_) => IsAntiSymmetricClause::TAG,
+            TypedefClause::IsCyclic(_) => IsCyclicClause::TAG,
+            TypedefClause::IsReflexive(_) => IsReflexiveClause::TAG,
+            TypedefClause::IsSymmetric(_) => IsSymmetricClause::TAG,
+            TypedefClause::IsAsymmetric(_) => IsAsymmetricClause::TAG,
+            TypedefClause::IsTransitive(_) => IsTransitiveClause::TAG,
+            TypedefClause::IsFunctional(_) => IsFunctionalClause::TAG,
+            TypedefClause::IsInverseFunctional(_) => IsInverseFunctionalClause::TAG,
+            TypedefClause::IsA(_) => IsAClause::TAG,
+            TypedefClause::IntersectionOf(_) => IntersectionOfClause::TAG,
+            TypedefClause::UnionOf(_) => UnionOfClause::TAG,
+            TypedefClause::EquivalentTo(_) => EquivalentToClause::TAG,
+            TypedefClause::DisjointFrom(_) => DisjointFromClause::TAG,
+            TypedefClause::InverseOf(_) => InverseOfClause::TAG,
+            TypedefClause::TransitiveOver(_) => TransitiveOverClause::TAG,
+            TypedefClause::EquivalentToChain(_) => EquivalentToChainClause::TAG,
+            TypedefClause::DisjointOver(_) => DisjointOverClause::TAG,
+            TypedefClause::Relationship(_) => RelationshipClause::TAG,
+            TypedefClause::IsObsolete(_) => IsObsoleteClause::TAG,
+            TypedefClause::ReplacedBy(_) => ReplacedByClause::TAG,
+            TypedefClause::Consider(_) => ConsiderClause::TAG,
+            TypedefClause::CreatedBy(_) => CreatedByClause::TAG,
+            TypedefClause::CreationDate(_) => CreationDateClause::TAG,
+            TypedefClause::ExpandAssertionTo(_) => ExpandAssertionToClause::TAG,
+            TypedefClause::ExpandExpressionTo(_) => ExpandExpressionToClause::TAG,
+            TypedefClause::IsMetadataTag(_) => IsMetadataTagClause::TAG,
+            TypedefClause::IsClassLevel(_) => IsClassLevelClause::TAG,
+        }
+    }
 }
 
 // TODO
+impl FreezePy for TypedefClause {
+    fn freeze_py(&self, py: Python) -> PyResult<()> {
+        AbstractClause::freeze_object(self.to_object(py).as_ref(py))?;
+        match self {
+            TypedefClause::Def(c) => c.try_borrow(py)?.xrefs.freeze_py(py),
+            TypedefClause::Synonym(c) => c.try_borrow(py)?.synonym.freeze_py(py),
+            TypedefClause::Xref(c) => c.try_borrow(py)?.xref.freeze_py(py),
+            TypedefClause::PropertyValue(c) => c.try_borrow(py)?.inner.freeze_py(py),
+            TypedefClause::ExpandAssertionTo(c) => c.try_borrow(py)?.xrefs.freeze_py(py),
+            TypedefClause::ExpandExpressionTo(c) => c.try_borrow(py)?.xrefs.freeze_py(py),
+            _ => Ok(()),
+        }
+    }
+}
+
 impl IntoPy<TypedefClause> for fastobo::ast::TypedefClause {
     fn into_py(self, py: Python) -> TypedefClause {
         use fastobo::ast::TypedefClause::*;
         match self {
-            IsAnonymous(b) => {
-                Py::new(py, IsAnonymousClause::new(b)).map(TypedefClause::IsAnonymous)
-            }
-            Name(n) => Py::new(py, NameClause::new(*n)).map(TypedefClause::Name),
+            IsAnonymous(b) => Ok(TypedefClause::IsAnonymous(Lazy::new(IsAnonymousClause::new(b)))),
+            Name(n) => Ok(TypedefClause::Name(Lazy::new(NameClause::new(*n)))),
             Namespace(ns) => {
                 Py::new(py, NamespaceClause::new(ns.into_py(py))).map(TypedefClause::Namespace)
             }
@@ -99,7 +167,7 @@ impl IntoPy<TypedefClause> for fastobo::ast::TypedefClause {
                 let xrefs = std::mem::take(def.xrefs_mut()).into_py(py);
                 Py::new(py, DefClause::new(text, xrefs)).map(TypedefClause::Def)
             }
-            Comment(c) => Py::new(py, CommentClause::new(*c)).map(TypedefClause::Comment),
+            Comment(c) => Ok(TypedefClause::Comment(Lazy::new(CommentClause::new(*c)))),
             Subset(s) => Py::new(py, SubsetClause::new(s.into_py(py))).map(TypedefClause::Subset),
             Synonym(s) => Py::new(py, s.into_py(py))
                 .map(SynonymClause::new)
@@ -113,33 +181,30 @@ impl IntoPy<TypedefClause> for fastobo::ast::TypedefClause {
                 .map(TypedefClause::PropertyValue),
             Domain(id) => Py::new(py, DomainClause::new(id.into_py(py))).map(TypedefClause::Domain),
             Range(id) => Py::new(py, RangeClause::new(id.into_py(py))).map(TypedefClause::Range),
-            Builtin(b) => Py::new(py, BuiltinClause::new(b)).map(TypedefClause::Builtin),
+            Builtin(b) => Ok(TypedefClause::Builtin(Lazy::new(BuiltinClause::new(b)))),
             HoldsOverChain(r1, r2) => Py::new(
                 py,
                 HoldsOverChainClause::new(r1.into_py(py), r2.into_py(py)),
             )
             .map(TypedefClause::HoldsOverChain),
-            IsAntiSymmetric(b) => {
-                Py::new(py, IsAntiSymmetricClause::new(b)).map(TypedefClause::IsAntiSymmetric)
-            }
-            IsCyclic(b) => Py::new(py, IsCyclicClause::new(b)).map(TypedefClause::IsCyclic),
-            IsReflexive(b) => {
-                Py::new(py, IsReflexiveClause::new(b)).map(TypedefClause::IsReflexive)
-            }
-            IsSymmetric(b) => {
-                Py::new(py, IsSymmetricClause::new(b)).map(TypedefClause::IsSymmetric)
-            }
-            IsAsymmetric(b) => {
-                Py::new(py, IsAsymmetricClause::new(b)).map(TypedefClause::IsAsymmetric)
-            }
-            IsTransitive(b) => {
-                Py::new(py, IsTransitiveClause::new(b)).map(TypedefClause::IsTransitive)
-            }
-            IsFunctional(b) => {
-                Py::new(py, IsFunctionalClause::new(b)).map(TypedefClause::IsFunctional)
-            }
-            IsInverseFunctional(b) => Py::new(py, IsInverseFunctionalClause::new(b))
-                .map(TypedefClause::IsInverseFunctional),
+            IsAntiSymmetric(b) => Ok(TypedefClause::IsAntiSymmetric(Lazy::new(
+                IsAntiSymmetricClause::new(b),
+            ))),
+            IsCyclic(b) => Ok(TypedefClause::IsCyclic(Lazy::new(IsCyclicClause::new(b)))),
+            IsReflexive(b) => Ok(TypedefClause::IsReflexive(Lazy::new(IsReflexiveClause::new(b)))),
+            IsSymmetric(b) => Ok(TypedefClause::IsSymmetric(Lazy::new(IsSymmetricClause::new(b)))),
+            IsAsymmetric(b) => Ok(TypedefClause::IsAsymmetric(Lazy::new(
+                IsAsymmetricClause::new(b),
+            ))),
+            IsTransitive(b) => Ok(TypedefClause::IsTransitive(Lazy::new(
+                IsTransitiveClause::new(b),
+            ))),
+            IsFunctional(b) => Ok(TypedefClause::IsFunctional(Lazy::new(
+                IsFunctionalClause::new(b),
+            ))),
+            IsInverseFunctional(b) => Ok(TypedefClause::IsInverseFunctional(Lazy::new(
+                IsInverseFunctionalClause::new(b),
+            ))),
             IsA(id) => Py::new(py, IsAClause::new(id.into_py(py))).map(TypedefClause::IsA),
             IntersectionOf(r) => Py::new(py, IntersectionOfClause::new(r.into_py(py)))
                 .map(TypedefClause::IntersectionOf),
@@ -167,16 +232,14 @@ impl IntoPy<TypedefClause> for fastobo::ast::TypedefClause {
                 Py::new(py, RelationshipClause::new(r.into_py(py), id.into_py(py)))
                     .map(TypedefClause::Relationship)
             }
-            IsObsolete(b) => Py::new(py, IsObsoleteClause::new(b)).map(TypedefClause::IsObsolete),
+            IsObsolete(b) => Ok(TypedefClause::IsObsolete(Lazy::new(IsObsoleteClause::new(b)))),
             ReplacedBy(id) => {
                 Py::new(py, ReplacedByClause::new(id.into_py(py))).map(TypedefClause::ReplacedBy)
             }
             Consider(id) => {
                 Py::new(py, ConsiderClause::new(id.into_py(py))).map(TypedefClause::Consider)
             }
-            CreatedBy(name) => {
-                Py::new(py, CreatedByClause::new(*name)).map(TypedefClause::CreatedBy)
-            }
+            CreatedBy(name) => Ok(TypedefClause::CreatedBy(Lazy::new(CreatedByClause::new(*name)))),
             CreationDate(dt) => {
                 Py::new(py, CreationDateClause::new(*dt)).map(TypedefClause::CreationDate)
             }
@@ -188,12 +251,12 @@ impl IntoPy<TypedefClause> for fastobo::ast::TypedefClause {
                 Py::new(py, ExpandExpressionToClause::new(*d, xrefs.into_py(py)))
                     .map(TypedefClause::ExpandExpressionTo)
             }
-            IsMetadataTag(b) => {
-                Py::new(py, IsMetadataTagClause::new(b)).map(TypedefClause::IsMetadataTag)
-            }
-            IsClassLevel(b) => {
-                Py::new(py, IsClassLevelClause::new(b)).map(TypedefClause::IsClassLevel)
-            }
+            IsMetadataTag(b) => Ok(TypedefClause::IsMetadataTag(Lazy::new(
+                IsMetadataTagClause::new(b),
+            ))),
+            IsClassLevel(b) => Ok(TypedefClause::IsClassLevel(Lazy::new(
+                IsClassLevelClause::new(b),
+            ))),
         }
         .expect("could not allocate memory for `TypedefClause` in Python heap")
     }
@@ -206,6 +269,17 @@ impl IntoPy<TypedefClause> for fastobo::ast::TypedefClause {
 #[base(AbstractEntityClause)]
 pub struct BaseTypedefClause {}
 
+#[pymethods]
+impl BaseTypedefClause {
+    // Allow user-defined subclasses, which are converted through their
+    // `raw_tag` and `raw_value` when they are added to a frame.
+    #[new]
+    #[pyo3(signature = (*_args, **_kwargs))]
+    fn __new__(_args: &PyTuple, _kwargs: Option<&PyDict>) -> PyClassInitializer<Self> {
+        Self::initializer()
+    }
+}
+
 // --- IsAnonymous -----------------------------------------------------------
 
 /// IsAnonymousClause(anonymous)
@@ -213,7 +287,7 @@ pub struct BaseTypedefClause {}
 ///
 /// A clause declaring whether or not the relationship has an anonymous id.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct IsAnonymousClause {
     #[pyo3(get, set)]
@@ -226,11 +300,7 @@ impl IsAnonymousClause {
     }
 }
 
-impl Display for IsAnonymousClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TypedefClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(IsAnonymousClause, anonymous);
 
 impl From<IsAnonymousClause> for fastobo::ast::TypedefClause {
     fn from(clause: IsAnonymousClause) -> Self {
@@ -244,6 +314,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for IsAnonymousClause {
     }
 }
 
+#[obo_clause(tag = "is_anonymous")]
 #[pymethods]
 impl IsAnonymousClause {
     #[new]
@@ -259,16 +330,12 @@ impl IsAnonymousClause {
         Ok(self.to_string())
     }
 
-    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.anonymous)
-    }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "is_anonymous").to_object(slf.py())
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
-    fn raw_value(&self) -> String {
-        self.anonymous.to_string()
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
     }
 }
 
@@ -279,7 +346,7 @@ impl IsAnonymousClause {
 ///
 /// A clause declaring the human-readable name of this relationship.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct NameClause {
     name: fastobo::ast::UnquotedString,
@@ -289,14 +356,15 @@ impl NameClause {
     pub fn new(name: fastobo::ast::UnquotedString) -> Self {
         Self { name }
     }
-}
 
-impl Display for NameClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TypedefClause::from(self.clone()).fmt(f)
+    /// Get a reference to the name of the clause.
+    pub fn name(&self) -> &fastobo::ast::UnquotedString {
+        &self.name
     }
 }
 
+impl_display_py!(NameClause, name);
+
 impl From<NameClause> for fastobo::ast::TypedefClause {
     fn from(clause: NameClause) -> Self {
         fastobo::ast::TypedefClause::Name(Box::new(clause.name))
@@ -309,6 +377,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for NameClause {
     }
 }
 
+#[obo_clause(tag = "name")]
 #[pymethods]
 impl NameClause {
     #[new]
@@ -324,8 +393,12 @@ impl NameClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.name)
+        self.richcmp_py(other, op)
     }
 
     /// `str`: the name of the current term.
@@ -338,14 +411,6 @@ impl NameClause {
     fn set_name(&mut self, name: String) {
         self.name = fastobo::ast::UnquotedString::new(name);
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "name").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.name.to_string()
-    }
 }
 
 // --- Namespace -------------------------------------------------------------
@@ -355,7 +420,7 @@ impl NameClause {
 ///
 /// A term clause declaring the namespace of this relationship.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct NamespaceClause {
     #[pyo3(set)]
@@ -376,12 +441,7 @@ impl ClonePy for NamespaceClause {
     }
 }
 
-impl Display for NamespaceClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(NamespaceClause, namespace);
 
 impl IntoPy<fastobo::ast::TypedefClause> for NamespaceClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -390,6 +450,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for NamespaceClause {
     }
 }
 
+#[obo_clause(tag = "namespace")]
 #[pymethods]
 impl NamespaceClause {
     #[new]
@@ -405,8 +466,12 @@ impl NamespaceClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.namespace)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -414,14 +479,6 @@ impl NamespaceClause {
     fn get_namespace(&self) -> PyResult<&Ident> {
         Ok(&self.namespace)
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "namespace").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.namespace.to_string()
-    }
 }
 
 // --- AltId -----------------------------------------------------------------
@@ -431,7 +488,7 @@ impl NamespaceClause {
 ///
 /// A clause defines an alternate id for this relationship.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct AltIdClause {
     #[pyo3(set)]
@@ -452,12 +509,7 @@ impl ClonePy for AltIdClause {
     }
 }
 
-impl Display for AltIdClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(AltIdClause, alt_id);
 
 impl IntoPy<fastobo::ast::TypedefClause> for AltIdClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -465,6 +517,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for AltIdClause {
     }
 }
 
+#[obo_clause(tag = "alt_id")]
 #[pymethods]
 impl AltIdClause {
     #[new]
@@ -480,8 +533,12 @@ impl AltIdClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.alt_id)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -489,14 +546,6 @@ impl AltIdClause {
     fn get_alt_id(&self) -> PyResult<&Ident> {
         Ok(&self.alt_id)
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "alt_id").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.alt_id.to_string()
-    }
 }
 
 // --- Def -------------------------------------------------------------------
@@ -514,7 +563,7 @@ impl AltIdClause {
 ///         definition, or `None`.
 ///
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct DefClause {
     definition: fastobo::ast::QuotedString,
@@ -536,12 +585,7 @@ impl ClonePy for DefClause {
     }
 }
 
-impl Display for DefClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(DefClause, definition, xrefs);
 
 impl IntoPy<fastobo::ast::TypedefClause> for DefClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -551,6 +595,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for DefClause {
     }
 }
 
+#[obo_clause(tag = "def")]
 #[pymethods]
 impl DefClause {
     #[new]
@@ -577,8 +622,12 @@ impl DefClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.definition && self.xrefs)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -598,10 +647,6 @@ impl DefClause {
         Ok(self.xrefs.clone_py(py))
     }
 
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "def").to_object(slf.py())
-    }
-
     fn raw_value(&self) -> String {
         self.definition.to_string()
     }
@@ -614,7 +659,7 @@ impl DefClause {
 ///
 /// A clause storing a comment for this relationship.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct CommentClause {
     comment: fastobo::ast::UnquotedString,
@@ -626,11 +671,7 @@ impl CommentClause {
     }
 }
 
-impl Display for CommentClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TypedefClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(CommentClause, comment);
 
 impl From<CommentClause> for fastobo::ast::TypedefClause {
     fn from(clause: CommentClause) -> Self {
@@ -644,6 +685,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for CommentClause {
     }
 }
 
+#[obo_clause(tag = "comment")]
 #[pymethods]
 impl CommentClause {
     #[new]
@@ -659,8 +701,12 @@ impl CommentClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.comment)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -673,14 +719,6 @@ impl CommentClause {
     fn set_comment(&mut self, comment: String) {
         self.comment = fastobo::ast::UnquotedString::new(comment);
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "comment").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.comment.to_string()
-    }
 }
 
 // --- Subset ----------------------------------------------------------------
@@ -690,7 +728,7 @@ impl CommentClause {
 ///
 /// A clause declaring a subset to which this relationship belongs.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct SubsetClause {
     #[pyo3(set)]
@@ -711,12 +749,7 @@ impl ClonePy for SubsetClause {
     }
 }
 
-impl Display for SubsetClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(SubsetClause, subset);
 
 impl IntoPy<fastobo::ast::TypedefClause> for SubsetClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -724,6 +757,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for SubsetClause {
     }
 }
 
+#[obo_clause(tag = "subset")]
 #[pymethods]
 impl SubsetClause {
     #[new]
@@ -739,8 +773,12 @@ impl SubsetClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.subset)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -748,14 +786,6 @@ impl SubsetClause {
     fn get_subset(&self) -> PyResult<&Ident> {
         Ok(&self.subset)
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "subset").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.subset.to_string()
-    }
 }
 
 // --- Synonym ---------------------------------------------------------------
@@ -765,7 +795,7 @@ impl SubsetClause {
 ///
 /// A clause giving a synonym for this relation, with some cross-references.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct SynonymClause {
     #[pyo3(get, set)]
@@ -787,12 +817,7 @@ impl ClonePy for SynonymClause {
     }
 }
 
-impl Display for SynonymClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(SynonymClause, synonym);
 
 impl IntoPy<fastobo::ast::TypedefClause> for SynonymClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -802,6 +827,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for SynonymClause {
     }
 }
 
+#[obo_clause(tag = "synonym")]
 #[pymethods]
 impl SynonymClause {
     #[new]
@@ -817,18 +843,12 @@ impl SynonymClause {
         Ok(self.to_string())
     }
 
-    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.synonym)
-    }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "synonym").to_object(slf.py())
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
-    fn raw_value(&self) -> String {
-        Python::with_gil(|py| {
-            format!("{}", &*self.synonym.as_ref(py).borrow())
-        })
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
     }
 }
 
@@ -839,7 +859,7 @@ impl SynonymClause {
 ///
 /// A cross-reference describing an analogous relation in another vocabulary.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct XrefClause {
     #[pyo3(get, set)]
@@ -861,12 +881,7 @@ impl ClonePy for XrefClause {
     }
 }
 
-impl Display for XrefClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(XrefClause, xref);
 
 impl From<Py<Xref>> for XrefClause {
     fn from(xref: Py<Xref>) -> Self {
@@ -891,6 +906,7 @@ impl IntoPy<XrefClause> for Xref {
     }
 }
 
+#[obo_clause(tag = "xref")]
 #[pymethods]
 impl XrefClause {
     #[new]
@@ -906,16 +922,12 @@ impl XrefClause {
         Ok(self.to_string())
     }
 
-    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.xref)
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "xref").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        Python::with_gil(|py| self.xref.as_ref(py).to_string())
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
     }
 }
 
@@ -931,7 +943,7 @@ impl XrefClause {
 ///         to annotate the current relationship.
 ///
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct PropertyValueClause {
     #[pyo3(set)]
@@ -954,12 +966,7 @@ impl ClonePy for PropertyValueClause {
     }
 }
 
-impl Display for PropertyValueClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(PropertyValueClause, inner);
 
 impl IntoPy<fastobo::ast::TypedefClause> for PropertyValueClause {
     fn into_py(self, py: Python) -> ast::TypedefClause {
@@ -967,11 +974,12 @@ impl IntoPy<fastobo::ast::TypedefClause> for PropertyValueClause {
     }
 }
 
+#[obo_clause(tag = "property_value")]
 #[pymethods]
 impl PropertyValueClause {
     #[new]
-    fn __init__(pv: PropertyValue) -> PyClassInitializer<Self> {
-        Self::new(pv).into()
+    fn __init__(property_value: PropertyValue) -> PyClassInitializer<Self> {
+        Self::new(property_value).into()
     }
 
     fn __repr__(&self) -> PyResult<PyObject> {
@@ -982,8 +990,12 @@ impl PropertyValueClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.inner)
+        self.richcmp_py(other, op)
     }
 
     /// `~fastobo.pv.AbstractPropertyValue`: an annotation of the relation.
@@ -991,14 +1003,6 @@ impl PropertyValueClause {
     fn property_value(&self) -> &PropertyValue {
         &self.inner
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "property_value").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.inner.to_string()
-    }
 }
 
 // --- Domain ----------------------------------------------------------------
@@ -1008,7 +1012,7 @@ impl PropertyValueClause {
 ///
 /// A clause declaring the domain of the relationship, if any.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct DomainClause {
     #[pyo3(set)]
@@ -1029,12 +1033,7 @@ impl ClonePy for DomainClause {
     }
 }
 
-impl Display for DomainClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(DomainClause, domain);
 
 impl IntoPy<fastobo::ast::TypedefClause> for DomainClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -1042,6 +1041,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for DomainClause {
     }
 }
 
+#[obo_clause(tag = "domain")]
 #[pymethods]
 impl DomainClause {
     #[new]
@@ -1057,8 +1057,12 @@ impl DomainClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.domain)
+        self.richcmp_py(other, op)
     }
 
     /// `~fastobo.id.Ident`: the identifier of the domain of the relation.
@@ -1066,14 +1070,6 @@ impl DomainClause {
     fn get_domain(&self) -> &Ident {
         &self.domain
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "domain").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.domain.to_string()
-    }
 }
 
 // --- Range -----------------------------------------------------------------
@@ -1083,7 +1079,7 @@ impl DomainClause {
 ///
 /// A clause declaring the range of the relationship, if any.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct RangeClause {
     #[pyo3(set)]
@@ -1104,12 +1100,7 @@ impl ClonePy for RangeClause {
     }
 }
 
-impl Display for RangeClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(RangeClause, range);
 
 impl IntoPy<fastobo::ast::TypedefClause> for RangeClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -1117,6 +1108,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for RangeClause {
     }
 }
 
+#[obo_clause(tag = "range")]
 #[pymethods]
 impl RangeClause {
     #[new]
@@ -1132,8 +1124,12 @@ impl RangeClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.range)
+        self.richcmp_py(other, op)
     }
 
     /// `~fastobo.id.Ident`: the identifier of the range of the typedef.
@@ -1141,14 +1137,6 @@ impl RangeClause {
     fn get_range(&self) -> &Ident {
         &self.range
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "range").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.range.to_string()
-    }
 }
 
 // --- Builtin ---------------------------------------------------------------
@@ -1158,7 +1146,7 @@ impl RangeClause {
 ///
 /// A clause declaring whether this relation is built-in to the OBO format.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct BuiltinClause {
     #[pyo3(get, set)]
@@ -1172,11 +1160,7 @@ impl BuiltinClause {
     }
 }
 
-impl Display for BuiltinClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TypedefClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(BuiltinClause, builtin);
 
 impl From<BuiltinClause> for fastobo::ast::TypedefClause {
     fn from(clause: BuiltinClause) -> Self {
@@ -1190,6 +1174,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for BuiltinClause {
     }
 }
 
+#[obo_clause(tag = "builtin")]
 #[pymethods]
 impl BuiltinClause {
     #[new]
@@ -1205,16 +1190,12 @@ impl BuiltinClause {
         Ok(self.to_string())
     }
 
-    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.builtin)
-    }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "builtin").to_object(slf.py())
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
-    fn raw_value(&self) -> String {
-        self.builtin.to_string()
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
     }
 }
 
@@ -1225,7 +1206,7 @@ impl BuiltinClause {
 ///
 /// An extension of the `transitive_over` tag for property chains.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct HoldsOverChainClause {
     #[pyo3(set)]
@@ -1249,12 +1230,7 @@ impl ClonePy for HoldsOverChainClause {
     }
 }
 
-impl Display for HoldsOverChainClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(HoldsOverChainClause, first, last);
 
 impl IntoPy<fastobo::ast::TypedefClause> for HoldsOverChainClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -1265,6 +1241,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for HoldsOverChainClause {
     }
 }
 
+#[obo_clause(tag = "holds_over_chain")]
 #[pymethods]
 impl HoldsOverChainClause {
     #[new]
@@ -1280,8 +1257,12 @@ impl HoldsOverChainClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.first && self.last)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -1295,14 +1276,6 @@ impl HoldsOverChainClause {
     fn get_last(&self) -> &Ident {
         &self.last
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "holds_over_chain").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        format!("{} {}", self.first, self.last)
-    }
 }
 
 // --- IsAntiSymmetric -------------------------------------------------------
@@ -1312,7 +1285,7 @@ impl HoldsOverChainClause {
 ///
 /// A clause declaring whether the relationship if anti-symmetric or not.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct IsAntiSymmetricClause {
     #[pyo3(get, set)]
@@ -1325,11 +1298,7 @@ impl IsAntiSymmetricClause {
     }
 }
 
-impl Display for IsAntiSymmetricClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TypedefClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(IsAntiSymmetricClause, anti_symmetric);
 
 impl From<IsAntiSymmetricClause> for fastobo::ast::TypedefClause {
     fn from(clause: IsAntiSymmetricClause) -> Self {
@@ -1343,6 +1312,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for IsAntiSymmetricClause {
     }
 }
 
+#[obo_clause(tag = "is_anti_symmetric")]
 #[pymethods]
 impl IsAntiSymmetricClause {
     #[new]
@@ -1358,16 +1328,12 @@ impl IsAntiSymmetricClause {
         Ok(self.to_string())
     }
 
-    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.anti_symmetric)
-    }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "is_anti_symmetric").to_object(slf.py())
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
-    fn raw_value(&self) -> String {
-        self.anti_symmetric.to_string()
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
     }
 }
 
@@ -1378,7 +1344,7 @@ impl IsAntiSymmetricClause {
 ///
 /// A clause declaring whether the relationship if cyclic or not.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct IsCyclicClause {
     #[pyo3(get, set)]
@@ -1391,11 +1357,7 @@ impl IsCyclicClause {
     }
 }
 
-impl Display for IsCyclicClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TypedefClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(IsCyclicClause, cyclic);
 
 impl From<IsCyclicClause> for fastobo::ast::TypedefClause {
     fn from(clause: IsCyclicClause) -> Self {
@@ -1409,6 +1371,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for IsCyclicClause {
     }
 }
 
+#[obo_clause(tag = "is_cyclic")]
 #[pymethods]
 impl IsCyclicClause {
     #[new]
@@ -1424,16 +1387,12 @@ impl IsCyclicClause {
         Ok(self.to_string())
     }
 
-    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.cyclic)
-    }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "is_cyclic").to_object(slf.py())
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
-    fn raw_value(&self) -> String {
-        self.cyclic.to_string()
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
     }
 }
 
@@ -1444,7 +1403,7 @@ impl IsCyclicClause {
 ///
 /// A clause declaring whether the relationship if reflexive or not.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct IsReflexiveClause {
     #[pyo3(get, set)]
@@ -1457,11 +1416,7 @@ impl IsReflexiveClause {
     }
 }
 
-impl Display for IsReflexiveClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TypedefClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(IsReflexiveClause, reflexive);
 
 impl From<IsReflexiveClause> for fastobo::ast::TypedefClause {
     fn from(clause: IsReflexiveClause) -> Self {
@@ -1475,6 +1430,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for IsReflexiveClause {
     }
 }
 
+#[obo_clause(tag = "is_reflexive")]
 #[pymethods]
 impl IsReflexiveClause {
     #[new]
@@ -1490,16 +1446,12 @@ impl IsReflexiveClause {
         Ok(self.to_string())
     }
 
-    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.reflexive)
-    }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "is_reflexive").to_object(slf.py())
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
-    fn raw_value(&self) -> String {
-        self.reflexive.to_string()
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
     }
 }
 
@@ -1510,7 +1462,7 @@ impl IsReflexiveClause {
 ///
 /// A clause declaring whether the relationship if symmetric or not.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct IsSymmetricClause {
     #[pyo3(get, set)]
@@ -1523,11 +1475,7 @@ impl IsSymmetricClause {
     }
 }
 
-impl Display for IsSymmetricClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TypedefClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(IsSymmetricClause, symmetric);
 
 impl From<IsSymmetricClause> for fastobo::ast::TypedefClause {
     fn from(clause: IsSymmetricClause) -> Self {
@@ -1541,6 +1489,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for IsSymmetricClause {
     }
 }
 
+#[obo_clause(tag = "is_symmetric")]
 #[pymethods]
 impl IsSymmetricClause {
     #[new]
@@ -1556,16 +1505,12 @@ impl IsSymmetricClause {
         Ok(self.to_string())
     }
 
-    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.symmetric)
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "is_symmetric").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.symmetric.to_string()
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
     }
 }
 
@@ -1576,7 +1521,7 @@ impl IsSymmetricClause {
 ///
 /// A clause declaring whether the relationship is asymmetric or not.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct IsAsymmetricClause {
     #[pyo3(get, set)]
@@ -1589,11 +1534,7 @@ impl IsAsymmetricClause {
     }
 }
 
-impl Display for IsAsymmetricClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TypedefClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(IsAsymmetricClause, asymmetric);
 
 impl From<IsAsymmetricClause> for fastobo::ast::TypedefClause {
     fn from(clause: IsAsymmetricClause) -> Self {
@@ -1607,6 +1548,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for IsAsymmetricClause {
     }
 }
 
+#[obo_clause(tag = "is_asymmetric")]
 #[pymethods]
 impl IsAsymmetricClause {
     #[new]
@@ -1622,16 +1564,12 @@ impl IsAsymmetricClause {
         Ok(self.to_string())
     }
 
-    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.asymmetric)
-    }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "is_asymmetric").to_object(slf.py())
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
-    fn raw_value(&self) -> String {
-        self.asymmetric.to_string()
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
     }
 }
 
@@ -1642,7 +1580,7 @@ impl IsAsymmetricClause {
 ///
 /// A clause declaring whether the relationship if transitive or not.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct IsTransitiveClause {
     #[pyo3(get, set)]
@@ -1655,11 +1593,7 @@ impl IsTransitiveClause {
     }
 }
 
-impl Display for IsTransitiveClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TypedefClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(IsTransitiveClause, transitive);
 
 impl From<IsTransitiveClause> for fastobo::ast::TypedefClause {
     fn from(clause: IsTransitiveClause) -> Self {
@@ -1673,6 +1607,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for IsTransitiveClause {
     }
 }
 
+#[obo_clause(tag = "is_transitive")]
 #[pymethods]
 impl IsTransitiveClause {
     #[new]
@@ -1688,16 +1623,12 @@ impl IsTransitiveClause {
         Ok(self.to_string())
     }
 
-    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.transitive)
-    }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "is_transitive").to_object(slf.py())
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
-    fn raw_value(&self) -> String {
-        self.transitive.to_string()
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
     }
 }
 
@@ -1708,7 +1639,7 @@ impl IsTransitiveClause {
 ///
 /// A clause declaring whether the relationship if functional or not.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct IsFunctionalClause {
     #[pyo3(get, set)]
@@ -1721,11 +1652,7 @@ impl IsFunctionalClause {
     }
 }
 
-impl Display for IsFunctionalClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TypedefClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(IsFunctionalClause, functional);
 
 impl From<IsFunctionalClause> for fastobo::ast::TypedefClause {
     fn from(clause: IsFunctionalClause) -> Self {
@@ -1739,6 +1666,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for IsFunctionalClause {
     }
 }
 
+#[obo_clause(tag = "is_functional")]
 #[pymethods]
 impl IsFunctionalClause {
     #[new]
@@ -1754,16 +1682,12 @@ impl IsFunctionalClause {
         Ok(self.to_string())
     }
 
-    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.functional)
-    }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "is_functional").to_object(slf.py())
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
-    fn raw_value(&self) -> String {
-        self.functional.to_string()
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
     }
 }
 
@@ -1774,7 +1698,7 @@ impl IsFunctionalClause {
 ///
 /// A clause declaring whether the relationship if inverse-functional or not.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct IsInverseFunctionalClause {
     #[pyo3(get, set)]
@@ -1787,11 +1711,7 @@ impl IsInverseFunctionalClause {
     }
 }
 
-impl Display for IsInverseFunctionalClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TypedefClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(IsInverseFunctionalClause, inverse_functional);
 
 impl From<IsInverseFunctionalClause> for fastobo::ast::TypedefClause {
     fn from(clause: IsInverseFunctionalClause) -> Self {
@@ -1805,6 +1725,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for IsInverseFunctionalClause {
     }
 }
 
+#[obo_clause(tag = "is_inverse_functional")]
 #[pymethods]
 impl IsInverseFunctionalClause {
     #[new]
@@ -1820,16 +1741,12 @@ impl IsInverseFunctionalClause {
         Ok(self.to_string())
     }
 
-    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.inverse_functional)
-    }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "is_inverse_functional").to_object(slf.py())
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
-    fn raw_value(&self) -> String {
-        self.inverse_functional.to_string()
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
     }
 }
 
@@ -1840,10 +1757,9 @@ impl IsInverseFunctionalClause {
 ///
 /// A clause declaring this relation is a subproperty of another relation.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct IsAClause {
-    #[pyo3(set)]
     typedef: Ident,
 }
 
@@ -1853,12 +1769,7 @@ impl IsAClause {
     }
 }
 
-impl Display for IsAClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(IsAClause, typedef);
 
 impl ClonePy for IsAClause {
     fn clone_py(&self, py: Python) -> Self {
@@ -1874,6 +1785,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for IsAClause {
     }
 }
 
+#[obo_clause(tag = "is_a")]
 #[pymethods]
 impl IsAClause {
     #[new]
@@ -1889,8 +1801,12 @@ impl IsAClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.typedef)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -1899,12 +1815,10 @@ impl IsAClause {
         &self.typedef
     }
 
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "is_a").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.typedef.to_string()
+    #[setter]
+    fn set_typedef(&mut self, typedef: Ident) {
+        crate::index::invalidate();
+        self.typedef = typedef;
     }
 }
 
@@ -1915,7 +1829,7 @@ impl IsAClause {
 ///
 /// Declares this relation is equivalent to the intersection of other relations.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct IntersectionOfClause {
     #[pyo3(set)]
@@ -1936,12 +1850,7 @@ impl ClonePy for IntersectionOfClause {
     }
 }
 
-impl Display for IntersectionOfClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(IntersectionOfClause, typedef);
 
 impl IntoPy<fastobo::ast::TypedefClause> for IntersectionOfClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -1949,6 +1858,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for IntersectionOfClause {
     }
 }
 
+#[obo_clause(tag = "intersection_of")]
 #[pymethods]
 impl IntersectionOfClause {
     #[new]
@@ -1964,22 +1874,18 @@ impl IntersectionOfClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.typedef)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
     fn get_typedef(&self) -> &Ident {
         &self.typedef
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "intersection_of").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.typedef.to_string()
-    }
 }
 
 // --- UnionOf ---------------------------------------------------------------
@@ -1989,7 +1895,7 @@ impl IntersectionOfClause {
 ///
 /// Declares the relation represents the union of several other relations.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct UnionOfClause {
     #[pyo3(set)]
@@ -2010,12 +1916,7 @@ impl ClonePy for UnionOfClause {
     }
 }
 
-impl Display for UnionOfClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(UnionOfClause, typedef);
 
 impl IntoPy<fastobo::ast::TypedefClause> for UnionOfClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -2023,6 +1924,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for UnionOfClause {
     }
 }
 
+#[obo_clause(tag = "union_of")]
 #[pymethods]
 impl UnionOfClause {
     #[new]
@@ -2038,8 +1940,12 @@ impl UnionOfClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.typedef)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -2047,14 +1953,6 @@ impl UnionOfClause {
     fn get_typedef(&self) -> &Ident {
         &self.typedef
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "union_of").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.typedef.to_string()
-    }
 }
 
 // --- EquivalentTo ----------------------------------------------------------
@@ -2064,7 +1962,7 @@ impl UnionOfClause {
 ///
 /// A clause indicating the relation is exactly equivalent to another relation.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct EquivalentToClause {
     #[pyo3(set)]
@@ -2085,12 +1983,7 @@ impl ClonePy for EquivalentToClause {
     }
 }
 
-impl Display for EquivalentToClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(EquivalentToClause, typedef);
 
 impl IntoPy<fastobo::ast::TypedefClause> for EquivalentToClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -2098,11 +1991,12 @@ impl IntoPy<fastobo::ast::TypedefClause> for EquivalentToClause {
     }
 }
 
+#[obo_clause(tag = "equivalent_to")]
 #[pymethods]
 impl EquivalentToClause {
     #[new]
-    fn __init__(id: Ident) -> PyClassInitializer<Self> {
-        Self::new(id).into()
+    fn __init__(typedef: Ident) -> PyClassInitializer<Self> {
+        Self::new(typedef).into()
     }
 
     fn __repr__(&self) -> PyResult<PyObject> {
@@ -2113,8 +2007,12 @@ impl EquivalentToClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.typedef)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -2122,14 +2020,6 @@ impl EquivalentToClause {
     fn get_typedef(&self) -> &Ident {
         &self.typedef
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "equivalent_to").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.typedef.to_string()
-    }
 }
 
 // --- DisjointFrom ----------------------------------------------------------
@@ -2139,7 +2029,7 @@ impl EquivalentToClause {
 ///
 /// A clause stating is disjoint from another relationship.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct DisjointFromClause {
     #[pyo3(set)]
@@ -2160,12 +2050,7 @@ impl ClonePy for DisjointFromClause {
     }
 }
 
-impl Display for DisjointFromClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(DisjointFromClause, typedef);
 
 impl IntoPy<fastobo::ast::TypedefClause> for DisjointFromClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -2173,6 +2058,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for DisjointFromClause {
     }
 }
 
+#[obo_clause(tag = "disjoint_from")]
 #[pymethods]
 impl DisjointFromClause {
     #[new]
@@ -2188,8 +2074,12 @@ impl DisjointFromClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.typedef)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -2197,14 +2087,6 @@ impl DisjointFromClause {
     fn get_typedef(&self) -> &Ident {
         &self.typedef
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "disjoint_from").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.typedef.to_string()
-    }
 }
 
 // --- InverseOf -------------------------------------------------------------
@@ -2214,7 +2096,7 @@ impl DisjointFromClause {
 ///
 /// A clause declaring the inverse of this relationship type.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct InverseOfClause {
     #[pyo3(set)]
@@ -2235,12 +2117,7 @@ impl ClonePy for InverseOfClause {
     }
 }
 
-impl Display for InverseOfClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(InverseOfClause, typedef);
 
 impl IntoPy<fastobo::ast::TypedefClause> for InverseOfClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -2248,6 +2125,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for InverseOfClause {
     }
 }
 
+#[obo_clause(tag = "inverse_of")]
 #[pymethods]
 impl InverseOfClause {
     #[new]
@@ -2263,8 +2141,12 @@ impl InverseOfClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.typedef)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -2272,14 +2154,6 @@ impl InverseOfClause {
     fn get_typedef(&self) -> &Ident {
         &self.typedef
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "inverse_of").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.typedef.to_string()
-    }
 }
 
 // --- TransitiveOver --------------------------------------------------------
@@ -2289,7 +2163,7 @@ impl InverseOfClause {
 ///
 /// A clause declaring another relation that this relation is transitive over.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct TransitiveOverClause {
     #[pyo3(set)]
@@ -2310,12 +2184,7 @@ impl ClonePy for TransitiveOverClause {
     }
 }
 
-impl Display for TransitiveOverClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(TransitiveOverClause, typedef);
 
 impl IntoPy<fastobo::ast::TypedefClause> for TransitiveOverClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -2323,6 +2192,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for TransitiveOverClause {
     }
 }
 
+#[obo_clause(tag = "transitive_over")]
 #[pymethods]
 impl TransitiveOverClause {
     #[new]
@@ -2338,8 +2208,12 @@ impl TransitiveOverClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.typedef)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -2347,14 +2221,6 @@ impl TransitiveOverClause {
     fn get_typedef(&self) -> &Ident {
         &self.typedef
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "transitive_over").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.typedef.to_string()
-    }
 }
 
 // --- EquivalentToChain -----------------------------------------------------
@@ -2364,7 +2230,7 @@ impl TransitiveOverClause {
 ///
 /// A clause declaring a property chain this relationship is equivalent to.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct EquivalentToChainClause {
     #[pyo3(set)]
@@ -2388,12 +2254,7 @@ impl ClonePy for EquivalentToChainClause {
     }
 }
 
-impl Display for EquivalentToChainClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(EquivalentToChainClause, first, last);
 
 impl IntoPy<fastobo::ast::TypedefClause> for EquivalentToChainClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -2404,6 +2265,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for EquivalentToChainClause {
     }
 }
 
+#[obo_clause(tag = "equivalent_to_chain")]
 #[pymethods]
 impl EquivalentToChainClause {
     #[new]
@@ -2419,8 +2281,12 @@ impl EquivalentToChainClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.first && self.last)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -2434,14 +2300,6 @@ impl EquivalentToChainClause {
     fn get_last(&self) -> &Ident {
         &self.last
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "equivalent_to_chain").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        format!("{} {}", self.first, self.last)
-    }
 }
 
 // --- DisjointOver ----------------------------------------------------------
@@ -2451,7 +2309,7 @@ impl EquivalentToChainClause {
 ///
 /// A clause declaring a relationship this relationship is disjoint over.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct DisjointOverClause {
     #[pyo3(set)]
@@ -2472,12 +2330,7 @@ impl ClonePy for DisjointOverClause {
     }
 }
 
-impl Display for DisjointOverClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(DisjointOverClause, typedef);
 
 impl IntoPy<fastobo::ast::TypedefClause> for DisjointOverClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -2485,6 +2338,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for DisjointOverClause {
     }
 }
 
+#[obo_clause(tag = "disjoint_over")]
 #[pymethods]
 impl DisjointOverClause {
     #[new]
@@ -2500,8 +2354,12 @@ impl DisjointOverClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.typedef)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -2509,14 +2367,6 @@ impl DisjointOverClause {
     fn get_typedef(&self) -> &Ident {
         &self.typedef
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "disjoint_over").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.typedef.to_string()
-    }
 }
 
 // --- Relationship ----------------------------------------------------------
@@ -2526,12 +2376,10 @@ impl DisjointOverClause {
 ///
 /// A clause declaring a relationship this relation has to another relation.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct RelationshipClause {
-    #[pyo3(set)]
     typedef: Ident,
-    #[pyo3(set)]
     target: Ident,
 }
 
@@ -2550,12 +2398,7 @@ impl ClonePy for RelationshipClause {
     }
 }
 
-impl Display for RelationshipClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(RelationshipClause, typedef, target);
 
 impl IntoPy<fastobo::ast::TypedefClause> for RelationshipClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -2566,6 +2409,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for RelationshipClause {
     }
 }
 
+#[obo_clause(tag = "relationship")]
 #[pymethods]
 impl RelationshipClause {
     #[new]
@@ -2581,8 +2425,12 @@ impl RelationshipClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.typedef && self.target)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -2590,17 +2438,21 @@ impl RelationshipClause {
         &self.typedef
     }
 
+    #[setter]
+    fn set_typedef(&mut self, typedef: Ident) {
+        crate::index::invalidate();
+        self.typedef = typedef;
+    }
+
     #[getter]
     fn get_target(&self) -> &Ident {
         &self.target
     }
 
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "relationship").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        format!("{} {}", self.typedef, self.target)
+    #[setter]
+    fn set_target(&mut self, target: Ident) {
+        crate::index::invalidate();
+        self.target = target;
     }
 }
 
@@ -2611,7 +2463,7 @@ impl RelationshipClause {
 ///
 /// A clause indicating whether or not this relationship is obsolete.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct IsObsoleteClause {
     #[pyo3(get, set)]
@@ -2624,11 +2476,7 @@ impl IsObsoleteClause {
     }
 }
 
-impl Display for IsObsoleteClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TypedefClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(IsObsoleteClause, obsolete);
 
 impl From<IsObsoleteClause> for fastobo::ast::TypedefClause {
     fn from(clause: IsObsoleteClause) -> Self {
@@ -2642,6 +2490,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for IsObsoleteClause {
     }
 }
 
+#[obo_clause(tag = "is_obsolete")]
 #[pymethods]
 impl IsObsoleteClause {
     #[new]
@@ -2657,16 +2506,12 @@ impl IsObsoleteClause {
         Ok(self.to_string())
     }
 
-    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.obsolete)
-    }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "is_obsolete").to_object(slf.py())
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
-    fn raw_value(&self) -> String {
-        self.obsolete.to_string()
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
     }
 }
 
@@ -2677,7 +2522,7 @@ impl IsObsoleteClause {
 ///
 /// A clause giving a relation which replaces this obsolete relation.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct ReplacedByClause {
     #[pyo3(set)]
@@ -2698,12 +2543,7 @@ impl ClonePy for ReplacedByClause {
     }
 }
 
-impl Display for ReplacedByClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(ReplacedByClause, typedef);
 
 impl IntoPy<fastobo::ast::TypedefClause> for ReplacedByClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -2711,6 +2551,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for ReplacedByClause {
     }
 }
 
+#[obo_clause(tag = "replaced_by")]
 #[pymethods]
 impl ReplacedByClause {
     #[new]
@@ -2726,8 +2567,12 @@ impl ReplacedByClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.typedef)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -2735,14 +2580,6 @@ impl ReplacedByClause {
     fn get_typedef(&self) -> &Ident {
         &self.typedef
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "replaced_by").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.typedef.to_string()
-    }
 }
 
 // --- Consider --------------------------------------------------------------
@@ -2752,7 +2589,7 @@ impl ReplacedByClause {
 ///
 /// A clause giving a potential substitute for an obsolete typedef.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct ConsiderClause {
     #[pyo3(set)]
@@ -2773,12 +2610,7 @@ impl ClonePy for ConsiderClause {
     }
 }
 
-impl Display for ConsiderClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(ConsiderClause, typedef);
 
 impl IntoPy<fastobo::ast::TypedefClause> for ConsiderClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -2786,6 +2618,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for ConsiderClause {
     }
 }
 
+#[obo_clause(tag = "consider")]
 #[pymethods]
 impl ConsiderClause {
     #[new]
@@ -2801,8 +2634,12 @@ impl ConsiderClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.typedef)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -2810,14 +2647,6 @@ impl ConsiderClause {
     fn get_typedef(&self) -> &Ident {
         &self.typedef
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "consider").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.typedef.to_string()
-    }
 }
 
 // --- CreatedBy -------------------------------------------------------------
@@ -2827,27 +2656,25 @@ impl ConsiderClause {
 ///
 /// A term clause stating the name of the creator of this relationship.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct CreatedByClause {
-    creator: fastobo::ast::UnquotedString,
+    creator: PooledString,
 }
 
 impl CreatedByClause {
     pub fn new(creator: fastobo::ast::UnquotedString) -> Self {
-        Self { creator }
+        Self {
+            creator: PooledString::from(creator),
+        }
     }
 }
 
-impl Display for CreatedByClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TypedefClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(CreatedByClause, creator);
 
 impl From<CreatedByClause> for fastobo::ast::TypedefClause {
     fn from(clause: CreatedByClause) -> Self {
-        fastobo::ast::TypedefClause::CreatedBy(Box::new(clause.creator))
+        fastobo::ast::TypedefClause::CreatedBy(Box::new(clause.creator.into()))
     }
 }
 
@@ -2857,6 +2684,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for CreatedByClause {
     }
 }
 
+#[obo_clause(tag = "created_by")]
 #[pymethods]
 impl CreatedByClause {
     #[new]
@@ -2872,8 +2700,12 @@ impl CreatedByClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.creator)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -2883,35 +2715,33 @@ impl CreatedByClause {
 
     #[setter]
     fn set_creator(&mut self, creator: String) {
-        self.creator = fastobo::ast::UnquotedString::new(creator);
-    }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "created_by").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.creator.to_string()
+        self.creator = PooledString::new(&creator);
     }
 }
 
 // --- CreationDate ----------------------------------------------------------
 
-/// CreationDateClause(date)
+/// CreationDateClause(date, *, naive="keep")
 /// --
 ///
 /// A clause declaring the date (and optionally time) a typedef was created.
 ///
 /// Arguments:
-///     date (`datetime.date`): The date this typedef was created. If a
-///         `datetime.datime` object is given, then the serialized value
-///         will also include the serialized time.
+///     date (`datetime.date` or `str`): The date this typedef was created.
+///         If a `datetime.datime` object is given, then the serialized value
+///         will also include the serialized time. If a `str` is given, it
+///         is parsed as an ISO-8601 date or datetime, and kept as written.
+///     naive (`str`): The policy for a naive `datetime.datetime`: either
+///         ``"keep"`` to serialize it without a timezone, ``"utc"`` to
+///         assume it is in UTC, ``"local"`` to assume it is in the local
+///         timezone of the system, or ``"error"`` to raise a `ValueError`.
 ///
 /// Warning:
 ///     The timezone of the `datetime` will only be extracted down to the
-///     minutes, seconds and smaller durations will be ignored. It is advised
-///     to use `datetime.timezone.utc` whenever possible to preserve the
-///     date and time properly.
+///     minutes, seconds and smaller durations will be ignored. A timezone
+///     of `datetime.timezone.utc` is serialized as ``Z``, and any other
+///     timezone with its ``±HH:MM`` offset, so that ``+00:00`` is kept
+///     as written in the source document.
 ///
 /// Example:
 ///     >>> d1 = datetime.date(2021, 1, 23)
@@ -2920,9 +2750,11 @@ impl CreatedByClause {
 ///     >>> d2 = datetime.datetime(2021, 1, 23, tzinfo=datetime.timezone.utc)
 ///     >>> print(fastobo.typedef.CreationDateClause(d2))
 ///     creation_date: 2021-01-23T00:00:00Z
+///     >>> print(fastobo.typedef.CreationDateClause("2021-01-23T08:00:00+01:00"))
+///     creation_date: 2021-01-23T08:00:00+01:00
 ///
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct CreationDateClause {
     date: fastobo::ast::CreationDate,
@@ -2934,11 +2766,7 @@ impl CreationDateClause {
     }
 }
 
-impl Display for CreationDateClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TypedefClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(CreationDateClause, date);
 
 impl From<CreationDateClause> for fastobo::ast::TypedefClause {
     fn from(clause: CreationDateClause) -> Self {
@@ -2952,25 +2780,15 @@ impl IntoPy<fastobo::ast::TypedefClause> for CreationDateClause {
     }
 }
 
+#[obo_clause(tag = "creation_date")]
 #[pymethods]
 impl CreationDateClause {
     #[new]
-    fn __init__(datetime: &PyAny) -> PyResult<PyClassInitializer<Self>> {
-        let py = datetime.py();
-        if let Ok(dt) = datetime.downcast::<PyDateTime>() {
-            let date = datetime_to_isodatetime(py, dt).map(From::from)?;
-            Ok(CreationDateClause::new(date).into())
-        } else {
-            match datetime.downcast::<PyDate>() {
-                Err(e) => {
-                    raise!(py, PyTypeError("expected datetime.date or datetime.datetime") from PyErr::from(e))
-                }
-                Ok(d) => {
-                    let date = date_to_isodate(py, d).map(From::from)?;
-                    Ok(CreationDateClause::new(date).into())
-                }
-            }
-        }
+    #[pyo3(signature = (date, *, naive = "keep"))]
+    fn __init__(date: &PyAny, naive: &str) -> PyResult<PyClassInitializer<Self>> {
+        let policy = NaivePolicy::from_str(naive)?;
+        let date = extract_creation_date(date.py(), date, policy)?;
+        Ok(CreationDateClause::new(date).into())
     }
 
     fn __repr__(&self) -> PyResult<PyObject> {
@@ -2985,8 +2803,12 @@ impl CreationDateClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.date)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -3001,29 +2823,9 @@ impl CreationDateClause {
 
     #[setter]
     fn set_date(&mut self, datetime: &PyAny) -> PyResult<()> {
-        let py = datetime.py();
-        if let Ok(dt) = datetime.downcast::<PyDateTime>() {
-            self.date = From::from(datetime_to_isodatetime(py, dt)?);
-        } else {
-            match datetime.downcast::<PyDate>() {
-                Err(e) => {
-                    raise!(py, PyTypeError("expected datetime.date or datetime.datetime") from PyErr::from(e))
-                }
-                Ok(d) => {
-                    self.date = From::from(date_to_isodate(py, d)?);
-                }
-            }
-        }
+        self.date = extract_creation_date(datetime.py(), datetime, NaivePolicy::Keep)?;
         Ok(())
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "creation_date").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.date.to_string()
-    }
 }
 
 // --- ExpandAssertionTo -----------------------------------------------------
@@ -3033,7 +2835,7 @@ impl CreationDateClause {
 ///
 /// An OWL macro that adds an `IAO:0000425` annotation to this relation.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct ExpandAssertionToClause {
     definition: fastobo::ast::QuotedString,
@@ -3058,12 +2860,7 @@ impl ClonePy for ExpandAssertionToClause {
     }
 }
 
-impl Display for ExpandAssertionToClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(ExpandAssertionToClause, definition, xrefs);
 
 impl IntoPy<fastobo::ast::TypedefClause> for ExpandAssertionToClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -3074,6 +2871,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for ExpandAssertionToClause {
     }
 }
 
+#[obo_clause(tag = "expand_assertion_to")]
 #[pymethods]
 impl ExpandAssertionToClause {
     #[new]
@@ -3094,8 +2892,12 @@ impl ExpandAssertionToClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.definition && self.xrefs)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -3114,15 +2916,6 @@ impl ExpandAssertionToClause {
     fn get_xrefs<'py>(&self, py: Python<'py>) -> PyResult<XrefList> {
         Ok(self.xrefs.clone_py(py))
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "expand_assertion_to").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        let xrefs: fastobo::ast::XrefList = Python::with_gil(|py| self.xrefs.clone_py(py).into_py(py));
-        format!("{} {}", self.definition, xrefs)
-    }
 }
 
 // --- ExpandExpressionTo ----------------------------------------------------
@@ -3132,7 +2925,7 @@ impl ExpandAssertionToClause {
 ///
 /// An OWL macro that adds an `IAO:0000424` annotation to this relation.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct ExpandExpressionToClause {
     definition: fastobo::ast::QuotedString,
@@ -3157,12 +2950,7 @@ impl ClonePy for ExpandExpressionToClause {
     }
 }
 
-impl Display for ExpandExpressionToClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let clause: fastobo::ast::TypedefClause = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        clause.fmt(f)
-    }
-}
+impl_display_py!(ExpandExpressionToClause, definition, xrefs);
 
 impl IntoPy<fastobo::ast::TypedefClause> for ExpandExpressionToClause {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefClause {
@@ -3173,6 +2961,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for ExpandExpressionToClause {
     }
 }
 
+#[obo_clause(tag = "expand_expression_to")]
 #[pymethods]
 impl ExpandExpressionToClause {
     #[new]
@@ -3193,8 +2982,12 @@ impl ExpandExpressionToClause {
         Ok(self.to_string())
     }
 
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
+    }
+
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.definition && self.xrefs)
+        self.richcmp_py(other, op)
     }
 
     #[getter]
@@ -3213,15 +3006,6 @@ impl ExpandExpressionToClause {
     fn get_xrefs<'py>(&self, py: Python<'py>) -> PyResult<XrefList> {
         Ok(self.xrefs.clone_py(py))
     }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "expand_expression_to").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        let xrefs: fastobo::ast::XrefList = Python::with_gil(|py| self.xrefs.clone_py(py).into_py(py));
-        format!("{} {}", self.definition, xrefs)
-    }
 }
 
 // --- IsMetadataTag ---------------------------------------------------------
@@ -3231,7 +3015,7 @@ impl ExpandExpressionToClause {
 ///
 /// A clause declaring whether this relationship is a metadata tag or not.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct IsMetadataTagClause {
     #[pyo3(get, set)]
@@ -3244,11 +3028,7 @@ impl IsMetadataTagClause {
     }
 }
 
-impl Display for IsMetadataTagClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TypedefClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(IsMetadataTagClause, metadata_tag);
 
 impl From<IsMetadataTagClause> for fastobo::ast::TypedefClause {
     fn from(clause: IsMetadataTagClause) -> Self {
@@ -3262,6 +3042,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for IsMetadataTagClause {
     }
 }
 
+#[obo_clause(tag = "is_metadata_tag")]
 #[pymethods]
 impl IsMetadataTagClause {
     #[new]
@@ -3277,16 +3058,12 @@ impl IsMetadataTagClause {
         Ok(self.to_string())
     }
 
-    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.metadata_tag)
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "is_metadata_tag").to_object(slf.py())
-    }
-
-    fn raw_value(&self) -> String {
-        self.metadata_tag.to_string()
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
     }
 }
 
@@ -3297,7 +3074,7 @@ impl IsMetadataTagClause {
 ///
 /// A clause declaring wether this relationship is class level or not.
 #[pyclass(extends=BaseTypedefClause, module="fastobo.typedef")]
-#[derive(Clone, ClonePy, Debug, FinalClass, EqPy)]
+#[derive(Clone, ClonePy, Debug, FinalClass, EqPy, HashPy, RichCmpPy)]
 #[base(BaseTypedefClause)]
 pub struct IsClassLevelClause {
     #[pyo3(get, set)]
@@ -3310,11 +3087,7 @@ impl IsClassLevelClause {
     }
 }
 
-impl Display for IsClassLevelClause {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        fastobo::ast::TypedefClause::from(self.clone()).fmt(f)
-    }
-}
+impl_display_py!(IsClassLevelClause, class_level);
 
 impl From<IsClassLevelClause> for fastobo::ast::TypedefClause {
     fn from(clause: IsClassLevelClause) -> Self {
@@ -3328,6 +3101,7 @@ impl IntoPy<fastobo::ast::TypedefClause> for IsClassLevelClause {
     }
 }
 
+#[obo_clause(tag = "is_class_level")]
 #[pymethods]
 impl IsClassLevelClause {
     #[new]
@@ -3343,15 +3117,11 @@ impl IsClassLevelClause {
         Ok(self.to_string())
     }
 
-    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp!(self, other, op, self.class_level)
-    }
-
-    fn raw_tag(slf: PyRef<'_, Self>) -> PyObject {
-        pyo3::intern!(slf.py(), "is_class_level").to_object(slf.py())
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
-    fn raw_value(&self) -> String {
-        self.class_level.to_string()
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
     }
 }
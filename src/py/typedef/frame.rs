@@ -1,10 +1,12 @@
+use std::collections::HashSet;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
 use std::fmt::Write;
+use std::mem::discriminant;
 use std::str::FromStr;
 
-use pyo3::exceptions::PyIndexError;
+use pyo3::class::basic::CompareOp;
 use pyo3::exceptions::PyTypeError;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
@@ -17,21 +19,63 @@ use pyo3::PyTypeInfo;
 
 use fastobo::ast;
 
+use super::super::abc::AbstractEntityClause;
 use super::super::abc::AbstractEntityFrame;
+use super::super::abc::AbstractFrame;
+use super::super::header::frame::HeaderFrame;
 use super::super::id::Ident;
 use super::clause::TypedefClause;
+use crate::error::Error;
+use crate::error::OwlError;
 use crate::utils::AbstractClass;
+use crate::utils::AssignPy;
 use crate::utils::ClonePy;
+use crate::utils::DisplayPy;
 use crate::utils::EqPy;
 use crate::utils::FinalClass;
+use crate::utils::FreezePy;
+use crate::utils::LazyVec;
+use crate::utils::RichCmpPy;
+
+/// Functions extracting the value of a given kind of boolean clause,
+/// either from a source line or from a clause wrapper.
+struct Flag {
+    ast: fn(&ast::TypedefClause) -> Option<bool>,
+    py: fn(&TypedefClause, Python) -> Option<bool>,
+}
+
+/// A function creating a given kind of boolean clause.
+type FlagNew = fn(bool) -> ast::TypedefClause;
+
+/// Create a `Flag` selecting the boolean clauses of the given variant.
+macro_rules! flag {
+    ($variant:ident) => {
+        Flag {
+            ast: |clause| match clause {
+                ast::TypedefClause::$variant(value) => Some(*value),
+                _ => None,
+            },
+            py: |clause, py| match clause {
+                TypedefClause::$variant(c) => {
+                    match IntoPy::<ast::TypedefClause>::into_py(c.borrow(py).clone(), py) {
+                        ast::TypedefClause::$variant(value) => Some(value),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            },
+        }
+    };
+}
 
 #[pyclass(extends=AbstractEntityFrame, module="fastobo.typedef")]
-#[derive(Debug, FinalClass, EqPy)]
+#[derive(Debug, FinalClass, EqPy, RichCmpPy)]
 #[base(AbstractEntityFrame)]
 pub struct TypedefFrame {
-    #[pyo3(set)]
     id: Ident,
-    clauses: Vec<TypedefClause>,
+    clauses: LazyVec<ast::Line<ast::TypedefClause>, TypedefClause>,
+    #[eqpy(ignore)]
+    frozen: bool,
 }
 
 impl TypedefFrame {
@@ -40,7 +84,125 @@ impl TypedefFrame {
     }
 
     pub fn with_clauses(id: Ident, clauses: Vec<TypedefClause>) -> Self {
-        Self { id, clauses }
+        Self {
+            id,
+            clauses: LazyVec::from(clauses),
+            frozen: false,
+        }
+    }
+
+    /// Get a reference to the identifier of the frame.
+    pub fn id(&self) -> &Ident {
+        &self.id
+    }
+
+    /// Get a reference to the clauses of the frame.
+    pub fn clauses(&self) -> &Vec<TypedefClause> {
+        &self.clauses
+    }
+
+    /// Get a mutable reference to the clauses of the frame.
+    pub fn clauses_mut(&mut self) -> &mut Vec<TypedefClause> {
+        &mut self.clauses
+    }
+
+    /// Get the value of the first boolean clause selected by `flag`.
+    ///
+    /// Returns `false` if the frame does not contain such a clause, which
+    /// is the default value of boolean clauses in the OBO format.
+    fn flag(&self, py: Python, flag: Flag) -> bool {
+        if let Some(lines) = self.clauses.source() {
+            return lines.iter().find_map(|line| (flag.ast)(line.as_ref())).unwrap_or(false);
+        }
+        self.clauses
+            .iter()
+            .find_map(|clause| (flag.py)(clause, py))
+            .unwrap_or(false)
+    }
+
+    /// Set the value of the boolean clauses selected by `flag`.
+    ///
+    /// Existing clauses are replaced with a clause with the new value,
+    /// and a new clause is added at the end of the frame if there is none
+    /// and `value` is `true`.
+    fn set_flag(&mut self, py: Python, value: bool, flag: Flag, new: FlagNew) -> PyResult<()> {
+        crate::utils::check_mutable(self.frozen, "TypedefFrame")?;
+        let mut found = false;
+        for clause in self.clauses.iter_mut() {
+            if (flag.py)(clause, py).is_some() {
+                *clause = new(value).into_py(py);
+                found = true;
+            }
+        }
+        if !found && value {
+            self.clauses.push(new(value).into_py(py));
+        }
+        Ok(())
+    }
+
+    /// Convert the frame to an AST frame.
+    ///
+    /// Clauses that have not been converted to Python objects yet are
+    /// copied from the source lines directly.
+    pub fn to_ast(&self, py: Python) -> ast::TypedefFrame {
+        let lines = match self.clauses.source() {
+            Some(lines) => lines.to_vec(),
+            None => self.clauses.iter().map(|c| Self::clause_line(c, py)).collect(),
+        };
+        ast::TypedefFrame::with_clauses(
+            ast::RelationIdent::new((&self.id).into_py(py)),
+            lines,
+        )
+    }
+
+    /// Get the size of the frame in memory, skipping the objects in `seen`.
+    pub fn deep_sizeof(&self, py: Python, seen: &mut HashSet<usize>) -> PyResult<usize> {
+        Ok(std::mem::size_of::<PyCell<Self>>()
+            + crate::utils::sizeof_shared(&self.id, py, seen)?
+            + crate::utils::sizeof_lazy_vec(&self.clauses, py, seen)?)
+    }
+
+    /// Update the frame in place to match an edited AST frame.
+    ///
+    /// Unchanged clauses are kept as they are, and changed clauses are
+    /// updated in place whenever possible, so that the clause objects
+    /// referenced from Python (and their comments) remain valid.
+    pub fn update(&mut self, py: Python, frame: ast::TypedefFrame) {
+        let id: ast::Ident = frame.id().as_ref().clone().into();
+        if IntoPy::<ast::Ident>::into_py(&self.id, py) != id {
+            self.id = id.into_py(py);
+        }
+        let lines = frame.into_iter().collect::<Vec<_>>();
+        if self.clauses.source().is_some() {
+            self.clauses = LazyVec::new(lines);
+            return;
+        }
+        crate::utils::update_items(
+            &mut self.clauses,
+            lines,
+            |clause| Self::clause_line(clause, py),
+            |a, b| discriminant(a.as_ref()) == discriminant(b.as_ref()),
+            |clause, line| Self::update_clause(clause, line, py),
+            |line| line.into_py(py),
+        );
+    }
+
+    /// Update a clause in place to match an edited AST line.
+    fn update_clause(clause: &TypedefClause, line: &ast::Line<ast::TypedefClause>, py: Python) -> bool {
+        let new: TypedefClause = line.as_ref().clone().into_py(py);
+        if !clause.assign_py(&new, py) {
+            return false;
+        }
+        let qualifiers = AbstractEntityClause::qualifiers_of(clause, py).ok().flatten();
+        qualifiers.as_ref() == line.qualifiers()
+            || AbstractEntityClause::set_qualifiers_of(clause, py, line.qualifiers().cloned()).is_ok()
+    }
+
+    /// Convert a clause to an AST line, with its qualifiers.
+    fn clause_line(clause: &TypedefClause, py: Python) -> ast::Line<ast::TypedefClause> {
+        let qualifiers = AbstractEntityClause::qualifiers_of(clause, py).ok().flatten();
+        let inner: ast::TypedefClause = clause.into_py(py);
+        ast::Line::new().and_inner(inner).and_qualifiers(qualifiers)
     }
 }
 
@@ -49,38 +211,67 @@ impl ClonePy for TypedefFrame {
         Self {
             id: self.id.clone_py(py),
             clauses: self.clauses.clone_py(py),
+            frozen: false,
+        }
+    }
+}
+
+impl DisplayPy for TypedefFrame {
+    fn fmt_py(&self, py: Python, f: &mut Formatter) -> FmtResult {
+        f.write_str("[Typedef]\nid: ")?;
+        self.id.fmt_py(py, f)?;
+        f.write_char('\n')?;
+        if let Some(lines) = self.clauses.source() {
+            for line in lines.iter() {
+                line.as_ref().fmt(f)?;
+                match line.qualifiers() {
+                    Some(q) if !q.is_empty() => write!(f, " {}", q)?,
+                    _ => (),
+                }
+                f.write_char('\n')?;
+            }
+            return Ok(());
+        }
+        for clause in self.clauses.iter() {
+            clause.fmt_py(py, f)?;
+            AbstractEntityClause::fmt_qualifiers_of(clause, py, f)?;
+            f.write_char('\n')?;
         }
+        Ok(())
     }
 }
 
 impl Display for TypedefFrame {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let frame: fastobo::ast::TypedefFrame = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        frame.fmt(f)
+        Python::with_gil(|py| self.fmt_py(py, f))
+    }
+}
+
+impl IntoPy<TypedefClause> for fastobo::ast::Line<fastobo::ast::TypedefClause> {
+    fn into_py(self, py: Python) -> TypedefClause {
+        let qualifiers = self.qualifiers().cloned();
+        let clause: TypedefClause = self.into_inner().into_py(py);
+        if qualifiers.is_some() {
+            AbstractEntityClause::set_qualifiers_of(&clause, py, qualifiers)
+                .expect("could not set qualifiers of a new `TypedefClause`");
+        }
+        clause
     }
 }
 
 impl IntoPy<TypedefFrame> for fastobo::ast::TypedefFrame {
     fn into_py(self, py: Python) -> TypedefFrame {
-        TypedefFrame::with_clauses(
-            self.id().as_ref().clone().into_py(py),
-            self.into_iter()
-                .map(|line| line.into_inner().into_py(py))
-                .collect(),
-        )
+        TypedefFrame {
+            id: self.id().as_ref().clone().into_py(py),
+            clauses: LazyVec::new(self.into_iter().collect()),
+            frozen: false,
+        }
     }
 }
 
 impl IntoPy<fastobo::ast::TypedefFrame> for TypedefFrame {
     fn into_py(self, py: Python) -> fastobo::ast::TypedefFrame {
-        fastobo::ast::TypedefFrame::with_clauses(
-            fastobo::ast::RelationIdent::new(self.id.into_py(py)),
-            self.clauses
-                .iter()
-                .map(|f| f.into_py(py))
-                .map(|c| fastobo::ast::Line::new().and_inner(c))
-                .collect(),
-        )
+        self.to_ast(py)
     }
 }
 
@@ -91,7 +282,7 @@ impl IntoPy<fastobo::ast::EntityFrame> for TypedefFrame {
     }
 }
 
-#[listlike(field = "clauses", type = "TypedefClause")]
+#[listlike(field = "clauses", type = "TypedefClause", frozen = "frozen")]
 #[pymethods]
 impl TypedefFrame {
     // FIXME: should accept any iterable.
@@ -111,54 +302,488 @@ impl TypedefFrame {
         impl_repr!(self, TypedefFrame(self.id))
     }
 
-    fn __str__(&self) -> PyResult<String> {
-        Ok(self.to_string())
+    fn __str__(slf: &PyCell<Self>) -> PyResult<String> {
+        AbstractFrame::render(slf)
     }
 
     fn __len__(&self) -> PyResult<usize> {
-        Ok(self.clauses.len())
-    }
-
-    fn __getitem__(&self, index: isize) -> PyResult<PyObject> {
-        if index < self.clauses.len() as isize {
-            let item = &self.clauses[index as usize];
-            Python::with_gil(|py| Ok(item.to_object(py)))
-        } else {
-            Err(PyIndexError::new_err("list index out of range"))
+        match self.clauses.source() {
+            Some(lines) => Ok(lines.len()),
+            None => Ok(self.clauses.len()),
         }
     }
 
-    fn __setitem__(&mut self, index: isize, elem: &PyAny) -> PyResult<()> {
-        if index as usize > self.clauses.len() {
-            return Err(PyIndexError::new_err("list index out of range"));
+    fn __contains__(&self, item: &PyAny) -> PyResult<bool> {
+        let py = item.py();
+        let clause = match TypedefClause::extract(item) {
+            Ok(clause) => clause,
+            Err(_) => return Ok(false),
+        };
+        if let Some(lines) = self.clauses.source() {
+            let clause: ast::TypedefClause = (&clause).into_py(py);
+            return Ok(lines.iter().any(|line| line.as_ref() == &clause));
         }
-        let clause = TypedefClause::extract(elem)?;
-        self.clauses[index as usize] = clause;
-        Ok(())
+        Ok(self.clauses.iter().any(|c| c.eq_py(&clause, py)))
     }
 
-    fn __delitem__(&mut self, index: isize) -> PyResult<()> {
-        if index as usize > self.clauses.len() {
-            return Err(PyIndexError::new_err("list index out of range"));
-        }
-        self.clauses.remove(index as usize);
-        Ok(())
+    /// Get the size of the frame in memory, in bytes.
+    ///
+    /// The size includes the identifier and the clauses of the frame.
+    fn __sizeof__(&self, py: Python) -> PyResult<usize> {
+        self.deep_sizeof(py, &mut HashSet::new())
     }
 
     fn __concat__(&self, other: &PyAny) -> PyResult<Py<Self>> {
         let py = other.py();
 
-        let iterator = PyIterator::from_object(py, other)?;
-        let mut new_clauses = self.clauses.clone_py(py);
-        for item in iterator {
+        let mut new_clauses = Vec::new();
+        for item in PyIterator::from_object(py, other)? {
             new_clauses.push(TypedefClause::extract(item?)?);
         }
 
-        Py::new(py, Self::with_clauses(self.id.clone_py(py), new_clauses))
+        let clauses = match self.clauses.source() {
+            Some(lines) => {
+                let mut lines = lines.to_vec();
+                lines.extend(new_clauses.iter().map(|c| Self::clause_line(c, py)));
+                LazyVec::new(lines)
+            }
+            None => self.clauses.clone_py(py).into_iter().chain(new_clauses).collect(),
+        };
+
+        Py::new(
+            py,
+            Self {
+                id: self.id.clone_py(py),
+                clauses,
+                frozen: false,
+            },
+        )
     }
 
     #[getter]
     fn get_id(&self) -> PyResult<&Ident> {
         Ok(&self.id)
     }
+
+    #[setter]
+    fn set_id(&mut self, id: Ident) -> PyResult<()> {
+        crate::utils::check_mutable(self.frozen, "TypedefFrame")?;
+        self.id = id;
+        Ok(())
+    }
+
+    /// `str` or `None`: the name of the relationship, from its first ``name`` clause.
+    #[getter]
+    fn get_name(&self, py: Python) -> Option<String> {
+        if let Some(lines) = self.clauses.source() {
+            return lines.iter().find_map(|line| match line.as_ref() {
+                ast::TypedefClause::Name(name) => Some(name.as_str().to_string()),
+                _ => None,
+            });
+        }
+        self.clauses.iter().find_map(|clause| match clause {
+            TypedefClause::Name(name) => Some(name.borrow(py).name().as_str().to_string()),
+            _ => None,
+        })
+    }
+
+    fn __hash__(&self) -> PyResult<u64> {
+        if self.frozen {
+            Ok(impl_hash!(self.to_string()))
+        } else {
+            Err(PyTypeError::new_err("unhashable type: 'TypedefFrame'"))
+        }
+    }
+
+    fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
+        self.richcmp_py(other, op)
+    }
+
+    /// `bool`: Whether the frame is frozen.
+    #[getter]
+    fn get_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Make the frame read-only.
+    ///
+    /// See `OboDoc.freeze` for the modifications rejected by a frozen frame.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.typedef.TypedefFrame(fastobo.id.UnprefixedIdent("part_of"))
+    ///     >>> frame.freeze()
+    ///     >>> frame.append(fastobo.typedef.NameClause("part of"))
+    ///     Traceback (most recent call last):
+    ///       ...
+    ///     TypeError: cannot modify frozen TypedefFrame instance
+    ///
+    #[pyo3(text_signature = "(self)")]
+    pub fn freeze(&mut self, py: Python) -> PyResult<()> {
+        self.clauses.freeze_py(py)?;
+        self.frozen = true;
+        Ok(())
+    }
+
+    /// `bool`: whether the relationship is anonymous.
+    #[getter]
+    fn get_is_anonymous(&self, py: Python) -> bool {
+        self.flag(py, flag!(IsAnonymous))
+    }
+
+    #[setter]
+    fn set_is_anonymous(&mut self, py: Python, value: bool) -> PyResult<()> {
+        self.set_flag(py, value, flag!(IsAnonymous), ast::TypedefClause::IsAnonymous)
+    }
+
+    /// `bool`: whether the relationship is built in the OBO format.
+    #[getter]
+    fn get_builtin(&self, py: Python) -> bool {
+        self.flag(py, flag!(Builtin))
+    }
+
+    #[setter]
+    fn set_builtin(&mut self, py: Python, value: bool) -> PyResult<()> {
+        self.set_flag(py, value, flag!(Builtin), ast::TypedefClause::Builtin)
+    }
+
+    /// `bool`: whether the relationship is anti-symmetric.
+    #[getter]
+    fn get_is_anti_symmetric(&self, py: Python) -> bool {
+        self.flag(py, flag!(IsAntiSymmetric))
+    }
+
+    #[setter]
+    fn set_is_anti_symmetric(&mut self, py: Python, value: bool) -> PyResult<()> {
+        self.set_flag(py, value, flag!(IsAntiSymmetric), ast::TypedefClause::IsAntiSymmetric)
+    }
+
+    /// `bool`: whether the relationship is cyclic.
+    #[getter]
+    fn get_is_cyclic(&self, py: Python) -> bool {
+        self.flag(py, flag!(IsCyclic))
+    }
+
+    #[setter]
+    fn set_is_cyclic(&mut self, py: Python, value: bool) -> PyResult<()> {
+        self.set_flag(py, value, flag!(IsCyclic), ast::TypedefClause::IsCyclic)
+    }
+
+    /// `bool`: whether the relationship is reflexive.
+    #[getter]
+    fn get_is_reflexive(&self, py: Python) -> bool {
+        self.flag(py, flag!(IsReflexive))
+    }
+
+    #[setter]
+    fn set_is_reflexive(&mut self, py: Python, value: bool) -> PyResult<()> {
+        self.set_flag(py, value, flag!(IsReflexive), ast::TypedefClause::IsReflexive)
+    }
+
+    /// `bool`: whether the relationship is symmetric.
+    #[getter]
+    fn get_is_symmetric(&self, py: Python) -> bool {
+        self.flag(py, flag!(IsSymmetric))
+    }
+
+    #[setter]
+    fn set_is_symmetric(&mut self, py: Python, value: bool) -> PyResult<()> {
+        self.set_flag(py, value, flag!(IsSymmetric), ast::TypedefClause::IsSymmetric)
+    }
+
+    /// `bool`: whether the relationship is asymmetric.
+    #[getter]
+    fn get_is_asymmetric(&self, py: Python) -> bool {
+        self.flag(py, flag!(IsAsymmetric))
+    }
+
+    #[setter]
+    fn set_is_asymmetric(&mut self, py: Python, value: bool) -> PyResult<()> {
+        self.set_flag(py, value, flag!(IsAsymmetric), ast::TypedefClause::IsAsymmetric)
+    }
+
+    /// `bool`: whether the relationship is transitive.
+    ///
+    /// The boolean properties of a typedef frame, such as this one, read
+    /// the value of the corresponding clause, and default to `False` when
+    /// the frame has no such clause. Setting them updates the existing
+    /// clauses in place, or adds a new clause at the end of the frame.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.typedef.TypedefFrame(
+    ///     ...     fastobo.id.UnprefixedIdent("part_of"),
+    ///     ...     [fastobo.typedef.NameClause("part of")],
+    ///     ... )
+    ///     >>> frame.is_transitive
+    ///     False
+    ///     >>> frame.is_transitive = True
+    ///     >>> print(frame)
+    ///     [Typedef]
+    ///     id: part_of
+    ///     name: part of
+    ///     is_transitive: true
+    ///     <BLANKLINE>
+    ///
+    #[getter]
+    fn get_is_transitive(&self, py: Python) -> bool {
+        self.flag(py, flag!(IsTransitive))
+    }
+
+    #[setter]
+    fn set_is_transitive(&mut self, py: Python, value: bool) -> PyResult<()> {
+        self.set_flag(py, value, flag!(IsTransitive), ast::TypedefClause::IsTransitive)
+    }
+
+    /// `bool`: whether the relationship is functional.
+    #[getter]
+    fn get_is_functional(&self, py: Python) -> bool {
+        self.flag(py, flag!(IsFunctional))
+    }
+
+    #[setter]
+    fn set_is_functional(&mut self, py: Python, value: bool) -> PyResult<()> {
+        self.set_flag(py, value, flag!(IsFunctional), ast::TypedefClause::IsFunctional)
+    }
+
+    /// `bool`: whether the relationship is inverse functional.
+    #[getter]
+    fn get_is_inverse_functional(&self, py: Python) -> bool {
+        self.flag(py, flag!(IsInverseFunctional))
+    }
+
+    #[setter]
+    fn set_is_inverse_functional(&mut self, py: Python, value: bool) -> PyResult<()> {
+        self.set_flag(py, value, flag!(IsInverseFunctional), ast::TypedefClause::IsInverseFunctional)
+    }
+
+    /// `bool`: whether the relationship is obsolete.
+    #[getter]
+    fn get_is_obsolete(&self, py: Python) -> bool {
+        self.flag(py, flag!(IsObsolete))
+    }
+
+    #[setter]
+    fn set_is_obsolete(&mut self, py: Python, value: bool) -> PyResult<()> {
+        self.set_flag(py, value, flag!(IsObsolete), ast::TypedefClause::IsObsolete)
+    }
+
+    /// `bool`: whether the relationship is a metadata tag.
+    #[getter]
+    fn get_is_metadata_tag(&self, py: Python) -> bool {
+        self.flag(py, flag!(IsMetadataTag))
+    }
+
+    #[setter]
+    fn set_is_metadata_tag(&mut self, py: Python, value: bool) -> PyResult<()> {
+        self.set_flag(py, value, flag!(IsMetadataTag), ast::TypedefClause::IsMetadataTag)
+    }
+
+    /// `bool`: whether the relationship is class level.
+    #[getter]
+    fn get_is_class_level(&self, py: Python) -> bool {
+        self.flag(py, flag!(IsClassLevel))
+    }
+
+    #[setter]
+    fn set_is_class_level(&mut self, py: Python, value: bool) -> PyResult<()> {
+        self.set_flag(py, value, flag!(IsClassLevel), ast::TypedefClause::IsClassLevel)
+    }
+
+    /// Get all the clauses of the frame with the given raw tag.
+    ///
+    /// Arguments:
+    ///     tag (str): The raw tag of the clauses to retrieve, as returned
+    ///         by the `raw_tag` method of each clause.
+    ///
+    /// Returns:
+    ///     `list`: The clauses with the given tag, in frame order. The
+    ///     clauses are not copied, so they can be edited in place.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.typedef.TypedefFrame(
+    ///     ...     fastobo.id.UnprefixedIdent("part_of"),
+    ///     ...     [
+    ///     ...         fastobo.typedef.NameClause("part of"),
+    ///     ...         fastobo.typedef.IsTransitiveClause(True),
+    ///     ...         fastobo.typedef.IsAClause(fastobo.id.UnprefixedIdent("overlaps")),
+    ///     ...     ]
+    ///     ... )
+    ///     >>> frame.get("is_transitive")
+    ///     [IsTransitiveClause(True)]
+    ///
+    #[pyo3(text_signature = "(self, tag)")]
+    fn get(&self, py: Python, tag: &str) -> Vec<TypedefClause> {
+        self.clauses
+            .iter()
+            .filter(|clause| clause.raw_tag() == tag)
+            .map(|clause| clause.clone_py(py))
+            .collect()
+    }
+
+    /// Remove and return all the clauses of the frame with the given raw tag.
+    ///
+    /// Arguments:
+    ///     tag (str): The raw tag of the clauses to remove, as returned
+    ///         by the `raw_tag` method of each clause.
+    ///
+    /// Returns:
+    ///     `list`: The removed clauses, in frame order.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.typedef.TypedefFrame(
+    ///     ...     fastobo.id.UnprefixedIdent("part_of"),
+    ///     ...     [
+    ///     ...         fastobo.typedef.NameClause("part of"),
+    ///     ...         fastobo.typedef.XrefClause(
+    ///     ...             fastobo.xref.Xref(fastobo.id.PrefixedIdent("BFO", "0000050"))
+    ///     ...         ),
+    ///     ...     ]
+    ///     ... )
+    ///     >>> frame.pop_clauses("xref")
+    ///     [XrefClause(Xref(PrefixedIdent('BFO', '0000050')))]
+    ///     >>> len(frame)
+    ///     1
+    ///
+    #[pyo3(text_signature = "(self, tag)")]
+    fn pop_clauses(&mut self, tag: &str) -> PyResult<Vec<TypedefClause>> {
+        crate::utils::check_mutable(self.frozen, "TypedefFrame")?;
+        let mut popped = Vec::new();
+        for clause in std::mem::take(&mut self.clauses) {
+            if clause.raw_tag() == tag {
+                popped.push(clause);
+            } else {
+                self.clauses.push(clause);
+            }
+        }
+        Ok(popped)
+    }
+
+    /// Get the set of raw tags of the clauses of the frame.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.typedef.TypedefFrame(
+    ///     ...     fastobo.id.UnprefixedIdent("part_of"),
+    ///     ...     [
+    ///     ...         fastobo.typedef.NameClause("part of"),
+    ///     ...         fastobo.typedef.IsTransitiveClause(True),
+    ///     ...         fastobo.typedef.IsAClause(fastobo.id.UnprefixedIdent("overlaps")),
+    ///     ...     ]
+    ///     ... )
+    ///     >>> sorted(frame.tags())
+    ///     ['is_a', 'is_transitive', 'name']
+    ///
+    #[pyo3(text_signature = "(self)")]
+    fn tags(&self) -> HashSet<&'static str> {
+        self.clauses.iter().map(|clause| clause.raw_tag()).collect()
+    }
+
+    /// Convert the frame to a `dict` of builtin Python types.
+    ///
+    /// Arguments:
+    ///     grouped (`bool`): Whether to group the raw values of the clauses
+    ///         by raw tag in a `dict`, instead of listing the clauses in
+    ///         frame order.
+    ///
+    /// Returns:
+    ///     `dict`: A dictionary with the ``type`` of the frame (``"Typedef"``),
+    ///     its ``id`` as a `str`, and its ``clauses`` as a `list` of `dict`
+    ///     with the raw ``tag`` and ``value`` of each clause, or as a `dict`
+    ///     mapping each raw tag to a `list` of raw values if ``grouped`` is
+    ///     `True`. The qualifiers of a clause, if any, are given as a `list` of
+    ///     `dict` with the ``key`` and ``value`` of each qualifier, under the
+    ///     ``qualifiers`` key of the clause `dict`, or of a `dict` with the raw
+    ///     ``value`` replacing the raw value of a grouped clause.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.typedef.TypedefFrame(
+    ///     ...     fastobo.id.UnprefixedIdent("part_of"),
+    ///     ...     [fastobo.typedef.NameClause("part of")],
+    ///     ... )
+    ///     >>> pprint(frame.to_dict())
+    ///     {'clauses': [{'tag': 'name', 'value': 'part of'}],
+    ///      'id': 'part_of',
+    ///      'type': 'Typedef'}
+    ///
+    #[pyo3(signature = (grouped = false), text_signature = "(self, grouped=False)")]
+    fn to_dict(&self, py: Python, grouped: bool) -> PyResult<PyObject> {
+        let frame: ast::TypedefFrame = self.to_ast(py);
+        crate::dict::entity_to_dict(py, ast::EntityFrame::from(frame), grouped)
+    }
+
+    /// Create a new frame from a `dict` created with `TypedefFrame.to_dict`.
+    ///
+    /// Raises:
+    ///     ValueError: When the dictionary does not describe a typedef frame.
+    ///     SyntaxError: When the identifier or a clause cannot be parsed.
+    ///
+    #[staticmethod]
+    #[pyo3(text_signature = "(d)")]
+    fn from_dict(py: Python, d: &PyAny) -> PyResult<Py<Self>> {
+        match crate::dict::entity_from_dict(d)? {
+            ast::EntityFrame::Typedef(frame) => {
+                let frame: TypedefFrame = frame.into_py(py);
+                Py::new(py, frame)
+            }
+            _ => Err(PyValueError::new_err("expected Typedef frame")),
+        }
+    }
+
+    /// Convert the frame to OWL and render the resulting axioms.
+    ///
+    /// This is useful to check how a single frame is translated without
+    /// exporting the whole document with `~fastobo.dump_owl`.
+    ///
+    /// Arguments:
+    ///     header (`~fastobo.header.HeaderFrame`, optional): The header of
+    ///         the document declaring the frame, used to resolve the ID
+    ///         spaces and unprefixed identifiers. If `None` given, use a
+    ///         header declaring an ``obo`` ontology and default namespace.
+    ///
+    /// Returns:
+    ///     `list` of `str`: The OWL axioms translated from the frame, in
+    ///     `Functional-style syntax <https://w3.org/TR/owl2-syntax/>`_.
+    ///
+    /// Raises:
+    ///     ValueError: When the conversion to OWL fails, e.g. because
+    ///         ``header`` lacks a required clause.
+    ///
+    #[pyo3(signature = (header = None), text_signature = "(self, header=None)")]
+    fn as_owl_functional(
+        &self,
+        py: Python,
+        header: Option<&PyCell<HeaderFrame>>,
+    ) -> PyResult<Vec<String>> {
+        let header: ast::HeaderFrame = match header {
+            Some(h) => h.borrow().clone_py(py).into_py(py),
+            None => crate::owl::default_header(),
+        };
+        let frame: ast::TypedefFrame = self.to_ast(py);
+        crate::owl::frame_to_ofn(header, ast::EntityFrame::from(frame))
+            .map_err(|e| PyErr::from(OwlError::from(e)))
+    }
+
+    /// Parse a single typedef frame from a string.
+    ///
+    /// Arguments:
+    ///     text (str): The serialized frame, starting with a ``[Typedef]``
+    ///         frame header.
+    ///
+    /// Raises:
+    ///     SyntaxError: When the text is not a valid typedef frame.
+    ///
+    /// Example:
+    ///     >>> frame = fastobo.typedef.TypedefFrame.loads(
+    ///     ...     "[Typedef]\nid: part_of\nis_transitive: true\n"
+    ///     ... )
+    ///     >>> frame.id
+    ///     UnprefixedIdent('part_of')
+    ///
+    #[staticmethod]
+    #[pyo3(text_signature = "(text)")]
+    fn loads(py: Python, text: &str) -> PyResult<Py<Self>> {
+        let source = format!("{}\n", text.trim());
+        match ast::TypedefFrame::from_str(&source) {
+            Ok(frame) => Py::new(py, IntoPy::<TypedefFrame>::into_py(frame, py)),
+            Err(e) => Err(PyErr::from(Error::from(e))),
+        }
+    }
 }
@@ -1,8 +1,16 @@
 pub mod clause;
 pub mod frame;
 
+use std::str::FromStr;
+
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
+use self::clause::TypedefClause;
+use self::frame::TypedefFrame;
+use crate::error::Error;
+use crate::utils::ParseClause;
+
 #[pymodule]
 #[pyo3(name = "typedef")]
 pub fn init(py: Python, m: &PyModule) -> PyResult<()> {
@@ -50,9 +58,48 @@ pub fn init(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<self::clause::IsMetadataTagClause>()?;
     m.add_class::<self::clause::IsClassLevelClause>()?;
 
+    m.add_function(wrap_pyfunction!(self::parse_clause, m)?)?;
+
     register!(py, m, TypedefFrame, "collections.abc", MutableSequence);
 
     m.add("__name__", "fastobo.typedef")?;
 
     Ok(())
 }
+
+/// Parse a single typedef clause from a line of text.
+///
+/// Trailing qualifiers and comments are supported, so that a clause line
+/// can be extracted verbatim from an OBO document.
+///
+/// Arguments:
+///     text (str): The serialized clause, such as ``"is_transitive: true"``.
+///
+/// Returns:
+///     `~fastobo.typedef.BaseTypedefClause`: The deserialized clause.
+///
+/// Raises:
+///     SyntaxError: When the text is not a valid typedef clause.
+///     ValueError: When the text does not contain exactly one clause.
+///
+/// Example:
+///     >>> fastobo.typedef.parse_clause("is_transitive: true")
+///     IsTransitiveClause(True)
+///
+#[pyfunction]
+#[pyo3(text_signature = "(text)")]
+pub fn parse_clause(py: Python, text: &str) -> PyResult<TypedefClause> {
+    let source = format!("[Typedef]\nid: x\n{}\n", text.trim_end());
+    let ast = fastobo::ast::TypedefFrame::from_str(&source).map_err(|e| PyErr::from(Error::from(e)))?;
+    let mut frame: TypedefFrame = ast.into_py(py);
+    match frame.clauses().len() {
+        1 => Ok(frame.clauses_mut().pop().unwrap()),
+        n => Err(PyValueError::new_err(format!("expected a single clause, found {}", n))),
+    }
+}
+
+impl ParseClause for TypedefClause {
+    fn parse_clause(py: Python, text: &str) -> PyResult<Self> {
+        self::parse_clause(py, text)
+    }
+}
@@ -0,0 +1,332 @@
+use std::collections::HashSet;
+
+use pyo3::prelude::*;
+use pyo3::types::PyAny;
+use pyo3::types::PyDict;
+use pyo3::types::PyTuple;
+use pyo3::PyTypeInfo;
+
+use fastobo::ast as obo;
+use fastobo::visit::VisitMut;
+
+use crate::utils::ClonePy;
+
+use super::doc::OboDoc;
+use super::header::clause::HeaderClause;
+use super::id::Ident;
+use super::instance::clause::InstanceClause;
+use super::instance::frame::InstanceFrame;
+use super::term::clause::TermClause;
+use super::term::frame::TermFrame;
+use super::typedef::clause::TypedefClause;
+use super::typedef::frame::TypedefFrame;
+
+// --- Module export ---------------------------------------------------------
+
+/// Visitors to rewrite the abstract syntax tree of OBO documents.
+///
+/// Subclass `~fastobo.visit.Visitor` and override some of its ``visit_*``
+/// methods to edit the frames, clauses or identifiers of a document in a
+/// single pass, without having to write a loop for each kind of frame.
+///
+#[pymodule]
+#[pyo3(name = "visit")]
+pub fn init(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<self::Visitor>()?;
+    m.add("__name__", "fastobo.visit")?;
+    Ok(())
+}
+
+// --- Visitor ---------------------------------------------------------------
+
+/// The names of the methods of `Visitor` which can be overridden.
+const METHODS: &[&str] = &[
+    "visit_header_clause",
+    "visit_ident",
+    "visit_instance_clause",
+    "visit_instance_frame",
+    "visit_term_clause",
+    "visit_term_frame",
+    "visit_typedef_clause",
+    "visit_typedef_frame",
+];
+
+/// Visitor()
+/// --
+///
+/// A base class for visitors editing an OBO document in place.
+///
+/// The `~Visitor.visit` method walks the whole document, and calls the
+/// ``visit_*`` method matching each element it encounters. A method can
+/// either return a new value to replace the visited one, or modify its
+/// argument directly and return `None`. Children of the visited element
+/// (such as the clauses of a frame) are visited afterwards.
+///
+/// Only the methods overridden by a subclass are called, so the elements
+/// of the document which are not visited are never converted to Python
+/// objects.
+///
+/// Example:
+///     >>> class Renamer(fastobo.visit.Visitor):
+///     ...     def visit_ident(self, ident):
+///     ...         if ident == fastobo.id.PrefixedIdent("MS", "1000031"):
+///     ...             return fastobo.id.PrefixedIdent("MS", "9999999")
+///     >>> doc = fastobo.loads(textwrap.dedent(
+///     ...     """
+///     ...     [Term]
+///     ...     id: MS:1000032
+///     ...     is_a: MS:1000031
+///     ...     """
+///     ... ))
+///     >>> Renamer().visit(doc)
+///     >>> print(doc[0])
+///     [Term]
+///     id: MS:1000032
+///     is_a: MS:9999999
+///     <BLANKLINE>
+///
+#[pyclass(subclass, module = "fastobo.visit")]
+#[derive(Debug, Default)]
+pub struct Visitor {}
+
+#[pymethods]
+impl Visitor {
+    #[new]
+    #[pyo3(signature = (*_args, **_kwargs))]
+    fn __new__(_args: &PyTuple, _kwargs: Option<&PyDict>) -> Self {
+        Self::default()
+    }
+
+    /// Visit an OBO document, editing it in place.
+    ///
+    /// Arguments:
+    ///     doc (`~fastobo.doc.OboDoc`): The document to visit.
+    ///
+    /// Raises:
+    ///     TypeError: When the document is frozen.
+    ///
+    #[pyo3(text_signature = "(self, doc)")]
+    fn visit(slf: &PyCell<Self>, doc: &PyCell<OboDoc>) -> PyResult<()> {
+        let py = slf.py();
+        let mut driver = Driver::new(slf.as_ref())?;
+        doc.try_borrow_mut()?.edit(py, |doc| driver.visit_doc(doc))?;
+        driver.error.map(Err).unwrap_or(Ok(()))
+    }
+
+    /// Visit a clause of the header frame.
+    ///
+    /// Returns:
+    ///     `~fastobo.header.BaseHeaderClause` or `None`: A clause to use
+    ///     in place of the visited one, or `None` to keep it.
+    ///
+    #[pyo3(text_signature = "(self, clause)")]
+    fn visit_header_clause(&self, _clause: &PyAny) -> Option<PyObject> {
+        None
+    }
+
+    /// Visit an identifier, wherever it appears in the document.
+    ///
+    /// Returns:
+    ///     `~fastobo.id.Ident` or `None`: An identifier to use in place of
+    ///     the visited one, or `None` to keep it.
+    ///
+    #[pyo3(text_signature = "(self, ident)")]
+    fn visit_ident(&self, _ident: &PyAny) -> Option<PyObject> {
+        None
+    }
+
+    /// Visit an instance frame.
+    ///
+    /// Returns:
+    ///     `~fastobo.instance.InstanceFrame` or `None`: A frame to use in
+    ///     place of the visited one, or `None` to keep it.
+    ///
+    #[pyo3(text_signature = "(self, frame)")]
+    fn visit_instance_frame(&self, _frame: &PyAny) -> Option<PyObject> {
+        None
+    }
+
+    /// Visit a clause of an instance frame.
+    ///
+    /// Returns:
+    ///     `~fastobo.instance.BaseInstanceClause` or `None`: A clause to
+    ///     use in place of the visited one, or `None` to keep it.
+    ///
+    #[pyo3(text_signature = "(self, clause)")]
+    fn visit_instance_clause(&self, _clause: &PyAny) -> Option<PyObject> {
+        None
+    }
+
+    /// Visit a term frame.
+    ///
+    /// Returns:
+    ///     `~fastobo.term.TermFrame` or `None`: A frame to use in place of
+    ///     the visited one, or `None` to keep it.
+    ///
+    #[pyo3(text_signature = "(self, frame)")]
+    fn visit_term_frame(&self, _frame: &PyAny) -> Option<PyObject> {
+        None
+    }
+
+    /// Visit a clause of a term frame.
+    ///
+    /// Returns:
+    ///     `~fastobo.term.BaseTermClause` or `None`: A clause to use in
+    ///     place of the visited one, or `None` to keep it.
+    ///
+    #[pyo3(text_signature = "(self, clause)")]
+    fn visit_term_clause(&self, _clause: &PyAny) -> Option<PyObject> {
+        None
+    }
+
+    /// Visit a typedef frame.
+    ///
+    /// Returns:
+    ///     `~fastobo.typedef.TypedefFrame` or `None`: A frame to use in
+    ///     place of the visited one, or `None` to keep it.
+    ///
+    #[pyo3(text_signature = "(self, frame)")]
+    fn visit_typedef_frame(&self, _frame: &PyAny) -> Option<PyObject> {
+        None
+    }
+
+    /// Visit a clause of a typedef frame.
+    ///
+    /// Returns:
+    ///     `~fastobo.typedef.BaseTypedefClause` or `None`: A clause to use
+    ///     in place of the visited one, or `None` to keep it.
+    ///
+    #[pyo3(text_signature = "(self, clause)")]
+    fn visit_typedef_clause(&self, _clause: &PyAny) -> Option<PyObject> {
+        None
+    }
+}
+
+// --- Driver ----------------------------------------------------------------
+
+/// A Rust visitor dispatching to the overridden methods of a `Visitor`.
+struct Driver<'py> {
+    visitor: &'py PyAny,
+    overridden: HashSet<&'static str>,
+    error: Option<PyErr>,
+}
+
+impl<'py> Driver<'py> {
+    fn new(visitor: &'py PyAny) -> PyResult<Self> {
+        let base = Visitor::type_object(visitor.py());
+        let ty = visitor.get_type();
+        let mut overridden = HashSet::new();
+        for name in METHODS {
+            if !ty.getattr(*name)?.is(base.getattr(*name)?) {
+                overridden.insert(*name);
+            }
+        }
+        Ok(Self {
+            visitor,
+            overridden,
+            error: None,
+        })
+    }
+
+    /// Call the `name` method of the visitor on a value, if overridden.
+    ///
+    /// The value is replaced with the returned object, or with the Python
+    /// object it was converted to if `None` was returned. Once an error
+    /// occurred, no other method is called.
+    fn dispatch<T, F, G>(&mut self, name: &'static str, value: &mut T, to_py: F, from_py: G)
+    where
+        T: Clone,
+        F: FnOnce(Python<'py>, T) -> PyResult<PyObject>,
+        G: FnOnce(&'py PyAny) -> PyResult<T>,
+    {
+        if self.error.is_some() || !self.overridden.contains(name) {
+            return;
+        }
+        let py = self.visitor.py();
+        let visitor = self.visitor;
+        let result = to_py(py, value.clone()).and_then(|obj| {
+            let returned = visitor.call_method1(name, (obj.clone_ref(py),))?;
+            if returned.is_none() {
+                from_py(obj.into_ref(py))
+            } else {
+                from_py(returned)
+            }
+        });
+        match result {
+            Ok(new) => *value = new,
+            Err(err) => self.error = Some(err),
+        }
+    }
+}
+
+macro_rules! dispatch_clause {
+    ($self:ident, $name:literal, $clause:ident, $ty:ident) => {
+        $self.dispatch(
+            $name,
+            $clause,
+            |py, clause| Ok(<obo::$ty as IntoPy<$ty>>::into_py(clause, py).to_object(py)),
+            |obj| Ok((&obj.extract::<$ty>()?).into_py(obj.py())),
+        )
+    };
+}
+
+macro_rules! dispatch_frame {
+    ($self:ident, $name:literal, $frame:ident, $ty:ident) => {
+        $self.dispatch(
+            $name,
+            $frame,
+            |py, frame| Py::new(py, <obo::$ty as IntoPy<$ty>>::into_py(frame, py)).map(|f| f.to_object(py)),
+            |obj| {
+                let cell = obj.downcast::<PyCell<$ty>>()?;
+                Ok(cell.borrow().clone_py(obj.py()).into_py(obj.py()))
+            },
+        )
+    };
+}
+
+impl VisitMut for Driver<'_> {
+    fn visit_header_clause(&mut self, clause: &mut obo::HeaderClause) {
+        dispatch_clause!(self, "visit_header_clause", clause, HeaderClause);
+        fastobo::visit::visit_mut::visit_header_clause(self, clause);
+    }
+
+    fn visit_ident(&mut self, id: &mut obo::Ident) {
+        self.dispatch(
+            "visit_ident",
+            id,
+            |py, id| Ok(<obo::Ident as IntoPy<Ident>>::into_py(id, py).to_object(py)),
+            |obj| Ok(obj.extract::<Ident>()?.into_py(obj.py())),
+        );
+        fastobo::visit::visit_mut::visit_ident(self, id);
+    }
+
+    fn visit_instance_frame(&mut self, frame: &mut obo::InstanceFrame) {
+        dispatch_frame!(self, "visit_instance_frame", frame, InstanceFrame);
+        fastobo::visit::visit_mut::visit_instance_frame(self, frame);
+    }
+
+    fn visit_instance_clause(&mut self, clause: &mut obo::InstanceClause) {
+        dispatch_clause!(self, "visit_instance_clause", clause, InstanceClause);
+        fastobo::visit::visit_mut::visit_instance_clause(self, clause);
+    }
+
+    fn visit_term_frame(&mut self, frame: &mut obo::TermFrame) {
+        dispatch_frame!(self, "visit_term_frame", frame, TermFrame);
+        fastobo::visit::visit_mut::visit_term_frame(self, frame);
+    }
+
+    fn visit_term_clause(&mut self, clause: &mut obo::TermClause) {
+        dispatch_clause!(self, "visit_term_clause", clause, TermClause);
+        fastobo::visit::visit_mut::visit_term_clause(self, clause);
+    }
+
+    fn visit_typedef_frame(&mut self, frame: &mut obo::TypedefFrame) {
+        dispatch_frame!(self, "visit_typedef_frame", frame, TypedefFrame);
+        fastobo::visit::visit_mut::visit_typedef_frame(self, frame);
+    }
+
+    fn visit_typedef_clause(&mut self, clause: &mut obo::TypedefClause) {
+        dispatch_clause!(self, "visit_typedef_clause", clause, TypedefClause);
+        fastobo::visit::visit_mut::visit_typedef_clause(self, clause);
+    }
+}
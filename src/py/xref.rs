@@ -1,13 +1,13 @@
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
+use std::fmt::Write;
 use std::rc::Rc;
 use std::str::FromStr;
 use std::string::ToString;
 
 use pyo3::class::basic::CompareOp;
 use pyo3::class::gc::PyVisit;
-use pyo3::exceptions::PyIndexError;
 use pyo3::exceptions::PyTypeError;
 use pyo3::gc::PyTraverseError;
 use pyo3::prelude::*;
@@ -21,15 +21,20 @@ use pyo3::PyTypeInfo;
 
 use super::id::Ident;
 use crate::utils::ClonePy;
+use crate::utils::DisplayPy;
 use crate::utils::EqPy;
+use crate::utils::FreezePy;
+use crate::utils::HashPy;
+use crate::utils::RichCmpPy;
 
 // --- Module export ---------------------------------------------------------
 
 #[pymodule]
 #[pyo3(name = "xref")]
-pub fn init(_py: Python, m: &PyModule) -> PyResult<()> {
+pub fn init(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<self::Xref>()?;
     m.add_class::<self::XrefList>()?;
+    register!(py, m, XrefList, "collections.abc", MutableSequence);
     m.add("__name__", "fastobo.xref")?;
     Ok(())
 }
@@ -47,38 +52,55 @@ pub fn init(_py: Python, m: &PyModule) -> PyResult<()> {
 ///     ...     fastobo.id.PrefixedIdent('ISBN', '978-0-321-84268-8'),
 ///     ... )
 #[pyclass(module = "fastobo.xref")]
-#[derive(Debug, EqPy)]
+#[derive(Debug, EqPy, HashPy, RichCmpPy)]
 pub struct Xref {
-    #[pyo3(set)]
     id: Ident,
     desc: Option<fastobo::ast::QuotedString>,
+    #[eqpy(ignore)]
+    frozen: bool,
 }
 
 impl Xref {
     pub fn new(id: Ident) -> Self {
-        Self { id, desc: None }
+        Self::with_desc(id, None)
     }
 
     pub fn with_desc(id: Ident, desc: Option<fastobo::ast::QuotedString>) -> Self {
-        Self { id, desc }
+        Self {
+            id,
+            desc,
+            frozen: false,
+        }
     }
 }
 
 impl ClonePy for Xref {
     fn clone_py(&self, py: Python) -> Self {
-        Xref {
-            id: self.id.clone_py(py),
-            desc: self.desc.clone(),
+        Xref::with_desc(self.id.clone_py(py), self.desc.clone())
+    }
+}
+
+impl FreezePy for Py<Xref> {
+    fn freeze_py(&self, py: Python) -> PyResult<()> {
+        self.try_borrow_mut(py)?.frozen = true;
+        Ok(())
+    }
+}
+
+impl DisplayPy for Xref {
+    fn fmt_py(&self, py: Python, f: &mut Formatter) -> FmtResult {
+        self.id.fmt_py(py, f)?;
+        if let Some(desc) = &self.desc {
+            f.write_char(' ')?;
+            desc.fmt(f)?;
         }
+        Ok(())
     }
 }
 
 impl Display for Xref {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        let xref: fastobo::ast::Xref = Python::with_gil(|py| {
-            self.clone_py(py).into_py(py)
-        });
-        xref.fmt(f)
+        Python::with_gil(|py| self.fmt_py(py, f))
     }
 }
 
@@ -107,14 +129,24 @@ impl Xref {
     /// Create a new `Xref` instance from an ID and an optional description.
     ///
     /// Arguments:
-    ///     id (~fastobo.id.Ident): the identifier of the reference.
+    ///     id (~fastobo.id.Ident or str): the identifier of the reference,
+    ///         which is parsed with `fastobo.id.parse` if given as a `str`.
     ///     desc (str, optional): an optional description for the reference.
+    ///
+    /// Raises:
+    ///     ValueError: When ``id`` is a `str` that is not a valid identifier.
+    ///
+    /// Example:
+    ///     >>> fastobo.xref.Xref("PMID:123", "a publication")
+    ///     Xref(PrefixedIdent('PMID', '123'), 'a publication')
+    ///
     #[new]
-    fn __init__(id: Ident, desc: Option<String>) -> Self {
+    fn __init__(id: &PyAny, desc: Option<String>) -> PyResult<Self> {
+        let id = super::id::extract_or_parse(id.py(), id)?;
         if let Some(s) = desc {
-            Self::with_desc(id, Some(fastobo::ast::QuotedString::new(s)))
+            Ok(Self::with_desc(id, Some(fastobo::ast::QuotedString::new(s))))
         } else {
-            Self::new(id)
+            Ok(Self::new(id))
         }
     }
 
@@ -137,7 +169,11 @@ impl Xref {
     }
 
     fn __richcmp__(&self, other: &PyAny, op: CompareOp) -> PyResult<PyObject> {
-        impl_richcmp_py!(self, other, op, self.id && self.desc)
+        self.richcmp_py(other, op)
+    }
+
+    fn __hash__(&self, py: Python) -> u64 {
+        self.hash_value_py(py)
     }
 
     /// `~fastobo.id.Ident`: the identifier of the reference.
@@ -146,6 +182,13 @@ impl Xref {
         Ok(&self.id)
     }
 
+    #[setter]
+    fn set_id(&mut self, id: Ident) -> PyResult<()> {
+        crate::utils::check_mutable(self.frozen, "Xref")?;
+        self.id = id;
+        Ok(())
+    }
+
     /// `str` or `None`: the description of the reference, if any.
     #[getter]
     fn get_desc(&self) -> PyResult<Option<&str>> {
@@ -157,6 +200,7 @@ impl Xref {
 
     #[setter]
     fn set_desc(&mut self, desc: Option<String>) -> PyResult<()> {
+        crate::utils::check_mutable(self.frozen, "Xref")?;
         self.desc = desc.map(fastobo::ast::QuotedString::new);
         Ok(())
     }
@@ -173,45 +217,107 @@ impl Xref {
 ///     >>> xrefs[0]
 ///     Xref(PrefixedIdent('PSI', 'MS'))
 ///
+///     A list can also be created from prefixed identifiers or URLs given
+///     as `str`, which are parsed with `fastobo.id.parse`:
+///
+///     >>> print(fastobo.xref.XrefList(["PMID:123", "ISBN:456"]))
+///     [PMID:123, ISBN:456]
+///
 #[pyclass(module = "fastobo.xref")]
-#[derive(Debug, Default, EqPy)]
+#[derive(Debug, Default, EqPy, HashPy)]
 pub struct XrefList {
     xrefs: Vec<Py<Xref>>,
+    #[eqpy(ignore)]
+    frozen: bool,
 }
 
 impl XrefList {
     /// Create a new `XrefList` from a vector of Xrefs.
     pub fn new(xrefs: Vec<Py<Xref>>) -> Self {
-        Self { xrefs }
+        Self {
+            xrefs,
+            frozen: false,
+        }
     }
 
     /// Create a new `XrefList` from a `PyIterator`.
+    ///
+    /// Items given as `str` are parsed as identifiers, and must be prefixed
+    /// identifiers or URLs. A `str` given in place of the iterable is rejected.
     pub fn collect(py: Python, xrefs: &PyAny) -> PyResult<Self> {
+        if xrefs.is_instance_of::<PyString>() {
+            return Err(PyTypeError::new_err("expected iterable of Xref, found str"));
+        }
         let mut vec = Vec::new();
         for item in PyIterator::from_object(py, xrefs)? {
             let i = item?;
             if let Ok(xref) = i.extract::<Py<Xref>>() {
                 vec.push(xref.clone_ref(py));
+            } else if let Ok(s) = i.downcast::<PyString>() {
+                let id = super::id::parse(py, s.to_str()?)?;
+                if let Ident::Unprefixed(_) = id {
+                    let msg = format!("expected Xref or prefixed identifier, found {:?}", s.to_str()?);
+                    return Err(PyTypeError::new_err(msg));
+                }
+                vec.push(Py::new(py, Xref::new(id))?);
             } else {
                 let ty = i.get_type().name()?;
                 let msg = format!("expected Xref, found {}", ty);
                 return Err(PyTypeError::new_err(msg));
             }
         }
-        Ok(Self { xrefs: vec })
+        Ok(Self::new(vec))
     }
 
     /// Check whether the `XrefList` is empty
     pub fn is_empty(&self) -> bool {
         self.xrefs.is_empty()
     }
+
+    /// Iterate over the xrefs of the `XrefList`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Py<Xref>> {
+        self.xrefs.iter()
+    }
 }
 
 impl ClonePy for XrefList {
     fn clone_py(&self, py: Python) -> Self {
-        XrefList {
-            xrefs: self.xrefs.clone_py(py),
+        XrefList::new(self.xrefs.clone_py(py))
+    }
+}
+
+/// Freezing a list by value only freezes its xrefs, since the list itself
+/// is copied whenever it is accessed from Python.
+impl FreezePy for XrefList {
+    fn freeze_py(&self, py: Python) -> PyResult<()> {
+        self.xrefs.freeze_py(py)
+    }
+}
+
+impl FreezePy for Py<XrefList> {
+    fn freeze_py(&self, py: Python) -> PyResult<()> {
+        let mut list = self.try_borrow_mut(py)?;
+        list.frozen = true;
+        list.xrefs.freeze_py(py)
+    }
+}
+
+impl DisplayPy for XrefList {
+    fn fmt_py(&self, py: Python, f: &mut Formatter) -> FmtResult {
+        f.write_char('[')?;
+        for (i, xref) in self.xrefs.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            xref.fmt_py(py, f)?;
         }
+        f.write_char(']')
+    }
+}
+
+impl Display for XrefList {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Python::with_gil(|py| self.fmt_py(py, f))
     }
 }
 
@@ -247,7 +353,7 @@ impl ToPyObject for XrefList {
     }
 }
 
-#[listlike(field = "xrefs", type = "Py<Xref>")]
+#[listlike(field = "xrefs", type = "Py<Xref>", frozen = "frozen")]
 #[pymethods]
 impl XrefList {
     #[new]
@@ -271,20 +377,20 @@ impl XrefList {
     }
 
     fn __str__(&self) -> PyResult<String> {
-        let frame: fastobo::ast::XrefList = Python::with_gil(|py| self.clone_py(py).into_py(py));
-        Ok(frame.to_string())
+        Ok(self.to_string())
+    }
+
+    fn __deepcopy__(&self, py: Python, _memo: &PyAny) -> PyResult<Py<Self>> {
+        let xrefs: fastobo::ast::XrefList = self.clone_py(py).into_py(py);
+        Py::new(py, IntoPy::<XrefList>::into_py(xrefs, py))
     }
 
     fn __len__(&self) -> PyResult<usize> {
         Ok(self.xrefs.len())
     }
 
-    fn __getitem__(&self, index: isize) -> PyResult<Py<Xref>> {
-        if index < self.xrefs.len() as isize {
-            Python::with_gil(|py| Ok(self.xrefs[index as usize].clone_ref(py)))
-        } else {
-            Err(PyIndexError::new_err("list index out of range"))
-        }
+    fn __sizeof__(&self, py: Python) -> PyResult<usize> {
+        Ok(std::mem::size_of::<PyCell<Self>>() + crate::utils::sizeof_vec(&self.xrefs, py)?)
     }
 
     fn __contains__(&self, item: &PyAny) -> PyResult<bool> {
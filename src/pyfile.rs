@@ -1,4 +1,5 @@
 use std::cell::RefCell;
+use std::io::BufWriter;
 use std::io::Error as IoError;
 use std::io::Read;
 use std::io::Write;
@@ -6,12 +7,16 @@ use std::marker::PhantomData;
 use std::sync::Arc;
 use std::sync::Mutex;
 
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::PyBlockingIOError;
 use pyo3::exceptions::PyOSError;
 use pyo3::exceptions::PyTypeError;
 use pyo3::gc::PyTraverseError;
 use pyo3::gc::PyVisit;
 use pyo3::prelude::*;
 use pyo3::types::PyBytes;
+use pyo3::types::PyString;
+use pyo3::types::PyTuple;
 use pyo3::AsPyPointer;
 use pyo3::PyDowncastError;
 use pyo3::PyNativeType;
@@ -42,70 +47,260 @@ macro_rules! transmute_file_error {
 
 // ---------------------------------------------------------------------------
 
-/// A wrapper around a readable Python file borrowed within a GIL lifetime.
-pub struct PyFileRead<'p> {
-    file: &'p PyAny,
+/// The method used to read the content of a Python file.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ReadMode {
+    /// Read bytes with `fh.read` and copy them.
+    Read,
+    /// Read bytes directly into the Rust buffer with `fh.readinto`.
+    ReadInto,
+    /// Read strings from a text file with `fh.read` and encode them.
+    Text,
 }
 
-impl<'p> PyFileRead<'p> {
-    pub fn from_ref(file: &'p PyAny) -> PyResult<PyFileRead<'p>> {
-        let res = file.call_method1("read", (0,))?;
-        if res.downcast::<PyBytes>().is_ok() {
-            Ok(PyFileRead { file })
+impl ReadMode {
+    /// Detect the read mode of a Python file from the result of `fh.read(0)`.
+    ///
+    /// Any object exposing the buffer protocol is accepted as bytes, and
+    /// `str` results denote a file opened in text mode. Non-blocking files
+    /// returning `None` are assumed to be opened in binary mode.
+    fn detect(file: &PyAny) -> PyResult<Self> {
+        let py = file.py();
+        let res = file.call_method1(pyo3::intern!(py, "read"), (0,))?;
+        if res.downcast::<PyString>().is_ok() {
+            Ok(ReadMode::Text)
+        } else if res.is_none()
+            || res.downcast::<PyBytes>().is_ok()
+            || PyBuffer::<u8>::get(res).is_ok()
+        {
+            // a `read` method patched on the instance must not be bypassed
+            let patched = file
+                .getattr(pyo3::intern!(py, "__dict__"))
+                .and_then(|d| d.contains(pyo3::intern!(py, "read")))
+                .unwrap_or(false);
+            match file.hasattr(pyo3::intern!(py, "readinto")) {
+                Ok(true) if !patched => Ok(ReadMode::ReadInto),
+                _ => Ok(ReadMode::Read),
+            }
         } else {
             let ty = res.get_type().name()?.to_string();
             Err(PyTypeError::new_err(format!(
-                "expected bytes, found {}",
+                "expected bytes or str, found {}",
                 ty
             )))
         }
     }
 }
 
-impl<'p> Read for PyFileRead<'p> {
-    fn read(&mut self, buf: &mut [u8]) -> Result<usize, IoError> {
-        match self.file.call_method1("read", (buf.len(),)) {
-            Ok(obj) => {
-                // Check `fh.read` returned bytes, else raise a `TypeError`.
-                if let Ok(bytes) = obj.extract::<&PyBytes>() {
-                    let b = bytes.as_bytes();
-                    (&mut buf[..b.len()]).copy_from_slice(b);
-                    Ok(b.len())
-                } else {
-                    let ty = obj.get_type().name()?.to_string();
-                    let msg = format!("expected bytes, found {}", ty);
-                    PyTypeError::new_err(msg).restore(self.file.py());
-                    Err(IoError::new(
-                        std::io::ErrorKind::Other,
-                        "fh.read did not return bytes",
-                    ))
-                }
+/// Copy the object returned by `fh.read` into `buf`.
+///
+/// Any object exposing the buffer protocol is accepted, so that readers
+/// returning `bytearray` or `memoryview` objects can be used as well.
+/// The bytes that do not fit into `buf` are kept in `pending`, and `None`
+/// is returned if no data was available from a non-blocking file.
+fn copy_read_result(
+    obj: &PyAny,
+    pending: &mut Vec<u8>,
+    buf: &mut [u8],
+) -> Result<Option<usize>, IoError> {
+    let py = obj.py();
+    if obj.is_none() {
+        return Ok(None);
+    } else if let Ok(bytes) = obj.downcast::<PyBytes>() {
+        return Ok(Some(copy_pending(bytes.as_bytes(), pending, buf)));
+    } else if let Ok(buffer) = PyBuffer::<u8>::get(obj) {
+        if let Ok(data) = buffer.to_vec(py) {
+            return Ok(Some(copy_pending(&data, pending, buf)));
+        }
+    }
+    let ty = obj.get_type().name()?.to_string();
+    let msg = format!("expected bytes, found {}", ty);
+    PyTypeError::new_err(msg).restore(py);
+    Err(IoError::other("fh.read did not return bytes"))
+}
+
+/// Copy `data` into `buf`, keeping the bytes that do not fit in `pending`.
+fn copy_pending(data: &[u8], pending: &mut Vec<u8>, buf: &mut [u8]) -> usize {
+    let n = data.len().min(buf.len());
+    buf[..n].copy_from_slice(&data[..n]);
+    pending.extend_from_slice(&data[n..]);
+    n
+}
+
+/// Read from a Python file opened in text mode into `buf`.
+///
+/// The strings returned by `fh.read` are encoded in UTF-8, and the bytes
+/// that do not fit into `buf` are kept in `pending` for the next call.
+fn read_text(
+    file: &PyAny,
+    pending: &mut Vec<u8>,
+    buf: &mut [u8],
+) -> Result<Option<usize>, IoError> {
+    let py = file.py();
+    match file.call_method1(pyo3::intern!(py, "read"), (buf.len(),)) {
+        Ok(obj) if obj.is_none() => Ok(None),
+        Ok(obj) => match obj.downcast::<PyString>() {
+            Ok(s) => Ok(Some(copy_pending(s.to_str()?.as_bytes(), pending, buf))),
+            Err(_) => {
+                let ty = obj.get_type().name()?.to_string();
+                let msg = format!("expected str, found {}", ty);
+                PyTypeError::new_err(msg).restore(py);
+                Err(IoError::other("fh.read did not return str"))
             }
-            Err(e) => {
-                transmute_file_error!(self, e, "read method failed", self.file.py())
+        },
+        Err(e) if e.is_instance_of::<PyBlockingIOError>(py) => Ok(None),
+        Err(e) => transmute_file_error!(self, e, "read method failed", py),
+    }
+}
+
+/// Read from a Python file into `buf` with `fh.readinto`.
+///
+/// The data is written directly into `buf` through a temporary `memoryview`,
+/// avoiding the allocation of an intermediate `bytes` object and a copy.
+fn read_into(file: &PyAny, buf: &mut [u8]) -> Result<Option<usize>, IoError> {
+    let py = file.py();
+
+    // SAFETY: the memoryview is released before `buf` is used again, so
+    //         the Python file cannot keep a reference to the Rust buffer.
+    let view = unsafe {
+        let ptr = pyo3::ffi::PyMemoryView_FromMemory(
+            buf.as_mut_ptr() as *mut std::os::raw::c_char,
+            buf.len() as pyo3::ffi::Py_ssize_t,
+            pyo3::ffi::PyBUF_WRITE,
+        );
+        match py.from_owned_ptr_or_err::<PyAny>(ptr) {
+            Ok(view) => view,
+            Err(e) => return transmute_file_error!(self, e, "memoryview creation failed", py),
+        }
+    };
+    let result = file.call_method1(pyo3::intern!(py, "readinto"), (view,));
+    if let Err(e) = view.call_method0(pyo3::intern!(py, "release")) {
+        return transmute_file_error!(self, e, "memoryview release failed", py);
+    }
+    match result {
+        Ok(obj) if obj.is_none() => Ok(None),
+        Ok(obj) => match obj.extract::<usize>() {
+            Ok(n) if n <= buf.len() => Ok(Some(n)),
+            _ => {
+                let ty = obj.get_type().name()?.to_string();
+                let msg = format!("expected int, found {}", ty);
+                PyTypeError::new_err(msg).restore(py);
+                Err(IoError::other("fh.readinto did not return a valid int"))
             }
+        },
+        Err(e) if e.is_instance_of::<PyBlockingIOError>(py) => Ok(None),
+        Err(e) => transmute_file_error!(self, e, "readinto method failed", py),
+    }
+}
+
+/// Wait for data to become available on a non-blocking Python file.
+///
+/// The GIL is released while waiting so that other Python threads can
+/// write to the file, and pending signals are checked so that a blocked
+/// read can still be interrupted with `KeyboardInterrupt`.
+fn wait_for_data(py: Python) -> Result<(), IoError> {
+    if let Err(e) = py.check_signals() {
+        e.restore(py);
+        return Err(IoError::other("interrupted while waiting for data"));
+    }
+    py.allow_threads(|| std::thread::sleep(std::time::Duration::from_millis(1)));
+    Ok(())
+}
+
+/// Read from a Python file into `buf`.
+///
+/// Short reads are returned as-is, since only an empty read denotes the
+/// end of the file. When a non-blocking file has no data available, i.e.
+/// when its methods return `None` or raise `BlockingIOError`, the read is
+/// retried until some data can be read.
+fn read_file(
+    file: &PyAny,
+    mode: ReadMode,
+    pending: &mut Vec<u8>,
+    buf: &mut [u8],
+) -> Result<usize, IoError> {
+    let py = file.py();
+    if !pending.is_empty() {
+        let n = pending.len().min(buf.len());
+        buf[..n].copy_from_slice(&pending[..n]);
+        pending.drain(..n);
+        return Ok(n);
+    }
+    loop {
+        let result = match mode {
+            ReadMode::Text => read_text(file, pending, buf)?,
+            ReadMode::ReadInto => read_into(file, buf)?,
+            ReadMode::Read => match file.call_method1(pyo3::intern!(py, "read"), (buf.len(),)) {
+                Ok(obj) => copy_read_result(obj, pending, buf)?,
+                Err(e) if e.is_instance_of::<PyBlockingIOError>(py) => None,
+                Err(e) => return transmute_file_error!(self, e, "read method failed", py),
+            },
+        };
+        match result {
+            Some(n) => return Ok(n),
+            None => wait_for_data(py)?,
         }
     }
 }
 
 // ---------------------------------------------------------------------------
 
+/// A wrapper around a readable Python file borrowed within a GIL lifetime.
+pub struct PyFileRead<'p> {
+    file: &'p PyAny,
+    mode: ReadMode,
+    pending: Vec<u8>,
+}
+
+impl<'p> PyFileRead<'p> {
+    pub fn from_ref(file: &'p PyAny) -> PyResult<PyFileRead<'p>> {
+        ReadMode::detect(file).map(|mode| PyFileRead {
+            file,
+            mode,
+            pending: Vec::new(),
+        })
+    }
+}
+
+impl<'p> Read for PyFileRead<'p> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, IoError> {
+        read_file(self.file, self.mode, &mut self.pending, buf)
+    }
+}
+
+// ---------------------------------------------------------------------------
+
 /// A wrapper around a writable Python file borrowed within a GIL lifetime.
+///
+/// Files opened in text mode are detected when `fh.write` rejects bytes,
+/// in which case the data is decoded from UTF-8 before being written.
 pub struct PyFileWrite<'p> {
     file: &'p PyAny,
+    text: bool,
+    pending: Vec<u8>,
 }
 
 impl<'p> PyFileWrite<'p> {
     pub fn from_ref(file: &'p PyAny) -> PyResult<PyFileWrite<'p>> {
-        file.call_method1("write", (PyBytes::new(file.py(), b""),))
-            .map(|_| PyFileWrite { file })
+        let py = file.py();
+        let text = match file.call_method1("write", (PyBytes::new(py, b""),)) {
+            Ok(_) => false,
+            Err(e) if e.is_instance_of::<PyTypeError>(py) => {
+                file.call_method1("write", ("",)).map_err(|_| e)?;
+                true
+            }
+            Err(e) => return Err(e),
+        };
+        Ok(PyFileWrite {
+            file,
+            text,
+            pending: Vec::new(),
+        })
     }
-}
 
-impl<'p> Write for PyFileWrite<'p> {
-    fn write(&mut self, buf: &[u8]) -> Result<usize, IoError> {
-        let bytes = PyBytes::new(self.file.py(), buf);
-        match self.file.call_method1("write", (bytes,)) {
+    /// Call `fh.write` with the given object, and check it returned an `int`.
+    fn call_write<O: IntoPy<Py<PyTuple>>>(&self, args: O) -> Result<usize, IoError> {
+        match self.file.call_method1("write", args) {
             Ok(obj) => {
                 // Check `fh.write` returned int, else raise a `TypeError`.
                 if let Ok(len) = usize::extract(&obj) {
@@ -126,6 +321,35 @@ impl<'p> Write for PyFileWrite<'p> {
         }
     }
 
+    /// Write the complete UTF-8 characters of `buf` to a text file.
+    ///
+    /// The bytes of a character split between two calls are kept until
+    /// the rest of the character is written.
+    fn write_text(&mut self, buf: &[u8]) -> Result<usize, IoError> {
+        self.pending.extend_from_slice(buf);
+        let valid = match std::str::from_utf8(&self.pending) {
+            Ok(s) => s.len(),
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(e) => return Err(IoError::new(std::io::ErrorKind::InvalidData, e)),
+        };
+        if valid > 0 {
+            let text = std::str::from_utf8(&self.pending[..valid]).unwrap();
+            self.call_write((text,))?;
+            self.pending.drain(..valid);
+        }
+        Ok(buf.len())
+    }
+}
+
+impl<'p> Write for PyFileWrite<'p> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, IoError> {
+        if self.text {
+            self.write_text(buf)
+        } else {
+            self.call_write((PyBytes::new(self.file.py(), buf),))
+        }
+    }
+
     fn flush(&mut self) -> Result<(), IoError> {
         match self.file.call_method0("flush") {
             Ok(_) => Ok(()),
@@ -138,25 +362,73 @@ impl<'p> Write for PyFileWrite<'p> {
 
 // ---------------------------------------------------------------------------
 
+/// A buffered writer around a writable Python file, flushing it when done.
+///
+/// Call `finish` to flush the buffer and the Python file and get any error
+/// that occurred. The writer is also flushed when dropped, e.g. when an
+/// error interrupts serialization, so that the data written so far is
+/// never lost in the buffer.
+pub struct PyFileBufWrite<'p> {
+    inner: Option<BufWriter<PyFileWrite<'p>>>,
+}
+
+impl<'p> PyFileBufWrite<'p> {
+    pub fn from_ref(file: &'p PyAny, capacity: usize) -> PyResult<PyFileBufWrite<'p>> {
+        PyFileWrite::from_ref(file).map(|f| PyFileBufWrite {
+            inner: Some(BufWriter::with_capacity(capacity, f)),
+        })
+    }
+
+    /// Flush the buffered data and the Python file.
+    pub fn finish(mut self) -> Result<(), IoError> {
+        self.inner.take().unwrap().flush()
+    }
+}
+
+impl<'p> Write for PyFileBufWrite<'p> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, IoError> {
+        self.inner.as_mut().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> Result<(), IoError> {
+        self.inner.as_mut().unwrap().flush()
+    }
+}
+
+impl<'p> Drop for PyFileBufWrite<'p> {
+    fn drop(&mut self) {
+        if let Some(mut writer) = self.inner.take() {
+            // do not call the file methods while an error is pending
+            let py = writer.get_ref().file.py();
+            if PyErr::occurred(py) {
+                return;
+            }
+            // errors cannot be reported here, so discard the Python error
+            // possibly set by the file methods
+            if writer.flush().is_err() {
+                let _ = PyErr::take(py);
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+
 /// A wrapper for a Python file that can outlive the GIL.
 pub struct PyFileGILRead {
     file: Mutex<PyObject>,
+    mode: ReadMode,
+    pending: Vec<u8>,
 }
 
 impl PyFileGILRead {
     pub fn from_ref(file: &PyAny) -> PyResult<PyFileGILRead> {
-        let res = file.call_method1("read", (0,))?;
-        if res.downcast::<PyBytes>().is_ok() {
-            Ok(PyFileGILRead {
-                file: Mutex::new(file.to_object(file.py())),
-            })
-        } else {
-            let ty = res.get_type().name()?.to_string();
-            Err(PyTypeError::new_err(format!(
-                "expected bytes, found {}",
-                ty
-            )))
-        }
+        let mode = ReadMode::detect(file)?;
+        Ok(PyFileGILRead {
+            file: Mutex::new(file.to_object(file.py())),
+            mode,
+            pending: Vec::new(),
+        })
     }
 
     pub fn file(&self) -> &Mutex<PyObject> {
@@ -166,27 +438,10 @@ impl PyFileGILRead {
 
 impl Read for PyFileGILRead {
     fn read(&mut self, buf: &mut [u8]) -> Result<usize, IoError> {
+        let Self { file, mode, pending } = self;
         Python::with_gil(|py| {
-            let file = self.file.lock().unwrap();
-            match file.to_object(py).call_method1(py, "read", (buf.len(),)) {
-                Ok(obj) => {
-                    // Check `fh.read` returned bytes, else raise a `TypeError`.
-                    if let Ok(bytes) = obj.downcast::<PyBytes>(py) {
-                        let b = bytes.as_bytes();
-                        (&mut buf[..b.len()]).copy_from_slice(b);
-                        Ok(b.len())
-                    } else {
-                        let ty = obj.as_ref(py).get_type().name()?.to_string();
-                        let msg = format!("expected bytes, found {}", ty);
-                        PyTypeError::new_err(msg).restore(py);
-                        Err(IoError::new(
-                            std::io::ErrorKind::Other,
-                            "fh.read did not return bytes",
-                        ))
-                    }
-                }
-                Err(e) => transmute_file_error!(self, e, "read method failed", py),
-            }
+            let file = file.get_mut().unwrap();
+            read_file(file.as_ref(py), *mode, pending, buf)
         })
     }
 }
@@ -0,0 +1,663 @@
+use std::collections::HashMap;
+
+use pyo3::prelude::*;
+use pyo3::types::PyAny;
+use pyo3::types::PyDict;
+
+use horned_owl::model::AnnotatedAxiom;
+use horned_owl::model::Annotation;
+use horned_owl::model::AnnotationSubject;
+use horned_owl::model::AnnotationValue;
+use horned_owl::model::AnonymousIndividual;
+use horned_owl::model::Axiom;
+use horned_owl::model::ClassExpression;
+use horned_owl::model::DataProperty;
+use horned_owl::model::DataRange;
+use horned_owl::model::Individual;
+use horned_owl::model::Literal;
+use horned_owl::model::ObjectPropertyExpression;
+use horned_owl::model::Ontology;
+use horned_owl::model::PropertyExpression;
+use horned_owl::model::SubObjectPropertyExpression;
+use horned_owl::ontology::axiom_mapped::AxiomMappedOntology;
+use horned_owl::vocab::WithIRI;
+
+const RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const RDFS: &str = "http://www.w3.org/2000/01/rdf-schema#";
+const OWL: &str = "http://www.w3.org/2002/07/owl#";
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+
+/// A node of an RDF graph.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Term {
+    Iri(String),
+    BNode(usize),
+    Literal {
+        value: String,
+        datatype: Option<String>,
+        lang: Option<String>,
+    },
+}
+
+impl Term {
+    fn iri<S: std::fmt::Display>(iri: S) -> Self {
+        Term::Iri(iri.to_string())
+    }
+
+    fn vocab(namespace: &str, local: &str) -> Self {
+        Term::Iri(format!("{}{}", namespace, local))
+    }
+
+    fn integer(n: u32) -> Self {
+        Term::Literal {
+            value: n.to_string(),
+            datatype: Some(format!("{}nonNegativeInteger", XSD)),
+            lang: None,
+        }
+    }
+}
+
+/// An RDF triple.
+pub type Triple = (Term, Term, Term);
+
+/// The part of the translation of an axiom its annotations are attached to.
+enum Main {
+    /// Triples which are reified with an `owl:Axiom` node.
+    Triples(Vec<Triple>),
+    /// A blank node standing for the whole axiom.
+    Node(Term),
+}
+
+/// A builder for the triples of the OWL 2 mapping to RDF graphs.
+#[derive(Default)]
+struct TripleBuilder {
+    triples: Vec<Triple>,
+    bnodes: usize,
+    anonymous: HashMap<String, usize>,
+}
+
+impl TripleBuilder {
+    fn bnode(&mut self) -> Term {
+        self.bnodes += 1;
+        Term::BNode(self.bnodes)
+    }
+
+    fn add(&mut self, s: Term, p: Term, o: Term) -> Triple {
+        let triple = (s, p, o);
+        self.triples.push(triple.clone());
+        triple
+    }
+
+    fn add_type(&mut self, s: Term, namespace: &str, local: &str) -> Triple {
+        self.add(s, Term::vocab(RDF, "type"), Term::vocab(namespace, local))
+    }
+
+    fn list(&mut self, items: Vec<Term>) -> Term {
+        let mut head = Term::vocab(RDF, "nil");
+        for item in items.into_iter().rev() {
+            let node = self.bnode();
+            self.add(node.clone(), Term::vocab(RDF, "first"), item);
+            self.add(node.clone(), Term::vocab(RDF, "rest"), head);
+            head = node;
+        }
+        head
+    }
+
+    /// Create a typed blank node with a single property.
+    fn node(&mut self, namespace: &str, ty: &str, property: &str, value: Term) -> Term {
+        let node = self.bnode();
+        self.add_type(node.clone(), namespace, ty);
+        self.add(node.clone(), Term::vocab(OWL, property), value);
+        node
+    }
+
+    fn literal(&self, literal: &Literal) -> Term {
+        match literal {
+            Literal::Simple { literal } => Term::Literal {
+                value: literal.clone(),
+                datatype: None,
+                lang: None,
+            },
+            Literal::Language { literal, lang } => Term::Literal {
+                value: literal.clone(),
+                datatype: None,
+                lang: Some(lang.clone()),
+            },
+            Literal::Datatype {
+                literal,
+                datatype_iri,
+            } => Term::Literal {
+                value: literal.clone(),
+                datatype: Some(datatype_iri.to_string()),
+                lang: None,
+            },
+        }
+    }
+
+    /// Get the blank node of an anonymous individual.
+    ///
+    /// Anonymous individuals with the same node ID are mapped to the same
+    /// blank node.
+    fn anonymous(&mut self, individual: &AnonymousIndividual) -> Term {
+        if let Some(&n) = self.anonymous.get(&**individual) {
+            return Term::BNode(n);
+        }
+        let node = self.bnode();
+        if let Term::BNode(n) = node {
+            self.anonymous.insert(individual.to_string(), n);
+        }
+        node
+    }
+
+    fn individual(&mut self, individual: &Individual) -> Term {
+        match individual {
+            Individual::Named(i) => Term::iri(&i.0),
+            Individual::Anonymous(a) => self.anonymous(a),
+        }
+    }
+
+    fn individuals(&mut self, individuals: &[Individual]) -> Vec<Term> {
+        individuals.iter().map(|i| self.individual(i)).collect()
+    }
+
+    fn ope(&mut self, ope: &ObjectPropertyExpression) -> Term {
+        match ope {
+            ObjectPropertyExpression::ObjectProperty(op) => Term::iri(&op.0),
+            ObjectPropertyExpression::InverseObjectProperty(op) => {
+                let node = self.bnode();
+                self.add(node.clone(), Term::vocab(OWL, "inverseOf"), Term::iri(&op.0));
+                node
+            }
+        }
+    }
+
+    fn opes(&mut self, opes: &[ObjectPropertyExpression]) -> Vec<Term> {
+        opes.iter().map(|ope| self.ope(ope)).collect()
+    }
+
+    fn dps(&self, dps: &[DataProperty]) -> Vec<Term> {
+        dps.iter().map(|dp| Term::iri(&dp.0)).collect()
+    }
+
+    fn data_range(&mut self, dr: &DataRange) -> Term {
+        match dr {
+            DataRange::Datatype(dt) => Term::iri(&dt.0),
+            DataRange::DataIntersectionOf(drs) | DataRange::DataUnionOf(drs) => {
+                let local = match dr {
+                    DataRange::DataIntersectionOf(_) => "intersectionOf",
+                    _ => "unionOf",
+                };
+                let items = drs.iter().map(|dr| self.data_range(dr)).collect();
+                let list = self.list(items);
+                self.node(RDFS, "Datatype", local, list)
+            }
+            DataRange::DataComplementOf(dr) => {
+                let complement = self.data_range(dr);
+                self.node(RDFS, "Datatype", "datatypeComplementOf", complement)
+            }
+            DataRange::DataOneOf(literals) => {
+                let items = literals.iter().map(|l| self.literal(l)).collect();
+                let list = self.list(items);
+                self.node(RDFS, "Datatype", "oneOf", list)
+            }
+            DataRange::DatatypeRestriction(dt, restrictions) => {
+                let mut items = Vec::with_capacity(restrictions.len());
+                for restriction in restrictions {
+                    let node = self.bnode();
+                    let value = self.literal(&restriction.l);
+                    self.add(node.clone(), Term::iri(restriction.f.iri_str()), value);
+                    items.push(node);
+                }
+                let list = self.list(items);
+                let node = self.node(RDFS, "Datatype", "onDatatype", Term::iri(&dt.0));
+                self.add(node.clone(), Term::vocab(OWL, "withRestrictions"), list);
+                node
+            }
+        }
+    }
+
+    fn class_expressions(&mut self, ces: &[ClassExpression]) -> Vec<Term> {
+        ces.iter().map(|ce| self.class_expression(ce)).collect()
+    }
+
+    fn restriction(&mut self, property: Term, local: &str, value: Term) -> Term {
+        let node = self.node(OWL, "Restriction", "onProperty", property);
+        self.add(node.clone(), Term::vocab(OWL, local), value);
+        node
+    }
+
+    fn cardinality(&mut self, property: Term, local: &str, n: u32, on: &str, filler: Term) -> Term {
+        let node = self.restriction(property, local, Term::integer(n));
+        self.add(node.clone(), Term::vocab(OWL, on), filler);
+        node
+    }
+
+    fn class_expression(&mut self, ce: &ClassExpression) -> Term {
+        match ce {
+            ClassExpression::Class(c) => Term::iri(&c.0),
+            ClassExpression::ObjectIntersectionOf(ces) | ClassExpression::ObjectUnionOf(ces) => {
+                let local = match ce {
+                    ClassExpression::ObjectIntersectionOf(_) => "intersectionOf",
+                    _ => "unionOf",
+                };
+                let items = self.class_expressions(ces);
+                let list = self.list(items);
+                self.node(OWL, "Class", local, list)
+            }
+            ClassExpression::ObjectComplementOf(bce) => {
+                let complement = self.class_expression(bce);
+                self.node(OWL, "Class", "complementOf", complement)
+            }
+            ClassExpression::ObjectOneOf(individuals) => {
+                let items = self.individuals(individuals);
+                let list = self.list(items);
+                self.node(OWL, "Class", "oneOf", list)
+            }
+            ClassExpression::ObjectSomeValuesFrom { ope, bce } => {
+                let (property, filler) = (self.ope(ope), self.class_expression(bce));
+                self.restriction(property, "someValuesFrom", filler)
+            }
+            ClassExpression::ObjectAllValuesFrom { ope, bce } => {
+                let (property, filler) = (self.ope(ope), self.class_expression(bce));
+                self.restriction(property, "allValuesFrom", filler)
+            }
+            ClassExpression::ObjectHasValue { ope, i } => {
+                let (property, value) = (self.ope(ope), self.individual(i));
+                self.restriction(property, "hasValue", value)
+            }
+            ClassExpression::ObjectHasSelf(ope) => {
+                let property = self.ope(ope);
+                let value = Term::Literal {
+                    value: String::from("true"),
+                    datatype: Some(format!("{}boolean", XSD)),
+                    lang: None,
+                };
+                self.restriction(property, "hasSelf", value)
+            }
+            ClassExpression::ObjectMinCardinality { n, ope, bce } => {
+                let (property, filler) = (self.ope(ope), self.class_expression(bce));
+                self.cardinality(property, "minQualifiedCardinality", *n, "onClass", filler)
+            }
+            ClassExpression::ObjectMaxCardinality { n, ope, bce } => {
+                let (property, filler) = (self.ope(ope), self.class_expression(bce));
+                self.cardinality(property, "maxQualifiedCardinality", *n, "onClass", filler)
+            }
+            ClassExpression::ObjectExactCardinality { n, ope, bce } => {
+                let (property, filler) = (self.ope(ope), self.class_expression(bce));
+                self.cardinality(property, "qualifiedCardinality", *n, "onClass", filler)
+            }
+            ClassExpression::DataSomeValuesFrom { dp, dr } => {
+                let filler = self.data_range(dr);
+                self.restriction(Term::iri(&dp.0), "someValuesFrom", filler)
+            }
+            ClassExpression::DataAllValuesFrom { dp, dr } => {
+                let filler = self.data_range(dr);
+                self.restriction(Term::iri(&dp.0), "allValuesFrom", filler)
+            }
+            ClassExpression::DataHasValue { dp, l } => {
+                let value = self.literal(l);
+                self.restriction(Term::iri(&dp.0), "hasValue", value)
+            }
+            ClassExpression::DataMinCardinality { n, dp, dr } => {
+                let filler = self.data_range(dr);
+                let property = Term::iri(&dp.0);
+                self.cardinality(property, "minQualifiedCardinality", *n, "onDataRange", filler)
+            }
+            ClassExpression::DataMaxCardinality { n, dp, dr } => {
+                let filler = self.data_range(dr);
+                let property = Term::iri(&dp.0);
+                self.cardinality(property, "maxQualifiedCardinality", *n, "onDataRange", filler)
+            }
+            ClassExpression::DataExactCardinality { n, dp, dr } => {
+                let filler = self.data_range(dr);
+                let property = Term::iri(&dp.0);
+                self.cardinality(property, "qualifiedCardinality", *n, "onDataRange", filler)
+            }
+        }
+    }
+
+    fn annotation(&mut self, subject: Term, annotation: &Annotation) -> Triple {
+        let value = match &annotation.av {
+            AnnotationValue::Literal(l) => self.literal(l),
+            AnnotationValue::IRI(iri) => Term::iri(iri),
+        };
+        self.add(subject, Term::iri(&annotation.ap.0), value)
+    }
+
+    /// Add the triples relating each consecutive pair of `items`.
+    fn pairwise(&mut self, items: Vec<Term>, namespace: &str, local: &str) -> Main {
+        let triples = items
+            .windows(2)
+            .map(|pair| self.add(pair[0].clone(), Term::vocab(namespace, local), pair[1].clone()))
+            .collect();
+        Main::Triples(triples)
+    }
+
+    /// Add the triples of an axiom relating all of `items` with each other.
+    ///
+    /// Two items are related with a single triple using `pairwise`, and
+    /// more items are listed as the members of a blank node of type `ty`.
+    fn all(&mut self, items: Vec<Term>, local: &str, ty: &str) -> Main {
+        if items.len() == 2 {
+            self.pairwise(items, OWL, local)
+        } else {
+            let list = self.list(items);
+            Main::Node(self.node(OWL, ty, "members", list))
+        }
+    }
+
+    /// Add the triples of a negative property assertion.
+    fn negative(&mut self, source: Term, property: Term, target: &str, value: Term) -> Main {
+        let node = self.node(OWL, "NegativePropertyAssertion", "sourceIndividual", source);
+        self.add(node.clone(), Term::vocab(OWL, "assertionProperty"), property);
+        self.add(node.clone(), Term::vocab(OWL, target), value);
+        Main::Node(node)
+    }
+
+    /// Add the triples of an axiom, and return the part its annotations
+    /// are attached to.
+    fn axiom(&mut self, axiom: &Axiom) -> Main {
+        let triple = match axiom {
+            Axiom::OntologyAnnotation(_) | Axiom::Import(_) => return Main::Triples(Vec::new()),
+            Axiom::DeclareClass(d) => self.add_type(Term::iri(&(d.0).0), OWL, "Class"),
+            Axiom::DeclareObjectProperty(d) => {
+                self.add_type(Term::iri(&(d.0).0), OWL, "ObjectProperty")
+            }
+            Axiom::DeclareAnnotationProperty(d) => {
+                self.add_type(Term::iri(&(d.0).0), OWL, "AnnotationProperty")
+            }
+            Axiom::DeclareDataProperty(d) => {
+                self.add_type(Term::iri(&(d.0).0), OWL, "DatatypeProperty")
+            }
+            Axiom::DeclareNamedIndividual(d) => {
+                self.add_type(Term::iri(&(d.0).0), OWL, "NamedIndividual")
+            }
+            Axiom::DeclareDatatype(d) => self.add_type(Term::iri(&(d.0).0), RDFS, "Datatype"),
+            Axiom::SubClassOf(ax) => {
+                let sub = self.class_expression(&ax.sub);
+                let sup = self.class_expression(&ax.sup);
+                self.add(sub, Term::vocab(RDFS, "subClassOf"), sup)
+            }
+            Axiom::EquivalentClasses(ax) => {
+                let items = self.class_expressions(&ax.0);
+                return self.pairwise(items, OWL, "equivalentClass");
+            }
+            Axiom::DisjointClasses(ax) => {
+                let items = self.class_expressions(&ax.0);
+                return self.all(items, "disjointWith", "AllDisjointClasses");
+            }
+            Axiom::DisjointUnion(ax) => {
+                let items = self.class_expressions(&ax.1);
+                let list = self.list(items);
+                self.add(Term::iri(&(ax.0).0), Term::vocab(OWL, "disjointUnionOf"), list)
+            }
+            Axiom::SubObjectPropertyOf(ax) => {
+                let sup = self.ope(&ax.sup);
+                match &ax.sub {
+                    SubObjectPropertyExpression::ObjectPropertyExpression(ope) => {
+                        let sub = self.ope(ope);
+                        self.add(sub, Term::vocab(RDFS, "subPropertyOf"), sup)
+                    }
+                    SubObjectPropertyExpression::ObjectPropertyChain(chain) => {
+                        let items = self.opes(chain);
+                        let list = self.list(items);
+                        self.add(sup, Term::vocab(OWL, "propertyChainAxiom"), list)
+                    }
+                }
+            }
+            Axiom::EquivalentObjectProperties(ax) => {
+                let items = self.opes(&ax.0);
+                return self.pairwise(items, OWL, "equivalentProperty");
+            }
+            Axiom::DisjointObjectProperties(ax) => {
+                let items = self.opes(&ax.0);
+                return self.all(items, "propertyDisjointWith", "AllDisjointProperties");
+            }
+            Axiom::InverseObjectProperties(ax) => {
+                let (first, second) = (Term::iri(&(ax.0).0), Term::iri(&(ax.1).0));
+                self.add(first, Term::vocab(OWL, "inverseOf"), second)
+            }
+            Axiom::ObjectPropertyDomain(ax) => {
+                let ope = self.ope(&ax.ope);
+                let ce = self.class_expression(&ax.ce);
+                self.add(ope, Term::vocab(RDFS, "domain"), ce)
+            }
+            Axiom::ObjectPropertyRange(ax) => {
+                let ope = self.ope(&ax.ope);
+                let ce = self.class_expression(&ax.ce);
+                self.add(ope, Term::vocab(RDFS, "range"), ce)
+            }
+            Axiom::FunctionalObjectProperty(ax) => {
+                let ope = self.ope(&ax.0);
+                self.add_type(ope, OWL, "FunctionalProperty")
+            }
+            Axiom::InverseFunctionalObjectProperty(ax) => {
+                let ope = self.ope(&ax.0);
+                self.add_type(ope, OWL, "InverseFunctionalProperty")
+            }
+            Axiom::ReflexiveObjectProperty(ax) => {
+                let ope = self.ope(&ax.0);
+                self.add_type(ope, OWL, "ReflexiveProperty")
+            }
+            Axiom::IrreflexiveObjectProperty(ax) => {
+                let ope = self.ope(&ax.0);
+                self.add_type(ope, OWL, "IrreflexiveProperty")
+            }
+            Axiom::SymmetricObjectProperty(ax) => {
+                let ope = self.ope(&ax.0);
+                self.add_type(ope, OWL, "SymmetricProperty")
+            }
+            Axiom::AsymmetricObjectProperty(ax) => {
+                let ope = self.ope(&ax.0);
+                self.add_type(ope, OWL, "AsymmetricProperty")
+            }
+            Axiom::TransitiveObjectProperty(ax) => {
+                let ope = self.ope(&ax.0);
+                self.add_type(ope, OWL, "TransitiveProperty")
+            }
+            Axiom::SubDataPropertyOf(ax) => {
+                let (sub, sup) = (Term::iri(&ax.sub.0), Term::iri(&ax.sup.0));
+                self.add(sub, Term::vocab(RDFS, "subPropertyOf"), sup)
+            }
+            Axiom::EquivalentDataProperties(ax) => {
+                let items = self.dps(&ax.0);
+                return self.pairwise(items, OWL, "equivalentProperty");
+            }
+            Axiom::DisjointDataProperties(ax) => {
+                let items = self.dps(&ax.0);
+                return self.all(items, "propertyDisjointWith", "AllDisjointProperties");
+            }
+            Axiom::DataPropertyDomain(ax) => {
+                let ce = self.class_expression(&ax.ce);
+                self.add(Term::iri(&ax.dp.0), Term::vocab(RDFS, "domain"), ce)
+            }
+            Axiom::DataPropertyRange(ax) => {
+                let dr = self.data_range(&ax.dr);
+                self.add(Term::iri(&ax.dp.0), Term::vocab(RDFS, "range"), dr)
+            }
+            Axiom::FunctionalDataProperty(ax) => {
+                self.add_type(Term::iri(&(ax.0).0), OWL, "FunctionalProperty")
+            }
+            Axiom::DatatypeDefinition(ax) => {
+                let dr = self.data_range(&ax.range);
+                self.add(Term::iri(&ax.kind.0), Term::vocab(OWL, "equivalentClass"), dr)
+            }
+            Axiom::HasKey(ax) => {
+                let ce = self.class_expression(&ax.ce);
+                let items = ax
+                    .vpe
+                    .iter()
+                    .map(|pe| match pe {
+                        PropertyExpression::ObjectPropertyExpression(ope) => self.ope(ope),
+                        PropertyExpression::DataProperty(dp) => Term::iri(&dp.0),
+                        PropertyExpression::AnnotationProperty(ap) => Term::iri(&ap.0),
+                    })
+                    .collect();
+                let list = self.list(items);
+                self.add(ce, Term::vocab(OWL, "hasKey"), list)
+            }
+            Axiom::SameIndividual(ax) => {
+                let items = self.individuals(&ax.0);
+                return self.pairwise(items, OWL, "sameAs");
+            }
+            Axiom::DifferentIndividuals(ax) => {
+                let items = self.individuals(&ax.0);
+                return self.all(items, "differentFrom", "AllDifferent");
+            }
+            Axiom::ClassAssertion(ax) => {
+                let i = self.individual(&ax.i);
+                let ce = self.class_expression(&ax.ce);
+                self.add(i, Term::vocab(RDF, "type"), ce)
+            }
+            Axiom::ObjectPropertyAssertion(ax) => {
+                let from = self.individual(&ax.from);
+                let to = self.individual(&ax.to);
+                match &ax.ope {
+                    ObjectPropertyExpression::ObjectProperty(op) => {
+                        self.add(from, Term::iri(&op.0), to)
+                    }
+                    ObjectPropertyExpression::InverseObjectProperty(op) => {
+                        self.add(to, Term::iri(&op.0), from)
+                    }
+                }
+            }
+            Axiom::NegativeObjectPropertyAssertion(ax) => {
+                let from = self.individual(&ax.from);
+                let to = self.individual(&ax.to);
+                let ope = self.ope(&ax.ope);
+                return self.negative(from, ope, "targetIndividual", to);
+            }
+            Axiom::DataPropertyAssertion(ax) => {
+                let from = self.individual(&ax.from);
+                let to = self.literal(&ax.to);
+                self.add(from, Term::iri(&ax.dp.0), to)
+            }
+            Axiom::NegativeDataPropertyAssertion(ax) => {
+                let from = self.individual(&ax.from);
+                let to = self.literal(&ax.to);
+                return self.negative(from, Term::iri(&ax.dp.0), "targetValue", to);
+            }
+            Axiom::AnnotationAssertion(ax) => {
+                let subject = match &ax.subject {
+                    AnnotationSubject::IRI(iri) => Term::iri(iri),
+                    AnnotationSubject::AnonymousIndividual(a) => self.anonymous(a),
+                };
+                self.annotation(subject, &ax.ann)
+            }
+            Axiom::SubAnnotationPropertyOf(ax) => {
+                let (sub, sup) = (Term::iri(&ax.sub.0), Term::iri(&ax.sup.0));
+                self.add(sub, Term::vocab(RDFS, "subPropertyOf"), sup)
+            }
+            Axiom::AnnotationPropertyDomain(ax) => {
+                self.add(Term::iri(&ax.ap.0), Term::vocab(RDFS, "domain"), Term::iri(&ax.iri))
+            }
+            Axiom::AnnotationPropertyRange(ax) => {
+                self.add(Term::iri(&ax.ap.0), Term::vocab(RDFS, "range"), Term::iri(&ax.iri))
+            }
+        };
+        Main::Triples(vec![triple])
+    }
+
+    /// Add the triples of an annotated axiom, reifying its annotations.
+    fn annotated_axiom(&mut self, axiom: &AnnotatedAxiom) {
+        let main = self.axiom(&axiom.axiom);
+        if axiom.ann.is_empty() {
+            return;
+        }
+        let nodes = match main {
+            Main::Node(node) => vec![node],
+            Main::Triples(triples) => triples
+                .into_iter()
+                .map(|(s, p, o)| {
+                    let node = self.node(OWL, "Axiom", "annotatedSource", s);
+                    self.add(node.clone(), Term::vocab(OWL, "annotatedProperty"), p);
+                    self.add(node.clone(), Term::vocab(OWL, "annotatedTarget"), o);
+                    node
+                })
+                .collect(),
+        };
+        for node in nodes {
+            for annotation in axiom.ann.iter() {
+                self.annotation(node.clone(), annotation);
+            }
+        }
+    }
+}
+
+/// Get the RDF triples of an OWL ontology.
+///
+/// The ontology header is translated to an `owl:Ontology` node with its
+/// version IRI, imports and annotations, and each axiom is translated
+/// following the OWL 2 mapping to RDF graphs.
+pub fn triples(ont: &AxiomMappedOntology) -> Vec<Triple> {
+    let mut builder = TripleBuilder::default();
+
+    let id = ont.id();
+    let ontology = match &id.iri {
+        Some(iri) => Term::iri(iri),
+        None => builder.bnode(),
+    };
+    builder.add_type(ontology.clone(), OWL, "Ontology");
+    if let Some(viri) = &id.viri {
+        builder.add(ontology.clone(), Term::vocab(OWL, "versionIRI"), Term::iri(viri));
+    }
+
+    for axiom in ont.i().iter() {
+        match &axiom.axiom {
+            Axiom::Import(import) => {
+                builder.add(ontology.clone(), Term::vocab(OWL, "imports"), Term::iri(&import.0));
+            }
+            Axiom::OntologyAnnotation(annotation) => {
+                builder.annotation(ontology.clone(), &annotation.0);
+            }
+            _ => builder.annotated_axiom(axiom),
+        }
+    }
+
+    builder.triples
+}
+
+/// Add RDF triples to an `rdflib.Graph` using its `add` method.
+///
+/// Blank nodes are created with `rdflib.BNode`, so that they never clash
+/// with the blank nodes already present in the graph.
+pub fn add_triples(graph: &PyAny, triples: Vec<Triple>) -> PyResult<()> {
+    let py = graph.py();
+    let rdflib = py.import(pyo3::intern!(py, "rdflib"))?;
+    let uriref = rdflib.getattr(pyo3::intern!(py, "URIRef"))?;
+    let bnode = rdflib.getattr(pyo3::intern!(py, "BNode"))?;
+    let literal = rdflib.getattr(pyo3::intern!(py, "Literal"))?;
+
+    let mut bnodes: HashMap<usize, &PyAny> = HashMap::new();
+    let mut to_py = |term: Term| -> PyResult<&PyAny> {
+        match term {
+            Term::Iri(iri) => uriref.call1((iri,)),
+            Term::BNode(n) => match bnodes.get(&n) {
+                Some(node) => Ok(*node),
+                None => {
+                    let node = bnode.call0()?;
+                    bnodes.insert(n, node);
+                    Ok(node)
+                }
+            },
+            Term::Literal {
+                value,
+                datatype,
+                lang,
+            } => {
+                let kwargs = PyDict::new(py);
+                kwargs.set_item("lang", lang)?;
+                if let Some(dt) = datatype {
+                    kwargs.set_item("datatype", uriref.call1((dt,))?)?;
+                }
+                literal.call((value,), Some(kwargs))
+            }
+        }
+    };
+
+    let add = graph.getattr(pyo3::intern!(py, "add"))?;
+    for (s, p, o) in triples {
+        add.call1(((to_py(s)?, to_py(p)?, to_py(o)?),))?;
+    }
+    Ok(())
+}
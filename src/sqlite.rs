@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use fastobo::ast as obo;
+use rusqlite::params;
+use rusqlite::Connection;
+
+use crate::index::RelationIndex;
+
+/// The schema of the database, following the layout of `semsql`.
+const SCHEMA: &str = "
+CREATE TABLE statements (
+    stanza TEXT,
+    subject TEXT,
+    predicate TEXT,
+    object TEXT,
+    value TEXT,
+    datatype TEXT,
+    language TEXT
+);
+CREATE TABLE entailed_edge (
+    subject TEXT,
+    predicate TEXT,
+    object TEXT
+);
+CREATE VIEW rdfs_label_statement AS
+    SELECT * FROM statements WHERE predicate = 'rdfs:label';
+CREATE VIEW has_oio_synonym_statement AS
+    SELECT * FROM statements WHERE predicate IN (
+        'oio:hasExactSynonym',
+        'oio:hasBroadSynonym',
+        'oio:hasNarrowSynonym',
+        'oio:hasRelatedSynonym'
+    );
+CREATE VIEW has_dbxref_statement AS
+    SELECT * FROM statements WHERE predicate = 'oio:hasDbXref';
+";
+
+/// A single row of the `statements` table.
+struct Statement {
+    subject: String,
+    predicate: String,
+    object: Option<String>,
+    value: Option<String>,
+    datatype: Option<&'static str>,
+}
+
+impl Statement {
+    fn object(subject: &str, predicate: &str, object: String) -> Self {
+        Self {
+            subject: subject.to_string(),
+            predicate: predicate.to_string(),
+            object: Some(object),
+            value: None,
+            datatype: None,
+        }
+    }
+
+    fn value(subject: &str, predicate: &str, value: String) -> Self {
+        Self {
+            subject: subject.to_string(),
+            predicate: predicate.to_string(),
+            object: None,
+            value: Some(value),
+            datatype: None,
+        }
+    }
+}
+
+/// Get the `oboInOwl` predicate of a synonym.
+fn synonym_predicate(synonym: &obo::Synonym) -> &'static str {
+    match synonym.scope() {
+        obo::SynonymScope::Exact => "oio:hasExactSynonym",
+        obo::SynonymScope::Broad => "oio:hasBroadSynonym",
+        obo::SynonymScope::Narrow => "oio:hasNarrowSynonym",
+        obo::SynonymScope::Related => "oio:hasRelatedSynonym",
+    }
+}
+
+/// A mapping of the identifiers of a document to CURIEs.
+///
+/// Unprefixed identifiers, such as relation or subset names, are mapped to
+/// the prefixed xref of their typedef frame if any, or are otherwise scoped
+/// to the ontology like `obo:ms#part_of`.
+struct Curies {
+    ontology: String,
+    shorthands: HashMap<String, String>,
+}
+
+impl Curies {
+    fn new(doc: &obo::OboDoc) -> Self {
+        let ontology = doc
+            .header()
+            .iter()
+            .find_map(|clause| match clause {
+                obo::HeaderClause::Ontology(o) => Some(o.to_string()),
+                _ => None,
+            })
+            .unwrap_or_default();
+        let mut shorthands = HashMap::new();
+        for frame in doc.entities() {
+            if let obo::EntityFrame::Typedef(t) = frame {
+                if let obo::Ident::Unprefixed(name) = t.id().as_ref().as_ref() {
+                    let xref = t.clauses().iter().find_map(|line| match line.as_ref() {
+                        obo::TypedefClause::Xref(x) => match x.id() {
+                            obo::Ident::Prefixed(p) => Some(p.to_string()),
+                            _ => None,
+                        },
+                        _ => None,
+                    });
+                    if let Some(curie) = xref {
+                        shorthands.insert(name.as_str().to_string(), curie);
+                    }
+                }
+            }
+        }
+        Self { ontology, shorthands }
+    }
+
+    fn curie<I: AsRef<obo::Ident> + ?Sized>(&self, id: &I) -> String {
+        match id.as_ref() {
+            obo::Ident::Unprefixed(name) => match self.shorthands.get(name.as_str()) {
+                Some(curie) => curie.clone(),
+                None if self.ontology.is_empty() => format!("obo:{}", name.as_str()),
+                None => format!("obo:{}#{}", self.ontology, name.as_str()),
+            },
+            other => other.to_string(),
+        }
+    }
+}
+
+macro_rules! frame_statements {
+    ($frame:expr, $clause:ident, $ty:literal, $parent:literal, $curies:ident, $stmts:ident) => {{
+        let id = $curies.curie($frame.id().as_ref());
+        $stmts.push(Statement::object(&id, "rdf:type", $ty.to_string()));
+        for line in $frame.clauses() {
+            let stmt = match line.as_ref() {
+                obo::$clause::Name(n) => Statement::value(&id, "rdfs:label", n.as_str().to_string()),
+                obo::$clause::Def(d) => Statement::value(&id, "IAO:0000115", d.text().as_str().to_string()),
+                obo::$clause::Comment(c) => Statement::value(&id, "rdfs:comment", c.as_str().to_string()),
+                obo::$clause::Namespace(ns) => Statement::value(&id, "oio:hasOBONamespace", ns.to_string()),
+                obo::$clause::Subset(s) => Statement::object(&id, "oio:inSubset", $curies.curie(&**s)),
+                obo::$clause::Xref(x) => Statement::value(&id, "oio:hasDbXref", x.id().to_string()),
+                obo::$clause::Synonym(s) => {
+                    Statement::value(&id, synonym_predicate(s), s.description().as_str().to_string())
+                }
+                obo::$clause::IsObsolete(b) => Statement {
+                    datatype: Some("xsd:boolean"),
+                    ..Statement::value(&id, "owl:deprecated", b.to_string())
+                },
+                obo::$clause::IsA(t) => Statement::object(&id, $parent, $curies.curie(&**t)),
+                obo::$clause::Relationship(r, t) => {
+                    Statement::object(&id, &$curies.curie(&**r), $curies.curie(&**t))
+                }
+                _ => continue,
+            };
+            $stmts.push(stmt);
+        }
+    }};
+}
+
+/// Extract the statements corresponding to the entity frames of `doc`.
+fn statements(doc: &obo::OboDoc) -> Vec<Statement> {
+    let curies = Curies::new(doc);
+    let mut stmts = Vec::new();
+    for frame in doc.entities() {
+        match frame {
+            obo::EntityFrame::Term(t) => {
+                frame_statements!(t, TermClause, "owl:Class", "rdfs:subClassOf", curies, stmts)
+            }
+            obo::EntityFrame::Typedef(t) => frame_statements!(
+                t,
+                TypedefClause,
+                "owl:ObjectProperty",
+                "rdfs:subPropertyOf",
+                curies,
+                stmts
+            ),
+            obo::EntityFrame::Instance(i) => {
+                let id = curies.curie(i.id().as_ref());
+                stmts.push(Statement::object(&id, "rdf:type", "owl:NamedIndividual".to_string()));
+                for line in i.clauses() {
+                    match line.as_ref() {
+                        obo::InstanceClause::Name(n) => {
+                            stmts.push(Statement::value(&id, "rdfs:label", n.as_str().to_string()))
+                        }
+                        obo::InstanceClause::InstanceOf(c) => {
+                            stmts.push(Statement::object(&id, "rdf:type", curies.curie(&**c)))
+                        }
+                        _ => (),
+                    }
+                }
+            }
+        }
+    }
+    stmts
+}
+
+/// Write `doc` to a new SQLite database at `path`.
+///
+/// When `entailed` is `true`, the `entailed_edge` table is filled with the
+/// reflexive transitive closure of the `is_a` clauses of the term frames.
+pub fn dump<P: AsRef<Path>>(doc: &obo::OboDoc, path: P, entailed: bool) -> rusqlite::Result<()> {
+    let mut conn = Connection::open(path)?;
+    conn.execute_batch(SCHEMA)?;
+
+    let tx = conn.transaction()?;
+    {
+        let mut insert = tx.prepare(
+            "INSERT INTO statements (stanza, subject, predicate, object, value, datatype)
+             VALUES (?1, ?1, ?2, ?3, ?4, ?5)",
+        )?;
+        for stmt in statements(doc) {
+            insert.execute(params![
+                stmt.subject,
+                stmt.predicate,
+                stmt.object,
+                stmt.value,
+                stmt.datatype,
+            ])?;
+        }
+
+        if entailed {
+            let mut insert = tx.prepare(
+                "INSERT INTO entailed_edge (subject, predicate, object) VALUES (?1, 'rdfs:subClassOf', ?2)",
+            )?;
+            let index = RelationIndex::new(doc.clone(), &["is_a"]);
+            for frame in doc.entities() {
+                if let obo::EntityFrame::Term(term) = frame {
+                    let id: obo::Ident = term.id().as_ref().clone().into();
+                    insert.execute(params![id.to_string(), id.to_string()])?;
+                    for ancestor in index.ancestors(&id) {
+                        insert.execute(params![id.to_string(), ancestor.to_string()])?;
+                    }
+                }
+            }
+        }
+    }
+    tx.commit()
+}
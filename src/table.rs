@@ -0,0 +1,284 @@
+use std::io::Result as IoResult;
+use std::io::Write;
+use std::sync::Arc;
+
+use arrow::array::ArrayRef;
+use arrow::array::BooleanBuilder;
+use arrow::array::ListBuilder;
+use arrow::array::StringBuilder;
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use fastobo::ast as obo;
+
+/// The columns of the entity table.
+pub const ENTITY_COLUMNS: &[&str] = &[
+    "id",
+    "type",
+    "name",
+    "namespace",
+    "definition",
+    "is_obsolete",
+    "parents",
+];
+
+/// The columns that can be written with `write_delimited`.
+pub const DELIMITED_COLUMNS: &[&str] = &[
+    "id",
+    "type",
+    "name",
+    "def",
+    "namespace",
+    "parents",
+    "obsolete",
+];
+
+/// The columns of the synonym table.
+pub const SYNONYM_COLUMNS: &[&str] = &["id", "synonym", "scope", "type", "xrefs"];
+
+/// The columns of the xref table.
+pub const XREF_COLUMNS: &[&str] = &["id", "xref", "description"];
+
+/// The metadata of an entity frame, as a row of the entity table.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EntityRow {
+    pub id: String,
+    pub kind: &'static str,
+    pub name: Option<String>,
+    pub namespace: Option<String>,
+    pub definition: Option<String>,
+    pub is_obsolete: bool,
+    pub parents: Vec<String>,
+}
+
+impl EntityRow {
+    /// Convert the row to a `dict` with the entity table columns as keys.
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let dict = PyDict::new(py);
+        dict.set_item("id", &self.id)?;
+        dict.set_item("type", self.kind)?;
+        dict.set_item("name", &self.name)?;
+        dict.set_item("namespace", &self.namespace)?;
+        dict.set_item("definition", &self.definition)?;
+        dict.set_item("is_obsolete", self.is_obsolete)?;
+        dict.set_item("parents", &self.parents)?;
+        Ok(dict)
+    }
+
+    /// Get the value of a column of a delimited file, or `None` if unknown.
+    ///
+    /// Missing values are written as empty strings, and the parents of
+    /// the entity are separated with a `|` character.
+    pub fn field(&self, column: &str) -> Option<String> {
+        let value = match column {
+            "id" => self.id.clone(),
+            "type" => self.kind.to_string(),
+            "name" => self.name.clone().unwrap_or_default(),
+            "def" => self.definition.clone().unwrap_or_default(),
+            "namespace" => self.namespace.clone().unwrap_or_default(),
+            "parents" => self.parents.join("|"),
+            "obsolete" => self.is_obsolete.to_string(),
+            _ => return None,
+        };
+        Some(value)
+    }
+}
+
+macro_rules! entity_row {
+    ($frame:expr, $clause:ident, $kind:literal, $parent:ident) => {{
+        let mut row = EntityRow {
+            id: $frame.id().as_ref().to_string(),
+            kind: $kind,
+            ..Default::default()
+        };
+        for line in $frame.clauses() {
+            match line.as_ref() {
+                obo::$clause::Name(n) => row.name = Some(n.as_str().to_string()),
+                obo::$clause::Namespace(ns) => row.namespace = Some(ns.to_string()),
+                obo::$clause::Def(def) => row.definition = Some(def.text().as_str().to_string()),
+                obo::$clause::IsObsolete(b) => row.is_obsolete = *b,
+                obo::$clause::$parent(id) => row.parents.push(id.to_string()),
+                _ => (),
+            }
+        }
+        row
+    }};
+}
+
+impl From<&obo::EntityFrame> for EntityRow {
+    fn from(frame: &obo::EntityFrame) -> Self {
+        match frame {
+            obo::EntityFrame::Term(t) => entity_row!(t, TermClause, "Term", IsA),
+            obo::EntityFrame::Typedef(t) => entity_row!(t, TypedefClause, "Typedef", IsA),
+            obo::EntityFrame::Instance(i) => entity_row!(i, InstanceClause, "Instance", InstanceOf),
+        }
+    }
+}
+
+/// A synonym of an entity frame, as a row of the synonym table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SynonymRow {
+    pub id: String,
+    pub synonym: String,
+    pub scope: String,
+    pub ty: Option<String>,
+    pub xrefs: Vec<String>,
+}
+
+impl SynonymRow {
+    /// Convert the row to a `dict` with the synonym table columns as keys.
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let dict = PyDict::new(py);
+        dict.set_item("id", &self.id)?;
+        dict.set_item("synonym", &self.synonym)?;
+        dict.set_item("scope", &self.scope)?;
+        dict.set_item("type", &self.ty)?;
+        dict.set_item("xrefs", &self.xrefs)?;
+        Ok(dict)
+    }
+}
+
+/// A cross-reference of an entity frame, as a row of the xref table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct XrefRow {
+    pub id: String,
+    pub xref: String,
+    pub description: Option<String>,
+}
+
+impl XrefRow {
+    /// Convert the row to a `dict` with the xref table columns as keys.
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let dict = PyDict::new(py);
+        dict.set_item("id", &self.id)?;
+        dict.set_item("xref", &self.xref)?;
+        dict.set_item("description", &self.description)?;
+        Ok(dict)
+    }
+}
+
+macro_rules! synonyms_and_xrefs {
+    ($frame:expr, $clause:ident, $synonyms:ident, $xrefs:ident) => {{
+        let id = $frame.id().as_ref().to_string();
+        for line in $frame.clauses() {
+            match line.as_ref() {
+                obo::$clause::Synonym(s) => $synonyms.push(SynonymRow {
+                    id: id.clone(),
+                    synonym: s.description().as_str().to_string(),
+                    scope: s.scope().to_string(),
+                    ty: s.ty().map(|ty| ty.to_string()),
+                    xrefs: s.xrefs().iter().map(|x| x.id().to_string()).collect(),
+                }),
+                obo::$clause::Xref(x) => $xrefs.push(XrefRow {
+                    id: id.clone(),
+                    xref: x.id().to_string(),
+                    description: x.description().map(|d| d.as_str().to_string()),
+                }),
+                _ => (),
+            }
+        }
+    }};
+}
+
+/// Iterate over the rows of the entity table of a document.
+pub fn entity_rows(doc: &obo::OboDoc) -> impl Iterator<Item = EntityRow> + '_ {
+    doc.entities().iter().map(EntityRow::from)
+}
+
+/// Iterate over the rows of the term table of a document.
+pub fn term_rows(doc: &obo::OboDoc) -> impl Iterator<Item = EntityRow> + '_ {
+    entity_rows(doc).filter(|row| row.kind == "Term")
+}
+
+/// Collect the rows of the synonym and xref tables of a document.
+pub fn synonym_and_xref_rows(doc: &obo::OboDoc) -> (Vec<SynonymRow>, Vec<XrefRow>) {
+    let mut synonyms = Vec::new();
+    let mut xrefs = Vec::new();
+    for frame in doc.entities() {
+        match frame {
+            obo::EntityFrame::Term(t) => synonyms_and_xrefs!(t, TermClause, synonyms, xrefs),
+            obo::EntityFrame::Typedef(t) => synonyms_and_xrefs!(t, TypedefClause, synonyms, xrefs),
+            obo::EntityFrame::Instance(i) => synonyms_and_xrefs!(i, InstanceClause, synonyms, xrefs),
+        }
+    }
+    (synonyms, xrefs)
+}
+
+/// Build an Arrow record batch from the rows of an entity table.
+///
+/// The `type` column is omitted, since Arrow export is limited to terms.
+pub fn to_record_batch<I>(rows: I) -> Result<RecordBatch, ArrowError>
+where
+    I: IntoIterator<Item = EntityRow>,
+{
+    let mut id = StringBuilder::new();
+    let mut name = StringBuilder::new();
+    let mut namespace = StringBuilder::new();
+    let mut definition = StringBuilder::new();
+    let mut is_obsolete = BooleanBuilder::new();
+    let mut parents = ListBuilder::new(StringBuilder::new());
+
+    for row in rows {
+        id.append_value(row.id);
+        name.append_option(row.name);
+        namespace.append_option(row.namespace);
+        definition.append_option(row.definition);
+        is_obsolete.append_value(row.is_obsolete);
+        for parent in row.parents {
+            parents.values().append_value(parent);
+        }
+        parents.append(true);
+    }
+
+    RecordBatch::try_from_iter(vec![
+        ("id", Arc::new(id.finish()) as ArrayRef),
+        ("name", Arc::new(name.finish()) as ArrayRef),
+        ("namespace", Arc::new(namespace.finish()) as ArrayRef),
+        ("definition", Arc::new(definition.finish()) as ArrayRef),
+        ("is_obsolete", Arc::new(is_obsolete.finish()) as ArrayRef),
+        ("parents", Arc::new(parents.finish()) as ArrayRef),
+    ])
+}
+
+/// Quote a field of a delimited file if it contains special characters.
+fn quote(field: &str, sep: char) -> std::borrow::Cow<'_, str> {
+    if field.contains([sep, '"', '\n', '\r']) {
+        std::borrow::Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        std::borrow::Cow::Borrowed(field)
+    }
+}
+
+/// Write the rows of an entity table to a delimited file, one at a time.
+///
+/// The first line contains the names of the `columns`, which must all be
+/// in `DELIMITED_COLUMNS`. Fields are quoted like in CSV files when needed.
+pub fn write_delimited<W, I, S>(mut writer: W, rows: I, columns: &[S], sep: char) -> IoResult<()>
+where
+    W: Write,
+    I: IntoIterator<Item = EntityRow>,
+    S: AsRef<str>,
+{
+    let mut line = String::new();
+    for (i, column) in columns.iter().enumerate() {
+        if i > 0 {
+            line.push(sep);
+        }
+        line.push_str(&quote(column.as_ref(), sep));
+    }
+    writeln!(writer, "{}", line)?;
+    for row in rows {
+        line.clear();
+        for (i, column) in columns.iter().enumerate() {
+            if i > 0 {
+                line.push(sep);
+            }
+            let field = row.field(column.as_ref()).unwrap_or_default();
+            line.push_str(&quote(&field, sep));
+        }
+        writeln!(writer, "{}", line)?;
+    }
+    writer.flush()
+}
@@ -0,0 +1,428 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use fastobo::ast as obo;
+use fastobo::semantics::OboClause;
+use fastobo::visit::VisitMut;
+
+/// A reference to an obsolete entity that could not be replaced.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnresolvedReference {
+    /// The identifier of the frame containing the reference.
+    pub frame: obo::Ident,
+    /// The identifier of the obsolete entity being referenced.
+    pub obsolete: obo::Ident,
+    /// The replacements suggested with `consider` or ambiguous `replaced_by`.
+    pub consider: Vec<obo::Ident>,
+}
+
+/// The replacement suggestions declared by the obsolete entities of a document.
+#[derive(Debug, Default)]
+struct Replacements {
+    obsolete: HashSet<obo::Ident>,
+    replaced_by: HashMap<obo::Ident, Vec<obo::Ident>>,
+    consider: HashMap<obo::Ident, Vec<obo::Ident>>,
+}
+
+impl Replacements {
+    fn new(doc: &obo::OboDoc) -> Self {
+        let mut r = Self::default();
+        for frame in doc.entities() {
+            match frame {
+                obo::EntityFrame::Term(term) => {
+                    let id: obo::Ident = term.id().as_ref().clone().into();
+                    for line in term.clauses() {
+                        match line.as_ref() {
+                            obo::TermClause::IsObsolete(true) => {
+                                r.obsolete.insert(id.clone());
+                            }
+                            obo::TermClause::ReplacedBy(target) => {
+                                let target = target.as_ref().clone().into();
+                                r.replaced_by.entry(id.clone()).or_default().push(target);
+                            }
+                            obo::TermClause::Consider(target) => {
+                                let target = target.as_ref().clone().into();
+                                r.consider.entry(id.clone()).or_default().push(target);
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+                obo::EntityFrame::Typedef(typedef) => {
+                    let id: obo::Ident = typedef.id().as_ref().clone().into();
+                    for line in typedef.clauses() {
+                        match line.as_ref() {
+                            obo::TypedefClause::IsObsolete(true) => {
+                                r.obsolete.insert(id.clone());
+                            }
+                            obo::TypedefClause::ReplacedBy(target) => {
+                                let target = target.as_ref().clone().into();
+                                r.replaced_by.entry(id.clone()).or_default().push(target);
+                            }
+                            obo::TypedefClause::Consider(target) => {
+                                let target = target.as_ref().clone();
+                                r.consider.entry(id.clone()).or_default().push(target);
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+                obo::EntityFrame::Instance(_) => (),
+            }
+        }
+        r
+    }
+
+    /// Get the final replacement of `id`, following chains of replacements.
+    ///
+    /// Returns `None` if `id` is not obsolete, or if it (or one of its
+    /// replacements) is obsolete without a single `replaced_by` target.
+    fn resolve(&self, id: &obo::Ident) -> Option<obo::Ident> {
+        let mut seen = HashSet::new();
+        let mut current = id;
+        while self.obsolete.contains(current) {
+            if !seen.insert(current) {
+                return None;
+            }
+            match self.replaced_by.get(current).map(Vec::as_slice) {
+                Some([target]) => current = target,
+                _ => return None,
+            }
+        }
+        if current == id {
+            None
+        } else {
+            Some(current.clone())
+        }
+    }
+}
+
+/// A helper to rewrite the references found in the clauses of a single frame.
+struct Replacer<'a> {
+    replacements: &'a Replacements,
+    frame: obo::Ident,
+    unresolved: &'a mut Vec<UnresolvedReference>,
+}
+
+impl Replacer<'_> {
+    fn replace<T>(&mut self, target: &mut T)
+    where
+        T: Clone + Into<obo::Ident> + From<obo::Ident>,
+    {
+        let id: obo::Ident = target.clone().into();
+        if let Some(new) = self.replacements.resolve(&id) {
+            *target = T::from(new);
+        } else if self.replacements.obsolete.contains(&id) {
+            let mut consider = Vec::new();
+            consider.extend(self.replacements.replaced_by.get(&id).into_iter().flatten().cloned());
+            consider.extend(self.replacements.consider.get(&id).into_iter().flatten().cloned());
+            self.unresolved.push(UnresolvedReference {
+                frame: self.frame.clone(),
+                obsolete: id,
+                consider,
+            });
+        }
+    }
+
+    fn replace_term_clause(&mut self, clause: &mut obo::TermClause) {
+        match clause {
+            obo::TermClause::IsA(cls)
+            | obo::TermClause::UnionOf(cls)
+            | obo::TermClause::EquivalentTo(cls)
+            | obo::TermClause::DisjointFrom(cls)
+            | obo::TermClause::IntersectionOf(None, cls) => self.replace(cls.as_mut()),
+            obo::TermClause::IntersectionOf(Some(rel), cls)
+            | obo::TermClause::Relationship(rel, cls) => {
+                self.replace(rel.as_mut());
+                self.replace(cls.as_mut());
+            }
+            _ => (),
+        }
+    }
+
+    fn replace_typedef_clause(&mut self, clause: &mut obo::TypedefClause) {
+        match clause {
+            obo::TypedefClause::Domain(cls) | obo::TypedefClause::Range(cls) => {
+                self.replace(cls.as_mut())
+            }
+            obo::TypedefClause::IsA(rel)
+            | obo::TypedefClause::IntersectionOf(rel)
+            | obo::TypedefClause::UnionOf(rel)
+            | obo::TypedefClause::EquivalentTo(rel)
+            | obo::TypedefClause::DisjointFrom(rel)
+            | obo::TypedefClause::InverseOf(rel)
+            | obo::TypedefClause::TransitiveOver(rel)
+            | obo::TypedefClause::DisjointOver(rel) => self.replace(rel.as_mut()),
+            obo::TypedefClause::HoldsOverChain(r1, r2)
+            | obo::TypedefClause::EquivalentToChain(r1, r2)
+            | obo::TypedefClause::Relationship(r1, r2) => {
+                self.replace(r1.as_mut());
+                self.replace(r2.as_mut());
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Replace references to obsolete entities with their `replaced_by` target.
+///
+/// Only the logical clauses of non-obsolete frames are rewritten. References
+/// to an obsolete entity without exactly one `replaced_by` target are left
+/// untouched and returned, together with the suggested replacements.
+pub fn apply_replacements(doc: &mut obo::OboDoc) -> Vec<UnresolvedReference> {
+    let replacements = Replacements::new(doc);
+    let mut unresolved = Vec::new();
+    for frame in doc.entities_mut().iter_mut() {
+        match frame {
+            obo::EntityFrame::Term(term) => {
+                let id: obo::Ident = term.id().as_ref().clone().into();
+                if replacements.obsolete.contains(&id) {
+                    continue;
+                }
+                let mut replacer = Replacer {
+                    replacements: &replacements,
+                    frame: id,
+                    unresolved: &mut unresolved,
+                };
+                for line in term.clauses_mut().iter_mut() {
+                    replacer.replace_term_clause(line.as_mut());
+                }
+            }
+            obo::EntityFrame::Typedef(typedef) => {
+                let id: obo::Ident = typedef.id().as_ref().clone().into();
+                if replacements.obsolete.contains(&id) {
+                    continue;
+                }
+                let mut replacer = Replacer {
+                    replacements: &replacements,
+                    frame: id,
+                    unresolved: &mut unresolved,
+                };
+                for line in typedef.clauses_mut().iter_mut() {
+                    replacer.replace_typedef_clause(line.as_mut());
+                }
+            }
+            obo::EntityFrame::Instance(_) => (),
+        }
+    }
+    unresolved
+}
+
+/// A visitor replacing every occurrence of an identifier with another one.
+struct IdRenamer<'a> {
+    old: &'a obo::Ident,
+    new: &'a obo::Ident,
+}
+
+impl VisitMut for IdRenamer<'_> {
+    fn visit_ident(&mut self, id: &mut obo::Ident) {
+        if id == self.old {
+            *id = self.new.clone();
+        }
+    }
+}
+
+/// A visitor replacing identifiers using a mapping table.
+struct IdMapper<'a> {
+    mapping: &'a HashMap<obo::Ident, obo::Ident>,
+}
+
+impl VisitMut for IdMapper<'_> {
+    fn visit_ident(&mut self, id: &mut obo::Ident) {
+        if let Some(new) = self.mapping.get(id) {
+            *id = new.clone();
+        }
+    }
+}
+
+/// Replace every identifier of `doc` which is a key of `mapping`.
+///
+/// Unlike `rename`, no `alt_id` clause is added to the renamed frames.
+pub fn map_idents(doc: &mut obo::OboDoc, mapping: &HashMap<obo::Ident, obo::Ident>) {
+    IdMapper { mapping }.visit_doc(doc);
+}
+
+/// Get the index where an `alt_id` clause should be inserted in a frame.
+///
+/// This is right after the `is_anonymous`, `name`, `namespace` and `alt_id`
+/// clauses, which is where they are expected in a serialized frame.
+fn alt_id_position<C, F>(clauses: &[obo::Line<C>], leading: F) -> usize
+where
+    F: Fn(&C) -> bool,
+{
+    clauses
+        .iter()
+        .position(|line| !leading(line.as_ref()))
+        .unwrap_or(clauses.len())
+}
+
+/// Rename every occurrence of the `old` identifier in `doc` to `new`.
+///
+/// If `keep_alt_id` is `true`, an `alt_id` clause with the old identifier
+/// is added to the renamed frame. Returns `true` if a frame was renamed.
+pub fn rename(
+    doc: &mut obo::OboDoc,
+    old: &obo::Ident,
+    new: &obo::Ident,
+    keep_alt_id: bool,
+) -> bool {
+    IdRenamer { old, new }.visit_doc(doc);
+
+    let alt_id = Box::new(old.clone());
+    for frame in doc.entities_mut().iter_mut() {
+        match frame {
+            obo::EntityFrame::Term(term)
+                if obo::Ident::from(term.id().as_ref().clone()) == *new =>
+            {
+                if keep_alt_id {
+                    let clauses = term.clauses_mut();
+                    let index = alt_id_position(clauses, |clause| {
+                        matches!(
+                            clause,
+                            obo::TermClause::IsAnonymous(_)
+                                | obo::TermClause::Name(_)
+                                | obo::TermClause::Namespace(_)
+                                | obo::TermClause::AltId(_)
+                        )
+                    });
+                    let clause = obo::TermClause::AltId(alt_id);
+                    clauses.insert(index, obo::Line::new().and_inner(clause));
+                }
+                return true;
+            }
+            obo::EntityFrame::Typedef(typedef)
+                if obo::Ident::from(typedef.id().as_ref().clone()) == *new =>
+            {
+                if keep_alt_id {
+                    let clauses = typedef.clauses_mut();
+                    let index = alt_id_position(clauses, |clause| {
+                        matches!(
+                            clause,
+                            obo::TypedefClause::IsAnonymous(_)
+                                | obo::TypedefClause::Name(_)
+                                | obo::TypedefClause::Namespace(_)
+                                | obo::TypedefClause::AltId(_)
+                        )
+                    });
+                    let clause = obo::TypedefClause::AltId(alt_id);
+                    clauses.insert(index, obo::Line::new().and_inner(clause));
+                }
+                return true;
+            }
+            obo::EntityFrame::Instance(instance)
+                if obo::Ident::from(instance.id().as_ref().clone()) == *new =>
+            {
+                if keep_alt_id {
+                    let clauses = instance.clauses_mut();
+                    let index = alt_id_position(clauses, |clause| {
+                        matches!(
+                            clause,
+                            obo::InstanceClause::IsAnonymous(_)
+                                | obo::InstanceClause::Name(_)
+                                | obo::InstanceClause::Namespace(_)
+                                | obo::InstanceClause::AltId(_)
+                        )
+                    });
+                    let clause = obo::InstanceClause::AltId(alt_id);
+                    clauses.insert(index, obo::Line::new().and_inner(clause));
+                }
+                return true;
+            }
+            _ => (),
+        }
+    }
+    false
+}
+
+/// A visitor rewriting the prefixes of identifiers and ID spaces.
+struct PrefixRemapper<'a> {
+    prefixes: &'a HashMap<String, String>,
+}
+
+impl VisitMut for PrefixRemapper<'_> {
+    fn visit_prefixed_ident(&mut self, id: &mut obo::PrefixedIdent) {
+        if let Some(prefix) = self.prefixes.get(id.prefix()) {
+            let new = obo::PrefixedIdent::new(prefix.as_str(), id.local());
+            *id = new;
+        }
+    }
+
+    fn visit_ident_prefix(&mut self, prefix: &mut obo::IdentPrefix) {
+        if let Some(new) = self.prefixes.get(prefix.as_str()) {
+            *prefix = obo::IdentPrefix::new(new.as_str());
+        }
+    }
+}
+
+/// Rewrite the prefixes of `doc` using the given prefix mapping.
+///
+/// Both the prefixed identifiers in any clause of the document and the
+/// prefixes of the `idspace` header clauses are rewritten.
+pub fn remap_prefixes(doc: &mut obo::OboDoc, prefixes: &HashMap<String, String>) {
+    PrefixRemapper { prefixes }.visit_doc(doc);
+}
+
+/// Retain the entity frames of `doc` matching a prefix or a namespace.
+///
+/// A frame is kept if the prefix of its identifier is one of `prefixes`, or
+/// if one of its `namespace` clauses is one of `namespaces`. Any criterion
+/// given as `None` is ignored, so that no frame is removed if both are.
+pub fn filter(
+    doc: &mut obo::OboDoc,
+    prefixes: Option<&HashSet<String>>,
+    namespaces: Option<&HashSet<String>>,
+) {
+    if prefixes.is_none() && namespaces.is_none() {
+        return;
+    }
+
+    let has_prefix = |id: obo::Ident| match (&id, prefixes) {
+        (obo::Ident::Prefixed(id), Some(prefixes)) => prefixes.contains(id.prefix()),
+        _ => false,
+    };
+    let in_namespace = |ns: &obo::NamespaceIdent| match namespaces {
+        Some(namespaces) => namespaces.contains(&ns.to_string()),
+        None => false,
+    };
+
+    doc.entities_mut().retain(|frame| match frame {
+        obo::EntityFrame::Term(term) => {
+            has_prefix(term.id().as_ref().clone().into())
+                || term.clauses().iter().any(|line| match line.as_ref() {
+                    obo::TermClause::Namespace(ns) => in_namespace(ns),
+                    _ => false,
+                })
+        }
+        obo::EntityFrame::Typedef(typedef) => {
+            has_prefix(typedef.id().as_ref().clone().into())
+                || typedef.clauses().iter().any(|line| match line.as_ref() {
+                    obo::TypedefClause::Namespace(ns) => in_namespace(ns),
+                    _ => false,
+                })
+        }
+        obo::EntityFrame::Instance(instance) => {
+            has_prefix(instance.id().as_ref().clone().into())
+                || instance.clauses().iter().any(|line| match line.as_ref() {
+                    obo::InstanceClause::Namespace(ns) => in_namespace(ns),
+                    _ => false,
+                })
+        }
+    });
+}
+
+/// Remove the clauses of an entity frame with a tag outside of `tags`.
+///
+/// The identifier of the frame is not a clause, and is always kept.
+pub fn retain_tags(frame: &mut obo::EntityFrame, tags: &HashSet<String>) {
+    match frame {
+        obo::EntityFrame::Term(term) => {
+            term.clauses_mut().retain(|line| tags.contains(line.as_ref().tag()))
+        }
+        obo::EntityFrame::Typedef(typedef) => {
+            typedef.clauses_mut().retain(|line| tags.contains(line.as_ref().tag()))
+        }
+        obo::EntityFrame::Instance(instance) => {
+            instance.clauses_mut().retain(|line| tags.contains(line.as_ref().tag()))
+        }
+    }
+}
@@ -1,16 +1,43 @@
+use std::cell::OnceCell;
+use std::cell::Ref;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::fmt::Write;
+use std::hash::Hash;
+use std::hash::Hasher as StdHasher;
+use std::iter::FromIterator;
 use std::ops::Deref;
+use std::ops::DerefMut;
+use std::os::raw::c_long;
+use std::sync::Arc;
 
+use pyo3::class::basic::CompareOp;
+use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::PyValueError;
 use pyo3::ffi::PyObject;
+use pyo3::pyclass::boolean_struct::False;
+use pyo3::types::PyAny;
+use pyo3::types::PySlice;
+use pyo3::types::PyString;
 use pyo3::AsPyPointer;
+use pyo3::IntoPy;
 use pyo3::Py;
 use pyo3::PyClass;
+use pyo3::PyCell;
 use pyo3::PyClassInitializer;
 use pyo3::PyRef;
+use pyo3::PyResult;
 use pyo3::PyTypeInfo;
 use pyo3::Python;
+use pyo3::ToPyObject;
 
 use fastobo::ast;
 
+use crate::py::id::InternContext;
+
 // ---
 
 /// A trait for objects that can be cloned while the GIL is held.
@@ -18,6 +45,16 @@ pub trait ClonePy {
     fn clone_py(&self, py: Python) -> Self;
 }
 
+macro_rules! derive_clonepy {
+    ($type:ty) => {
+        impl ClonePy for $type {
+            fn clone_py(&self, _py: Python) -> Self {
+                self.clone()
+            }
+        }
+    };
+}
+
 impl<T> ClonePy for Py<T> {
     fn clone_py(&self, py: Python) -> Self {
         self.clone_ref(py)
@@ -42,6 +79,18 @@ where
     }
 }
 
+derive_clonepy!(bool);
+derive_clonepy!(fastobo::ast::CreationDate);
+derive_clonepy!(fastobo::ast::IdentPrefix);
+derive_clonepy!(fastobo::ast::Import);
+derive_clonepy!(fastobo::ast::NaiveDateTime);
+derive_clonepy!(fastobo::ast::PrefixedIdent);
+derive_clonepy!(fastobo::ast::QuotedString);
+derive_clonepy!(fastobo::ast::SynonymScope);
+derive_clonepy!(fastobo::ast::UnprefixedIdent);
+derive_clonepy!(fastobo::ast::UnquotedString);
+derive_clonepy!(fastobo::ast::Url);
+
 // ---
 
 macro_rules! derive_eqpy {
@@ -116,6 +165,590 @@ derive_eqpy!(fastobo::ast::UnprefixedIdent);
 derive_eqpy!(fastobo::ast::UnquotedString);
 derive_eqpy!(fastobo::ast::Url);
 
+/// A trait for Python classes with rich comparison derived from `EqPy`.
+///
+/// Deriving this trait with `#[derive(RichCmpPy)]` makes `__richcmp__`
+/// compare the same fields as `eq_py`, so that both never get out of sync.
+pub trait RichCmpPy: EqPy + PyClass {
+    fn richcmp_py(&self, other: &PyAny, op: CompareOp) -> PyResult<pyo3::PyObject>;
+}
+
+// ---
+
+macro_rules! derive_hashpy {
+    ($type:ty) => {
+        impl HashPy for $type {
+            fn hash_py<H: StdHasher>(&self, state: &mut H, _py: Python) {
+                self.hash(state)
+            }
+        }
+    };
+}
+
+/// A trait for objects that can be hashed while the GIL is held.
+///
+/// Implementations must only hash the data compared by `EqPy`, so that
+/// objects that are equal with `eq_py` also have the same hash.
+pub trait HashPy {
+    fn hash_py<H: StdHasher>(&self, state: &mut H, py: Python);
+
+    /// Compute the hash of the object, as returned by `__hash__`.
+    fn hash_value_py(&self, py: Python) -> u64 {
+        let mut hasher = Hasher::default();
+        self.hash_py(&mut hasher, py);
+        hasher.finish()
+    }
+}
+
+impl<T> HashPy for Option<T>
+where
+    T: HashPy,
+{
+    fn hash_py<H: StdHasher>(&self, state: &mut H, py: Python) {
+        match self {
+            Some(x) => {
+                true.hash(state);
+                x.hash_py(state, py);
+            }
+            None => false.hash(state),
+        }
+    }
+}
+
+impl<T> HashPy for Vec<T>
+where
+    T: HashPy,
+{
+    fn hash_py<H: StdHasher>(&self, state: &mut H, py: Python) {
+        self.len().hash(state);
+        for x in self.iter() {
+            x.hash_py(state, py);
+        }
+    }
+}
+
+impl<T> HashPy for Py<T>
+where
+    T: HashPy + PyClass,
+{
+    fn hash_py<H: StdHasher>(&self, state: &mut H, py: Python) {
+        self.borrow(py).hash_py(state, py)
+    }
+}
+
+derive_hashpy!(bool);
+derive_hashpy!(fastobo::ast::CreationDate);
+derive_hashpy!(fastobo::ast::IdentPrefix);
+derive_hashpy!(fastobo::ast::Import);
+derive_hashpy!(fastobo::ast::NaiveDateTime);
+derive_hashpy!(fastobo::ast::PrefixedIdent);
+derive_hashpy!(fastobo::ast::QuotedString);
+derive_hashpy!(fastobo::ast::SynonymScope);
+derive_hashpy!(fastobo::ast::UnprefixedIdent);
+derive_hashpy!(fastobo::ast::UnquotedString);
+derive_hashpy!(fastobo::ast::Url);
+
+// ---
+
+macro_rules! derive_displaypy {
+    ($type:ty) => {
+        impl DisplayPy for $type {
+            fn fmt_py(&self, _py: Python, f: &mut Formatter) -> FmtResult {
+                self.fmt(f)
+            }
+        }
+    };
+}
+
+/// A trait for objects that can be written in OBO syntax while the GIL is held.
+///
+/// Contrary to converting an object to its `fastobo` syntax tree counterpart
+/// before formatting it, the Python objects it references are borrowed, so
+/// that no copy of the object is needed.
+pub trait DisplayPy {
+    fn fmt_py(&self, py: Python, f: &mut Formatter) -> FmtResult;
+
+    /// Get a wrapper implementing `Display` with the given GIL token.
+    fn display_py<'py>(&'py self, py: Python<'py>) -> PyDisplay<'py, Self> {
+        PyDisplay { inner: self, py }
+    }
+}
+
+/// A wrapper implementing `Display` for a `DisplayPy` type.
+pub struct PyDisplay<'py, T: ?Sized> {
+    inner: &'py T,
+    py: Python<'py>,
+}
+
+impl<'py, T: DisplayPy + ?Sized> Display for PyDisplay<'py, T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        self.inner.fmt_py(self.py, f)
+    }
+}
+
+impl<T> DisplayPy for Py<T>
+where
+    T: DisplayPy + PyClass,
+{
+    fn fmt_py(&self, py: Python, f: &mut Formatter) -> FmtResult {
+        self.borrow(py).fmt_py(py, f)
+    }
+}
+
+derive_displaypy!(bool);
+derive_displaypy!(fastobo::ast::CreationDate);
+derive_displaypy!(fastobo::ast::IdentPrefix);
+derive_displaypy!(fastobo::ast::Import);
+derive_displaypy!(fastobo::ast::NaiveDateTime);
+derive_displaypy!(fastobo::ast::QuotedString);
+derive_displaypy!(fastobo::ast::UnquotedString);
+
+// ---
+
+/// A trait for values that may not have been allocated on the Python heap yet.
+pub trait TryAsObject {
+    /// Get the Python object of the value, without creating it if needed.
+    fn try_as_object(&self, py: Python) -> Option<pyo3::PyObject>;
+}
+
+impl<T> TryAsObject for Py<T> {
+    fn try_as_object(&self, py: Python) -> Option<pyo3::PyObject> {
+        Some(self.clone_ref(py).into_py(py))
+    }
+}
+
+/// A trait for values that can be overwritten without changing their identity.
+pub trait AssignPy {
+    /// Overwrite the value of `self` with a copy of the value of `other`.
+    ///
+    /// Returns `false`, leaving `self` unchanged, if `other` is a different
+    /// kind of value, or if the object of `self` is currently borrowed.
+    fn assign_py(&self, other: &Self, py: Python) -> bool;
+}
+
+impl<T> AssignPy for Py<T>
+where
+    T: PyClass<Frozen = False> + ClonePy,
+{
+    fn assign_py(&self, other: &Self, py: Python) -> bool {
+        if self.is(other) {
+            return true;
+        }
+        let value = other.borrow(py).clone_py(py);
+        match self.try_borrow_mut(py) {
+            Ok(mut this) => {
+                *this = value;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+/// A trait for values that can be made read-only while the GIL is held.
+pub trait FreezePy {
+    /// Freeze the value, and the values it owns.
+    fn freeze_py(&self, py: Python) -> PyResult<()>;
+}
+
+impl<T> FreezePy for Vec<T>
+where
+    T: FreezePy,
+{
+    fn freeze_py(&self, py: Python) -> PyResult<()> {
+        self.iter().try_for_each(|x| x.freeze_py(py))
+    }
+}
+
+impl<T> FreezePy for Option<T>
+where
+    T: FreezePy,
+{
+    fn freeze_py(&self, py: Python) -> PyResult<()> {
+        self.iter().try_for_each(|x| x.freeze_py(py))
+    }
+}
+
+/// A Python class instance stored by value until it is needed as an object.
+///
+/// Allocating a Python object for every simple clause dominates the time
+/// spent loading a document, although most of them are never accessed from
+/// Python. A `Lazy` keeps the value inline, and allocates the object the
+/// first time it is requested, reusing it afterwards so that accessing the
+/// same clause twice returns the same object.
+#[derive(Debug)]
+pub struct Lazy<T: PyClass> {
+    cell: RefCell<LazyState<T>>,
+}
+
+#[derive(Debug)]
+enum LazyState<T: PyClass> {
+    Inline(T),
+    Object(Py<T>),
+}
+
+impl<T> Lazy<T>
+where
+    T: PyClass + ClonePy + Into<PyClassInitializer<T>>,
+{
+    /// Store a value inline, without allocating a Python object.
+    pub fn new(value: T) -> Self {
+        Self {
+            cell: RefCell::new(LazyState::Inline(value)),
+        }
+    }
+
+    /// Get the Python object of the value, allocating it if needed.
+    pub fn object(&self, py: Python) -> PyResult<Py<T>> {
+        let mut state = self.cell.borrow_mut();
+        let object = match &*state {
+            LazyState::Object(object) => return Ok(object.clone_ref(py)),
+            LazyState::Inline(value) => Py::new(py, value.clone_py(py))?,
+        };
+        *state = LazyState::Object(object.clone_ref(py));
+        Ok(object)
+    }
+
+    /// Borrow the value, without allocating a Python object.
+    pub fn borrow<'py>(&'py self, py: Python<'py>) -> LazyRef<'py, T> {
+        let state = self.cell.borrow();
+        if let LazyState::Object(object) = &*state {
+            let cell: &'py PyCell<T> = object.clone_ref(py).into_ref(py);
+            return LazyRef::Object(cell.borrow());
+        }
+        LazyRef::Inline(Ref::map(state, |state| match state {
+            LazyState::Inline(value) => value,
+            LazyState::Object(_) => unreachable!(),
+        }))
+    }
+}
+
+impl<T: PyClass> From<Py<T>> for Lazy<T> {
+    fn from(object: Py<T>) -> Self {
+        Self {
+            cell: RefCell::new(LazyState::Object(object)),
+        }
+    }
+}
+
+/// A reference to the value of a `Lazy`, wherever it is stored.
+pub enum LazyRef<'py, T: PyClass> {
+    Inline(Ref<'py, T>),
+    Object(PyRef<'py, T>),
+}
+
+impl<'py, T: PyClass> Deref for LazyRef<'py, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        match self {
+            LazyRef::Inline(r) => r,
+            LazyRef::Object(r) => r,
+        }
+    }
+}
+
+/// Cloning a `Lazy` allocates its object, so that the clone references the
+/// same Python object, like cloning a `Py` does.
+impl<T> ClonePy for Lazy<T>
+where
+    T: PyClass + ClonePy + Into<PyClassInitializer<T>>,
+{
+    fn clone_py(&self, py: Python) -> Self {
+        let object = self
+            .object(py)
+            .expect("could not allocate memory for object in Python heap");
+        Self::from(object)
+    }
+}
+
+impl<T> EqPy for Lazy<T>
+where
+    T: PyClass + ClonePy + EqPy + Into<PyClassInitializer<T>>,
+{
+    fn eq_py(&self, other: &Self, py: Python) -> bool {
+        self.borrow(py).eq_py(&other.borrow(py), py)
+    }
+}
+
+impl<T> HashPy for Lazy<T>
+where
+    T: PyClass + ClonePy + HashPy + Into<PyClassInitializer<T>>,
+{
+    fn hash_py<H: StdHasher>(&self, state: &mut H, py: Python) {
+        self.borrow(py).hash_py(state, py)
+    }
+}
+
+impl<T> DisplayPy for Lazy<T>
+where
+    T: PyClass + ClonePy + DisplayPy + Into<PyClassInitializer<T>>,
+{
+    fn fmt_py(&self, py: Python, f: &mut Formatter) -> FmtResult {
+        self.borrow(py).fmt_py(py, f)
+    }
+}
+
+impl<T> TryAsObject for Lazy<T>
+where
+    T: PyClass,
+{
+    fn try_as_object(&self, py: Python) -> Option<pyo3::PyObject> {
+        match &*self.cell.borrow() {
+            LazyState::Inline(_) => None,
+            LazyState::Object(object) => Some(object.clone_ref(py).into_py(py)),
+        }
+    }
+}
+
+impl<T> AssignPy for Lazy<T>
+where
+    T: PyClass<Frozen = False> + ClonePy + Into<PyClassInitializer<T>>,
+{
+    fn assign_py(&self, other: &Self, py: Python) -> bool {
+        let value = other.borrow(py).clone_py(py);
+        match &mut *self.cell.borrow_mut() {
+            LazyState::Inline(this) => {
+                *this = value;
+                true
+            }
+            LazyState::Object(object) => match object.try_borrow_mut(py) {
+                Ok(mut this) => {
+                    *this = value;
+                    true
+                }
+                Err(_) => false,
+            },
+        }
+    }
+}
+
+impl<T> ToPyObject for Lazy<T>
+where
+    T: PyClass + ClonePy + Into<PyClassInitializer<T>>,
+{
+    fn to_object(&self, py: Python) -> pyo3::PyObject {
+        self.object(py)
+            .expect("could not allocate memory for object in Python heap")
+            .into_py(py)
+    }
+}
+
+impl<T> IntoPy<pyo3::PyObject> for Lazy<T>
+where
+    T: PyClass + Into<PyClassInitializer<T>>,
+{
+    fn into_py(self, py: Python) -> pyo3::PyObject {
+        match self.cell.into_inner() {
+            LazyState::Inline(value) => Py::new(py, value)
+                .expect("could not allocate memory for object in Python heap")
+                .into_py(py),
+            LazyState::Object(object) => object.into_py(py),
+        }
+    }
+}
+
+impl<T> AsPyPointer for Lazy<T>
+where
+    T: PyClass + ClonePy + Into<PyClassInitializer<T>>,
+{
+    fn as_ptr(&self) -> *mut PyObject {
+        // the object is kept alive by `self` once allocated
+        Python::with_gil(|py| {
+            self.object(py)
+                .expect("could not allocate memory for object in Python heap")
+                .as_ptr()
+        })
+    }
+}
+
+// ---
+
+/// A vector of Python values converted from Rust values on first access.
+///
+/// Frames loaded from a document keep their clauses as the `fastobo::ast`
+/// lines they were parsed into, since most of them are never accessed from
+/// Python. The lines are converted all at once the first time the vector
+/// is dereferenced, and the converted values are kept afterwards. The
+/// identifier and string pools enabled when the vector was created are
+/// used for the conversion, so that lazily converted clauses still share
+/// the pooled objects of their document.
+#[derive(Debug)]
+pub struct LazyVec<S, T> {
+    source: RefCell<Vec<S>>,
+    items: OnceCell<Vec<T>>,
+    context: RefCell<InternContext>,
+}
+
+impl<S, T> LazyVec<S, T> {
+    /// Store Rust values to be converted on first access.
+    pub fn new(source: Vec<S>) -> Self {
+        Self {
+            source: RefCell::new(source),
+            items: OnceCell::new(),
+            context: RefCell::new(InternContext::current()),
+        }
+    }
+
+    /// Get the Rust values, if they have not been converted yet.
+    pub fn source(&self) -> Option<Ref<'_, Vec<S>>> {
+        match self.items.get() {
+            None => Some(self.source.borrow()),
+            Some(_) => None,
+        }
+    }
+}
+
+impl<S, T> LazyVec<S, T>
+where
+    S: IntoPy<T>,
+{
+    /// Get the converted values, consuming the vector.
+    pub fn into_vec(mut self) -> Vec<T> {
+        self.deref_mut();
+        self.items.take().unwrap_or_default()
+    }
+}
+
+impl<S, T> Default for LazyVec<S, T> {
+    fn default() -> Self {
+        Self::from(Vec::new())
+    }
+}
+
+impl<S, T> From<Vec<T>> for LazyVec<S, T> {
+    fn from(items: Vec<T>) -> Self {
+        Self {
+            source: RefCell::new(Vec::new()),
+            items: OnceCell::from(items),
+            context: RefCell::default(),
+        }
+    }
+}
+
+impl<S, T> FromIterator<T> for LazyVec<S, T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
+
+impl<S, T> IntoIterator for LazyVec<S, T>
+where
+    S: IntoPy<T>,
+{
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_vec().into_iter()
+    }
+}
+
+impl<S, T> Deref for LazyVec<S, T>
+where
+    S: IntoPy<T>,
+{
+    type Target = Vec<T>;
+    fn deref(&self) -> &Vec<T> {
+        self.items.get_or_init(|| {
+            let source = self.source.take();
+            let _pools = self.context.take().enter();
+            Python::with_gil(|py| source.into_iter().map(|s| s.into_py(py)).collect())
+        })
+    }
+}
+
+impl<S, T> DerefMut for LazyVec<S, T>
+where
+    S: IntoPy<T>,
+{
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        let _ = <Self as Deref>::deref(self);
+        self.items.get_mut().expect("items should have been converted")
+    }
+}
+
+/// Cloning a `LazyVec` converts its values, so that the clone references
+/// the same Python objects, like a shallow copy of a `list` does.
+impl<S, T> ClonePy for LazyVec<S, T>
+where
+    S: IntoPy<T>,
+    T: ClonePy,
+{
+    fn clone_py(&self, py: Python) -> Self {
+        Self::from(self.deref().clone_py(py))
+    }
+}
+
+impl<S, T> EqPy for LazyVec<S, T>
+where
+    S: IntoPy<T>,
+    T: EqPy,
+{
+    fn eq_py(&self, other: &Self, py: Python) -> bool {
+        self.deref().eq_py(other.deref(), py)
+    }
+}
+
+impl<S, T> HashPy for LazyVec<S, T>
+where
+    S: IntoPy<T>,
+    T: HashPy,
+{
+    fn hash_py<H: StdHasher>(&self, state: &mut H, py: Python) {
+        self.deref().hash_py(state, py)
+    }
+}
+
+// ---
+
+/// An immutable string, shared between clauses when string pooling is enabled.
+///
+/// Values such as the creator of `created_by` clauses are often repeated
+/// in every frame of a document. Since they can only be replaced and never
+/// edited in place, sharing them is invisible from Python.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PooledString(Arc<str>);
+
+impl PooledString {
+    /// Create a string, taken from the string pool if one is enabled.
+    pub fn new(s: &str) -> Self {
+        Self(crate::py::id::pooled_str(s))
+    }
+
+    /// Get the string as a `str` slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for PooledString {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        ast::UnquotedString::new(self.as_str().to_string()).fmt(f)
+    }
+}
+
+impl From<ast::UnquotedString> for PooledString {
+    fn from(s: ast::UnquotedString) -> Self {
+        Self::new(s.as_str())
+    }
+}
+
+impl From<PooledString> for ast::UnquotedString {
+    fn from(s: PooledString) -> Self {
+        ast::UnquotedString::new(s.as_str().to_string())
+    }
+}
+
+impl ToPyObject for PooledString {
+    fn to_object(&self, py: Python) -> pyo3::PyObject {
+        self.as_str().to_object(py)
+    }
+}
+
+derive_clonepy!(PooledString);
+derive_eqpy!(PooledString);
+derive_hashpy!(PooledString);
+derive_displaypy!(PooledString);
+
 // ---
 
 /// A trait for Python classes that are purely abstract.
@@ -126,6 +759,288 @@ pub trait AbstractClass: PyClass {
 /// A trait for Python classes that are final.
 pub trait FinalClass: PyClass {}
 
+/// A trait for clause wrappers that can be parsed from a single clause line.
+///
+/// Instances of user-defined clause subclasses have no Rust counterpart,
+/// so they are converted by parsing their `raw_tag` and `raw_value`.
+pub trait ParseClause: Sized {
+    fn parse_clause(py: Python, text: &str) -> PyResult<Self>;
+}
+
 // ---
 
 pub type Hasher = std::collections::hash_map::DefaultHasher;
+
+// ---
+
+/// Resolve a Python sequence index against a sequence of length `len`.
+///
+/// Negative indices are counted from the end of the sequence, and `None`
+/// is returned if the index is out of bounds.
+pub fn normalize_index(index: isize, len: usize) -> Option<usize> {
+    let index = if index < 0 { index + len as isize } else { index };
+    if index >= 0 && (index as usize) < len {
+        Some(index as usize)
+    } else {
+        None
+    }
+}
+
+/// Clone the elements of `vec` selected by `slice`.
+pub fn get_slice<T: ClonePy>(vec: &[T], slice: &PySlice, py: Python) -> PyResult<Vec<T>> {
+    let indices = slice.indices(vec.len() as c_long)?;
+    Ok((0..indices.slicelength)
+        .map(|i| vec[(indices.start + i * indices.step) as usize].clone_py(py))
+        .collect())
+}
+
+/// Replace the elements of `vec` selected by `slice` with `items`.
+///
+/// This follows the semantics of `list.__setitem__`: a contiguous slice
+/// can be replaced by any number of items, but an extended slice must be
+/// replaced by exactly as many items as it selects.
+pub fn set_slice<T>(vec: &mut Vec<T>, slice: &PySlice, items: Vec<T>) -> PyResult<()> {
+    let indices = slice.indices(vec.len() as c_long)?;
+    if indices.step == 1 {
+        let start = indices.start as usize;
+        let stop = indices.stop.max(indices.start) as usize;
+        vec.splice(start..stop, items);
+    } else if indices.slicelength as usize != items.len() {
+        let msg = format!(
+            "attempt to assign sequence of size {} to extended slice of size {}",
+            items.len(),
+            indices.slicelength,
+        );
+        return Err(PyValueError::new_err(msg));
+    } else {
+        let mut index = indices.start;
+        for item in items {
+            vec[index as usize] = item;
+            index += indices.step;
+        }
+    }
+    Ok(())
+}
+
+/// Remove the elements of `vec` selected by `slice`.
+pub fn del_slice<T>(vec: &mut Vec<T>, slice: &PySlice) -> PyResult<()> {
+    let indices = slice.indices(vec.len() as c_long)?;
+    let mut selected = (0..indices.slicelength)
+        .map(|i| (indices.start + i * indices.step) as usize)
+        .collect::<Vec<usize>>();
+    selected.sort_unstable();
+    for index in selected.into_iter().rev() {
+        vec.remove(index);
+    }
+    Ok(())
+}
+
+/// An iterator over a list-like container, from the last item to the first.
+///
+/// The container is indexed on every step, so that items removed during
+/// the iteration are not yielded, like with the builtin `reversed`.
+#[pyo3::pyclass(module = "fastobo")]
+pub struct ReverseIterator {
+    list: pyo3::PyObject,
+    index: usize,
+}
+
+impl ReverseIterator {
+    pub fn new(list: pyo3::PyObject, len: usize) -> Self {
+        Self { list, index: len }
+    }
+}
+
+#[pyo3::pymethods]
+impl ReverseIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<pyo3::PyObject>> {
+        let list = self.list.as_ref(py);
+        let len = list.len()?;
+        if self.index == 0 || self.index > len {
+            self.index = 0;
+            return Ok(None);
+        }
+        self.index -= 1;
+        list.get_item(self.index).map(|item| Some(item.to_object(py)))
+    }
+}
+
+// ---
+
+/// Extract a set of strings from a `str` or an iterable of `str`.
+pub fn str_set(object: &PyAny) -> PyResult<HashSet<String>> {
+    if let Ok(s) = object.downcast::<PyString>() {
+        Ok(std::iter::once(s.to_str()?.to_string()).collect())
+    } else {
+        object.iter()?.map(|item| item?.extract()).collect()
+    }
+}
+
+/// Get the raw tag of a clause object, as returned by its `raw_tag` method.
+pub fn raw_tag<T: ToPyObject>(clause: &T, py: Python) -> PyResult<String> {
+    clause.to_object(py).call_method0(py, "raw_tag")?.extract(py)
+}
+
+/// Get the size of a Python object, as returned by its `__sizeof__` method.
+pub fn sizeof<T: ToPyObject>(object: &T, py: Python) -> PyResult<usize> {
+    object.to_object(py).call_method0(py, "__sizeof__")?.extract(py)
+}
+
+/// Estimate the memory used by a vector of Python objects.
+///
+/// This counts the buffer of the vector, and the size of each element
+/// as reported by its own `__sizeof__` method.
+pub fn sizeof_vec<T: ToPyObject>(vec: &Vec<T>, py: Python) -> PyResult<usize> {
+    let mut size = vec.capacity() * std::mem::size_of::<T>();
+    for item in vec.iter() {
+        size += sizeof(item, py)?;
+    }
+    Ok(size)
+}
+
+/// Get the size of a Python object, unless it was already counted.
+///
+/// Identifiers are interned and shared between frames and clauses, so
+/// the address of every object counted is recorded in `seen` to only
+/// count it once.
+pub fn sizeof_shared<T: ToPyObject>(
+    object: &T,
+    py: Python,
+    seen: &mut HashSet<usize>,
+) -> PyResult<usize> {
+    let object = object.to_object(py);
+    if seen.insert(object.as_ptr() as usize) {
+        object.call_method0(py, "__sizeof__")?.extract(py)
+    } else {
+        Ok(0)
+    }
+}
+
+/// Estimate the memory used by a lazily converted vector.
+///
+/// Unconverted values are sized directly from their AST, using the
+/// length of their serialization to estimate the strings they own,
+/// so that no Python object is created. Converted values are sized
+/// with [`sizeof_shared`].
+pub fn sizeof_lazy_vec<S, T>(
+    vec: &LazyVec<S, T>,
+    py: Python,
+    seen: &mut HashSet<usize>,
+) -> PyResult<usize>
+where
+    S: Display + IntoPy<T>,
+    T: ToPyObject,
+{
+    if let Some(lines) = vec.source() {
+        let mut counter = ByteCounter(0);
+        for line in lines.iter() {
+            write!(counter, "{}", line).expect("counting bytes cannot fail");
+        }
+        return Ok(lines.capacity() * std::mem::size_of::<S>() + counter.0);
+    }
+    let mut size = vec.capacity() * std::mem::size_of::<T>();
+    for item in vec.iter() {
+        size += sizeof_shared(item, py, seen)?;
+    }
+    Ok(size)
+}
+
+/// A `fmt::Write` sink counting the bytes written to it.
+struct ByteCounter(usize);
+
+impl Write for ByteCounter {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// Update a list of items in place to match a list of edited AST values.
+///
+/// Items are paired with the values of the same kind found at the start
+/// and at the end of both lists, and in order in between. A paired item
+/// is left untouched if its AST is equal to the new value, updated with
+/// `update` otherwise, or replaced with a new item created with `create`
+/// if it could not be updated. Values without a paired item are created
+/// with `create`, and items without a paired value are removed.
+pub fn update_items<T, L, A, K, U, C>(
+    items: &mut Vec<T>,
+    values: Vec<L>,
+    to_ast: A,
+    same_kind: K,
+    mut update: U,
+    mut create: C,
+) where
+    L: PartialEq,
+    A: FnMut(&T) -> L,
+    K: Fn(&L, &L) -> bool,
+    U: FnMut(&T, &L) -> bool,
+    C: FnMut(L) -> T,
+{
+    let old = items.iter().map(to_ast).collect::<Vec<L>>();
+    let common = old.len().min(values.len());
+    let prefix = (0..common)
+        .take_while(|&i| same_kind(&old[i], &values[i]))
+        .count();
+    let suffix = (0..common - prefix)
+        .take_while(|&i| same_kind(&old[old.len() - 1 - i], &values[values.len() - 1 - i]))
+        .count();
+
+    let mut paired = std::mem::take(items)
+        .into_iter()
+        .zip(old)
+        .map(Some)
+        .collect::<Vec<_>>();
+    let (n, m) = (values.len(), paired.len());
+    for (j, value) in values.into_iter().enumerate() {
+        let i = if j >= n - suffix {
+            Some(j + m - n)
+        } else if j < m - suffix {
+            Some(j)
+        } else {
+            None
+        };
+        match i.and_then(|i| paired[i].take()) {
+            Some((item, ast)) if ast == value => items.push(item),
+            Some((item, _)) if update(&item, &value) => items.push(item),
+            _ => items.push(create(value)),
+        }
+    }
+}
+
+/// Reject the modification of a frozen object with a `TypeError`.
+///
+/// Documents and frames are only modified after this check passes, so it
+/// also invalidates the relation indices cached by documents.
+pub fn check_mutable(frozen: bool, name: &str) -> PyResult<()> {
+    if frozen {
+        let msg = format!("cannot modify frozen {} instance", name);
+        Err(PyTypeError::new_err(msg))
+    } else {
+        crate::index::invalidate();
+        Ok(())
+    }
+}
+
+/// Set or delete an attribute of an object, unless the object is frozen.
+///
+/// This is used by the `__setattr__` and `__delattr__` methods of classes
+/// with many properties, so that all their setters share a single check.
+/// The attribute is deleted when `value` is `None`.
+pub fn setattr_mutable(
+    object: &PyAny,
+    frozen: bool,
+    name: &PyAny,
+    value: Option<&PyAny>,
+) -> PyResult<()> {
+    check_mutable(frozen, object.get_type().name()?)?;
+    let value = value.map(|v| v.as_ptr()).unwrap_or(std::ptr::null_mut());
+    match unsafe { pyo3::ffi::PyObject_GenericSetAttr(object.as_ptr(), name.as_ptr(), value) } {
+        -1 => Err(pyo3::PyErr::fetch(object.py())),
+        _ => Ok(()),
+    }
+}
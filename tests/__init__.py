@@ -5,8 +5,10 @@ from . import (
     test_header,
     test_id,
     test_pv,
+    test_syn,
     test_term,
     test_typedef,
+    test_visit,
     test_xref
 )
 
@@ -17,7 +19,9 @@ def load_tests(loader, suite, pattern):
     suite.addTests(loader.loadTestsFromModule(test_header))
     suite.addTests(loader.loadTestsFromModule(test_id))
     suite.addTests(loader.loadTestsFromModule(test_pv))
+    suite.addTests(loader.loadTestsFromModule(test_syn))
     suite.addTests(loader.loadTestsFromModule(test_term))
     suite.addTests(loader.loadTestsFromModule(test_typedef))
+    suite.addTests(loader.loadTestsFromModule(test_visit))
     suite.addTests(loader.loadTestsFromModule(test_xref))
     return suite
\ No newline at end of file
@@ -1,6 +1,8 @@
 # coding: utf-8
 
+import copy
 import datetime
+import sys
 import unittest
 
 import fastobo
@@ -46,6 +48,225 @@ class _TestFrame(object):
         self.assertRaises(TypeError, self.Frame, self.id, ["abc"])
         self.assertRaises(TypeError, self.Frame, self.id, "abc")
 
+    def test_to_dict(self):
+        frame = self.Frame(self.id, [
+            self.NameClause("thing"),
+            self.CreatedByClause("Martin Larralde")
+        ])
+        d = frame.to_dict()
+        self.assertEqual(d["id"], "MS:1000031")
+        self.assertEqual(d["clauses"], [
+            {"tag": "name", "value": "thing"},
+            {"tag": "created_by", "value": "Martin Larralde"},
+        ])
+        self.assertEqual(str(self.Frame.from_dict(d)), str(frame))
+        d["type"] = "Other"
+        self.assertRaises(ValueError, self.Frame.from_dict, d)
+
+    def test_eq(self):
+        frame = self.Frame(self.id, [self.NameClause("thing")])
+        self.assertEqual(frame, self.Frame(self.id, [self.NameClause("thing")]))
+        self.assertFalse(frame != self.Frame(self.id, [self.NameClause("thing")]))
+        self.assertNotEqual(frame, self.Frame(self.id, [self.NameClause("other")]))
+        self.assertNotEqual(frame, self.Frame(self.id))
+        self.assertEqual(self.Frame.loads(str(frame)), frame)
+        self.assertNotEqual(frame, 1)
+
+    def test_eq_qualifiers(self):
+        frame = self.Frame(self.id, [self.NameClause("thing")])
+        qualified = self.Frame(self.id, [self.NameClause("thing")])
+        qualifier = fastobo.qual.Qualifier(fastobo.id.UnprefixedIdent("source"), "TST:001")
+        qualified[0].qualifiers.append(qualifier)
+        self.assertNotEqual(frame, qualified)
+        self.assertEqual(qualified, copy.deepcopy(qualified))
+        qualified[0].qualifiers.clear()
+        self.assertEqual(frame, qualified)
+
+    def test_to_dict_grouped(self):
+        frame = self.Frame(self.id, [
+            self.NameClause("thing"),
+            self.CreatedByClause("Martin Larralde"),
+            self.NameClause("other"),
+        ])
+        d = frame.to_dict(grouped=True)
+        self.assertEqual(d["id"], "MS:1000031")
+        self.assertEqual(d["clauses"], {
+            "name": ["thing", "other"],
+            "created_by": ["Martin Larralde"],
+        })
+        loaded = self.Frame.from_dict(d)
+        self.assertEqual(len(loaded), 3)
+        self.assertEqual(loaded.get("name"), frame.get("name"))
+
+    def test_to_dict_qualifiers(self):
+        text = "[{}]\nid: MS:1000031\nalt_id: MS:1000030 {{comment=\"a comment\"}}\n"
+        frame = self.Frame.loads(text.format(self.Frame.__name__[:-len("Frame")]))
+        qualifiers = [{"key": "comment", "value": "a comment"}]
+        d = frame.to_dict()
+        self.assertEqual(d["clauses"], [
+            {"tag": "alt_id", "value": "MS:1000030", "qualifiers": qualifiers},
+        ])
+        self.assertEqual(self.Frame.from_dict(d), frame)
+        d = frame.to_dict(grouped=True)
+        self.assertEqual(d["clauses"], {
+            "alt_id": [{"value": "MS:1000030", "qualifiers": qualifiers}],
+        })
+        self.assertEqual(str(self.Frame.from_dict(d)), str(frame))
+
+    def test_str(self):
+        frame = self.Frame(self.id, [
+            self.NameClause("thing"),
+            self.CreatedByClause("Martin Larralde")
+        ])
+        header = "[{}]".format(self.Frame.__name__[:-len("Frame")])
+        self.assertEqual(str(frame), "\n".join([
+            header,
+            "id: MS:1000031",
+            "name: thing",
+            "created_by: Martin Larralde",
+            "",
+        ]))
+
+    def test_loads(self):
+        frame = self.Frame(self.id, [
+            self.NameClause("thing"),
+            self.CreatedByClause("Martin Larralde")
+        ])
+        loaded = self.Frame.loads(str(frame))
+        self.assertIsInstance(loaded, self.Frame)
+        self.assertEqual(str(loaded), str(frame))
+        self.assertRaises(SyntaxError, self.Frame.loads, "id: MS:1000031")
+
+    def test_loads_len(self):
+        frame = self.Frame(self.id, [
+            self.NameClause("thing"),
+            self.CreatedByClause("Martin Larralde")
+        ])
+        loaded = self.Frame.loads(str(frame))
+        self.assertEqual(len(loaded), 2)
+        self.assertEqual(list(loaded), list(frame))
+        self.assertEqual(len(loaded), 2)
+
+    def test_loads_contains(self):
+        frame = self.Frame(self.id, [self.NameClause("thing")])
+        loaded = self.Frame.loads(str(frame))
+        self.assertIn(self.NameClause("thing"), loaded)
+        self.assertNotIn(self.NameClause("other"), loaded)
+        self.assertNotIn("thing", loaded)
+        self.assertIn(self.NameClause("thing"), list(loaded))
+        self.assertNotIn(self.NameClause("other"), loaded)
+
+    def test_loads_clause_identity(self):
+        frame = self.Frame(self.id, [self.NameClause("thing")])
+        loaded = self.Frame.loads(str(frame))
+        self.assertIs(loaded[0], loaded[0])
+        loaded[0].name = "other"
+        self.assertEqual(loaded[0].name, "other")
+        self.assertIn("name: other", str(loaded))
+        self.assertIs(copy.copy(loaded)[0], loaded[0])
+
+    def test_name(self):
+        frame = self.Frame(self.id, [self.CreatedByClause("Martin Larralde")])
+        self.assertIs(frame.name, None)
+        frame.append(self.NameClause("thing"))
+        self.assertEqual(frame.name, "thing")
+        loaded = self.Frame.loads(str(frame))
+        self.assertEqual(loaded.name, "thing")
+        loaded[1].name = "other"
+        self.assertEqual(loaded.name, "other")
+
+    def test_add_loaded(self):
+        frame = self.Frame(self.id, [self.NameClause("thing")])
+        loaded = self.Frame.loads(str(frame))
+        other = loaded + [self.CreatedByClause("Martin Larralde")]
+        self.assertEqual(len(other), 2)
+        self.assertEqual(str(other), str(frame + [self.CreatedByClause("Martin Larralde")]))
+        self.assertEqual(str(loaded), str(frame))
+
+    def test_sizeof(self):
+        empty = self.Frame(self.id)
+        frame = self.Frame(self.id, [
+            self.NameClause("thing"),
+            self.CreatedByClause("Martin Larralde")
+        ])
+        self.assertGreater(sys.getsizeof(frame), sys.getsizeof(empty))
+
+    def test_loads_sizeof(self):
+        empty = self.Frame.loads(str(self.Frame(self.id)))
+        frame = self.Frame(self.id, [
+            self.NameClause("thing"),
+            self.CreatedByClause("Martin Larralde")
+        ])
+        loaded = self.Frame.loads(str(frame))
+        self.assertGreater(sys.getsizeof(loaded), sys.getsizeof(empty))
+        self.assertEqual(list(loaded), list(frame))
+
+    def test_copy(self):
+        frame = self.Frame(self.id, [self.NameClause("thing")])
+        copy_ = copy.copy(frame)
+        self.assertEqual(str(copy_), str(frame))
+        self.assertIsNot(copy_, frame)
+        self.assertIs(copy_[0], frame[0])
+        copy_.append(self.CreatedByClause("Martin Larralde"))
+        self.assertEqual(len(frame), 1)
+
+    def test_deepcopy(self):
+        frame = self.Frame(self.id, [self.NameClause("thing")])
+        copy_ = copy.deepcopy(frame)
+        self.assertEqual(str(copy_), str(frame))
+        self.assertIsNot(copy_[0], frame[0])
+        copy_[0].name = "other"
+        self.assertEqual(frame[0].name, "thing")
+
+    def test_freeze(self):
+        frame = self.Frame(self.id, [self.NameClause("thing")])
+        self.assertFalse(frame.frozen)
+        self.assertRaises(TypeError, hash, frame)
+        frame.freeze()
+        self.assertTrue(frame.frozen)
+        other = self.Frame(self.id, [self.NameClause("thing")])
+        other.freeze()
+        self.assertEqual(hash(frame), hash(other))
+        self.assertRaises(TypeError, frame.append, self.CreatedByClause("Martin Larralde"))
+        self.assertRaises(TypeError, frame.pop)
+        self.assertRaises(TypeError, frame.clear)
+        self.assertRaises(TypeError, frame.__delitem__, 0)
+        self.assertRaises(TypeError, setattr, frame, "id", self.id)
+        self.assertEqual(len(frame), 1)
+        self.assertTrue(frame[0].frozen)
+        self.assertRaises(TypeError, setattr, frame[0], "comments", ["a comment"])
+        self.assertFalse(copy.copy(frame).frozen)
+        self.assertFalse(copy.deepcopy(frame)[0].frozen)
+
+    def test_get(self):
+        c1 = self.NameClause("thing")
+        c2 = self.CreatedByClause("Martin Larralde")
+        frame = self.Frame(self.id, [c1, c2, c1])
+        self.assertEqual(frame.get("name"), [c1, c1])
+        self.assertIs(frame.get("name")[0], frame[0])
+        self.assertEqual(frame.get("created_by"), [c2])
+        self.assertEqual(frame.get("is_a"), [])
+
+    def test_tags(self):
+        frame = self.Frame(self.id, [
+            self.NameClause("thing"),
+            self.CreatedByClause("Martin Larralde"),
+            self.NameClause("other"),
+        ])
+        self.assertEqual(frame.tags(), {"name", "created_by"})
+        self.assertEqual(self.Frame(self.id).tags(), set())
+
+    def test_pop_clauses(self):
+        c1 = self.NameClause("thing")
+        c2 = self.CreatedByClause("Martin Larralde")
+        frame = self.Frame(self.id, [c1, c2, c1])
+        self.assertEqual(frame.pop_clauses("name"), [c1, c1])
+        self.assertEqual(list(frame), [c2])
+        self.assertEqual(frame.pop_clauses("name"), [])
+        self.assertEqual(list(frame), [c2])
+        frame.freeze()
+        self.assertRaises(TypeError, frame.pop_clauses, "created_by")
+
     def test_append(self):
         frame = self.Frame(self.id)
         self.assertEqual(len(frame), 0)
@@ -59,6 +280,112 @@ class _TestFrame(object):
         self.assertEqual(frame[0], c1)
         self.assertEqual(frame[1], c2)
 
+    def test_extend(self):
+        c1 = self.NameClause("thing")
+        c2 = self.CreatedByClause("Martin Larralde")
+        frame = self.Frame(self.id, [c1])
+        frame.extend([c2, c1])
+        self.assertEqual(list(frame), [c1, c2, c1])
+        frame.extend(iter([c2]))
+        self.assertEqual(list(frame), [c1, c2, c1, c2])
+        self.assertRaises(TypeError, frame.extend, 1)
+        self.assertRaises(TypeError, frame.extend, [c1, 1])
+        self.assertEqual(len(frame), 4)
+
+    def test_add(self):
+        c1 = self.NameClause("thing")
+        c2 = self.CreatedByClause("Martin Larralde")
+        frame = self.Frame(self.id, [c1])
+        other = frame + [c2]
+        self.assertIsInstance(other, self.Frame)
+        self.assertEqual(other.id, self.id)
+        self.assertEqual(list(other), [c1, c2])
+        self.assertEqual(list(frame), [c1])
+        self.assertRaises(TypeError, frame.__add__, [1])
+        self.assertRaises(TypeError, lambda: frame + 1)
+
+    def test_iadd(self):
+        c1 = self.NameClause("thing")
+        c2 = self.CreatedByClause("Martin Larralde")
+        frame = self.Frame(self.id, [c1])
+        alias = frame
+        frame += [c2]
+        self.assertIs(frame, alias)
+        self.assertEqual(list(frame), [c1, c2])
+        frame += frame
+        self.assertEqual(list(frame), [c1, c2, c1, c2])
+        with self.assertRaises(TypeError):
+            frame += [1]
+
+    def test_index(self):
+        c1 = self.NameClause("thing")
+        c2 = self.CreatedByClause("Martin Larralde")
+        c3 = self.NameClause("other thing")
+        frame = self.Frame(self.id, [c1, c2, c1])
+        self.assertEqual(frame.index(c1), 0)
+        self.assertEqual(frame.index(c2), 1)
+        self.assertEqual(frame.index(c1, 1), 2)
+        self.assertEqual(frame.index(c1, -1), 2)
+        self.assertRaises(ValueError, frame.index, c1, 1, 2)
+        self.assertRaises(ValueError, frame.index, c3)
+        self.assertRaises(TypeError, frame.index, 1)
+
+    def test_remove(self):
+        c1 = self.NameClause("thing")
+        c2 = self.CreatedByClause("Martin Larralde")
+        c3 = self.NameClause("other thing")
+        frame = self.Frame(self.id, [c1, c2, c1])
+        frame.remove(c1)
+        self.assertEqual(list(frame), [c2, c1])
+        frame.remove(c1)
+        self.assertEqual(list(frame), [c2])
+        self.assertRaises(ValueError, frame.remove, c3)
+        self.assertRaises(TypeError, frame.remove, 1)
+
+    def test_sort(self):
+        c1 = self.NameClause("thing")
+        c2 = self.CreatedByClause("Martin Larralde")
+        c3 = self.NameClause("other thing")
+        frame = self.Frame(self.id, [c1, c2, c3])
+        frame.sort()
+        self.assertEqual(list(frame), [c2, c3, c1])
+        frame.sort(reverse=True)
+        self.assertEqual(list(frame), [c1, c3, c2])
+        frame.sort(key=lambda c: c.raw_tag())
+        self.assertEqual(list(frame), [c2, c1, c3])
+        self.assertRaises(TypeError, frame.sort, key=lambda c: c if c is c2 else 1)
+
+    def test_sort_key_uses_frame(self):
+        c1 = self.NameClause("thing")
+        c2 = self.CreatedByClause("Martin Larralde")
+        frame = self.Frame(self.id, [c1, c2])
+        frame.sort(key=lambda c: -frame.index(c))
+        self.assertEqual(list(frame), [c2, c1])
+        self.assertRaises(ValueError, frame.sort, key=lambda c: frame.append(c1) or 0)
+        c3 = self.NameClause("other thing")
+        self.assertRaises(ValueError, frame.sort, key=lambda c: frame.__setitem__(0, c3) or 0)
+
+    def test_sort_stable_reverse(self):
+        c1 = self.NameClause("thing")
+        c2 = self.CreatedByClause("Martin Larralde")
+        c3 = self.NameClause("other thing")
+        frame = self.Frame(self.id, [c1, c2, c3])
+        frame.sort(key=lambda c: c.raw_tag() == "name", reverse=True)
+        self.assertEqual(list(frame), [c1, c3, c2])
+
+    def test_reversed(self):
+        c1 = self.NameClause("thing")
+        c2 = self.CreatedByClause("Martin Larralde")
+        frame = self.Frame(self.id, [c1, c2])
+        self.assertEqual(list(reversed(frame)), [c2, c1])
+        self.assertEqual(list(frame), [c1, c2])
+        self.assertEqual(list(reversed(self.Frame(self.id))), [])
+        it = reversed(frame)
+        self.assertIs(iter(it), it)
+        self.assertEqual(next(it), c2)
+        frame.clear()
+        self.assertRaises(StopIteration, next, it)
+
     def test_reverse(self):
         c1 = self.NameClause("thing")
         c2 = self.CreatedByClause("Martin Larralde")
@@ -89,6 +416,55 @@ class _TestFrame(object):
         self.assertEqual(x2, c1)
         self.assertRaises(IndexError, frame.pop)
 
+    def test_getitem(self):
+        c1 = self.NameClause("thing")
+        c2 = self.CreatedByClause("Martin Larralde")
+        frame = self.Frame(self.id, [c1, c2])
+        self.assertEqual(frame[0], c1)
+        self.assertEqual(frame[-1], c2)
+        self.assertRaises(IndexError, frame.__getitem__, 2)
+        self.assertRaises(IndexError, frame.__getitem__, -3)
+        sliced = frame[1:]
+        self.assertIsInstance(sliced, self.Frame)
+        self.assertEqual(sliced.id, self.id)
+        self.assertEqual(list(sliced), [c2])
+        self.assertEqual(list(frame[::-1]), [c2, c1])
+
+    def test_setitem(self):
+        c1 = self.NameClause("thing")
+        c2 = self.CreatedByClause("Martin Larralde")
+        c3 = self.NameClause("other thing")
+        frame = self.Frame(self.id, [c1, c2])
+        frame[0] = c3
+        self.assertEqual(list(frame), [c3, c2])
+        frame[-1] = c1
+        self.assertEqual(list(frame), [c3, c1])
+        frame[:1] = [c1, c2]
+        self.assertEqual(list(frame), [c1, c2, c1])
+        frame[::2] = [c3, c3]
+        self.assertEqual(list(frame), [c3, c2, c3])
+        self.assertRaises(ValueError, frame.__setitem__, slice(None, None, 2), [c1])
+        self.assertRaises(IndexError, frame.__setitem__, 3, c1)
+        self.assertRaises(IndexError, frame.__setitem__, -4, c1)
+        self.assertRaises(TypeError, frame.__setitem__, 0, 1)
+
+    def test_delitem(self):
+        c1 = self.NameClause("thing")
+        c2 = self.CreatedByClause("Martin Larralde")
+        c3 = self.NameClause("other thing")
+        frame = self.Frame(self.id, [c1, c2, c3, c1])
+        del frame[-1]
+        self.assertEqual(list(frame), [c1, c2, c3])
+        del frame[0]
+        self.assertEqual(list(frame), [c2, c3])
+        self.assertRaises(IndexError, frame.__delitem__, 2)
+        self.assertRaises(IndexError, frame.__delitem__, -3)
+        frame = self.Frame(self.id, [c1, c2, c3, c1])
+        del frame[::2]
+        self.assertEqual(list(frame), [c2, c1])
+        del frame[:]
+        self.assertEqual(list(frame), [])
+
 # --- DefClause --------------------------------------------------------------
 
 class _TestDefClause(object):
@@ -106,6 +482,15 @@ class _TestDefClause(object):
         clause = self.type("definition", fastobo.xref.XrefList([x]))
         self.assertEqual(repr(clause), "DefClause('definition', XrefList([{!r}]))".format(x))
 
+    def test_hash(self):
+        id_ = fastobo.id.PrefixedIdent('ISBN', '0321842685')
+        c1 = self.type("definition", fastobo.xref.XrefList([fastobo.xref.Xref(id_)]))
+        c2 = self.type("definition", fastobo.xref.XrefList([fastobo.xref.Xref(id_)]))
+        c3 = self.type("definition")
+        self.assertEqual(c1, c2)
+        self.assertEqual(hash(c1), hash(c2))
+        self.assertEqual(len({c1, c2, c3}), 2)
+
 
 # --- ConsiderClause ---------------------------------------------------------
 
@@ -130,6 +515,18 @@ class _TestConsiderClause(object):
     def test_eq(self):
         self.assertEqual(self.type(self.id), self.type(self.id))
         self.assertNotEqual(self.type(self.id), self.type(self.id2))
+        self.assertFalse(self.type(self.id) != self.type(self.id))
+
+    def test_hash(self):
+        self.assertEqual(hash(self.type(self.id)), hash(self.type(self.id)))
+        clauses = {self.type(self.id), self.type(self.id), self.type(self.id2)}
+        self.assertEqual(len(clauses), 2)
+
+    def test_copy(self):
+        clause = self.type(self.id)
+        self.assertEqual(copy.copy(clause), clause)
+        self.assertIsNot(copy.copy(clause), clause)
+        self.assertEqual(copy.deepcopy(clause), clause)
 
 
 # --- IsObsoleteClause -------------------------------------------------------
@@ -192,3 +589,53 @@ class _TestCreationDateClause(object):
         clause.date = d2
         self.assertEqual(str(clause), "creation_date: 2021-02-15T12:30:00Z")
         self.assertIsInstance(clause.date, datetime.datetime)
+
+    def test_str(self):
+        clause = self.type("2021-01-23T08:00:00+01:00")
+        self.assertEqual(str(clause), "creation_date: 2021-01-23T08:00:00+01:00")
+        self.assertIsInstance(clause.date, datetime.datetime)
+        clause.date = "2021-02-15"
+        self.assertEqual(str(clause), "creation_date: 2021-02-15")
+        self.assertEqual(clause.date, datetime.date(2021, 2, 15))
+        self.assertRaises(ValueError, self.type, "23/01/2021")
+        self.assertRaises(TypeError, self.type, 20210123)
+
+    def test_naive(self):
+        d1 = datetime.datetime(2021, 1, 23, 12)
+        self.assertEqual(str(self.type(d1)), "creation_date: 2021-01-23T12:00:00")
+        self.assertEqual(str(self.type(d1, naive="keep")), "creation_date: 2021-01-23T12:00:00")
+        self.assertEqual(str(self.type(d1, naive="utc")), "creation_date: 2021-01-23T12:00:00Z")
+        local = self.type(d1, naive="local").date
+        self.assertIsNot(local.tzinfo, None)
+        self.assertEqual(local, d1.astimezone())
+        self.assertRaises(ValueError, self.type, d1, naive="error")
+        self.assertRaises(ValueError, self.type, d1, naive="unknown")
+        d2 = datetime.datetime(2021, 1, 23, 12, tzinfo=datetime.timezone.utc)
+        self.assertEqual(str(self.type(d2, naive="error")), "creation_date: 2021-01-23T12:00:00Z")
+        d3 = datetime.date(2021, 1, 23)
+        self.assertEqual(str(self.type(d3, naive="error")), "creation_date: 2021-01-23")
+
+    def test_datetime_fraction(self):
+        d1 = datetime.datetime(2021, 1, 23, 12, 0, 0, 123000, tzinfo=datetime.timezone.utc)
+        clause = self.type(d1)
+        self.assertEqual(str(clause), "creation_date: 2021-01-23T12:00:00.12Z")
+        self.assertEqual(clause.date, d1)
+        d2 = datetime.datetime(2021, 1, 23, 12, 0, 0, 654321)
+        clause.date = d2
+        self.assertEqual(clause.date.microsecond, 654321)
+
+    def test_datetime_offset(self):
+        offsets = {
+            "+05:30": datetime.timezone(datetime.timedelta(hours=5, minutes=30)),
+            "-03:45": datetime.timezone(-datetime.timedelta(hours=3, minutes=45)),
+            "-00:30": datetime.timezone(-datetime.timedelta(minutes=30)),
+            "+00:00": datetime.timezone(datetime.timedelta(0), "+00:00"),
+            "Z": datetime.timezone.utc,
+        }
+        for offset, tz in offsets.items():
+            clause = self.type(datetime.datetime(2021, 1, 23, 12, tzinfo=tz))
+            expected = "creation_date: 2021-01-23T12:00:00{}".format(offset)
+            self.assertEqual(str(clause), expected)
+            self.assertEqual(clause.date.utcoffset(), tz.utcoffset(None))
+            clause.date = clause.date
+            self.assertEqual(str(clause), expected)
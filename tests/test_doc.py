@@ -1,10 +1,38 @@
 # coding: utf-8
 
+import copy
 import datetime
+import json
+import sys
 import unittest
 
 import fastobo
 
+try:
+    import networkx
+except ImportError:
+    networkx = None
+
+try:
+    import pyhornedowl
+except ImportError:
+    pyhornedowl = None
+
+try:
+    import pyarrow
+except ImportError:
+    pyarrow = None
+
+try:
+    import pandas
+except ImportError:
+    pandas = None
+
+try:
+    import rdflib
+except ImportError:
+    rdflib = None
+
 
 # -- OboDoc ------------------------------------------------------------------
 
@@ -63,3 +91,537 @@ class TestOboDoc(unittest.TestCase):
         self.assertRaises(TypeError, self.type, [1], self.entities)
         self.assertRaises(TypeError, self.type, ["abc"], self.entities)
         self.assertRaises(TypeError, self.type, "abc", self.entities)
+
+    def test_eq(self):
+        doc = self.type(self.header, self.entities)
+        self.assertEqual(doc, self.type(self.header, self.entities))
+        self.assertFalse(doc != self.type(self.header, self.entities))
+        self.assertEqual(fastobo.loads(str(doc)), doc)
+        self.assertNotEqual(doc, self.type(self.header))
+        self.assertNotEqual(doc, self.type(entities=self.entities))
+        self.assertNotEqual(doc, self.header)
+
+    def test_from_frames(self):
+        doc = self.type.from_frames(self.header, iter(self.entities))
+        self.assertIs(doc.header, self.header)
+        self.assertEqual(len(doc), 2)
+        self.assertIs(doc[0], self.entities[0])
+        empty = self.type.from_frames()
+        self.assertEqual(len(empty.header), 0)
+        self.assertEqual(len(empty), 0)
+        self.assertRaises(TypeError, self.type.from_frames, self.header, [1])
+        self.assertRaises(TypeError, self.type.from_frames, self.header, [self.header])
+
+    def test_add(self):
+        d1 = self.type(self.header, self.entities[:1])
+        d2 = self.type(entities=self.entities[1:])
+        doc = d1 + d2
+        self.assertIsInstance(doc, self.type)
+        self.assertEqual(doc.header, d1.header)
+        self.assertEqual(list(doc), self.entities)
+        self.assertEqual(len(d1), 1)
+        self.assertEqual(len(d2), 1)
+
+    def test_contains(self):
+        doc = self.type(self.header, self.entities)
+        self.assertIn("MS:1000031", doc)
+        self.assertIn(fastobo.id.PrefixedIdent("MS", "1000031"), doc)
+        self.assertIn(fastobo.id.UnprefixedIdent("part_of"), doc)
+        self.assertIn(self.entities[0], doc)
+        self.assertNotIn("MS:1000032", doc)
+        self.assertNotIn(fastobo.term.TermFrame(fastobo.id.PrefixedIdent("MS", "1000032")), doc)
+        self.assertRaises(TypeError, doc.__contains__, 1)
+
+    def test_views(self):
+        doc = self.type(self.header, self.entities)
+        self.assertEqual(len(doc.terms), 1)
+        self.assertEqual(list(doc.terms), [self.entities[0]])
+        self.assertEqual(len(doc.typedefs), 1)
+        self.assertEqual(list(doc.typedefs), [self.entities[1]])
+        self.assertEqual(len(doc.instances), 0)
+        self.assertEqual(list(doc.instances), [])
+        terms = doc.terms
+        doc.append(fastobo.term.TermFrame(fastobo.id.PrefixedIdent("MS", "1000032")))
+        self.assertEqual(len(terms), 2)
+
+    def test_iter(self):
+        doc = self.type(self.header, self.entities)
+        it = iter(doc)
+        self.assertIs(iter(it), it)
+        self.assertEqual(next(it), self.entities[0])
+        self.assertEqual(next(it), self.entities[1])
+        self.assertRaises(StopIteration, next, it)
+        self.assertEqual(list(doc), self.entities)
+
+    def test_setitem(self):
+        doc = self.type(self.header, self.entities)
+        frame = fastobo.term.TermFrame(fastobo.id.PrefixedIdent("MS", "1000032"))
+        doc[-1] = frame
+        self.assertEqual(list(doc), [self.entities[0], frame])
+        doc[1:] = self.entities
+        self.assertEqual(list(doc), [self.entities[0], *self.entities])
+        self.assertRaises(IndexError, doc.__setitem__, 3, frame)
+        self.assertRaises(TypeError, doc.__setitem__, 0, 1)
+
+    def test_delitem(self):
+        doc = self.type(self.header, self.entities)
+        del doc[-1]
+        self.assertEqual(list(doc), self.entities[:1])
+        self.assertRaises(IndexError, doc.__delitem__, 1)
+        doc = self.type(self.header, self.entities)
+        del doc[:1]
+        self.assertEqual(list(doc), self.entities[1:])
+
+    def test_reversed(self):
+        doc = self.type(self.header, self.entities)
+        self.assertEqual(list(reversed(doc)), self.entities[::-1])
+
+    @unittest.skipUnless(networkx, "networkx is not installed")
+    def test_to_networkx(self):
+        doc = fastobo.loads(
+            "ontology: tst\n\n"
+            "[Term]\nid: TST:001\nname: first\n\n"
+            "[Term]\nid: TST:002\nname: second\nis_a: TST:001\n"
+            "relationship: part_of TST:001\nsynonym: \"2nd\" EXACT []\n\n"
+            "[Typedef]\nid: part_of\nname: part of\n"
+        )
+        graph = doc.to_networkx()
+        self.assertIsInstance(graph, networkx.MultiDiGraph)
+        self.assertEqual(graph.graph["ontology"], "tst")
+        self.assertEqual(graph.graph["typedefs"], [{"id": "part_of", "name": "part of"}])
+        self.assertEqual(set(graph.nodes), {"TST:001", "TST:002"})
+        self.assertEqual(graph.nodes["TST:002"]["name"], "second")
+        self.assertEqual(graph.nodes["TST:002"]["synonym"], ['"2nd" EXACT []'])
+        self.assertEqual(
+            sorted(graph.out_edges("TST:002", keys=True)),
+            [("TST:002", "TST:001", "is_a"), ("TST:002", "TST:001", "part_of")]
+        )
+
+    @unittest.skipUnless(pyarrow, "pyarrow is not installed")
+    def test_to_arrow(self):
+        doc = fastobo.loads(
+            "[Term]\nid: TST:001\nname: first\nnamespace: test\n\n"
+            "[Term]\nid: TST:002\ndef: \"The second.\" []\nis_a: TST:001\n"
+            "is_obsolete: true\n\n"
+            "[Typedef]\nid: part_of\n"
+        )
+        batch = doc.to_arrow()
+        self.assertIsInstance(batch, pyarrow.RecordBatch)
+        self.assertEqual(batch.to_pydict(), {
+            "id": ["TST:001", "TST:002"],
+            "name": ["first", None],
+            "namespace": ["test", None],
+            "definition": [None, "The second."],
+            "is_obsolete": [False, True],
+            "parents": [[], ["TST:001"]],
+        })
+
+    def test_to_records(self):
+        doc = fastobo.loads(
+            "[Term]\nid: TST:001\nname: first\nxref: TST:003 \"third\"\n\n"
+            "[Typedef]\nid: part_of\nsynonym: \"part of\" EXACT [TST:004]\n"
+        )
+        self.assertEqual(doc.to_records(), [
+            {
+                "id": "TST:001",
+                "type": "Term",
+                "name": "first",
+                "namespace": None,
+                "definition": None,
+                "is_obsolete": False,
+                "parents": [],
+            },
+            {
+                "id": "part_of",
+                "type": "Typedef",
+                "name": None,
+                "namespace": None,
+                "definition": None,
+                "is_obsolete": False,
+                "parents": [],
+            },
+        ])
+        self.assertEqual(doc.to_records("synonyms"), [
+            {"id": "part_of", "synonym": "part of", "scope": "EXACT", "type": None, "xrefs": ["TST:004"]},
+        ])
+        self.assertEqual(doc.to_records("xrefs"), [
+            {"id": "TST:001", "xref": "TST:003", "description": "third"},
+        ])
+        self.assertRaises(ValueError, doc.to_records, "clauses")
+
+    @unittest.skipUnless(pandas, "pandas is not installed")
+    def test_to_pandas(self):
+        doc = fastobo.loads("[Term]\nid: TST:001\nname: first\n")
+        df = doc.to_pandas()
+        self.assertIsInstance(df, pandas.DataFrame)
+        self.assertEqual(list(df["name"]), ["first"])
+        self.assertEqual(list(doc.to_pandas("xrefs").columns), ["id", "xref", "description"])
+
+    @unittest.skipUnless(pyhornedowl, "pyhornedowl is not installed")
+    def test_to_pyhornedowl(self):
+        doc = fastobo.loads(
+            "ontology: tst\ndefault-namespace: tst\n\n"
+            "[Term]\nid: TST:001\nname: first\n\n"
+            "[Term]\nid: TST:002\nis_a: TST:001\n"
+        )
+        ont = doc.to_pyhornedowl(version_iri="http://example.com/tst/1.0/tst.owl")
+        self.assertEqual(ont.get_iri(), "http://purl.obolibrary.org/obo/tst.owl")
+        self.assertEqual(ont.get_version_iri(), "http://example.com/tst/1.0/tst.owl")
+        self.assertIn(
+            "http://purl.obolibrary.org/obo/TST_001",
+            ont.get_superclasses("http://purl.obolibrary.org/obo/TST_002"),
+        )
+
+    @unittest.skipUnless(rdflib, "rdflib is not installed")
+    def test_to_rdflib(self):
+        doc = fastobo.loads(
+            "ontology: tst\ndefault-namespace: test\n\n"
+            "[Term]\nid: TST:001\nname: first\n\n"
+            "[Term]\nid: TST:002\nis_a: TST:001\nrelationship: part_of TST:001 {source=\"TST:003\"}\n"
+        )
+        graph = rdflib.Graph()
+        self.assertIs(doc.to_rdflib(graph), graph)
+        tst1 = rdflib.URIRef("http://purl.obolibrary.org/obo/TST_001")
+        tst2 = rdflib.URIRef("http://purl.obolibrary.org/obo/TST_002")
+        self.assertIn((tst1, rdflib.RDF.type, rdflib.OWL.Class), graph)
+        self.assertEqual(str(graph.value(tst1, rdflib.RDFS.label)), "first")
+        self.assertIn((tst2, rdflib.RDFS.subClassOf, tst1), graph)
+        restrictions = [
+            r for r in graph.objects(tst2, rdflib.RDFS.subClassOf)
+            if isinstance(r, rdflib.BNode)
+        ]
+        self.assertEqual(len(restrictions), 1)
+        self.assertEqual(graph.value(restrictions[0], rdflib.OWL.someValuesFrom), tst1)
+        axioms = [
+            a for a in graph.subjects(rdflib.OWL.annotatedTarget, restrictions[0])
+        ]
+        self.assertEqual(len(axioms), 1)
+        self.assertEqual(graph.value(axioms[0], rdflib.OWL.annotatedSource), tst2)
+
+    def test_ancestors(self):
+        doc = fastobo.loads(
+            "[Term]\nid: TST:001\n\n"
+            "[Term]\nid: TST:002\nis_a: TST:001\n\n"
+            "[Term]\nid: TST:003\nis_a: TST:002\nrelationship: part_of TST:004\n\n"
+            "[Term]\nid: TST:004\n"
+        )
+        ancestors = doc.ancestors("TST:003")
+        self.assertEqual(set(map(str, ancestors)), {"TST:001", "TST:002"})
+        ancestors = doc.ancestors(fastobo.id.PrefixedIdent("TST", "003"), ["is_a", "part_of"])
+        self.assertEqual(set(map(str, ancestors)), {"TST:001", "TST:002", "TST:004"})
+        self.assertEqual(doc.ancestors("TST:001"), set())
+        self.assertRaises(SyntaxError, doc.ancestors, "not an id")
+        self.assertRaises(TypeError, doc.ancestors, 1)
+
+    def test_descendants(self):
+        doc = fastobo.loads(
+            "[Term]\nid: TST:001\n\n"
+            "[Term]\nid: TST:002\nis_a: TST:001\n\n"
+            "[Term]\nid: TST:003\nis_a: TST:002\n"
+        )
+        descendants = doc.descendants("TST:001")
+        self.assertEqual(set(map(str, descendants)), {"TST:002", "TST:003"})
+        closure = doc.subclasses_closure()
+        self.assertEqual(
+            {str(k): set(map(str, v)) for k, v in closure.items()},
+            {"TST:001": {"TST:002", "TST:003"}, "TST:002": {"TST:003"}},
+        )
+
+    def test_ancestors_modified(self):
+        doc = fastobo.loads(
+            "[Term]\nid: TST:001\n\n"
+            "[Term]\nid: TST:002\nis_a: TST:001\n\n"
+            "[Term]\nid: TST:003\n"
+        )
+        self.assertEqual(doc.ancestors("TST:003"), set())
+        frame = doc[2]
+        frame.append(fastobo.term.IsAClause(fastobo.id.PrefixedIdent("TST", "002")))
+        self.assertEqual(set(map(str, doc.ancestors("TST:003"))), {"TST:001", "TST:002"})
+        doc[1][0].term = fastobo.id.PrefixedIdent("TST", "004")
+        self.assertEqual(set(map(str, doc.ancestors("TST:003"))), {"TST:002", "TST:004"})
+        frame[0].term.local = "001"
+        self.assertEqual(set(map(str, doc.ancestors("TST:003"))), {"TST:001"})
+        del doc[2]
+        self.assertEqual(doc.ancestors("TST:003"), set())
+
+    def test_extract_subset(self):
+        doc = fastobo.loads(
+            "[Term]\nid: TST:001\n\n"
+            "[Term]\nid: TST:002\nis_a: TST:001\n\n"
+            "[Term]\nid: TST:003\nis_a: TST:002\nrelationship: part_of TST:001\n"
+            "relationship: has_part TST:004\n\n"
+            "[Term]\nid: TST:004\n\n"
+            "[Typedef]\nid: part_of\n\n"
+            "[Typedef]\nid: has_part\n"
+        )
+        subset = doc.extract_subset(["TST:003"])
+        self.assertEqual([str(f.id) for f in subset], ["TST:001", "TST:002", "TST:003", "part_of"])
+        self.assertEqual(len(subset[2]), 2)
+        subset = doc.extract_subset(["TST:001", "TST:003"], closure="none")
+        self.assertEqual([str(f.id) for f in subset], ["TST:001", "TST:003", "part_of"])
+        self.assertEqual(subset[1][0], fastobo.term.IsAClause(fastobo.id.PrefixedIdent("TST", "001")))
+        subset = doc.extract_subset(["TST:002"], closure="descendants")
+        self.assertEqual([str(f.id) for f in subset], ["TST:002", "TST:003"])
+        self.assertRaises(ValueError, doc.extract_subset, ["TST:001"], closure="other")
+
+    def test_apply_replacements(self):
+        doc = fastobo.loads(
+            "[Term]\nid: TST:001\nis_a: TST:002\nrelationship: part_of TST:003\n\n"
+            "[Term]\nid: TST:002\nis_obsolete: true\nreplaced_by: TST:005\n\n"
+            "[Term]\nid: TST:003\nis_obsolete: true\nconsider: TST:004\nconsider: TST:005\n\n"
+            "[Term]\nid: TST:006\nis_obsolete: true\nreplaced_by: TST:002\n\n"
+            "[Term]\nid: TST:007\nis_a: TST:006\n"
+        )
+        unresolved = doc.apply_replacements()
+        self.assertEqual(len(unresolved), 1)
+        frame, obsolete, consider = unresolved[0]
+        self.assertEqual(str(frame), "TST:001")
+        self.assertEqual(str(obsolete), "TST:003")
+        self.assertEqual([str(id) for id in consider], ["TST:004", "TST:005"])
+        self.assertEqual(str(doc[0][0]), "is_a: TST:005")
+        self.assertEqual(str(doc[0][1]), "relationship: part_of TST:003")
+        self.assertEqual(str(doc[4][0]), "is_a: TST:005")
+        self.assertEqual(str(doc[3][1]), "replaced_by: TST:002")
+
+    def test_apply_replacements_objects(self):
+        doc = fastobo.loads(
+            "[Term]\nid: TST:001\nname: test\nis_a: TST:002\n\n"
+            "[Term]\nid: TST:002\nis_obsolete: true\nreplaced_by: TST:003\n"
+        )
+        frame, name, is_a = doc[0], doc[0][0], doc[0][1]
+        is_a.comments = ["to be reviewed"]
+        doc.apply_replacements()
+        self.assertIs(doc[0], frame)
+        self.assertIs(doc[0][0], name)
+        self.assertIs(doc[0][1], is_a)
+        self.assertEqual(str(is_a), "is_a: TST:003")
+        self.assertEqual(is_a.comments, ["to be reviewed"])
+
+    def test_rename(self):
+        doc = fastobo.loads(
+            "subsetdef: TST:001 \"not really a subset\"\n\n"
+            "[Term]\nid: TST:001\nname: test\n\n"
+            "[Term]\nid: TST:002\nis_a: TST:001\nrelationship: part_of TST:001\n"
+            "xref: TST:001\n"
+        )
+        doc.rename("TST:001", fastobo.id.PrefixedIdent("TST", "100"))
+        self.assertEqual(str(doc.header[0]), 'subsetdef: TST:100 "not really a subset"')
+        self.assertEqual(str(doc[0].id), "TST:100")
+        self.assertEqual(str(doc[0][1]), "alt_id: TST:001")
+        self.assertEqual(
+            [str(clause) for clause in doc[1]],
+            ["is_a: TST:100", "relationship: part_of TST:100", "xref: TST:100"],
+        )
+        doc.rename("TST:002", "TST:200", keep_alt_id=False)
+        self.assertEqual(len(doc[1]), 3)
+        self.assertRaises(ValueError, doc.rename, "TST:100", "TST:200")
+
+    def test_rename_objects(self):
+        doc = fastobo.loads(
+            "[Term]\nid: TST:001\nname: test\ndef: \"a test\" []\n\n"
+            "[Term]\nid: TST:002\nis_a: TST:001\n"
+        )
+        frame, name, definition = doc[0], doc[0][0], doc[0][1]
+        is_a = doc[1][0]
+        is_a.comments = ["to be reviewed"]
+        doc.rename("TST:001", "TST:100")
+        self.assertIs(doc[0], frame)
+        self.assertEqual(str(frame.id), "TST:100")
+        self.assertEqual(len(frame), 3)
+        self.assertIs(frame[0], name)
+        self.assertEqual(str(frame[1]), "alt_id: TST:001")
+        self.assertIs(frame[2], definition)
+        self.assertIs(doc[1][0], is_a)
+        self.assertEqual(str(is_a), "is_a: TST:100")
+        self.assertEqual(is_a.comments, ["to be reviewed"])
+
+    def test_remap_prefixes(self):
+        doc = fastobo.loads(
+            "idspace: TST http://example.com/tst/\n\n"
+            "[Term]\nid: TST:001\nis_a: TST:002\nxref: OTHER:001\n\n"
+            "[Typedef]\nid: part_of\n"
+        )
+        doc.remap_prefixes({"TST": "NEW"})
+        self.assertEqual(str(doc.header[0]), "idspace: NEW http://example.com/tst/")
+        self.assertEqual(doc[0].id, fastobo.id.PrefixedIdent("NEW", "001"))
+        self.assertEqual(str(doc[0][0]), "is_a: NEW:002")
+        self.assertEqual(str(doc[0][1]), "xref: OTHER:001")
+        self.assertEqual(str(doc[1].id), "part_of")
+
+    def test_remap_prefixes_objects(self):
+        doc = fastobo.loads(
+            "idspace: TST http://example.com/tst/\n\n"
+            "[Term]\nid: TST:001\nis_a: TST:002\nxref: OTHER:001\n"
+        )
+        idspace, frame = doc.header[0], doc[0]
+        is_a, xref = frame[0], frame[1]
+        idspace.comments = ["test prefix"]
+        doc.remap_prefixes({"TST": "NEW"})
+        self.assertIs(doc.header[0], idspace)
+        self.assertEqual(idspace.comments, ["test prefix"])
+        self.assertIs(doc[0], frame)
+        self.assertIs(frame[0], is_a)
+        self.assertIs(frame[1], xref)
+        self.assertEqual(str(is_a), "is_a: NEW:002")
+
+    def test_compact_ids_inplace(self):
+        doc = fastobo.loads(
+            "idspace: MassBank http://www.massbank.jp/jsp/FwdRecord.jsp?id=\n\n"
+            "[Term]\nid: http://purl.obolibrary.org/obo/CHEBI_27958\n"
+            "xref: http://www.massbank.jp/jsp/FwdRecord.jsp?id=EA281701\n"
+        )
+        expected = str(doc.compact_ids())
+        self.assertIsNone(doc.compact_ids(inplace=True))
+        self.assertEqual(str(doc), expected)
+        self.assertEqual(doc[0].id, fastobo.id.PrefixedIdent("CHEBI", "27958"))
+        self.assertIsNone(doc.decompact_ids(inplace=True))
+        self.assertEqual(str(doc[0][0]), "xref: http://www.massbank.jp/jsp/FwdRecord.jsp?id=EA281701")
+        doc.freeze()
+        self.assertRaises(TypeError, doc.compact_ids, inplace=True)
+        self.assertRaises(TypeError, doc.decompact_ids, inplace=True)
+
+    def test_compact_ids_inplace_objects(self):
+        doc = fastobo.loads(
+            "idspace: MassBank http://www.massbank.jp/jsp/FwdRecord.jsp?id=\n\n"
+            "[Term]\nid: http://purl.obolibrary.org/obo/CHEBI_27958\n"
+            "name: cocaine\n"
+            "xref: http://www.massbank.jp/jsp/FwdRecord.jsp?id=EA281701\n",
+            preserve_comments=True,
+        )
+        frame = doc[0]
+        name, xref = frame[0], frame[1]
+        frame.comments = ["first term"]
+        xref.comments = ["to be reviewed"]
+        doc.compact_ids(inplace=True)
+        self.assertIs(doc[0], frame)
+        self.assertIs(doc[0][0], name)
+        self.assertIs(doc[0][1], xref)
+        self.assertEqual(str(xref), "xref: MassBank:EA281701")
+        self.assertEqual(frame.comments, ["first term"])
+        self.assertEqual(xref.comments, ["to be reviewed"])
+
+    def test_map_idents(self):
+        doc = fastobo.loads(
+            "[Term]\nid: TST:001\n\n"
+            "[Term]\nid: TST:002\nis_a: TST:001\nxref: TST:003\n"
+        )
+        mapped = doc.map_idents({
+            "TST:001": "NEW:001",
+            fastobo.id.PrefixedIdent("TST", "003"): fastobo.id.PrefixedIdent("NEW", "003"),
+        })
+        self.assertEqual(mapped[0].id, fastobo.id.PrefixedIdent("NEW", "001"))
+        self.assertEqual(str(mapped[1][0]), "is_a: NEW:001")
+        self.assertEqual(str(mapped[1][1]), "xref: NEW:003")
+        self.assertEqual(doc[0].id, fastobo.id.PrefixedIdent("TST", "001"))
+
+    def test_map_idents_callable(self):
+        doc = fastobo.loads("[Term]\nid: TST:001\n\n[Term]\nid: TST:002\nis_a: TST:001\n")
+        def mapping(id):
+            if id == fastobo.id.PrefixedIdent("TST", "001"):
+                return "NEW:001"
+        mapped = doc.map_idents(mapping)
+        self.assertEqual(mapped[0].id, fastobo.id.PrefixedIdent("NEW", "001"))
+        self.assertEqual(mapped[1].id, fastobo.id.PrefixedIdent("TST", "002"))
+        self.assertEqual(str(mapped[1][0]), "is_a: NEW:001")
+        def failing(id):
+            raise RuntimeError("failed")
+        self.assertRaises(RuntimeError, doc.map_idents, failing)
+        self.assertRaises(TypeError, doc.map_idents, 1)
+
+    def test_prefix_map(self):
+        doc = fastobo.loads(
+            "idspace: TST http://example.com/tst/\n"
+            "ontology: tst\n"
+        )
+        prefixes = doc.prefix_map()
+        self.assertIsInstance(prefixes, dict)
+        self.assertEqual(prefixes["TST"], "http://example.com/tst/")
+        self.assertIn("oboInOwl", prefixes)
+        self.assertNotIn("TST", fastobo.doc.OboDoc().prefix_map())
+
+    def test_filter(self):
+        doc = fastobo.loads(
+            "[Term]\nid: GO:001\nnamespace: biological_process\n\n"
+            "[Term]\nid: GO:002\nnamespace: cellular_component\n\n"
+            "[Term]\nid: CHEBI:001\n\n"
+            "[Typedef]\nid: part_of\nnamespace: biological_process\n"
+        )
+        self.assertEqual(len(doc.filter()), 4)
+        filtered = doc.filter(prefixes=["CHEBI"])
+        self.assertEqual([str(f.id) for f in filtered], ["CHEBI:001"])
+        filtered = doc.filter(namespaces="biological_process")
+        self.assertEqual([str(f.id) for f in filtered], ["GO:001", "part_of"])
+        filtered = doc.filter(prefixes={"CHEBI"}, namespaces=["cellular_component"])
+        self.assertEqual([str(f.id) for f in filtered], ["GO:002", "CHEBI:001"])
+        self.assertEqual(len(doc), 4)
+
+    def test_to_dict(self):
+        doc = fastobo.loads(
+            "format-version: 1.4\n\n"
+            "[Term]\nid: TST:001\nname: test\nis_a: TST:002\n\n"
+            "[Typedef]\nid: part_of\n"
+        )
+        d = doc.to_dict()
+        self.assertEqual(d["header"]["clauses"], [{"tag": "format-version", "value": "1.4"}])
+        self.assertEqual([e["type"] for e in d["entities"]], ["Term", "Typedef"])
+        self.assertEqual(json.loads(json.dumps(d)), d)
+        self.assertEqual(str(fastobo.doc.OboDoc.from_dict(d)), str(doc))
+        d["entities"][0]["clauses"].append({"tag": "is_a", "value": ""})
+        self.assertRaises(SyntaxError, fastobo.doc.OboDoc.from_dict, d)
+
+    def test_sizeof(self):
+        empty = self.type(self.header)
+        doc = self.type(self.header, self.entities)
+        self.assertGreater(sys.getsizeof(doc), sys.getsizeof(empty))
+        self.assertGreater(sys.getsizeof(empty), sys.getsizeof(self.header))
+
+    def test_copy(self):
+        doc = fastobo.loads("[Term]\nid: TST:001\nname: test\n")
+        copy_ = copy.copy(doc)
+        self.assertIsNot(copy_, doc)
+        self.assertIs(copy_[0], doc[0])
+        deepcopy = copy.deepcopy(doc)
+        self.assertEqual(str(deepcopy), str(doc))
+        self.assertIsNot(deepcopy[0], doc[0])
+        deepcopy[0][0].name = "other"
+        self.assertEqual(doc[0][0].name, "test")
+
+    def test_freeze(self):
+        doc = fastobo.loads("format-version: 1.4\n\n[Term]\nid: TST:001\n")
+        self.assertFalse(doc.frozen)
+        self.assertRaises(TypeError, hash, doc)
+        doc.freeze()
+        self.assertTrue(doc.frozen)
+        self.assertTrue(doc.header.frozen)
+        self.assertTrue(doc[0].frozen)
+        self.assertEqual(len({doc, doc}), 1)
+        self.assertRaises(TypeError, doc.append, fastobo.term.TermFrame(fastobo.id.PrefixedIdent("TST", "002")))
+        self.assertRaises(TypeError, doc.header.clear)
+        self.assertRaises(TypeError, doc.rename, "TST:001", "TST:002")
+        self.assertRaises(TypeError, setattr, doc, "header", fastobo.header.HeaderFrame())
+        self.assertEqual(str(doc.compact_ids()), str(doc))
+        self.assertFalse(doc.compact_ids().frozen)
+
+    def test_freeze_clauses(self):
+        doc = fastobo.loads(
+            '[Term]\n'
+            'id: TST:001\n'
+            'def: "a term" [PMID:1]\n'
+            'synonym: "thing" EXACT [PMID:2]\n'
+            'is_a: TST:002 {source="TST:003"}\n'
+            'property_value: dc:title "Term" xsd:string\n'
+        )
+        doc.freeze()
+        frame = doc[0]
+        self.assertTrue(all(clause.frozen for clause in frame))
+        self.assertRaises(TypeError, setattr, frame[0], "definition", "other")
+        self.assertRaises(TypeError, setattr, frame[0].xrefs[0], "desc", "other")
+        self.assertRaises(TypeError, setattr, frame[1].synonym, "desc", "other")
+        self.assertRaises(TypeError, frame[1].synonym.xrefs.clear)
+        self.assertRaises(TypeError, frame[2].qualifiers.clear)
+        self.assertRaises(TypeError, setattr, frame[2].qualifiers[0], "value", "TST:004")
+        self.assertRaises(TypeError, setattr, frame[3].property_value, "value", "other")
+        other = fastobo.loads(str(doc))
+        other.freeze()
+        self.assertEqual(hash(doc), hash(other))
+        self.assertEqual(hash(frame), hash(other[0]))
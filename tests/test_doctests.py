@@ -6,13 +6,25 @@ import os
 import sys
 import datetime
 import doctest
+import importlib.util
 import warnings
 import pprint
 import textwrap
 import types
+import unittest
 
 import fastobo
 
+# doctests requiring an optional dependency, skipped if it is not installed
+_REQUIREMENTS = {
+    "OboDoc.to_networkx": "networkx",
+    "OboDoc.to_pyhornedowl": "pyhornedowl",
+    "from_pyhornedowl": "pyhornedowl",
+    "OboDoc.to_arrow": "pyarrow",
+    "OboDoc.to_pandas": "pandas",
+    "OboDoc.to_rdflib": "rdflib",
+}
+
 
 def _load_tests_from_module(tests, module, globs, setUp=None, tearDown=None):
     """Load tests from module, iterating through submodules"""
@@ -41,6 +53,9 @@ def load_tests(loader, tests, ignore):
     _current_cwd = os.getcwd()
 
     def setUp(self):
+        for name, module in _REQUIREMENTS.items():
+            if self.name.endswith(name) and importlib.util.find_spec(module) is None:
+                raise unittest.SkipTest("{} is not installed".format(module))
         warnings.simplefilter("ignore")
         os.chdir(os.path.realpath(os.path.join(__file__, "..", "data")))
 
@@ -1,16 +1,95 @@
 # coding: utf-8
 
+import copy
+import csv
+import datetime
+import gzip
 import io
+import json
 import os
+import sqlite3
+import tempfile
 import unittest
 
 import fastobo
 
+try:
+    import pyhornedowl
+except ImportError:
+    pyhornedowl = None
+
 MS = os.path.realpath(os.path.join(__file__, "..", "data", "ms.obo"))
 MS_FRAMES = 2941
 
+
+class PartialReader(object):
+    """A non-blocking reader returning short chunks or no data at all.
+    """
+
+    def __init__(self, data, chunk_size=7):
+        self.f = io.BytesIO(data)
+        self.chunk_size = chunk_size
+        self.calls = 0
+
+    def read(self, n=-1):
+        self.calls += 1
+        if n != 0 and self.calls % 3 == 0:
+            return None
+        return self.f.read(min(n, self.chunk_size) if n > 0 else n)
+
+
 class TestLoad(unittest.TestCase):
 
+    def test_tags(self):
+        doc = fastobo.load(MS, tags=("name",))
+        self.assertEqual(len(doc), MS_FRAMES)
+        self.assertTrue(all(
+            clause.raw_tag() == "name"
+            for frame in doc
+            for clause in frame
+        ))
+        self.assertRaises(ValueError, fastobo.load, MS, tags=("name",), locations=True)
+
+    def test_buffer_size(self):
+        with open(MS, 'rb') as f:
+            doc = fastobo.load(f, buffer_size=16)
+        self.assertEqual(len(doc), MS_FRAMES)
+        self.assertRaises(ValueError, fastobo.load, MS, buffer_size=0)
+
+    def test_readinto(self):
+        with open(MS, 'rb') as f:
+            raw = f.raw
+            self.assertTrue(hasattr(raw, "readinto"))
+            doc = fastobo.load(raw)
+        self.assertEqual(len(doc), MS_FRAMES)
+
+    def test_read_bytearray(self):
+        class BytearrayReader(object):
+            def __init__(self, data):
+                self.f = io.BytesIO(data)
+            def read(self, n=-1):
+                return bytearray(self.f.read(n))
+
+        with open(MS, 'rb') as f:
+            doc = fastobo.load(BytearrayReader(f.read()))
+        self.assertEqual(len(doc), MS_FRAMES)
+
+    def test_partial_read(self):
+        with open(MS, 'rb') as f:
+            doc = fastobo.load(PartialReader(f.read()))
+        self.assertEqual(len(doc), MS_FRAMES)
+
+    def test_oversized_read(self):
+        class OversizedReader(object):
+            def __init__(self, data):
+                self.f = io.BytesIO(data)
+            def read(self, n=-1):
+                return self.f.read(n * 2 + 1 if n > 0 else n)
+
+        with open(MS, 'rb') as f:
+            doc = fastobo.load(OversizedReader(f.read()))
+        self.assertEqual(len(doc), MS_FRAMES)
+
     def test_file_not_found(self):
         self.assertRaises(FileNotFoundError, fastobo.load, "abcdef")
 
@@ -18,8 +97,10 @@ class TestLoad(unittest.TestCase):
         self.assertRaises(TypeError, fastobo.load, 1)
         self.assertRaises(TypeError, fastobo.load, [])
 
+    def test_text_mode(self):
         with open(MS) as f:
-            self.assertRaises(TypeError, fastobo.load, f)
+            doc = fastobo.load(f)
+        self.assertEqual(len(doc), MS_FRAMES)
 
     def test_error_propagation(self):
 
@@ -62,6 +143,41 @@ class TestLoad(unittest.TestCase):
     def test_threading_invalid(self):
         self.assertRaises(ValueError, fastobo.load, MS, threads=-1)
 
+    def test_preserve_comments_footer(self):
+        text = (
+            "[Term]\n"
+            "id: TST:001\n"
+            "\n"
+            "! first note\n"
+            "\n"
+            "! second note\n"
+        )
+        doc = fastobo.loads(text, preserve_comments=True)
+        self.assertEqual(doc.footer, ["first note", "", "second note"])
+        self.assertEqual(str(doc), text)
+        doc.footer = []
+        self.assertNotIn("note", str(doc))
+
+    def test_preserve_comments_blank_lines(self):
+        text = (
+            "format-version: 1.4\n"
+            "\n"
+            "! first term\n"
+            "\n"
+            "[Term]\n"
+            "id: TST:001\n"
+        )
+        doc = fastobo.loads(text, preserve_comments=True)
+        self.assertEqual(doc[0].comments, ["first term", ""])
+        self.assertEqual(str(doc), text)
+
+    def test_locations(self):
+        doc = fastobo.load(MS, locations=True)
+        file, start, end = doc[0].location
+        self.assertEqual(file, MS)
+        self.assertLessEqual(start, end)
+        self.assertLess(doc[0].location[2], doc[1].location[1])
+
 
 class TestIter(unittest.TestCase):
 
@@ -72,8 +188,16 @@ class TestIter(unittest.TestCase):
         self.assertRaises(TypeError, fastobo.iter, 1)
         self.assertRaises(TypeError, fastobo.iter, [])
 
+    def test_text_mode(self):
         with open(MS) as f:
-            self.assertRaises(TypeError, fastobo.iter, f)
+            frame_count = sum(1 for _ in fastobo.iter(f))
+        self.assertEqual(frame_count, MS_FRAMES)
+
+    def test_partial_read(self):
+        with open(MS, 'rb') as f:
+            reader = PartialReader(f.read())
+        frame_count = sum(1 for _ in fastobo.iter(reader))
+        self.assertEqual(frame_count, MS_FRAMES)
 
     def test_syntax_error(self):
         f = io.BytesIO(b"format-version: 1.4\ndate: 05:20:2021 12:00\n")
@@ -106,6 +230,42 @@ class TestIter(unittest.TestCase):
     def test_threading_invalid(self):
         self.assertRaises(ValueError, fastobo.iter, MS, threads=-1)
 
+    def test_prefixes(self):
+        frames = list(fastobo.iter(MS, prefixes=["PEFF"]))
+        self.assertEqual(len(frames), 58)
+        self.assertTrue(all(frame.id.prefix == "PEFF" for frame in frames))
+
+        with open(MS, 'rb') as f:
+            frames = list(fastobo.iter(f, prefixes=("MS", "")))
+            self.assertEqual(len(frames), MS_FRAMES - 58)
+
+    def test_prefixes_header(self):
+        f = io.BytesIO(b"format-version: 1.4\n\n[Term]\nid: A:1\n\n[Term]\nid: B:1\n")
+        reader = fastobo.iter(f, prefixes=["B"])
+        self.assertEqual(len(reader.header()), 1)
+        self.assertEqual([str(frame.id) for frame in reader], ["B:1"])
+
+    def test_tags(self):
+        for frame in fastobo.iter(MS, tags=["name", "is_a"]):
+            self.assertTrue(all(clause.raw_tag() in ("name", "is_a") for clause in frame))
+
+    def test_buffer_size(self):
+        frame_count = sum(1 for _ in fastobo.iter(MS, buffer_size=16))
+        self.assertEqual(frame_count, MS_FRAMES)
+        self.assertRaises(ValueError, fastobo.iter, MS, buffer_size=0)
+
+    def test_queue_size(self):
+        expected = [frame.id for frame in fastobo.iter(MS)]
+        with open(MS, 'rb') as f:
+            actual = [frame.id for frame in fastobo.iter(f, queue_size=4)]
+        self.assertEqual(actual, expected)
+        self.assertRaises(ValueError, fastobo.iter, MS, queue_size=0)
+
+    def test_queue_size_dropped(self):
+        reader = fastobo.iter(MS, queue_size=1)
+        self.assertIsNotNone(next(reader))
+        del reader
+
 class TestLoads(unittest.TestCase):
 
     @classmethod
@@ -127,3 +287,799 @@ class TestLoads(unittest.TestCase):
 
     def test_threading_invalid(self):
         self.assertRaises(ValueError, fastobo.loads, self.text, threads=-1)
+
+    def test_preserve_comments(self):
+        text = (
+            "format-version: 1.4\n"
+            "\n"
+            "! first term\n"
+            "[Term]\n"
+            "id: TST:001\n"
+            "! to be reviewed\n"
+            "name: test item\n"
+        )
+        doc = fastobo.loads(text, preserve_comments=True)
+        self.assertEqual(doc[0].comments, ["first term"])
+        self.assertEqual(doc[0][0].comments, ["to be reviewed"])
+        self.assertEqual(str(doc), text)
+        doc[0][0].comments = []
+        self.assertNotIn("to be reviewed", str(doc))
+
+    def test_locations(self):
+        text = (
+            "format-version: 1.4\n"
+            "\n"
+            "[Term]\n"
+            "id: TST:001\n"
+            "! a comment\n"
+            "name: test item\n"
+            "\n"
+            "[Typedef]\n"
+            "id: part_of\n"
+        )
+        doc = fastobo.loads(text, locations=True)
+        self.assertEqual(doc.header.location, (None, 1, 1))
+        self.assertEqual(doc.header[0].line, 1)
+        self.assertEqual(doc[0].location, (None, 3, 6))
+        self.assertEqual(doc[0][0].line, 6)
+        self.assertEqual(doc[1].location, (None, 8, 9))
+
+    def test_locations_default(self):
+        doc = fastobo.loads("[Term]\nid: TST:001\nname: test\n")
+        self.assertIs(doc[0].location, None)
+        self.assertIs(doc[0][0].line, None)
+
+    def test_preserve_comments_default(self):
+        doc = fastobo.loads("[Term]\nid: TST:001\n! comment\nname: test\n")
+        self.assertEqual(doc[0].comments, [])
+        self.assertEqual(doc[0][0].comments, [])
+        self.assertNotIn("comment", str(doc))
+
+    def test_str(self):
+        text = (
+            "format-version: 1.4\n"
+            "idspace: TST http://example.com/tst/ \"test\"\n"
+            "\n"
+            "[Term]\n"
+            "id: TST:001\n"
+            "def: \"a definition\" [PMID:123, ISBN:456 \"a book\"]\n"
+            "synonym: \"other\" EXACT []\n"
+            "is_a: TST:002 {source=\"TST:003\"}\n"
+            "intersection_of: part_of TST:003\n"
+            "property_value: IAO:0000114 \"x\" xsd:string\n"
+            "\n"
+            "[Typedef]\n"
+            "id: part_of\n"
+            "holds_over_chain: part_of part_of\n"
+        )
+        self.assertEqual(str(fastobo.loads(text)), text)
+
+    def test_intern_ids(self):
+        text = "[Term]\nid: TST:001\nis_a: TST:003\n\n[Term]\nid: TST:002\nis_a: TST:003\n"
+        doc = fastobo.loads(text, intern_ids=True)
+        self.assertIs(doc[0][0].term, doc[1][0].term)
+        doc = fastobo.loads(text)
+        self.assertIsNot(doc[0][0].term, doc[1][0].term)
+        self.assertEqual(doc[0][0].term, doc[1][0].term)
+
+    def test_string_pool(self):
+        text = "[Term]\nid: TST:001\nnamespace: test\n\n[Term]\nid: TST:002\nnamespace: test\n"
+        doc = fastobo.loads(text, string_pool=True)
+        self.assertIs(doc[0][0].namespace, doc[1][0].namespace)
+        doc = fastobo.loads(text)
+        self.assertIsNot(doc[0][0].namespace, doc[1][0].namespace)
+        self.assertEqual(doc[0][0].namespace, doc[1][0].namespace)
+
+    def test_string_pool_created_by(self):
+        text = "[Term]\nid: TST:001\ncreated_by: tester\n\n[Term]\nid: TST:002\ncreated_by: tester\n"
+        doc = fastobo.loads(text, string_pool=True)
+        self.assertEqual(doc[0][0].creator, "tester")
+        self.assertEqual(doc[0][0], doc[1][0])
+        doc[0][0].creator = "other"
+        self.assertEqual(doc[1][0].creator, "tester")
+        self.assertEqual(str(doc), text.replace("tester", "other", 1))
+
+
+class TestResolveImports(unittest.TestCase):
+
+    def setUp(self):
+        self.imported = fastobo.loads(
+            "import: main\n\n"
+            "[Term]\nid: TST:001\nname: first\n\n"
+            "[Term]\nid: TST:002\nname: second\n"
+        )
+        self.doc = fastobo.loads(
+            "import: tst\nformat-version: 1.4\n\n"
+            "[Term]\nid: TST:002\ndef: \"a definition\" []\n"
+        )
+
+    def test_loader(self):
+        loader = {"tst": self.imported, "main": self.doc}.__getitem__
+        resolved = fastobo.resolve_imports(self.doc, loader)
+        self.assertEqual(len(resolved.header), 1)
+        self.assertEqual(len(resolved), 2)
+        self.assertEqual(str(resolved[0].id), "TST:002")
+        self.assertEqual(len(resolved[0]), 2)
+        self.assertEqual(str(resolved[1].id), "TST:001")
+        # original documents are left untouched
+        self.assertEqual(len(self.doc.header), 2)
+        self.assertEqual(len(self.doc[0]), 1)
+
+    def test_import_cycle_to_root(self):
+        root = fastobo.loads(
+            "ontology: main\nimport: tst\n\n[Term]\nid: TST:002\n"
+        )
+        loaded = []
+        def loader(reference):
+            loaded.append(reference)
+            return self.imported
+        resolved = fastobo.resolve_imports(root, loader)
+        self.assertEqual(loaded, ["tst"])
+        self.assertEqual(len(resolved), 2)
+
+    def test_merge_instances(self):
+        imported = fastobo.loads(
+            "[Instance]\nid: TST:001\ninstance_of: TST:100\n\n"
+            "[Instance]\nid: TST:002\n"
+        )
+        doc = fastobo.loads("import: tst\n\n[Instance]\nid: TST:001\nname: first\n")
+        resolved = fastobo.resolve_imports(doc, {"tst": imported}.__getitem__)
+        self.assertEqual(len(resolved), 2)
+        self.assertIsInstance(resolved[0], fastobo.instance.InstanceFrame)
+        self.assertEqual(str(resolved[0].id), "TST:001")
+        self.assertEqual(len(resolved[0]), 2)
+        self.assertEqual(str(resolved[1].id), "TST:002")
+
+    def test_loader_type_error(self):
+        self.assertRaises(TypeError, fastobo.resolve_imports, self.doc, lambda ref: 1)
+
+    def test_remote_without_loader(self):
+        doc = fastobo.loads("import: http://purl.obolibrary.org/obo/go.obo\n")
+        self.assertRaises(ValueError, fastobo.resolve_imports, doc)
+
+    def test_local_file(self):
+        with tempfile.TemporaryDirectory() as tmp:
+            path = os.path.join(tmp, "tst.obo")
+            with open(path, "w") as f:
+                f.write("[Term]\nid: TST:001\n\n[Term]\nid: TST:002\n")
+            doc = fastobo.loads("import: file://{}\n".format(path))
+            resolved = fastobo.resolve_imports(doc)
+        self.assertEqual(len(resolved), 2)
+        self.assertEqual(str(resolved[0].id), "TST:001")
+
+    def test_remote_nested_without_loader(self):
+        doc = fastobo.loads("import: file://{}\n".format(MS))
+        self.assertRaises(ValueError, fastobo.resolve_imports, doc)
+
+    def test_relative_path(self):
+        with tempfile.TemporaryDirectory() as tmp:
+            os.mkdir(os.path.join(tmp, "imports"))
+            with open(os.path.join(tmp, "main.obo"), "w") as f:
+                f.write("import: imports/first.obo\n\n[Term]\nid: TST:001\n")
+            with open(os.path.join(tmp, "imports", "first.obo"), "w") as f:
+                f.write("import: second.obo\n\n[Term]\nid: TST:002\n")
+            with open(os.path.join(tmp, "imports", "second.obo"), "w") as f:
+                f.write("[Term]\nid: TST:003\n")
+            path = os.path.join(tmp, "main.obo")
+            doc = fastobo.load(path)
+            resolved = fastobo.resolve_imports(doc, path=path)
+            self.assertEqual([str(frame.id) for frame in resolved], ["TST:001", "TST:002", "TST:003"])
+            resolved = fastobo.load(path, resolve_imports=True)
+            self.assertEqual([str(frame.id) for frame in resolved], ["TST:001", "TST:002", "TST:003"])
+
+    def test_load_options(self):
+        with tempfile.TemporaryDirectory() as tmp:
+            with open(os.path.join(tmp, "main.obo"), "w") as f:
+                f.write("import: imported.obo\n\n[Term]\nid: TST:001\nname: first\ndef: \"a\" []\n")
+            with open(os.path.join(tmp, "imported.obo"), "w") as f:
+                f.write("[Term]\nid: TST:002\nname: second\ndef: \"b\" []\n")
+            path = os.path.join(tmp, "main.obo")
+            resolved = fastobo.load(path, tags={"name"}, resolve_imports=True)
+            self.assertEqual(len(resolved), 2)
+            self.assertEqual(len(resolved[0]), 1)
+            self.assertEqual(len(resolved[1]), 1)
+            self.assertEqual(resolved[1][0].raw_tag(), "name")
+
+    def test_load_url_loader(self):
+        documents = {"http://example.com/tst.obo": b"[Term]\nid: TST:001\n"}
+        doc = fastobo.loads("import: http://example.com/tst.obo\n")
+        loader = lambda url: fastobo.load_url(url, documents.__getitem__)
+        resolved = fastobo.resolve_imports(doc, loader)
+        self.assertEqual(len(resolved), 1)
+        self.assertEqual(str(resolved[0].id), "TST:001")
+
+
+class TestLoadUrl(unittest.TestCase):
+
+    URL = "http://example.com/ms.obo"
+
+    def setUp(self):
+        with open(MS, 'rb') as f:
+            self.data = f.read()
+
+    def test_bytes(self):
+        doc = fastobo.load_url(self.URL, lambda url: self.data)
+        self.assertEqual(len(doc), MS_FRAMES)
+
+    def test_file_handle(self):
+        doc = fastobo.load_url(self.URL, lambda url: io.BytesIO(self.data))
+        self.assertEqual(len(doc), MS_FRAMES)
+
+    def test_gzip(self):
+        data = gzip.compress(self.data)
+        doc = fastobo.load_url(self.URL + ".gz", lambda url: data)
+        self.assertEqual(len(doc), MS_FRAMES)
+
+    def test_fetcher_arguments(self):
+        urls = []
+        def fetcher(url):
+            urls.append(url)
+            return self.data
+        fastobo.load_url(self.URL, fetcher)
+        self.assertEqual(urls, [self.URL])
+
+    def test_content_type(self):
+        class Response(io.BytesIO):
+            headers = {"Content-Type": "application/json; charset=utf-8"}
+
+        doc = fastobo.loads("[Term]\nid: TST:001\nname: test\n")
+        buffer = io.BytesIO()
+        fastobo.dump_graph(doc, buffer)
+        # the URL extension is ignored when the content type is known
+        url = "http://example.com/tst.obo"
+        loaded = fastobo.load_url(url, lambda url: Response(buffer.getvalue()))
+        self.assertEqual(str(loaded[0].id), "TST:001")
+
+    def test_detect_content(self):
+        doc = fastobo.loads("[Term]\nid: TST:001\n")
+        buffer = io.BytesIO()
+        fastobo.dump_graph(doc, buffer)
+        loaded = fastobo.load_url("http://example.com/tst", lambda url: buffer.getvalue())
+        self.assertEqual(str(loaded[0].id), "TST:001")
+
+    def test_type_error(self):
+        self.assertRaises(TypeError, fastobo.load_url, self.URL, lambda url: 1)
+
+    def test_syntax_error(self):
+        fetcher = lambda url: b"hello there"
+        self.assertRaises(SyntaxError, fastobo.load_url, self.URL, fetcher)
+
+    def test_unreadable_format(self):
+        fetcher = lambda url: b"Prefix(:=<http://example.com/>)"
+        self.assertRaises(ValueError, fastobo.load_url, "http://example.com/tst.ofn", fetcher)
+
+
+class TestDiff(unittest.TestCase):
+
+    def test_diff(self):
+        old = fastobo.loads(
+            "format-version: 1.4\n\n"
+            "[Term]\nid: TST:001\nname: first\ncomment: old\n\n"
+            "[Term]\nid: TST:002\n\n"
+            "[Term]\nid: TST:003\nname: third\n"
+        )
+        new = fastobo.loads(
+            "format-version: 1.4\n\n"
+            "[Term]\nid: TST:001\nname: first\nis_a: TST:003\ncomment: new\n\n"
+            "[Term]\nid: TST:003\nname: third\n\n"
+            "[Term]\nid: TST:004\n"
+        )
+        changes = fastobo.diff(old, new)
+        self.assertEqual([(str(c.id), c.status) for c in changes], [
+            ("TST:001", "modified"),
+            ("TST:002", "removed"),
+            ("TST:004", "added"),
+        ])
+        modified = changes[0]
+        self.assertEqual(modified.added, [fastobo.term.IsAClause(fastobo.id.PrefixedIdent("TST", "003"))])
+        self.assertEqual(modified.removed, [])
+        self.assertEqual(modified.modified, [
+            (fastobo.term.CommentClause("old"), fastobo.term.CommentClause("new"))
+        ])
+        self.assertIsNone(changes[1].new)
+        self.assertIsNone(changes[2].old)
+
+    def test_diff_instance(self):
+        old = fastobo.loads(
+            "[Instance]\nid: TST:001\ninstance_of: TST:100\ncomment: old\n"
+        )
+        new = fastobo.loads(
+            "[Instance]\nid: TST:001\ninstance_of: TST:100\n"
+            "instance_of: TST:200\ncomment: new\n"
+        )
+        changes = fastobo.diff(old, new)
+        self.assertEqual([(str(c.id), c.status) for c in changes], [
+            ("TST:001", "modified"),
+        ])
+        self.assertEqual(changes[0].added, [
+            fastobo.instance.InstanceOfClause(fastobo.id.PrefixedIdent("TST", "200"))
+        ])
+        self.assertEqual(changes[0].removed, [])
+        self.assertEqual(changes[0].modified, [
+            (fastobo.instance.CommentClause("old"), fastobo.instance.CommentClause("new"))
+        ])
+
+    def test_diff_header(self):
+        old = fastobo.loads("format-version: 1.2\n")
+        new = fastobo.loads("format-version: 1.4\nontology: tst\n")
+        changes = fastobo.diff(old, new)
+        self.assertEqual(len(changes), 1)
+        self.assertIsNone(changes[0].id)
+        self.assertEqual(len(changes[0].added), 1)
+        self.assertEqual(len(changes[0].modified), 1)
+
+    def test_diff_qualifiers(self):
+        old = fastobo.loads('[Term]\nid: TST:001\nis_a: TST:002 {source="TST:100"}\n')
+        new = fastobo.loads('[Term]\nid: TST:001\nis_a: TST:002 {source="TST:200"}\n')
+        changes = fastobo.diff(old, new)
+        self.assertEqual([(str(c.id), c.status) for c in changes], [
+            ("TST:001", "modified"),
+        ])
+        (before, after), = changes[0].modified
+        self.assertEqual(str(before.qualifiers), '{source="TST:100"}')
+        self.assertEqual(str(after.qualifiers), '{source="TST:200"}')
+
+    def test_diff_reordered(self):
+        old = fastobo.loads("[Term]\nid: TST:001\nname: first\nis_a: TST:002\n")
+        new = fastobo.loads("[Term]\nid: TST:001\nis_a: TST:002\nname: first\n")
+        self.assertEqual(fastobo.diff(old, new), [])
+        old = fastobo.loads("format-version: 1.4\nontology: tst\n")
+        new = fastobo.loads("ontology: tst\nformat-version: 1.4\n")
+        self.assertEqual(fastobo.diff(old, new), [])
+
+    def test_no_changes(self):
+        doc = fastobo.load(MS)
+        self.assertEqual(fastobo.diff(doc, doc), [])
+
+
+class TestGraph(unittest.TestCase):
+
+    def setUp(self):
+        self.doc = fastobo.loads(
+            "format-version: 1.4\n"
+            "ontology: tst\n"
+            "\n"
+            "[Term]\n"
+            "id: TST:001\n"
+            "name: test item\n"
+        )
+
+    def test_gzip(self):
+        buffer = io.BytesIO()
+        fastobo.dump_graph(self.doc, buffer, compression="gzip")
+        data = buffer.getvalue()
+        self.assertEqual(data[:2], b"\x1f\x8b")
+        self.assertIn(b"TST_001", gzip.decompress(data))
+        doc = fastobo.load_graph(io.BytesIO(data))
+        self.assertEqual(doc[0].id, fastobo.id.PrefixedIdent("TST", "001"))
+        self.assertRaises(ValueError, fastobo.load_graph, io.BytesIO(data), compression=None)
+
+    def test_iter_graph(self):
+        buffer = io.BytesIO()
+        fastobo.dump_graph(self.doc, buffer)
+        frames = list(fastobo.iter_graph(io.BytesIO(buffer.getvalue())))
+        self.assertEqual(len(frames), 1)
+        self.assertEqual(frames[0].id, fastobo.id.PrefixedIdent("TST", "001"))
+        self.assertEqual(str(frames[0].get("name")[0]), "name: test item")
+        self.assertRaises(ValueError, list, fastobo.iter_graph(io.BytesIO(b"[]")))
+
+    def test_iter_graph_dropped(self):
+        doc = fastobo.loads("".join(
+            "[Term]\nid: TST:{:03}\n\n".format(i) for i in range(100)
+        ))
+        buffer = io.BytesIO()
+        fastobo.dump_graph(doc, buffer)
+        reader = fastobo.iter_graph(io.BytesIO(buffer.getvalue()))
+        self.assertIsNotNone(next(reader))
+        del reader
+
+    def test_dump_flush(self):
+        class FlushedBytesIO(io.BytesIO):
+            flushed = False
+            def flush(self):
+                self.flushed = True
+                super().flush()
+
+        buffer = FlushedBytesIO()
+        fastobo.dump_graph(self.doc, buffer, buffer_size=1 << 20)
+        self.assertTrue(buffer.flushed)
+        self.assertIn("graphs", json.loads(buffer.getvalue()))
+        self.assertRaises(ValueError, fastobo.dump_graph, self.doc, buffer, buffer_size=0)
+
+    def test_text_mode(self):
+        buffer = io.StringIO()
+        fastobo.dump_graph(self.doc, buffer)
+        doc = fastobo.load_graph(io.StringIO(buffer.getvalue()))
+        self.assertEqual(doc[0].id, fastobo.id.PrefixedIdent("TST", "001"))
+
+    def test_graph_meta(self):
+        title = {"pred": "http://purl.org/dc/elements/1.1/title", "val": "Test Ontology"}
+        buffer = io.BytesIO()
+        fastobo.dump_graph(self.doc, buffer)
+        graph = json.loads(buffer.getvalue())
+        graph["graphs"][0].setdefault("meta", {}).setdefault("basicPropertyValues", []).append(title)
+
+        def values(meta):
+            return [{"pred": pv["pred"], "val": pv["val"]} for pv in meta["basicPropertyValues"]]
+
+        doc = fastobo.load_graph(io.BytesIO(json.dumps(graph).encode()))
+        self.assertIn(title, values(doc.graph_meta))
+        buffer = io.BytesIO()
+        fastobo.dump_graph(doc, buffer)
+        meta = json.loads(buffer.getvalue())["graphs"][0]["meta"]
+        self.assertEqual(values(meta).count(title), 1)
+
+        self.assertIsNone(self.doc.graph_meta)
+        doc.graph_meta = None
+        self.assertIsNone(doc.graph_meta)
+        with self.assertRaises(ValueError):
+            doc.graph_meta = {"deprecated": "yes"}
+
+    def test_node_meta(self):
+        doc = fastobo.loads(
+            "format-version: 1.4\n"
+            "ontology: tst\n"
+            "subsetdef: test_slim \"Test slim\"\n"
+            "\n"
+            "[Term]\n"
+            "id: TST:001\n"
+            "comment: a test comment\n"
+            "subset: test_slim\n"
+            "is_obsolete: true\n"
+        )
+        buffer = io.BytesIO()
+        fastobo.dump_graph(doc, buffer)
+        node = json.loads(buffer.getvalue())["graphs"][0]["nodes"][0]
+        self.assertTrue(node["meta"]["deprecated"])
+        self.assertEqual(node["meta"]["comments"], ["a test comment"])
+        self.assertEqual(node["meta"]["subsets"], ["http://purl.obolibrary.org/obo/tst#test_slim"])
+
+        frame = fastobo.load_graph(io.BytesIO(buffer.getvalue()))[0]
+        self.assertEqual(frame.tags(), {"comment", "subset", "is_obsolete"})
+        self.assertEqual(len(frame.get("subset")), 1)
+        self.assertEqual(str(frame.get("comment")[0]), "comment: a test comment")
+
+    def test_compression_error(self):
+        self.assertRaises(ValueError, fastobo.dump_graph, self.doc, io.BytesIO(), compression="bz2")
+
+
+class TestDumpSqlite(unittest.TestCase):
+
+    def setUp(self):
+        self.doc = fastobo.loads(
+            "[Term]\n"
+            "id: TST:001\n"
+            "name: first\n"
+            "synonym: \"1st\" EXACT []\n"
+            "xref: TST:003\n"
+            "\n"
+            "[Term]\n"
+            "id: TST:002\n"
+            "name: second\n"
+            "is_a: TST:001\n"
+        )
+
+    def test_statements(self):
+        with tempfile.TemporaryDirectory() as tmp:
+            path = os.path.join(tmp, "tst.db")
+            fastobo.dump_sqlite(self.doc, path)
+            db = sqlite3.connect(path)
+            labels = db.execute("SELECT subject, value FROM rdfs_label_statement ORDER BY subject")
+            self.assertEqual(labels.fetchall(), [("TST:001", "first"), ("TST:002", "second")])
+            synonyms = db.execute("SELECT subject, predicate, value FROM has_oio_synonym_statement")
+            self.assertEqual(synonyms.fetchall(), [("TST:001", "oio:hasExactSynonym", "1st")])
+            xrefs = db.execute("SELECT subject, value FROM has_dbxref_statement")
+            self.assertEqual(xrefs.fetchall(), [("TST:001", "TST:003")])
+            edges = db.execute("SELECT * FROM entailed_edge")
+            self.assertEqual(edges.fetchall(), [])
+            db.close()
+
+    def test_entailed_edges(self):
+        with tempfile.TemporaryDirectory() as tmp:
+            path = os.path.join(tmp, "tst.db")
+            fastobo.dump_sqlite(self.doc, path)
+            fastobo.dump_sqlite(self.doc, path, entailed_edges=True)
+            db = sqlite3.connect(path)
+            edges = db.execute("SELECT subject, object FROM entailed_edge ORDER BY subject, object")
+            self.assertEqual(
+                edges.fetchall(),
+                [("TST:001", "TST:001"), ("TST:002", "TST:001"), ("TST:002", "TST:002")],
+            )
+            db.close()
+
+    def test_shorthand_curies(self):
+        doc = fastobo.loads(
+            "ontology: tst\n"
+            "\n"
+            "[Term]\n"
+            "id: TST:001\n"
+            "subset: slim\n"
+            "relationship: part_of TST:002\n"
+            "relationship: has_part TST:002\n"
+            "\n"
+            "[Typedef]\n"
+            "id: part_of\n"
+            "xref: BFO:0000050\n"
+        )
+        with tempfile.TemporaryDirectory() as tmp:
+            path = os.path.join(tmp, "tst.db")
+            fastobo.dump_sqlite(doc, path)
+            db = sqlite3.connect(path)
+            rows = db.execute(
+                "SELECT predicate, object FROM statements "
+                "WHERE subject = 'TST:001' AND predicate != 'rdf:type' ORDER BY predicate"
+            )
+            self.assertEqual(rows.fetchall(), [
+                ("BFO:0000050", "TST:002"),
+                ("obo:tst#has_part", "TST:002"),
+                ("oio:inSubset", "obo:tst#slim"),
+            ])
+            db.close()
+
+
+
+
+class TestConvert(unittest.TestCase):
+
+    def setUp(self):
+        self.text = (
+            "format-version: 1.4\n"
+            "ontology: tst\n"
+            "default-namespace: tst\n"
+            "\n"
+            "[Term]\n"
+            "id: TST:001\n"
+            "name: first\n"
+        )
+
+    def test_obo_to_obojson(self):
+        with tempfile.TemporaryDirectory() as tmp:
+            src = os.path.join(tmp, "tst.obo")
+            dst = os.path.join(tmp, "tst.json")
+            with open(src, "w") as f:
+                f.write(self.text)
+            fastobo.convert(src, dst)
+            doc = fastobo.load_graph(dst)
+        self.assertEqual(str(doc[0].id), "TST:001")
+
+    def test_obojson_to_obo(self):
+        with tempfile.TemporaryDirectory() as tmp:
+            src = os.path.join(tmp, "tst.json.gz")
+            fastobo.dump_graph(fastobo.loads(self.text), src)
+            dst = io.BytesIO()
+            fastobo.convert(src, dst, to_format="obo")
+        doc = fastobo.loads(dst.getvalue().decode())
+        self.assertEqual(str(doc[0].id), "TST:001")
+
+    def test_detect_content(self):
+        src = io.BytesIO(self.text.encode())
+        dst = io.BytesIO()
+        fastobo.convert(src, dst, to_format="ofn")
+        self.assertIn("Declaration(Class(", dst.getvalue().decode())
+
+    def test_owl_source(self):
+        src = io.BytesIO(b"Prefix(:=<http://example.com/>)\nOntology()\n")
+        self.assertRaises(ValueError, fastobo.convert, src, io.BytesIO(), to_format="obo")
+
+    def test_unknown_format(self):
+        src = io.BytesIO(self.text.encode())
+        self.assertRaises(ValueError, fastobo.convert, src, io.BytesIO())
+        self.assertRaises(ValueError, fastobo.convert, src, io.BytesIO(), to_format="xyz")
+
+class TestDumpTsv(unittest.TestCase):
+
+    def setUp(self):
+        self.doc = fastobo.loads(
+            "[Term]\n"
+            "id: TST:001\n"
+            "name: first\n"
+            "def: \"The first\tterm.\" []\n"
+            "\n"
+            "[Term]\n"
+            "id: TST:002\n"
+            "name: second\n"
+            "is_a: TST:001\n"
+            "is_obsolete: true\n"
+        )
+
+    def test_columns(self):
+        f = io.BytesIO()
+        fastobo.dump_tsv(self.doc, f)
+        lines = f.getvalue().decode().splitlines()
+        self.assertEqual(lines[0], "id\tname\tdef\tnamespace\tparents\tobsolete")
+        self.assertEqual(lines[1], 'TST:001\tfirst\t"The first\tterm."\t\t\tfalse')
+        self.assertEqual(lines[2], "TST:002\tsecond\t\t\tTST:001\ttrue")
+
+    def test_select_columns(self):
+        f = io.BytesIO()
+        fastobo.dump_tsv(self.doc, f, columns=["parents", "id"])
+        self.assertEqual(f.getvalue(), b"parents\tid\n\tTST:001\nTST:001\tTST:002\n")
+
+    def test_csv(self):
+        with tempfile.TemporaryDirectory() as tmp:
+            path = os.path.join(tmp, "tst.csv")
+            fastobo.dump_tsv(self.doc, path, columns=["id", "def"], sep=",")
+            with open(path, newline="") as f:
+                rows = list(csv.reader(f))
+        self.assertEqual(rows, [["id", "def"], ["TST:001", "The first\tterm."], ["TST:002", ""]])
+
+    def test_invalid_column(self):
+        self.assertRaises(ValueError, fastobo.dump_tsv, self.doc, io.BytesIO(), columns=["xyz"])
+
+@unittest.skipUnless(pyhornedowl, "pyhornedowl is not installed")
+class TestFromPyhornedowl(unittest.TestCase):
+
+    def test_roundtrip(self):
+        doc = fastobo.loads(
+            "format-version: 1.4\n"
+            "ontology: tst\n"
+            "default-namespace: tst\n"
+            "\n"
+            "[Term]\n"
+            "id: TST:001\n"
+            "name: first\n"
+            "\n"
+            "[Term]\n"
+            "id: TST:002\n"
+            "is_a: TST:001\n"
+            "relationship: part_of TST:001\n"
+            "\n"
+            "[Typedef]\n"
+            "id: part_of\n"
+            "is_transitive: true\n"
+        )
+        converted = fastobo.from_pyhornedowl(doc.to_pyhornedowl())
+        self.assertEqual(converted.header.ontology, "tst")
+        frames = {str(frame.id): frame for frame in converted}
+        first = frames["TST:001"]
+        self.assertEqual(first.name, "first")
+        second = frames["TST:002"]
+        self.assertIn(fastobo.term.IsAClause(fastobo.id.PrefixedIdent("TST", "001")), second)
+        self.assertIn(
+            fastobo.term.RelationshipClause(
+                fastobo.id.UnprefixedIdent("part_of"),
+                fastobo.id.PrefixedIdent("TST", "001"),
+            ),
+            second,
+        )
+        part_of = frames["part_of"]
+        self.assertIsInstance(part_of, fastobo.typedef.TypedefFrame)
+        self.assertIn(fastobo.typedef.IsTransitiveClause(True), part_of)
+
+    def test_type_error(self):
+        self.assertRaises(AttributeError, fastobo.from_pyhornedowl, object())
+
+
+class TestDumpOwl(unittest.TestCase):
+
+    def test_gci(self):
+        doc = fastobo.loads(
+            "format-version: 1.4\n"
+            "ontology: tst\n"
+            "default-namespace: tst\n"
+            "\n"
+            "[Term]\n"
+            "id: TST:001\n"
+            'relationship: part_of TST:002 {gci_relation="part_of", gci_filler="TST:003"}\n'
+        )
+        buffer = io.BytesIO()
+        fastobo.dump_owl(doc, buffer)
+        owl = buffer.getvalue().decode()
+        self.assertIn("ObjectIntersectionOf", owl)
+        self.assertIn("TST_003", owl)
+
+    def test_prefixes(self):
+        doc = fastobo.loads(
+            "format-version: 1.4\n"
+            "ontology: tst\n"
+            "default-namespace: tst\n"
+            "\n"
+            "[Term]\n"
+            "id: TST:001\n"
+        )
+        buffer = io.BytesIO()
+        prefixes = {"tst": "http://example.com/tst/", "": "http://example.com/"}
+        fastobo.dump_owl(doc, buffer, prefixes=prefixes)
+        owl = buffer.getvalue().decode()
+        self.assertIn("Prefix(tst:=<http://example.com/tst/>)", owl)
+        self.assertIn("Prefix(:=<http://example.com/>)", owl)
+        self.assertRaises(ValueError, fastobo.dump_owl, doc, io.BytesIO(), prefixes={"_": "http://example.com/"})
+
+    def test_ontology_iri(self):
+        doc = fastobo.loads(
+            "format-version: 1.4\n"
+            "default-namespace: tst\n"
+            "\n"
+            "[Term]\n"
+            "id: TST:001\n"
+        )
+        buffer = io.BytesIO()
+        fastobo.dump_owl(
+            doc,
+            buffer,
+            ontology_iri="http://example.com/tst.owl",
+            version_iri="http://example.com/2021-01-23/tst.owl",
+        )
+        owl = buffer.getvalue().decode()
+        self.assertIn(
+            "Ontology(<http://example.com/tst.owl> <http://example.com/2021-01-23/tst.owl>",
+            owl,
+        )
+        self.assertIsNone(doc.header.ontology)
+
+    def test_missing_ontology(self):
+        doc = fastobo.loads(
+            "format-version: 1.4\n"
+            "default-namespace: tst\n"
+            "\n"
+            "[Term]\n"
+            "id: TST:001\n"
+        )
+        with self.assertRaises(fastobo.exceptions.MissingOntologyClauseError) as ctx:
+            fastobo.dump_owl(doc, io.BytesIO())
+        self.assertIsInstance(ctx.exception, fastobo.exceptions.MissingClauseError)
+        self.assertEqual(ctx.exception.clause, "ontology")
+        doc.header.append(fastobo.header.OntologyClause("tst"))
+        fastobo.dump_owl(doc, io.BytesIO())
+
+    def test_missing_namespace(self):
+        doc = fastobo.loads(
+            "format-version: 1.4\n"
+            "ontology: tst\n"
+            "\n"
+            "[Term]\n"
+            "id: TST:001\n"
+        )
+        with self.assertRaises(fastobo.exceptions.MissingNamespaceError) as ctx:
+            fastobo.dump_owl(doc, io.BytesIO())
+        self.assertIsInstance(ctx.exception, ValueError)
+        doc.header.append(fastobo.header.DefaultNamespaceClause("tst"))
+        fastobo.dump_owl(doc, io.BytesIO())
+
+
+class TestClauses(unittest.TestCase):
+
+    def setUp(self):
+        self.doc = fastobo.loads(
+            "[Term]\n"
+            "id: TST:001\n"
+            "name: thing\n"
+            'def: "A thing." [TST:002]\n'
+            "is_a: TST:000\n"
+            "relationship: part_of TST:003\n"
+            "union_of: TST:004\n"
+            "creation_date: 2021-01-01\n"
+            "\n"
+            "[Typedef]\n"
+            "id: part_of\n"
+            "name: part of\n"
+            'def: "A part." [TST:002]\n'
+            "is_transitive: true\n"
+            "equivalent_to: has_part\n"
+            "creation_date: 2021-01-01\n"
+            "\n"
+            "[Instance]\n"
+            "id: TST:005\n"
+            "name: instance\n"
+            "instance_of: TST:001\n"
+            "creation_date: 2021-01-01\n"
+        )
+
+    def test_protocol(self):
+        for frame in self.doc:
+            for clause in frame:
+                with self.subTest(clause=str(clause)):
+                    tag = clause.raw_tag()
+                    self.assertIsInstance(tag, str)
+                    self.assertEqual(str(clause).split(":", 1)[0], tag)
+                    self.assertTrue(repr(clause).startswith(type(clause).__name__ + "("))
+                    self.assertEqual(clause, copy.copy(clause))
+                    self.assertEqual(hash(clause), hash(copy.copy(clause)))
+                    self.assertNotEqual(clause, object())
+
+    def test_keyword_arguments(self):
+        date = datetime.date(2021, 1, 1)
+        for module in (fastobo.term, fastobo.typedef, fastobo.instance):
+            with self.subTest(module=module.__name__):
+                self.assertEqual(module.CreationDateClause(date=date).date, date)
+                self.assertEqual(module.NameClause(name="thing").name, "thing")
@@ -35,6 +35,73 @@ class TestHeaderFrame(unittest.TestCase):
         self.assertRaises(TypeError, self.type, ["abc"])
         self.assertRaises(TypeError, self.type, "abc")
 
+    def test_eq(self):
+        clauses = [
+            fastobo.header.FormatVersionClause("1.2"),
+            fastobo.header.SavedByClause("Martin Larralde"),
+        ]
+        self.assertEqual(self.type(clauses), self.type(clauses))
+        self.assertFalse(self.type(clauses) != self.type(clauses))
+        self.assertNotEqual(self.type(clauses), self.type(clauses[:1]))
+        self.assertNotEqual(self.type(clauses), clauses)
+
+    def test_format_version(self):
+        frame = self.type([fastobo.header.OntologyClause("test")])
+        self.assertIs(frame.format_version, None)
+        frame.format_version = "1.4"
+        self.assertEqual(frame.format_version, "1.4")
+        self.assertIsInstance(frame[0], fastobo.header.FormatVersionClause)
+        frame.format_version = "1.2"
+        self.assertEqual(len(frame), 2)
+        self.assertEqual(frame[0].version, "1.2")
+        frame.format_version = None
+        self.assertEqual(len(frame), 1)
+
+    def test_metadata_properties(self):
+        frame = self.type()
+        frame.ontology = "test"
+        frame.saved_by = "Martin Larralde"
+        frame.data_version = "releases/2024-01-01"
+        frame.default_namespace = fastobo.id.UnprefixedIdent("test")
+        frame.date = datetime.datetime(2024, 1, 1, 12, 30)
+        self.assertEqual(
+            [clause.raw_tag() for clause in frame],
+            ["data-version", "date", "saved-by", "default-namespace", "ontology"],
+        )
+        self.assertEqual(frame.ontology, "test")
+        self.assertEqual(frame.saved_by, "Martin Larralde")
+        self.assertEqual(frame.data_version, "releases/2024-01-01")
+        self.assertEqual(frame.default_namespace, fastobo.id.UnprefixedIdent("test"))
+        self.assertEqual(frame.date, datetime.datetime(2024, 1, 1, 12, 30))
+
+    def test_subsetdefs(self):
+        subset = fastobo.id.UnprefixedIdent("goslim_generic")
+        frame = self.type([
+            fastobo.header.SubsetdefClause(subset, "Generic GO slim"),
+            fastobo.header.SubsetdefClause(subset, "Duplicate"),
+            fastobo.header.OntologyClause("go"),
+        ])
+        subsetdefs = frame.subsetdefs
+        self.assertEqual(list(subsetdefs), ["goslim_generic"])
+        self.assertIs(subsetdefs["goslim_generic"], frame[0])
+
+    def test_synonymtypedefs(self):
+        frame = fastobo.loads(
+            'synonymtypedef: systematic_synonym "Systematic synonym" EXACT\n'
+            'synonymtypedef: UK_SPELLING "British spelling"\n'
+        ).header
+        synonymtypedefs = frame.synonymtypedefs
+        self.assertEqual(len(synonymtypedefs), 2)
+        self.assertEqual(synonymtypedefs["systematic_synonym"].scope, "EXACT")
+        self.assertIs(synonymtypedefs["UK_SPELLING"].scope, None)
+        self.assertEqual(synonymtypedefs["UK_SPELLING"].description, "British spelling")
+
+    def test_metadata_properties_frozen(self):
+        frame = self.type()
+        frame.freeze()
+        with self.assertRaises(TypeError):
+            frame.ontology = "test"
+
 # --- HeaderClause -----------------------------------------------------------
 
 class _TestUnquotedStringClause(object):
@@ -63,10 +130,16 @@ class _TestUnquotedStringClause(object):
         self.assertEqual(x, x)
         y = self.type("1.2")
         self.assertEqual(x, y)
+        self.assertFalse(x != y)
         z = self.type("1.4")
         self.assertNotEqual(x, z)
         self.assertNotEqual(y, z)
 
+    def test_hash(self):
+        x = self.type("1.2")
+        self.assertEqual(hash(x), hash(self.type("1.2")))
+        self.assertEqual(len({x, self.type("1.2"), self.type("1.4")}), 2)
+
 # --- FormatVersion ----------------------------------------------------------
 
 class TestFormatVersionClause(_TestUnquotedStringClause, unittest.TestCase):
@@ -158,12 +231,24 @@ class TestDateClause(unittest.TestCase):
         with self.assertRaises(TypeError):
             vc1.date = 1
 
+    def test_property_date_truncated(self):
+        clause = self.type(datetime.datetime(2019, 4, 8, 16, 51, 30, 500))
+        self.assertEqual(clause.date, datetime.datetime(2019, 4, 8, 16, 51))
+        clause.date = datetime.datetime(2020, 1, 2, 3, 4, 5)
+        self.assertEqual(clause.date, datetime.datetime(2020, 1, 2, 3, 4))
+        self.assertEqual(str(clause), "date: 02:01:2020 03:04")
+
 # --- SavedBy ----------------------------------------------------------------
 
 class TestSavedByClause(_TestUnquotedStringClause, unittest.TestCase):
 
     type = fastobo.header.SavedByClause
 
+    def test_raw_tag(self):
+        clause = self.type("Martin Larralde")
+        self.assertEqual(clause.raw_tag(), "saved-by")
+        self.assertEqual(clause.raw_value(), "Martin Larralde")
+
 # --- AutoGeneratedBy --------------------------------------------------------
 
 class TestAutoGeneratedByClause(_TestUnquotedStringClause, unittest.TestCase):
@@ -200,4 +285,44 @@ class TestOwlAxiomsClause(_TestUnquotedStringClause, unittest.TestCase):
 
     type = fastobo.header.OwlAxiomsClause
 
+# --- parse_clause -----------------------------------------------------------
+
+class TestParseClause(unittest.TestCase):
+
+    def test_parse(self):
+        clause = fastobo.header.parse_clause("format-version: 1.4")
+        self.assertIsInstance(clause, fastobo.header.FormatVersionClause)
+        self.assertEqual(clause.version, "1.4")
+
+    def test_value_error(self):
+        self.assertRaises(ValueError, fastobo.header.parse_clause, "")
+
+
 # --- UnreservedClause -------------------------------------------------------
+
+class TestUnreservedClause(unittest.TestCase):
+
+    type = fastobo.header.UnreservedClause
+
+    def test_init(self):
+        clause = self.type("exported-from", "http://example.com/ont.owl")
+        self.assertEqual(clause.tag, "exported-from")
+        self.assertEqual(clause.value, "http://example.com/ont.owl")
+        self.assertEqual(clause.raw_tag(), "exported-from")
+
+    def test_init_reserved(self):
+        self.assertRaises(ValueError, self.type, "format-version", "1.4")
+        self.assertRaises(ValueError, self.type, "", "value")
+        self.assertRaises(ValueError, self.type, "my tag", "value")
+
+    def test_round_trip(self):
+        clause = self.type("exported-from", "http://example.com/ont.owl")
+        frame = fastobo.header.HeaderFrame([clause])
+        doc = fastobo.loads(str(frame))
+        self.assertEqual(doc.header[0], clause)
+
+    def test_parse_clause(self):
+        clause = fastobo.header.parse_clause("exported-from", "value")
+        self.assertEqual(clause, self.type("exported-from", "value"))
+        clause = fastobo.header.parse_clause("format-version", "1.4")
+        self.assertIsInstance(clause, fastobo.header.FormatVersionClause)
@@ -1,5 +1,6 @@
 # coding: utf-8
 
+import copy
 import datetime
 import unittest
 
@@ -9,15 +10,24 @@ import fastobo
 class _TestBaseIdent(object):
 
     type = NotImplemented
+    example = NotImplemented
 
     def test_init_type_error(self):
         self.assertRaises(TypeError, self.type, 123)
         self.assertRaises(TypeError, self.type, [])
 
+    def test_copy(self):
+        ident = fastobo.id.parse(self.example)
+        self.assertIsInstance(copy.copy(ident), self.type)
+        self.assertEqual(copy.copy(ident), ident)
+        self.assertIsNot(copy.copy(ident), ident)
+        self.assertEqual(copy.deepcopy(ident), ident)
+
 
 class TestUnprefixedIdent(_TestBaseIdent, unittest.TestCase):
 
     type = fastobo.id.UnprefixedIdent
+    example = 'derived_from'
 
     def test_init(self):
         try:
@@ -49,6 +59,7 @@ class TestUnprefixedIdent(_TestBaseIdent, unittest.TestCase):
 class TestPrefixedIdent(_TestBaseIdent, unittest.TestCase):
 
     type = fastobo.id.PrefixedIdent
+    example = 'GO:0070412'
 
     def test_init(self):
         try:
@@ -62,6 +73,17 @@ class TestPrefixedIdent(_TestBaseIdent, unittest.TestCase):
         self.assertRaises(TypeError, self.type, 123, "0070412")
         self.assertRaises(TypeError, self.type, [], "0070412")
 
+    def test_init_validate(self):
+        self.assertEqual(
+            self.type("GO", "0070412", validate=True),
+            self.type("GO", "0070412"),
+        )
+        self.assertRaises(ValueError, self.type, "GO ", "0070412", validate=True)
+        self.assertRaises(ValueError, self.type, "GO", "0070 412", validate=True)
+        self.assertRaises(ValueError, self.type, "", "0070412", validate=True)
+        with self.assertRaisesRegex(ValueError, "position 4"):
+            self.type("GO", "0070 412", validate=True)
+
     def test_hash(self):
         ident = self.type("GO", "0070412")
         self.assertEqual(hash(ident), hash(self.type("GO", "0070412")))
@@ -71,6 +93,7 @@ class TestPrefixedIdent(_TestBaseIdent, unittest.TestCase):
 class TestUrl(_TestBaseIdent, unittest.TestCase):
 
     type = fastobo.id.Url
+    example = 'http://purl.obolibrary.org/obo/GO_0070412'
 
     def test_init(self):
         try:
@@ -96,7 +119,96 @@ class TestUrl(_TestBaseIdent, unittest.TestCase):
         self.assertLess(url, self.type('http://purl.obolibrary.org/obo/GO_0070413'))
         self.assertRaises(TypeError, url.__lt__, 'http://purl.obolibrary.org/obo/GO_0070413')
 
+    def test_components(self):
+        url = self.type('https://user@example.com:8080/path/to/file.owl?q=1#frag')
+        self.assertEqual(url.scheme, 'https')
+        self.assertEqual(url.host, 'example.com')
+        self.assertEqual(url.path, '/path/to/file.owl')
+        self.assertEqual(url.fragment, 'frag')
+        url = self.type('urn:isbn:0451450523')
+        self.assertEqual(url.scheme, 'urn')
+        self.assertIs(url.host, None)
+        self.assertEqual(url.path, 'isbn:0451450523')
+        self.assertIs(url.fragment, None)
+
+    def test_to_prefixed(self):
+        url = self.type('http://purl.obolibrary.org/obo/GO_0070412')
+        self.assertEqual(url.to_prefixed(), fastobo.id.PrefixedIdent('GO', '0070412'))
+        url = self.type('http://example.com/tst/001')
+        self.assertRaises(ValueError, url.to_prefixed)
+        prefixes = {'TST': 'http://example.com/tst/'}
+        self.assertEqual(url.to_prefixed(prefixes), fastobo.id.PrefixedIdent('TST', '001'))
+
     def test_hash(self):
         url = self.type('http://purl.obolibrary.org/obo/GO_0070412')
         self.assertEqual(hash(url), hash(self.type('http://purl.obolibrary.org/obo/GO_0070412')))
         self.assertNotEqual(hash(url), hash(self.type('http://purl.obolibrary.org/obo/GO_0070413')))
+
+
+class TestCompact(unittest.TestCase):
+
+    def test_default_rule(self):
+        ident = fastobo.id.compact("http://purl.obolibrary.org/obo/GO_0005623")
+        self.assertEqual(ident, fastobo.id.PrefixedIdent("GO", "0005623"))
+
+    def test_prefix_map(self):
+        prefixes = {"TST": "http://example.com/", "SUB": "http://example.com/sub/"}
+        url = fastobo.id.Url("http://example.com/sub/001")
+        self.assertEqual(
+            fastobo.id.compact(url, prefixes),
+            fastobo.id.PrefixedIdent("SUB", "001"),
+        )
+
+    def test_doc_prefix_map(self):
+        doc = fastobo.loads("idspace: TST http://example.com/tst/\n")
+        self.assertEqual(
+            fastobo.id.compact("http://example.com/tst/001", doc),
+            fastobo.id.PrefixedIdent("TST", "001"),
+        )
+
+    def test_no_match(self):
+        url = fastobo.id.Url("http://example.com/001")
+        self.assertEqual(fastobo.id.compact(url), url)
+
+
+class TestExpand(unittest.TestCase):
+
+    def test_default_rule(self):
+        url = fastobo.id.expand(fastobo.id.PrefixedIdent("GO", "0005623"))
+        self.assertEqual(url, fastobo.id.Url("http://purl.obolibrary.org/obo/GO_0005623"))
+
+    def test_prefix_map(self):
+        url = fastobo.id.expand("TST:001", {"TST": "http://example.com/tst/"})
+        self.assertEqual(url, fastobo.id.Url("http://example.com/tst/001"))
+
+    def test_round_trip(self):
+        prefixes = {"TST": "http://example.com/tst/"}
+        ident = fastobo.id.PrefixedIdent("TST", "001")
+        url = fastobo.id.expand(ident, prefixes)
+        self.assertEqual(fastobo.id.compact(url, prefixes), ident)
+
+    def test_unprefixed(self):
+        ident = fastobo.id.UnprefixedIdent("part_of")
+        self.assertEqual(fastobo.id.expand(ident), ident)
+
+
+class TestCheck(unittest.TestCase):
+
+    def test_valid(self):
+        self.assertEqual(fastobo.id.check("GO:0005623"), {"valid": True, "kind": "prefixed"})
+        self.assertEqual(fastobo.id.check("part_of"), {"valid": True, "kind": "unprefixed"})
+        self.assertEqual(
+            fastobo.id.check("http://purl.obolibrary.org/obo/GO_0005623"),
+            {"valid": True, "kind": "url"},
+        )
+
+    def test_invalid(self):
+        result = fastobo.id.check("GO:0005623 extra")
+        self.assertFalse(result["valid"])
+        self.assertEqual(result["position"], 10)
+        self.assertIsInstance(result["expected"], list)
+        self.assertIsInstance(result["message"], str)
+
+    def test_consistent_with_is_valid(self):
+        for s in ("GO:0005623", "part of", "", "x:y:z", "related_to"):
+            self.assertEqual(fastobo.id.check(s)["valid"], fastobo.id.is_valid(s))
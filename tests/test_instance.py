@@ -0,0 +1,132 @@
+# coding: utf-8
+
+import unittest
+
+import fastobo
+
+from .common import (
+    _TestFrame,
+    _TestIsObsoleteClause,
+    _TestDefClause,
+    _TestConsiderClause,
+    _TestCreationDateClause,
+)
+
+# --- InstanceFrame ----------------------------------------------------------
+
+class TestInstanceFrame(_TestFrame, unittest.TestCase):
+    Frame = fastobo.instance.InstanceFrame
+    NameClause = fastobo.instance.NameClause
+    CreatedByClause = fastobo.instance.CreatedByClause
+
+    def test_init_term_clause_error(self):
+        clause = fastobo.term.IsAClause(fastobo.id.PrefixedIdent("ex", "Person"))
+        self.assertRaises(TypeError, self.Frame, self.id, [clause])
+
+    def test_str(self):
+        frame = self.Frame(self.id, [
+            fastobo.instance.NameClause("John"),
+            fastobo.instance.InstanceOfClause(fastobo.id.PrefixedIdent("ex", "Person")),
+        ])
+        self.assertEqual(
+            str(frame).splitlines(),
+            ["[Instance]", "id: MS:1000031", "name: John", "instance_of: ex:Person"],
+        )
+
+    def test_setitem_loaded(self):
+        doc = fastobo.loads(
+            "[Instance]\nid: ex:john\nname: John\ninstance_of: ex:Person\n"
+        )
+        frame = doc[0]
+        frame[-1] = fastobo.instance.InstanceOfClause(fastobo.id.PrefixedIdent("ex", "Man"))
+        frame[:1] = [fastobo.instance.NameClause("Johnny")]
+        self.assertEqual(
+            str(frame).splitlines(),
+            ["[Instance]", "id: ex:john", "name: Johnny", "instance_of: ex:Man"],
+        )
+        frame.freeze()
+        self.assertRaises(TypeError, frame.__setitem__, 0, fastobo.instance.NameClause("John"))
+
+    def test_delitem_loaded(self):
+        doc = fastobo.loads(
+            "[Instance]\nid: ex:john\nname: John\ninstance_of: ex:Person\n"
+        )
+        frame = doc[0]
+        del frame[-1]
+        self.assertEqual(str(frame).splitlines(), ["[Instance]", "id: ex:john", "name: John"])
+        del frame[:]
+        self.assertEqual(len(frame), 0)
+        doc[0] = self.Frame(self.id)
+        del doc[-1]
+        self.assertEqual(len(doc), 0)
+
+
+# --- InstanceOfClause -------------------------------------------------------
+
+class TestInstanceOfClause(unittest.TestCase):
+    type = fastobo.instance.InstanceOfClause
+
+    def setUp(self):
+        self.id = fastobo.id.PrefixedIdent("ex", "Person")
+
+    def test_init(self):
+        clause = self.type(self.id)
+        self.assertEqual(clause.term, self.id)
+
+    def test_init_type_error(self):
+        self.assertRaises(TypeError, self.type)
+        self.assertRaises(TypeError, self.type, 1)
+
+    def test_raw_tag(self):
+        self.assertEqual(self.type(self.id).raw_tag(), "instance_of")
+
+    def test_str(self):
+        self.assertEqual(str(self.type(self.id)), "instance_of: ex:Person")
+
+
+# --- RelationshipClause -----------------------------------------------------
+
+class TestRelationshipClause(unittest.TestCase):
+    type = fastobo.instance.RelationshipClause
+
+    def test_str(self):
+        clause = self.type(
+            fastobo.id.UnprefixedIdent("married_to"),
+            fastobo.id.PrefixedIdent("ex", "jane"),
+        )
+        self.assertEqual(clause.raw_tag(), "relationship")
+        self.assertEqual(str(clause), "relationship: married_to ex:jane")
+
+
+# --- DefClause --------------------------------------------------------------
+
+class TestDefClause(_TestDefClause, unittest.TestCase):
+    type = fastobo.instance.DefClause
+
+
+# --- ConsiderClause ---------------------------------------------------------
+
+class TestConsiderClause(_TestConsiderClause, unittest.TestCase):
+    type = fastobo.instance.ConsiderClause
+
+
+# --- IsObsoleteClause -------------------------------------------------------
+
+class TestIsObsoleteClause(_TestIsObsoleteClause, unittest.TestCase):
+    type = fastobo.instance.IsObsoleteClause
+
+
+# --- CreationDateClause -----------------------------------------------------
+
+class TestCreationDateClause(_TestCreationDateClause, unittest.TestCase):
+    type = fastobo.instance.CreationDateClause
+
+
+# --- parse_clause -----------------------------------------------------------
+
+class TestParseClause(unittest.TestCase):
+
+    def test_parse(self):
+        clause = fastobo.instance.parse_clause("instance_of: ex:Person")
+        self.assertIsInstance(clause, fastobo.instance.InstanceOfClause)
+        self.assertEqual(clause.term, fastobo.id.PrefixedIdent("ex", "Person"))
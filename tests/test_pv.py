@@ -1,5 +1,6 @@
 # coding: utf-8
 
+import collections.abc
 import datetime
 import unittest
 
@@ -28,6 +29,18 @@ class TestLiteralPropertyValue(unittest.TestCase):
         self.assertRaises(TypeError, self.type, rel, 1, dt)
         self.assertRaises(TypeError, self.type, rel, value, 1)
 
+    def test_init_default_datatype(self):
+        rel = fastobo.id.UnprefixedIdent("creation_date")
+        pv = self.type(rel, "2019-04-08")
+        self.assertEqual(pv.datatype, fastobo.id.PrefixedIdent("xsd", "string"))
+        self.assertEqual(str(pv), 'creation_date "2019-04-08" xsd:string')
+
+    def test_init_str(self):
+        pv = self.type("dc:title", "Gene Ontology", "xsd:string")
+        self.assertEqual(pv.relation, fastobo.id.PrefixedIdent("dc", "title"))
+        self.assertEqual(pv.datatype, fastobo.id.PrefixedIdent("xsd", "string"))
+        self.assertRaises(ValueError, self.type, "dc:title lang", "Gene Ontology")
+
     def test_property_relation(self):
         rel = fastobo.id.UnprefixedIdent("creation_date")
         value = "2019-04-08T23:21:05Z"
@@ -114,3 +127,52 @@ class TestResourcePropertyValue(unittest.TestCase):
             "UnprefixedIdent('derived_from'), "
             "PrefixedIdent('MS', '1000031'))"
         )
+
+
+class TestParse(unittest.TestCase):
+
+    def test_resource(self):
+        pv = fastobo.pv.parse("IAO:0000412 http://purl.obolibrary.org/obo/go.owl")
+        self.assertIsInstance(pv, fastobo.pv.ResourcePropertyValue)
+        self.assertEqual(pv.relation, fastobo.id.PrefixedIdent("IAO", "0000412"))
+        self.assertEqual(pv.value, fastobo.id.Url("http://purl.obolibrary.org/obo/go.owl"))
+
+    def test_literal(self):
+        pv = fastobo.pv.parse('dc:title "Gene Ontology" xsd:string')
+        self.assertIsInstance(pv, fastobo.pv.LiteralPropertyValue)
+        self.assertEqual(pv.value, "Gene Ontology")
+        self.assertEqual(str(pv), 'dc:title "Gene Ontology" xsd:string')
+
+    def test_syntax_error(self):
+        self.assertRaises(SyntaxError, fastobo.pv.parse, "")
+        self.assertRaises(SyntaxError, fastobo.pv.parse, "dc:title")
+        self.assertRaises(SyntaxError, fastobo.pv.parse, 'dc:title "Gene Ontology" xsd:string extra')
+
+
+class TestPropertyValueList(unittest.TestCase):
+
+    type = fastobo.pv.PropertyValueList
+
+    def setUp(self):
+        self.pv1 = fastobo.pv.parse('dc:title "Gene Ontology" xsd:string')
+        self.pv2 = fastobo.pv.parse("RO:0002161 NCBITaxon:9606")
+
+    def test_init_type_error(self):
+        self.assertRaises(TypeError, self.type, 1)
+        self.assertRaises(TypeError, self.type, [1])
+
+    def test_str(self):
+        self.assertEqual(
+            str(self.type([self.pv1, self.pv2])),
+            '[dc:title "Gene Ontology" xsd:string, RO:0002161 NCBITaxon:9606]',
+        )
+
+    def test_mutable_sequence(self):
+        pvs = self.type([self.pv1])
+        self.assertIsInstance(pvs, collections.abc.MutableSequence)
+        pvs.insert(0, self.pv2)
+        self.assertEqual(list(pvs), [self.pv2, self.pv1])
+        self.assertEqual(pvs.index(self.pv1), 1)
+        pvs.remove(self.pv2)
+        self.assertEqual(pvs, self.type([self.pv1]))
+
@@ -0,0 +1,85 @@
+# coding: utf-8
+
+import collections.abc
+import copy
+import unittest
+
+import fastobo
+
+
+class TestQualifier(unittest.TestCase):
+
+    type = fastobo.qual.Qualifier
+
+    def setUp(self):
+        self.key = fastobo.id.UnprefixedIdent("cardinality")
+
+    def test_init_type_error(self):
+        self.assertRaises(TypeError, self.type, 1, "1")
+        self.assertRaises(TypeError, self.type, self.key, 1)
+
+    def test_str(self):
+        self.assertEqual(str(self.type(self.key, "1")), 'cardinality="1"')
+
+    def test_eq(self):
+        self.assertEqual(self.type(self.key, "1"), self.type(self.key, "1"))
+        self.assertNotEqual(self.type(self.key, "1"), self.type(self.key, "2"))
+
+
+class TestQualifierList(unittest.TestCase):
+
+    type = fastobo.qual.QualifierList
+
+    def test_init_type_error(self):
+        self.assertRaises(TypeError, self.type, 1)
+        self.assertRaises(TypeError, self.type, [1])
+
+    def test_str(self):
+        q1 = fastobo.qual.Qualifier(fastobo.id.UnprefixedIdent("a"), "1")
+        q2 = fastobo.qual.Qualifier(fastobo.id.UnprefixedIdent("b"), "2")
+        self.assertEqual(str(self.type([q1, q2])), '{a="1", b="2"}')
+
+    def test_mutable_sequence(self):
+        self.assertIsInstance(self.type(), collections.abc.MutableSequence)
+
+
+class TestClauseQualifiers(unittest.TestCase):
+
+    text = (
+        "[Term]\n"
+        "id: TST:001\n"
+        'is_a: TST:002 {source="TST:003"}\n'
+        "name: thing\n"
+        "\n"
+        "[Typedef]\n"
+        "id: part_of\n"
+        'is_transitive: true {source="TST:003"}\n'
+        "\n"
+        "[Instance]\n"
+        "id: TST:004\n"
+        'instance_of: TST:001 {source="TST:003"}\n'
+    )
+
+    def test_roundtrip(self):
+        doc = fastobo.loads(self.text)
+        for frame in doc:
+            self.assertEqual(len(frame[0].qualifiers), 1)
+            self.assertEqual(frame[0].qualifiers[0].value, "TST:003")
+        self.assertEqual(len(doc[0][1].qualifiers), 0)
+        self.assertEqual(str(doc), self.text)
+
+    def test_set(self):
+        clause = fastobo.term.NameClause("thing")
+        self.assertEqual(len(clause.qualifiers), 0)
+        q = fastobo.qual.Qualifier(fastobo.id.UnprefixedIdent("source"), "x")
+        clause.qualifiers = [q]
+        self.assertEqual(list(clause.qualifiers), [q])
+        frame = fastobo.term.TermFrame(fastobo.id.PrefixedIdent("TST", "001"), [clause])
+        self.assertEqual(str(frame).splitlines()[-1], 'name: thing {source="x"}')
+        self.assertRaises(TypeError, setattr, clause, "qualifiers", [1])
+
+    def test_copy(self):
+        doc = fastobo.loads(self.text)
+        clause = copy.copy(doc[0][0])
+        self.assertEqual(list(clause.qualifiers), list(doc[0][0].qualifiers))
+        self.assertIsNot(clause.qualifiers, doc[0][0].qualifiers)
@@ -0,0 +1,137 @@
+# coding: utf-8
+
+import collections.abc
+import unittest
+
+import fastobo
+
+
+class TestSynonymScope(unittest.TestCase):
+
+    type = fastobo.syn.SynonymScope
+
+    def test_str(self):
+        self.assertEqual(str(self.type.EXACT), "EXACT")
+        self.assertEqual(str(self.type.BROAD), "BROAD")
+        self.assertEqual(str(self.type.NARROW), "NARROW")
+        self.assertEqual(str(self.type.RELATED), "RELATED")
+
+    def test_eq(self):
+        self.assertEqual(self.type.EXACT, self.type.EXACT)
+        self.assertNotEqual(self.type.EXACT, self.type.BROAD)
+
+
+class TestSynonym(unittest.TestCase):
+
+    type = fastobo.syn.Synonym
+
+    def test_init_scope(self):
+        syn = self.type("assay", fastobo.syn.SynonymScope.NARROW)
+        self.assertEqual(syn.scope, "NARROW")
+        self.assertEqual(str(syn), '"assay" NARROW []')
+        self.assertEqual(syn, self.type("assay", "NARROW"))
+
+    def test_hash(self):
+        s1 = self.type("assay", "EXACT")
+        s2 = self.type("assay", fastobo.syn.SynonymScope.EXACT)
+        self.assertEqual(hash(s1), hash(s2))
+        self.assertEqual(len({s1, s2, self.type("assay", "BROAD")}), 2)
+
+    def test_init_scope_error(self):
+        self.assertRaises(ValueError, self.type, "assay", "exact")
+        self.assertRaises(ValueError, self.type, "assay", "UNKNOWN")
+        self.assertRaises(TypeError, self.type, "assay", 1)
+
+    def test_set_scope(self):
+        syn = self.type("assay", "EXACT")
+        syn.scope = fastobo.syn.SynonymScope.RELATED
+        self.assertEqual(syn.scope, "RELATED")
+        syn.scope = "BROAD"
+        self.assertEqual(syn.scope, "BROAD")
+        with self.assertRaises(ValueError):
+            syn.scope = "broad"
+        self.assertEqual(syn.scope, "BROAD")
+
+    def test_resolve_type(self):
+        ty = fastobo.id.UnprefixedIdent("systematic_synonym")
+        header = fastobo.header.HeaderFrame([
+            fastobo.header.SynonymTypedefClause(ty, "Systematic synonym"),
+        ])
+        syn = self.type("C6H12O6", "EXACT", ty)
+        self.assertEqual(syn.resolve_type(header), ("Systematic synonym", None))
+        self.assertIs(self.type("glucose", "EXACT").resolve_type(header), None)
+        undeclared = self.type("glucose", "EXACT", fastobo.id.UnprefixedIdent("common"))
+        self.assertRaises(ValueError, undeclared.resolve_type, header)
+        self.assertRaises(TypeError, syn.resolve_type, [])
+
+    def test_xrefs_append(self):
+        syn = self.type("assay", "EXACT")
+        syn.xrefs.append(fastobo.xref.Xref(fastobo.id.PrefixedIdent("PMID", "123")))
+        self.assertEqual(len(syn.xrefs), 1)
+        self.assertEqual(str(syn), '"assay" EXACT [PMID:123]')
+
+    def test_xrefs_set(self):
+        syn = self.type("assay", "EXACT")
+        x1 = fastobo.xref.Xref(fastobo.id.PrefixedIdent("PMID", "123"))
+        x2 = fastobo.xref.Xref(fastobo.id.PrefixedIdent("ISBN", "456"))
+        syn.xrefs = [x1, x2]
+        self.assertIsInstance(syn.xrefs, fastobo.xref.XrefList)
+        self.assertEqual(str(syn), '"assay" EXACT [PMID:123, ISBN:456]')
+        xrefs = fastobo.xref.XrefList([x1])
+        syn.xrefs = xrefs
+        self.assertIs(syn.xrefs, xrefs)
+        with self.assertRaises(TypeError):
+            syn.xrefs = [1]
+
+
+class TestSynonymList(unittest.TestCase):
+
+    type = fastobo.syn.SynonymList
+
+    def setUp(self):
+        self.s1 = fastobo.syn.Synonym("assay", "EXACT")
+        self.s2 = fastobo.syn.Synonym("test", "BROAD")
+
+    def test_init(self):
+        synonyms = self.type([self.s1, self.s2])
+        self.assertEqual(len(synonyms), 2)
+        self.assertIs(synonyms[0], self.s1)
+        self.assertEqual(len(self.type()), 0)
+
+    def test_init_type_error(self):
+        self.assertRaises(TypeError, self.type, 1)
+        self.assertRaises(TypeError, self.type, [1])
+
+    def test_str(self):
+        self.assertEqual(str(self.type()), "[]")
+        self.assertEqual(
+            str(self.type([self.s1, self.s2])),
+            '["assay" EXACT [], "test" BROAD []]',
+        )
+
+    def test_repr(self):
+        self.assertEqual(repr(self.type()), "SynonymList()")
+        self.assertEqual(
+            repr(self.type([self.s1])),
+            "SynonymList([{!r}])".format(self.s1),
+        )
+
+    def test_eq(self):
+        synonyms = self.type([self.s1])
+        self.assertEqual(synonyms, self.type([fastobo.syn.Synonym("assay", "EXACT")]))
+        self.assertNotEqual(synonyms, self.type([self.s2]))
+
+    def test_contains(self):
+        synonyms = self.type([self.s1])
+        self.assertIn(fastobo.syn.Synonym("assay", "EXACT"), synonyms)
+        self.assertNotIn(self.s2, synonyms)
+        self.assertRaises(TypeError, synonyms.__contains__, 1)
+
+    def test_mutable_sequence(self):
+        synonyms = self.type()
+        self.assertIsInstance(synonyms, collections.abc.MutableSequence)
+        synonyms.append(self.s1)
+        synonyms.extend([self.s2])
+        self.assertEqual(list(synonyms), [self.s1, self.s2])
+        self.assertIs(synonyms.pop(0), self.s1)
+        self.assertRaises(TypeError, synonyms.append, 1)
@@ -20,6 +20,143 @@ class TestTermFrame(_TestFrame, unittest.TestCase):
     NameClause = fastobo.term.NameClause
     CreatedByClause = fastobo.term.CreatedByClause
 
+    def test_definition(self):
+        frame = self.Frame(self.id, [
+            fastobo.term.NameClause("thing"),
+            fastobo.term.CommentClause("a comment"),
+        ])
+        self.assertIs(frame.definition, None)
+        self.assertIs(frame.definition_xrefs, None)
+        frame.definition = "a thing"
+        self.assertEqual(frame.definition, "a thing")
+        self.assertEqual(len(frame.definition_xrefs), 0)
+        self.assertIsInstance(frame[1], fastobo.term.DefClause)
+        frame[1] = fastobo.term.DefClause("old", [fastobo.xref.Xref(self.id)])
+        self.assertEqual(frame.definition, "old")
+        self.assertEqual(len(frame.definition_xrefs), 1)
+        frame.definition = "new"
+        self.assertEqual(len(frame), 3)
+        self.assertEqual(str(frame[1]), 'def: "new" []')
+        frame.definition = None
+        self.assertEqual(len(frame), 2)
+        self.assertIs(frame.definition, None)
+
+    def test_synonyms(self):
+        s1 = fastobo.syn.Synonym("model", "EXACT")
+        s2 = fastobo.syn.Synonym("instrument", "BROAD")
+        frame = self.Frame(self.id, [
+            fastobo.term.SynonymClause(s1),
+            fastobo.term.NameClause("thing"),
+            fastobo.term.SynonymClause(s2),
+        ])
+        self.assertEqual(frame.synonyms, [s1, s2])
+        self.assertIs(frame.synonyms[0], frame[0].synonym)
+        frame.synonyms[0].desc = "other"
+        self.assertEqual(frame[0].synonym.desc, "other")
+        self.assertEqual(self.Frame(self.id).synonyms, [])
+
+    def test_xrefs(self):
+        x1 = fastobo.xref.Xref(fastobo.id.PrefixedIdent("PSI", "MS"))
+        x2 = fastobo.xref.Xref(fastobo.id.PrefixedIdent("MOD", "00000"))
+        frame = self.Frame(self.id, [
+            fastobo.term.DefClause("thing", [x1]),
+            fastobo.term.XrefClause(x2),
+        ])
+        self.assertEqual(list(frame.xrefs()), [x2])
+        self.assertEqual(list(frame.xrefs(include_definition=True)), [x1, x2])
+        self.assertIs(frame.xrefs()[0], frame[1].xref)
+        self.assertEqual(len(self.Frame(self.id).xrefs()), 0)
+
+    def test_relationships(self):
+        is_a = fastobo.id.UnprefixedIdent("is_a")
+        part_of = fastobo.id.UnprefixedIdent("part_of")
+        t1 = fastobo.id.PrefixedIdent("GO", "0043231")
+        t2 = fastobo.id.PrefixedIdent("GO", "0005737")
+        frame = self.Frame(self.id, [
+            fastobo.term.IsAClause(t1),
+            fastobo.term.RelationshipClause(part_of, t2),
+            fastobo.term.NameClause("thing"),
+            fastobo.term.RelationshipClause(fastobo.id.UnprefixedIdent("part_of"), t1),
+        ])
+        relationships = frame.relationships()
+        self.assertEqual(list(relationships), [is_a, part_of])
+        self.assertEqual(relationships[is_a], [t1])
+        self.assertEqual(relationships[part_of], [t2, t1])
+        self.assertEqual(self.Frame(self.id).relationships(), {})
+
+    def test_as_owl_functional(self):
+        frame = self.Frame(self.id, [
+            fastobo.term.IsAClause(fastobo.id.PrefixedIdent("GO", "0043231")),
+        ])
+        axioms = frame.as_owl_functional()
+        self.assertIn("SubClassOf(obo:MS_1000031 obo:GO_0043231)", axioms)
+        self.assertEqual(axioms, sorted(axioms))
+        header = fastobo.header.HeaderFrame([
+            fastobo.header.FormatVersionClause("1.4"),
+            fastobo.header.DefaultNamespaceClause(fastobo.id.UnprefixedIdent("tst")),
+        ])
+        self.assertRaises(
+            fastobo.exceptions.MissingOntologyClauseError,
+            frame.as_owl_functional,
+            header,
+        )
+
+
+    def test_user_defined_clause(self):
+
+        class LabelClause(fastobo.term.BaseTermClause):
+            def __init__(self, label):
+                self.label = label
+            def raw_tag(self):
+                return "name"
+            def raw_value(self):
+                return self.label
+
+        frame = self.Frame(self.id, [LabelClause("thing")])
+        self.assertIsInstance(frame[0], fastobo.term.NameClause)
+        self.assertEqual(frame[0].name, "thing")
+        frame.append(LabelClause("other"))
+        self.assertEqual(frame[1], fastobo.term.NameClause("other"))
+
+        class EmptyClause(fastobo.term.BaseTermClause):
+            pass
+
+        self.assertRaises(TypeError, self.Frame, self.id, [EmptyClause()])
+        self.assertRaises(TypeError, frame.append, EmptyClause())
+
+
+# --- RelationshipClause -----------------------------------------------------
+
+class TestRelationshipClause(unittest.TestCase):
+    type = fastobo.term.RelationshipClause
+
+    def setUp(self):
+        self.part_of = fastobo.id.UnprefixedIdent("part_of")
+        self.target = fastobo.id.PrefixedIdent("UBERON", "0000062")
+        self.filler = fastobo.id.PrefixedIdent("NCBITaxon", "7955")
+
+    def test_gci(self):
+        clause = self.type(self.part_of, self.target)
+        self.assertIs(clause.gci_relation, None)
+        self.assertIs(clause.gci_filler, None)
+        clause.gci_relation = self.part_of
+        clause.gci_filler = self.filler
+        self.assertEqual(clause.gci_relation, self.part_of)
+        self.assertEqual(clause.gci_filler, self.filler)
+        self.assertEqual(len(clause.qualifiers), 2)
+        clause.gci_filler = None
+        self.assertIs(clause.gci_filler, None)
+        self.assertEqual(len(clause.qualifiers), 1)
+
+    def test_gci_parse(self):
+        frame = fastobo.loads(
+            "[Term]\n"
+            "id: UBERON:0002107\n"
+            'relationship: part_of UBERON:0000062 {gci_relation="part_of", gci_filler="NCBITaxon:7955"}\n'
+        )[0]
+        self.assertEqual(frame[0].gci_relation, self.part_of)
+        self.assertEqual(frame[0].gci_filler, self.filler)
+
 
 # --- DefClause --------------------------------------------------------------
 
@@ -43,3 +180,24 @@ class TestIsObsoleteClause(_TestIsObsoleteClause, unittest.TestCase):
 
 class TestCreationDateClause(_TestCreationDateClause, unittest.TestCase):
     type = fastobo.term.CreationDateClause
+
+
+# --- parse_clause -----------------------------------------------------------
+
+class TestParseClause(unittest.TestCase):
+
+    def test_parse(self):
+        clause = fastobo.term.parse_clause("is_a: GO:0000001 ! parent")
+        self.assertIsInstance(clause, fastobo.term.IsAClause)
+        self.assertEqual(clause.term, fastobo.id.PrefixedIdent("GO", "0000001"))
+
+    def test_parse_qualifiers(self):
+        clause = fastobo.term.parse_clause('is_a: GO:0000001 {source="GOC:x"}')
+        self.assertEqual(str(clause.qualifiers), '{source="GOC:x"}')
+
+    def test_syntax_error(self):
+        self.assertRaises(SyntaxError, fastobo.term.parse_clause, "is_a GO:0000001")
+
+    def test_value_error(self):
+        self.assertRaises(ValueError, fastobo.term.parse_clause, "")
+        self.assertRaises(ValueError, fastobo.term.parse_clause, "name: a\nname: b")
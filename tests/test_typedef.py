@@ -20,6 +20,32 @@ class TestTypedefFrame(_TestFrame, unittest.TestCase):
     NameClause = fastobo.typedef.NameClause
     CreatedByClause = fastobo.typedef.CreatedByClause
 
+    def test_boolean_properties(self):
+        frame = self.Frame(self.id, [
+            fastobo.typedef.IsTransitiveClause(True),
+            fastobo.typedef.IsSymmetricClause(False),
+        ])
+        self.assertTrue(frame.is_transitive)
+        self.assertFalse(frame.is_symmetric)
+        self.assertFalse(frame.is_functional)
+        frame.is_transitive = False
+        self.assertFalse(frame.is_transitive)
+        self.assertEqual(str(frame[0]), "is_transitive: false")
+        frame.is_functional = False
+        self.assertEqual(len(frame), 2)
+        frame.is_metadata_tag = True
+        self.assertEqual(len(frame), 3)
+        self.assertEqual(frame[2], fastobo.typedef.IsMetadataTagClause(True))
+        self.assertTrue(frame.is_metadata_tag)
+        frame.freeze()
+        with self.assertRaises(TypeError):
+            frame.builtin = True
+
+    def test_as_owl_functional(self):
+        frame = self.Frame(self.id, [fastobo.typedef.IsTransitiveClause(True)])
+        axioms = frame.as_owl_functional()
+        self.assertTrue(any(ax.startswith("TransitiveObjectProperty(") for ax in axioms))
+
 
 # --- DefClause --------------------------------------------------------------
 
@@ -43,3 +69,15 @@ class TestIsObsoleteClause(_TestIsObsoleteClause, unittest.TestCase):
 
 class TestCreationDateClause(_TestCreationDateClause, unittest.TestCase):
     type = fastobo.typedef.CreationDateClause
+
+
+# --- parse_clause -----------------------------------------------------------
+
+class TestParseClause(unittest.TestCase):
+
+    def test_parse(self):
+        clause = fastobo.typedef.parse_clause("is_transitive: true")
+        self.assertIsInstance(clause, fastobo.typedef.IsTransitiveClause)
+
+    def test_syntax_error(self):
+        self.assertRaises(SyntaxError, fastobo.typedef.parse_clause, "is_transitive: maybe")
@@ -0,0 +1,73 @@
+# coding: utf-8
+
+import unittest
+
+import fastobo
+
+
+class TestVisitor(unittest.TestCase):
+
+    type = fastobo.visit.Visitor
+
+    def setUp(self):
+        self.doc = fastobo.loads(
+            "[Term]\nid: TST:001\nname: first\nis_a: TST:002\n\n"
+            "[Term]\nid: TST:002\nname: second\n\n"
+            "[Typedef]\nid: part_of\nname: part of\n"
+        )
+
+    def test_visit_default(self):
+        expected = str(self.doc)
+        self.assertIsNone(self.type().visit(self.doc))
+        self.assertEqual(str(self.doc), expected)
+
+    def test_visit_ident(self):
+        class Renamer(self.type):
+            def visit_ident(self, ident):
+                if ident == fastobo.id.PrefixedIdent("TST", "002"):
+                    return fastobo.id.PrefixedIdent("TST", "003")
+        Renamer().visit(self.doc)
+        self.assertEqual(str(self.doc[0][1]), "is_a: TST:003")
+        self.assertEqual(self.doc[1].id, fastobo.id.PrefixedIdent("TST", "003"))
+
+    def test_visit_ident_objects(self):
+        class Renamer(self.type):
+            def visit_ident(self, ident):
+                if ident == fastobo.id.PrefixedIdent("TST", "002"):
+                    return fastobo.id.PrefixedIdent("TST", "003")
+        frame, clause = self.doc[0], self.doc[0][1]
+        clause.comments = ["to be reviewed"]
+        Renamer().visit(self.doc)
+        self.assertIs(self.doc[0], frame)
+        self.assertIs(self.doc[0][1], clause)
+        self.assertEqual(str(clause), "is_a: TST:003")
+        self.assertEqual(clause.comments, ["to be reviewed"])
+
+    def test_visit_clause(self):
+        class Upper(self.type):
+            def visit_term_clause(self, clause):
+                if isinstance(clause, fastobo.term.NameClause):
+                    return fastobo.term.NameClause(clause.name.upper())
+        Upper().visit(self.doc)
+        self.assertEqual(self.doc[0][0].name, "FIRST")
+        self.assertEqual(self.doc[1][0].name, "SECOND")
+        self.assertEqual(self.doc[2][0].name, "part of")
+
+    def test_visit_frame_inplace(self):
+        class Obsoleter(self.type):
+            def visit_typedef_frame(self, frame):
+                frame.append(fastobo.typedef.IsObsoleteClause(True))
+        Obsoleter().visit(self.doc)
+        self.assertEqual(str(self.doc[2][-1]), "is_obsolete: true")
+        self.assertEqual(len(self.doc[2]), 2)
+        self.assertEqual(len(self.doc[0]), 2)
+
+    def test_visit_error(self):
+        class Failing(self.type):
+            def visit_term_frame(self, frame):
+                raise RuntimeError("failed")
+        self.assertRaises(RuntimeError, Failing().visit, self.doc)
+
+    def test_visit_frozen(self):
+        self.doc.freeze()
+        self.assertRaises(TypeError, self.type().visit, self.doc)
@@ -1,6 +1,8 @@
 # coding: utf-8
 
+import copy
 import datetime
+import sys
 import unittest
 
 import fastobo
@@ -21,6 +23,12 @@ class TestXref(unittest.TestCase):
         except Exception:
             self.fail("could not create `Xref` instance with description")
 
+    def test_init_str(self):
+        xref = self.type("PMID:123", "a publication")
+        self.assertEqual(xref.id, fastobo.id.PrefixedIdent("PMID", "123"))
+        self.assertEqual(xref.desc, "a publication")
+        self.assertRaises(ValueError, self.type, "PMID:123 456")
+
     def test_init_type_error(self):
         id = fastobo.id.PrefixedIdent('ISBN', '0321842685')
         desc = "Hacker's Delight (2nd Edition)"
@@ -45,8 +53,17 @@ class TestXref(unittest.TestCase):
         x2 = self.type(i1)
         self.assertIsNot(x1, x2)
         self.assertEqual(x1, x2)
+        self.assertFalse(x1 != x2)
         x3 = self.type(i2)
-        self.assertNotEqual(x1, x2)
+        self.assertNotEqual(x1, x3)
+
+    def test_hash(self):
+        id = fastobo.id.PrefixedIdent('ISBN', '0321842685')
+        desc = "Hacker's Delight (2nd Edition)"
+        x1 = self.type(id, desc)
+        x2 = self.type(fastobo.id.PrefixedIdent('ISBN', '0321842685'), desc)
+        self.assertEqual(hash(x1), hash(x2))
+        self.assertEqual(len({x1, x2, self.type(id)}), 2)
 
 
 class TestXrefList(unittest.TestCase):
@@ -73,6 +90,26 @@ class TestXrefList(unittest.TestCase):
         except Exception:
             self.fail("could not create `XrefList` instance from iterator")
 
+    def test_sizeof(self):
+        xrefs = self.type([self.x1, self.x2])
+        self.assertGreater(sys.getsizeof(xrefs), sys.getsizeof(self.type()))
+
+    def test_init_str(self):
+        xrefs = self.type(["PMID:123", self.x2])
+        self.assertEqual(str(xrefs), "[PMID:123, fastobo]")
+        self.assertEqual(xrefs[0].id, fastobo.id.PrefixedIdent("PMID", "123"))
+        self.assertRaises(ValueError, self.type, ["PMID:123 456"])
+        self.assertRaises(TypeError, self.type, [1])
+
+    def test_copy(self):
+        xrefs = self.type([self.x1, self.x2])
+        copy_ = copy.copy(xrefs)
+        self.assertEqual(str(copy_), str(xrefs))
+        self.assertIs(copy_[0], xrefs[0])
+        deepcopy = copy.deepcopy(xrefs)
+        self.assertEqual(str(deepcopy), str(xrefs))
+        self.assertIsNot(deepcopy[0], xrefs[0])
+
     def test_init_type_error(self):
         # Errors on an iterator of type != Xref
         self.assertRaises(TypeError, self.type, "abc")
@@ -113,3 +150,42 @@ class TestXrefList(unittest.TestCase):
         self.assertEqual( repr(self.type()), "XrefList()" )
         self.assertEqual( repr(self.type([x1])), "XrefList([{!r}])".format(x1) )
         self.assertEqual( repr(self.type([x1, x2])), "XrefList([{!r}, {!r}])".format(x1, x2) )
+
+    def test_getitem_slice(self):
+        x1, x2 = self.x1, self.x2
+        l = self.type([x1, x2, x1])
+        self.assertIsInstance(l[1:], self.type)
+        self.assertEqual(list(l[1:]), [x2, x1])
+        self.assertEqual(list(l[::-1]), [x1, x2, x1])
+        self.assertEqual(list(l[::2]), [x1, x1])
+        self.assertEqual(list(l[5:]), [])
+
+    def test_setitem_slice(self):
+        x1, x2 = self.x1, self.x2
+        l = self.type([x1, x2, x1])
+        l[1:] = [x1]
+        self.assertEqual(list(l), [x1, x1])
+        l[-1] = x2
+        self.assertEqual(list(l), [x1, x2])
+        self.assertRaises(TypeError, l.__setitem__, slice(None), ["abc"])
+
+    def test_delitem_slice(self):
+        x1, x2 = self.x1, self.x2
+        l = self.type([x1, x2, x1])
+        del l[1:]
+        self.assertEqual(list(l), [x1])
+
+    def test_iadd(self):
+        x1, x2 = self.x1, self.x2
+        l = self.type([x1])
+        l += self.type([x2])
+        self.assertEqual(list(l), [x1, x2])
+        l2 = l + [x1]
+        self.assertIsInstance(l2, self.type)
+        self.assertEqual(list(l2), [x1, x2, x1])
+        self.assertEqual(list(l), [x1, x2])
+
+    def test_reversed(self):
+        x1, x2 = self.x1, self.x2
+        l = self.type([x1, x2])
+        self.assertEqual(list(reversed(l)), [x2, x1])